@@ -20,41 +20,261 @@ pub fn is_reflect_ignore_field(field: &syn::Field) -> bool {
 pub enum InspectorAttribute {
     Assignment(syn::Member, syn::Expr),
     Tag(syn::Member),
+    /// `name(a = 1, b)`, sets fields of the `Option<_>` options field `name`
+    Nested(syn::Member, Vec<InspectorAttribute>),
 }
 
 impl InspectorAttribute {
-    pub fn lhs(&self) -> &syn::Member {
+    /// The value of `name = value`, where `value` can also be a string like `"path::to_fn"`
+    fn expr_assignment(&self, name: &str) -> syn::Result<Option<syn::Expr>> {
+        let InspectorAttribute::Assignment(syn::Member::Named(ident), expr) = self else {
+            return Ok(None);
+        };
+        if ident != name {
+            return Ok(None);
+        }
+        match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(path),
+                ..
+            }) => path.parse().map(Some),
+            expr => Ok(Some(expr.clone())),
+        }
+    }
+
+    /// The string of `name = "value"`
+    fn string_assignment(&self, name: &str) -> Option<&syn::LitStr> {
+        match self {
+            InspectorAttribute::Assignment(
+                syn::Member::Named(ident),
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }),
+            ) if ident == name => Some(value),
+            _ => None,
+        }
+    }
+
+    /// `validate = path::to_fn`, which isn't an option of the field type
+    pub fn validator(&self) -> syn::Result<Option<syn::Expr>> {
+        self.expr_assignment("validate")
+    }
+
+    /// `with = path::to_fn`, a custom widget replacing the default UI of the field
+    pub fn widget(&self) -> syn::Result<Option<syn::Expr>> {
+        self.expr_assignment("with")
+    }
+
+    /// `label = "Name"`, `collapse`, `default_open` or `tooltip = ".."`, which change how the field is displayed in its struct
+    pub fn display(&self) -> Option<TokenStream> {
         match self {
-            InspectorAttribute::Assignment(member, _) => member,
-            InspectorAttribute::Tag(member) => member,
+            InspectorAttribute::Assignment(syn::Member::Named(ident), expr)
+                if ident == "label" || ident == "tooltip" =>
+            {
+                Some(
+                    quote! { display.#ident = ::std::option::Option::Some(::std::convert::Into::into(#expr)); },
+                )
+            }
+            InspectorAttribute::Tag(syn::Member::Named(ident))
+                if ident == "collapse" || ident == "default_open" =>
+            {
+                Some(quote! { display.#ident = true; })
+            }
+            _ => None,
         }
     }
 
-    pub fn rhs(&self) -> TokenStream {
+    /// `label = "Name"` or `skip` on an enum variant, which change how it is displayed in the variant selection
+    pub fn variant_display(&self) -> Option<TokenStream> {
         match self {
-            InspectorAttribute::Assignment(_, expr) => quote! { #expr },
-            InspectorAttribute::Tag(_) => quote! { true },
+            InspectorAttribute::Assignment(syn::Member::Named(ident), expr) if ident == "label" => {
+                Some(
+                    quote! { display.label = ::std::option::Option::Some(::std::convert::Into::into(#expr)); },
+                )
+            }
+            InspectorAttribute::Tag(syn::Member::Named(ident)) if ident == "skip" => {
+                Some(quote! { display.skip = true; })
+            }
+            _ => None,
+        }
+    }
+
+    /// `curve(..)` or `gradient(..)`, which set the `CurveOptions` of a `Vec<Vec2>` or the `GradientOptions`
+    /// of a `Vec<(f32, Color)>` displayed as a curve or gradient editor, instead of the options of their elements.
+    ///
+    /// Returns the options type and the statements applying the nested attributes to `receiver`.
+    pub fn editor_options(
+        &self,
+        receiver: &TokenStream,
+    ) -> Option<(TokenStream, Vec<TokenStream>)> {
+        let (ident, nested) = match self {
+            InspectorAttribute::Tag(syn::Member::Named(ident)) => (ident, &[][..]),
+            InspectorAttribute::Nested(syn::Member::Named(ident), nested) => (ident, &nested[..]),
+            _ => return None,
+        };
+        let options = match ident.to_string().as_str() {
+            "curve" => {
+                quote! { ::bevy_inspector_egui::inspector_options::std_options::CurveOptions }
+            }
+            "gradient" => {
+                quote! { ::bevy_inspector_egui::inspector_options::std_options::GradientOptions }
+            }
+            _ => return None,
+        };
+        let statements = nested
+            .iter()
+            .map(|attribute| attribute.apply_to(receiver))
+            .collect();
+        Some((options, statements))
+    }
+
+    /// `computed = Self::function` on a struct, with the name of the function as label of the row
+    pub fn computed(&self) -> syn::Result<Option<(syn::Expr, String)>> {
+        let Some(expr) = self.expr_assignment("computed")? else {
+            return Ok(None);
+        };
+        let syn::Expr::Path(path) = &expr else {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "expected a path to a function like `Self::function`",
+            ));
+        };
+        let name = path.path.segments.last().unwrap().ident.to_string();
+        Ok(Some((expr, name)))
+    }
+
+    /// Statements applying this attribute to the options value `receiver`
+    pub fn apply_to(&self, receiver: &TokenStream) -> TokenStream {
+        match self {
+            InspectorAttribute::Assignment(member, expr) => quote! {
+                #receiver.#member = ::std::convert::Into::into(#expr);
+            },
+            InspectorAttribute::Tag(member) => quote! {
+                #receiver.#member = true;
+            },
+            InspectorAttribute::Nested(member, attributes) => {
+                let nested = quote! { nested };
+                let attributes = attributes
+                    .iter()
+                    .map(|attribute| attribute.apply_to(&nested));
+                quote! {
+                    {
+                        let #nested = #receiver.#member.get_or_insert_with(::std::default::Default::default);
+                        #(#attributes)*
+                    }
+                }
+            }
         }
     }
 }
 
+fn parse_inspectable_attribute(input: syn::parse::ParseStream) -> syn::Result<InspectorAttribute> {
+    let ident: syn::Member = input.parse()?;
+    if input.peek(syn::Token![=]) {
+        let _eq_token: syn::Token![=] = input.parse()?;
+        let expr: syn::Expr = input.parse()?;
+        Ok(InspectorAttribute::Assignment(ident, expr))
+    } else if input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in input);
+        let nested = content.parse_terminated(parse_inspectable_attribute, syn::Token![,])?;
+        Ok(InspectorAttribute::Nested(
+            ident,
+            nested.into_iter().collect(),
+        ))
+    } else {
+        Ok(InspectorAttribute::Tag(ident))
+    }
+}
+
 fn parse_inspectable_attributes(
     input: syn::parse::ParseStream,
 ) -> syn::Result<impl Iterator<Item = InspectorAttribute>> {
-    let parse_attribute = |input: syn::parse::ParseStream| {
-        let ident: syn::Member = input.parse()?;
-        if input.peek(syn::Token![=]) {
-            let _eq_token: syn::Token![=] = input.parse()?;
-            let expr: syn::Expr = input.parse()?;
-            Ok(InspectorAttribute::Assignment(ident, expr))
+    input
+        .parse_terminated(parse_inspectable_attribute, syn::Token![,])
+        .map(IntoIterator::into_iter)
+}
+
+/// Replaces `Self` in `tokens` by `ty`, so that `Self::function` can be used outside of `impl Type`
+pub fn replace_self(tokens: TokenStream, ty: &TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => quote! { <#ty> },
+            proc_macro2::TokenTree::Group(group) => {
+                let mut replaced =
+                    proc_macro2::Group::new(group.delimiter(), replace_self(group.stream(), ty));
+                replaced.set_span(group.span());
+                quote! { #replaced }
+            }
+            token => quote! { #token },
+        })
+        .collect()
+}
+
+impl InspectorAttribute {
+    pub fn member(&self) -> &syn::Member {
+        match self {
+            InspectorAttribute::Assignment(member, _)
+            | InspectorAttribute::Tag(member)
+            | InspectorAttribute::Nested(member, _) => member,
+        }
+    }
+}
+
+/// `#[inspector(..)]` attributes on a struct or enum
+#[derive(Default)]
+pub struct ContainerAttributes {
+    /// `computed = Self::function`, with the name of the function
+    pub computed: Vec<(syn::Expr, String)>,
+    /// `docs`, to bake the doc comments of the fields into their tooltips
+    pub docs: bool,
+    /// `override_where_clause = "T: Trait"`, replacing the where clause of the generated impl
+    pub override_where_clause:
+        Option<syn::punctuated::Punctuated<syn::WherePredicate, syn::Token![,]>>,
+}
+
+pub fn extract_container_attributes(attrs: &[syn::Attribute]) -> syn::Result<ContainerAttributes> {
+    let mut container = ContainerAttributes::default();
+    for attribute in extract_inspector_attributes(attrs)? {
+        if let Some(computed) = attribute.computed()? {
+            container.computed.push(computed);
+        } else if matches!(&attribute, InspectorAttribute::Tag(syn::Member::Named(ident)) if ident == "docs")
+        {
+            container.docs = true;
+        } else if let Some(predicates) = attribute.string_assignment("override_where_clause") {
+            container.override_where_clause =
+                Some(predicates.parse_with(syn::punctuated::Punctuated::parse_terminated)?);
         } else {
-            Ok(InspectorAttribute::Tag(ident))
+            return Err(syn::Error::new_spanned(
+                attribute.member(),
+                "expected `computed = Self::function`, `docs` or `override_where_clause = \"..\"`",
+            ));
         }
-    };
+    }
+    Ok(container)
+}
 
-    input
-        .parse_terminated(parse_attribute, syn::Token![,])
-        .map(IntoIterator::into_iter)
+/// The doc comment of `attrs`, without the leading space of each line
+pub fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) => Some(line.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
 }
 
 pub fn extract_inspector_attributes(
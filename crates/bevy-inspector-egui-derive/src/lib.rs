@@ -20,39 +20,37 @@ pub fn inspectable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
     let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
+    let container = attributes::extract_container_attributes(&input.attrs)?;
 
+    let mut bounds = Vec::new();
     let fields = data
         .fields
         .iter()
         .filter(|field| !attributes::is_reflect_ignore_field(field))
         .enumerate()
         .filter_map(|(i, field)| {
-            let ty = &field.ty;
-            let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                Ok(attrs) => attrs,
-                Err(e) => return Some(Err(e)),
-            };
-            if attrs.is_empty() {
-                return None;
-            }
-            let attrs = attrs.into_iter().map(|attribute| {
-                let name = attribute.lhs();
-                let value = attribute.rhs();
-                quote! {
-                    field_options.#name = ::std::convert::Into::into(#value);
-                }
-            });
-
-            Some(Ok(quote! {
-                let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                #(#attrs)*
-                options.insert(::bevy_inspector_egui::inspector_options::Target::Field(#i), <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
-            }))
+            let target = quote! { ::bevy_inspector_egui::inspector_options::Target::Field(#i) };
+            expand_field(field, target, container.docs, &mut bounds).transpose()
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
     let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    let ty = quote! { #type_name #ty_generics };
+    if !container.computed.is_empty() {
+        bounds.push(quote! { #ty: #bevy_reflect::Reflect });
+    }
+    let where_clause = where_clause(&input.generics, &container, bounds, &ty);
+    let fields = fields
+        .into_iter()
+        .map(|field| attributes::replace_self(field, &ty));
+    let computed = container.computed.iter().map(|(expr, name)| {
+        let expr = attributes::replace_self(quote! { #expr }, &ty);
+        quote! {
+            options.push_computed(::bevy_inspector_egui::inspector_options::ComputedRow::new::<#ty, _>(#name, #expr));
+        }
+    });
 
     Ok(quote! {
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
@@ -62,6 +60,7 @@ fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStr
                 let mut options = ::bevy_inspector_egui::InspectorOptions::default();
 
                 #(#fields)*
+                #(#computed)*
 
                 options
             }
@@ -71,53 +70,67 @@ fn expand_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStr
 
 fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream> {
     let bevy_reflect = quote! { ::bevy_inspector_egui::__macro_exports::bevy_reflect };
+    let container = attributes::extract_container_attributes(&input.attrs)?;
+    if let Some((expr, _)) = container.computed.first() {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "`computed` is only supported on structs",
+        ));
+    }
 
+    let mut bounds = Vec::new();
     let fields = data
         .variants
         .iter()
         .enumerate()
         .map(|(variant_index, variant)| {
-            let attrs = variant
+            let display = attributes::extract_inspector_attributes(&variant.attrs)?
+                .iter()
+                .map(|attribute| {
+                    attribute.variant_display().ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            attribute.member(),
+                            "expected `label = \"..\"` or `skip`",
+                        )
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let display = (!display.is_empty()).then(|| {
+                quote! {
+                    let mut display = ::bevy_inspector_egui::inspector_options::VariantDisplay::default();
+                    #(#display)*
+                    options.insert_variant_display(#variant_index, display);
+                }
+            });
+
+            let mut attrs = variant
                 .fields
                 .iter()
                 .filter(|field| !attributes::is_reflect_ignore_field(field))
                 .enumerate()
                 .filter_map(|(field_index, field)| {
-                    let ty = &field.ty;
-                    let attrs = match attributes::extract_inspector_attributes(&field.attrs) {
-                        Ok(attrs) => attrs,
-                        Err(e) => return Some(Err(e)),
-                    };
-                    if attrs.is_empty() {
-                        return None;
-                    }
-                    let attrs = attrs.into_iter().map(|attribute| {
-                        let name = attribute.lhs();
-                        let value = attribute.rhs();
-                        quote! {
-                            field_options.#name = std::convert::Into::into(#value);
+                    let target = quote! {
+                        ::bevy_inspector_egui::inspector_options::Target::VariantField {
+                            variant_index: #variant_index,
+                            field_index: #field_index,
                         }
-                    });
-
-                    Some(Ok(quote! {
-                        let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
-                        #(#attrs)*
-                        options.insert(
-                            ::bevy_inspector_egui::inspector_options::Target::VariantField {
-                                variant_index: #variant_index,
-                                field_index: #field_index,
-                            },
-                            <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options)
-                        );
-                    }))
+                    };
+                    expand_field(field, target, container.docs, &mut bounds).transpose()
                 })
                 .collect::<syn::Result<Vec<_>>>()?;
+            attrs.extend(display);
             Ok(attrs)
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
     let type_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let ty = quote! { #type_name #ty_generics };
+    let where_clause = where_clause(&input.generics, &container, bounds, &ty);
+    let fields = fields
+        .into_iter()
+        .flatten()
+        .map(|field| attributes::replace_self(field, &ty));
 
     Ok(quote! {
         impl #impl_generics #bevy_reflect::FromType<#type_name #ty_generics> for ::bevy_inspector_egui::InspectorOptions
@@ -126,13 +139,139 @@ fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream>
             fn from_type() -> Self {
                 let mut options = ::bevy_inspector_egui::InspectorOptions::default();
 
-                #(#(#fields)*)*
+                #(#fields)*
 
                 options
             }
         }
     })
 }
+/// Statements inserting the options, validator, custom widget and display settings of `field` into `options`
+///
+/// The bounds on the field type required by the attributes are added to `bounds`.
+fn expand_field(
+    field: &syn::Field,
+    target: TokenStream,
+    docs: bool,
+    bounds: &mut Vec<TokenStream>,
+) -> syn::Result<Option<TokenStream>> {
+    let ty = &field.ty;
+    let mut attrs = attributes::extract_inspector_attributes(&field.attrs)?;
+
+    let mut validators = Vec::new();
+    let mut widgets = Vec::new();
+    let mut display = Vec::new();
+    let mut editor_options = None;
+    let field_options = quote! { field_options };
+    if let Some(docs) = docs
+        .then(|| attributes::doc_comment(&field.attrs))
+        .flatten()
+    {
+        display.push(quote! { display.tooltip = ::std::option::Option::Some(::std::convert::Into::into(#docs)); });
+    }
+    for attribute in std::mem::take(&mut attrs) {
+        if let Some(validator) = attribute.validator()? {
+            validators.push(validator);
+        } else if let Some(widget) = attribute.widget()? {
+            widgets.push(widget);
+        } else if let Some(statement) = attribute.display() {
+            display.push(statement);
+        } else if let Some(options) = attribute.editor_options(&field_options) {
+            if editor_options.replace(options).is_some() {
+                return Err(syn::Error::new_spanned(
+                    attribute.member(),
+                    "only one editor can be selected",
+                ));
+            }
+        } else {
+            attrs.push(attribute);
+        }
+    }
+    if let (Some(attribute), Some(_)) = (attrs.first(), &editor_options) {
+        return Err(syn::Error::new_spanned(
+            attribute.member(),
+            "options of the editor go inside of it, like `curve(sorted)`",
+        ));
+    }
+    if attrs.is_empty()
+        && editor_options.is_none()
+        && validators.is_empty()
+        && widgets.is_empty()
+        && display.is_empty()
+    {
+        return Ok(None);
+    }
+
+    if !attrs.is_empty() {
+        bounds.push(quote! { #ty: ::bevy_inspector_egui::inspector_options::InspectorOptionsType });
+    }
+    if !validators.is_empty() || !widgets.is_empty() {
+        bounds.push(quote! { #ty: ::bevy_inspector_egui::__macro_exports::bevy_reflect::Reflect });
+    }
+
+    let editor_options = editor_options.map(|(ty, statements)| {
+        quote! {
+            let mut field_options = <#ty as ::std::default::Default>::default();
+            #(#statements)*
+            options.insert(#target, field_options);
+        }
+    });
+    let options = (!attrs.is_empty()).then(|| {
+        let attrs = attrs
+            .iter()
+            .map(|attribute| attribute.apply_to(&field_options));
+        quote! {
+            let mut field_options = <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::DeriveOptions::default();
+            #(#attrs)*
+            options.insert(#target, <#ty as ::bevy_inspector_egui::inspector_options::InspectorOptionsType>::options_from_derive(field_options));
+        }
+    });
+
+    let display = (!display.is_empty()).then(|| {
+        quote! {
+            let mut display = ::bevy_inspector_egui::inspector_options::FieldDisplay::default();
+            #(#display)*
+            options.insert_display(#target, display);
+        }
+    });
+
+    Ok(Some(quote! {
+        #options
+        #editor_options
+        #display
+        #(
+            options.insert_validator(#target, ::bevy_inspector_egui::inspector_options::Validator::new::<#ty>(#validators));
+        )*
+        #(
+            options.insert_widget(#target, ::bevy_inspector_egui::inspector_options::CustomWidget::new::<#ty>(#widgets));
+        )*
+    }))
+}
+
+/// The where clause of the generated impl, which is either `override_where_clause`,
+/// or the where clause of the type with the `bounds` required by the attributes if it is generic.
+///
+/// `Self` is replaced by `ty`, because it refers to `InspectorOptions` in the generated impl.
+fn where_clause(
+    generics: &syn::Generics,
+    container: &attributes::ContainerAttributes,
+    bounds: Vec<TokenStream>,
+    ty: &TokenStream,
+) -> TokenStream {
+    if let Some(predicates) = &container.override_where_clause {
+        return attributes::replace_self(quote! { where #predicates }, ty);
+    }
+    if generics.params.is_empty() {
+        let where_clause = &generics.where_clause;
+        return quote! { #where_clause };
+    }
+    let predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter());
+    attributes::replace_self(quote! { where #(#predicates,)* #(#bounds,)* }, ty)
+}
+
 fn expand_union(_: &DeriveInput, data: &DataUnion) -> syn::Result<TokenStream> {
     Err(syn::Error::new_spanned(
         data.union_token,
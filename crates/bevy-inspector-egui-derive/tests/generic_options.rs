@@ -46,3 +46,60 @@ fn phantom_data() {
         .unwrap();
     assert_eq!(options.min, Some(0.0));
 }
+
+#[test]
+fn generic_with_attributes() {
+    fn positive(value: &mut f32) -> Result<(), String> {
+        if *value >= 0.0 {
+            Ok(())
+        } else {
+            Err("negative".to_owned())
+        }
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(computed = Self::doubled)]
+    struct Generic<T> {
+        #[inspector(validate = Self::check)]
+        t: T,
+        #[inspector(min = 0.0, validate = positive)]
+        other: f32,
+    }
+
+    impl<T: Reflect> Generic<T> {
+        fn check(_: &mut T) -> Result<(), String> {
+            Ok(())
+        }
+        fn doubled(&self) -> f32 {
+            self.other * 2.0
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Generic<u8>>>::from_type();
+
+    assert!(options.validator(Target::Field(0)).is_some());
+    assert!(options.validator(Target::Field(1)).is_some());
+    assert_eq!(options.computed().len(), 1);
+}
+
+#[test]
+fn override_where_clause() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(override_where_clause = "T: Reflect + Default")]
+    enum Generic<T> {
+        A(#[inspector(min = 0.0)] f32),
+        B(T),
+    }
+
+    let options = <InspectorOptions as FromType<Generic<u8>>>::from_type();
+
+    let options = options
+        .get(Target::VariantField {
+            variant_index: 0,
+            field_index: 0,
+        })
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(options.min, Some(0.0));
+}
@@ -1,13 +1,17 @@
 use bevy_ecs::entity::Entity;
 use bevy_inspector_egui::{
     inspector_options::{
-        std_options::{EntityDisplay, EntityOptions, NumberOptions, QuatDisplay, QuatOptions},
+        std_options::{
+            EntityDisplay, EntityOptions, FilePickerOptions, NumberOptions, OptionOptions,
+            QuatDisplay, QuatOptions, StringOptions,
+        },
         Target,
     },
     InspectorOptions,
 };
 use bevy_math::Quat;
 use bevy_reflect::{FromType, Reflect};
+use std::path::PathBuf;
 
 #[test]
 fn expr_attribute() {
@@ -54,3 +58,278 @@ fn expr_attribute() {
         .unwrap();
     assert!(matches!(entity_options.display, EntityDisplay::Id));
 }
+
+#[test]
+fn string_options() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(multiline, max_len = 16)]
+        notes: String,
+        #[inspector(password)]
+        secret: String,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let notes_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<StringOptions>()
+        .unwrap();
+    assert!(notes_options.multiline);
+    assert!(!notes_options.password);
+    assert_eq!(notes_options.max_len, Some(16));
+
+    let secret_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<StringOptions>()
+        .unwrap();
+    assert!(secret_options.password);
+    assert_eq!(secret_options.max_len, None);
+}
+
+#[test]
+fn nested_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(file_picker(extensions = "png, .jpg", relative_to_assets))]
+        texture: String,
+        #[inspector(extensions = "ron")]
+        path: PathBuf,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let texture_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<StringOptions>()
+        .unwrap();
+    let file_picker = texture_options.file_picker.as_ref().unwrap();
+    assert!(file_picker.relative_to_assets);
+    assert_eq!(file_picker.extensions().collect::<Vec<_>>(), ["png", "jpg"]);
+
+    let path_options = options
+        .get(Target::Field(1))
+        .unwrap()
+        .downcast_ref::<FilePickerOptions>()
+        .unwrap();
+    assert!(!path_options.relative_to_assets);
+    assert_eq!(path_options.extensions().collect::<Vec<_>>(), ["ron"]);
+}
+
+#[test]
+fn validate_attribute() {
+    fn even(value: &mut u32) -> Result<(), String> {
+        match *value % 2 {
+            0 => Ok(()),
+            _ => Err("odd".to_owned()),
+        }
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min = 2, validate = even)]
+        lanes: u32,
+        #[inspector(validate = "even")]
+        other: u32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let lanes_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<u32>>()
+        .unwrap();
+    assert_eq!(lanes_options.min, Some(2));
+    assert!(options.get(Target::Field(1)).is_none());
+
+    let validator = options.validator(Target::Field(0)).unwrap();
+    assert_eq!(validator.validate(&mut 4u32), Ok(()));
+    assert_eq!(validator.validate(&mut 3u32), Err("odd".to_owned()));
+    assert!(options.validator(Target::Field(1)).is_some());
+}
+
+#[test]
+fn computed_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(computed = Self::sum, computed = "Self::product")]
+    struct Test {
+        a: f32,
+        b: f32,
+    }
+
+    impl Test {
+        fn sum(&self) -> f32 {
+            self.a + self.b
+        }
+        fn product(&self) -> f32 {
+            self.a * self.b
+        }
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let rows = options.computed();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].name(), "sum");
+    assert_eq!(rows[1].name(), "product");
+
+    let value = Test { a: 2.0, b: 3.0 };
+    let sum = rows[0].compute(&value).unwrap();
+    assert_eq!(sum.try_downcast_ref::<f32>(), Some(&5.0));
+    assert!(rows[0].compute(&1.0f32).is_none());
+}
+
+#[test]
+fn with_attribute() {
+    fn widget(
+        value: &mut f32,
+        _: &mut bevy_inspector_egui::egui::Ui,
+        _: &dyn std::any::Any,
+        _: bevy_inspector_egui::egui::Id,
+        _: bevy_inspector_egui::reflect_inspector::InspectorUi,
+    ) -> bool {
+        *value += 1.0;
+        true
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(with = widget, min = 0.0)]
+        a: f32,
+        b: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    assert!(options.widget(Target::Field(0)).is_some());
+    assert!(options.widget(Target::Field(1)).is_none());
+    let number_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(number_options.min, Some(0.0));
+}
+
+#[test]
+fn display_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(label = "Fancy Name", min = 0.0)]
+        a: f32,
+        #[inspector(collapse)]
+        b: Vec<f32>,
+        #[inspector(default_open)]
+        c: Vec<f32>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let a = options.display(Target::Field(0)).unwrap();
+    assert_eq!(a.label.as_deref(), Some("Fancy Name"));
+    assert!(!a.is_collapsible());
+    assert!(options.get(Target::Field(0)).is_some());
+
+    let b = options.display(Target::Field(1)).unwrap();
+    assert!(b.collapse && !b.default_open);
+    assert!(options.get(Target::Field(1)).is_none());
+
+    let c = options.display(Target::Field(2)).unwrap();
+    assert!(c.is_collapsible() && c.default_open);
+}
+
+#[test]
+fn docs_attribute() {
+    #[derive(Reflect, InspectorOptions)]
+    #[inspector(docs)]
+    struct Test {
+        /// The first field.
+        ///
+        /// With more details.
+        a: f32,
+        #[inspector(tooltip = "Explicit")]
+        b: f32,
+        c: f32,
+    }
+
+    #[derive(Reflect, InspectorOptions)]
+    struct Undocumented {
+        /// Not baked in
+        a: f32,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+    let a = options.display(Target::Field(0)).unwrap();
+    assert_eq!(
+        a.tooltip.as_deref(),
+        Some("The first field.\n\nWith more details.")
+    );
+    let b = options.display(Target::Field(1)).unwrap();
+    assert_eq!(b.tooltip.as_deref(), Some("Explicit"));
+    assert!(options.display(Target::Field(2)).is_none());
+
+    let options = <InspectorOptions as FromType<Undocumented>>::from_type();
+    assert!(options.display(Target::Field(0)).is_none());
+}
+
+#[test]
+fn variant_attributes() {
+    #[derive(Reflect, InspectorOptions)]
+    enum Test {
+        A,
+        #[inspector(label = "Fancy B")]
+        B(#[inspector(min = 0.0)] f32),
+        #[inspector(skip)]
+        C,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    assert!(options.variant_display(0).is_none());
+    let b = options.variant_display(1).unwrap();
+    assert_eq!(b.label.as_deref(), Some("Fancy B"));
+    assert!(!b.skip);
+    assert!(options
+        .get(Target::VariantField {
+            variant_index: 1,
+            field_index: 0
+        })
+        .is_some());
+    assert!(options.variant_display(2).unwrap().skip);
+}
+
+#[test]
+fn option_options() {
+    #[derive(Reflect, InspectorOptions)]
+    struct Test {
+        #[inspector(min = 1.0, replace_with_default)]
+        a: Option<f32>,
+    }
+
+    let options = <InspectorOptions as FromType<Test>>::from_type();
+
+    let option_options = options
+        .get(Target::Field(0))
+        .unwrap()
+        .downcast_ref::<InspectorOptions>()
+        .unwrap();
+    let inner = option_options
+        .get(Target::VariantField {
+            variant_index: 1,
+            field_index: 0,
+        })
+        .unwrap()
+        .downcast_ref::<NumberOptions<f32>>()
+        .unwrap();
+    assert_eq!(inner.min, Some(1.0));
+    let own = option_options
+        .get(Target::Container)
+        .unwrap()
+        .downcast_ref::<OptionOptions>()
+        .unwrap();
+    assert!(own.replace_with_default);
+}
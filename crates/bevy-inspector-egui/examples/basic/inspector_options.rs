@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_egui::EguiContext;
-use bevy_inspector_egui::inspector_options::std_options::NumberDisplay;
+use bevy_inspector_egui::inspector_egui_impls::ColorGradient;
+use bevy_inspector_egui::inspector_options::std_options::{NumberDisplay, NumberOptions};
 use bevy_inspector_egui::{prelude::*, DefaultInspectorConfigPlugin};
 use bevy_utils::HashMap;
 use bevy_window::PrimaryWindow;
@@ -16,8 +17,25 @@ struct Config {
     #[inspector(min = 10, max = 20)] // same for Vec<T>
     vec: Vec<u32>,
     hash_map: HashMap<u32, String>,
+    // `Percent` has options registered for the type, see `main`
+    opacity: Percent,
+    #[inspector(multiline, max_len = 200)]
+    notes: String,
+    #[inspector(password)]
+    secret: String,
+    // `Vec<Vec2>` with `CurveOptions` is displayed as a curve editor
+    #[inspector(curve(min = Vec2::ZERO, max = Vec2::ONE, sorted))]
+    easing: Vec<Vec2>,
+    // `ColorGradient`, or `Vec<(f32, Color)>` with `gradient(..)`, is displayed as a gradient editor
+    sky: ColorGradient,
+    #[inspector(gradient(height = 16.0))]
+    palette: Vec<(f32, Color)>,
 }
 
+// Newtypes can get default options once instead of on every field using them
+#[derive(Reflect)]
+struct Percent(f32);
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -25,6 +43,19 @@ impl Default for Config {
             option: None,
             vec: Vec::default(),
             hash_map: HashMap::from([(0, "foo".to_owned()), (1, "bar".to_owned())]),
+            opacity: Percent(1.0),
+            notes: String::new(),
+            secret: "hunter2".to_owned(),
+            easing: vec![Vec2::ZERO, Vec2::new(0.4, 0.1), Vec2::ONE],
+            sky: ColorGradient::new([
+                (0.0, Color::srgb(0.1, 0.2, 0.5)),
+                (0.7, Color::srgb(0.5, 0.7, 1.0)),
+                (1.0, Color::srgb(1.0, 0.8, 0.6)),
+            ]),
+            palette: vec![
+                (0.0, Color::srgb(1.0, 0.0, 0.0)),
+                (1.0, Color::srgb(0.0, 0.0, 1.0)),
+            ],
         }
     }
 }
@@ -73,6 +104,9 @@ fn main() {
         .register_type::<Config>()
         .register_type::<Shape>()
         .register_type::<UiData>()
+        .register_inspector_options::<Percent>(InspectorOptions::newtype(
+            NumberOptions::<f32>::normalized().with_display(NumberDisplay::Slider),
+        ))
         .add_systems(Startup, setup)
         .add_systems(Update, ui_example)
         .run();
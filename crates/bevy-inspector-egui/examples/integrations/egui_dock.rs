@@ -3,7 +3,7 @@ use bevy_asset::{ReflectAsset, UntypedAssetId};
 use bevy_egui::{EguiContext, EguiContextSettings, EguiPostUpdateSet};
 use bevy_inspector_egui::bevy_inspector::hierarchy::{hierarchy_ui, SelectedEntities};
 use bevy_inspector_egui::bevy_inspector::{
-    self, ui_for_entities_shared_components, ui_for_entity_with_children,
+    self, batch, ui_for_entities_shared_components, ui_for_entity_with_children,
 };
 use bevy_inspector_egui::DefaultInspectorConfigPlugin;
 use std::any::TypeId;
@@ -245,8 +245,18 @@ impl egui_dock::TabViewer for TabViewer<'_> {
             EguiWindow::Assets => select_asset(ui, &type_registry, self.world, self.selection),
             EguiWindow::Inspector => match *self.selection {
                 InspectorSelection::Entities => match self.selected_entities.as_slice() {
-                    &[entity] => ui_for_entity_with_children(self.world, entity, ui),
-                    entities => ui_for_entities_shared_components(self.world, entities, ui),
+                    &[entity] => {
+                        ui_for_entity_with_children(self.world, entity, ui);
+                    }
+                    [] => {}
+                    _ => {
+                        batch::ui_for_batch_operations(self.world, ui, self.selected_entities);
+                        ui_for_entities_shared_components(
+                            self.world,
+                            self.selected_entities.as_slice(),
+                            ui,
+                        )
+                    }
                 },
                 InspectorSelection::Resource(type_id, ref name) => {
                     ui.label(name);
@@ -256,7 +266,7 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                         ui,
                         name,
                         &type_registry,
-                    )
+                    );
                 }
                 InspectorSelection::Asset(type_id, ref name, handle) => {
                     ui.label(name);
@@ -343,7 +353,7 @@ fn select_resource(
             )
         })
         .collect();
-    resources.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+    resources.sort_by_key(|(name, _)| *name);
 
     for (resource_name, type_id) in resources {
         let selected = match *selection {
@@ -374,7 +384,7 @@ fn select_asset(
             ))
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    assets.sort_by_key(|(name, ..)| *name);
 
     for (asset_name, asset_type_id, reflect_asset) in assets {
         let handles: Vec<_> = reflect_asset.ids(world).collect();
@@ -0,0 +1,211 @@
+//! Tools to align, distribute and snap the [`Transform`]s of multiple entities.
+//!
+//! [`ui_for_entities_shared_components`](super::ui_for_entities_shared_components) displays
+//! [`ui_for_arrange`] when all entities have a `Transform`.
+//! The operations only change the local translation along the chosen [`Axis`] (or all axes when snapping).
+
+use bevy_ecs::prelude::*;
+use bevy_math::Vec3;
+use bevy_transform::components::Transform;
+
+/// The axis along which translations are arranged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    #[default]
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+
+/// Where translations are moved to by [`Arrange::Align`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Min,
+    Center,
+    Max,
+}
+
+/// An operation on the translations of multiple entities
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arrange {
+    /// Move all translations to the minimum, center or maximum of the translations along the axis
+    Align(Axis, Alignment),
+    /// Space the translations evenly between the outermost ones along the axis, keeping their order
+    Distribute(Axis),
+    /// Round every component of the translations to a multiple of the grid size
+    SnapToGrid(f32),
+}
+
+impl Arrange {
+    /// Applies the operation to the `translations`
+    pub fn apply(self, translations: &mut [Vec3]) {
+        match self {
+            Arrange::Align(axis, alignment) => {
+                let i = axis.index();
+                let (min, max) = translations
+                    .iter()
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), t| {
+                        (min.min(t[i]), max.max(t[i]))
+                    });
+                let target = match alignment {
+                    Alignment::Min => min,
+                    Alignment::Center => (min + max) / 2.0,
+                    Alignment::Max => max,
+                };
+                for translation in translations {
+                    translation[i] = target;
+                }
+            }
+            Arrange::Distribute(axis) => {
+                let i = axis.index();
+                if translations.len() < 3 {
+                    return;
+                }
+                let mut order: Vec<usize> = (0..translations.len()).collect();
+                order.sort_by(|&a, &b| translations[a][i].total_cmp(&translations[b][i]));
+                let min = translations[order[0]][i];
+                let max = translations[order[order.len() - 1]][i];
+                let step = (max - min) / (order.len() - 1) as f32;
+                for (n, index) in order.into_iter().enumerate() {
+                    translations[index][i] = min + step * n as f32;
+                }
+            }
+            Arrange::SnapToGrid(size) => {
+                if size <= 0.0 {
+                    return;
+                }
+                for translation in translations {
+                    *translation = (*translation / size).round() * size;
+                }
+            }
+        }
+    }
+
+    /// Applies the operation to the translation of the [`Transform`]s of `entities`
+    pub fn apply_to_entities(self, world: &mut World, entities: &[Entity]) {
+        let entities: Vec<Entity> = entities
+            .iter()
+            .copied()
+            .filter(|&entity| world.get::<Transform>(entity).is_some())
+            .collect();
+        let mut translations: Vec<Vec3> = entities
+            .iter()
+            .map(|&entity| world.get::<Transform>(entity).unwrap().translation)
+            .collect();
+        self.apply(&mut translations);
+        for (entity, translation) in entities.into_iter().zip(translations) {
+            let mut transform = world.get_mut::<Transform>(entity).unwrap();
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ArrangeState {
+    axis: Axis,
+    grid_size: f32,
+}
+
+impl Default for ArrangeState {
+    fn default() -> Self {
+        Self {
+            axis: Axis::X,
+            grid_size: 1.0,
+        }
+    }
+}
+
+/// Display buttons to [`Arrange`] the translations of `entities`, and apply the clicked one.
+///
+/// Returns `true` if an operation was applied.
+pub fn ui_for_arrange(world: &mut World, entities: &[Entity], ui: &mut egui::Ui) -> bool {
+    let id = ui.id().with("arrange");
+    let mut state = ui.data_mut(|data| data.get_temp::<ArrangeState>(id).unwrap_or_default());
+
+    let mut arrange = None;
+    ui.horizontal(|ui| {
+        ui.label("Axis");
+        ui.selectable_value(&mut state.axis, Axis::X, "X");
+        ui.selectable_value(&mut state.axis, Axis::Y, "Y");
+        ui.selectable_value(&mut state.axis, Axis::Z, "Z");
+    });
+    ui.horizontal(|ui| {
+        ui.label("Align");
+        for (alignment, label) in [
+            (Alignment::Min, "Min"),
+            (Alignment::Center, "Center"),
+            (Alignment::Max, "Max"),
+        ] {
+            if ui.button(label).clicked() {
+                arrange = Some(Arrange::Align(state.axis, alignment));
+            }
+        }
+        if ui
+            .add_enabled(entities.len() > 2, egui::Button::new("Distribute"))
+            .on_hover_text("Space the entities evenly between the outermost ones")
+            .clicked()
+        {
+            arrange = Some(Arrange::Distribute(state.axis));
+        }
+    });
+    ui.horizontal(|ui| {
+        if ui.button("Snap to grid").clicked() {
+            arrange = Some(Arrange::SnapToGrid(state.grid_size));
+        }
+        ui.add(
+            egui::DragValue::new(&mut state.grid_size)
+                .speed(0.1)
+                .range(0.001..=f32::MAX),
+        );
+    });
+    ui.data_mut(|data| data.insert_temp(id, state));
+
+    match arrange {
+        Some(arrange) => {
+            arrange.apply_to_entities(world, entities);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec3;
+
+    use super::{Alignment, Arrange, Axis};
+
+    #[test]
+    fn arrange_translations() {
+        let mut translations = [
+            Vec3::new(4.0, 1.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+        ];
+
+        Arrange::Distribute(Axis::X).apply(&mut translations);
+        assert_eq!(translations.map(|t| t.x), [4.0, 0.0, 2.0]);
+
+        Arrange::Align(Axis::Y, Alignment::Center).apply(&mut translations);
+        assert_eq!(translations.map(|t| t.y), [2.0; 3]);
+
+        Arrange::Align(Axis::X, Alignment::Max).apply(&mut translations);
+        assert_eq!(translations.map(|t| t.x), [4.0; 3]);
+
+        let mut translations = [Vec3::new(1.2, -0.7, 2.6)];
+        Arrange::SnapToGrid(0.5).apply(&mut translations);
+        assert_eq!(translations, [Vec3::new(1.0, -0.5, 2.5)]);
+    }
+}
@@ -0,0 +1,68 @@
+//! A panel listing the loaded images with their thumbnails.
+//!
+//! Entries can be dragged onto any `Handle<Image>` field, like the texture slots of a `StandardMaterial`.
+//! With the `editor` feature, the panel is available as the [`EditorTab::AssetBrowser`](crate::editor::EditorTab::AssetBrowser).
+
+use bevy_asset::{AssetServer, Assets, Handle};
+use bevy_ecs::world::World;
+use bevy_image::Image;
+
+use super::errors::resource_does_not_exist;
+use crate::{
+    inspector_egui_impls::image::image_thumbnail, restricted_world_view::RestrictedWorldView,
+    utils::pretty_type_name,
+};
+
+const THUMBNAIL_SIZE: f32 = 48.0;
+
+/// Display the images which were loaded from a path, filtered by a search field.
+pub fn asset_browser_ui(world: &mut World, ui: &mut egui::Ui) {
+    let Some(asset_server) = world.get_resource::<AssetServer>() else {
+        resource_does_not_exist(ui, &pretty_type_name::<AssetServer>());
+        return;
+    };
+    let Some(images) = world.get_resource::<Assets<Image>>() else {
+        resource_does_not_exist(ui, &pretty_type_name::<Assets<Image>>());
+        return;
+    };
+
+    let mut entries: Vec<(String, Handle<Image>)> = images
+        .ids()
+        .filter_map(|id| {
+            let path = asset_server.get_path(id)?;
+            Some((path.to_string(), asset_server.get_id_handle(id)?))
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let id = egui::Id::new("asset_browser");
+    let filter_id = id.with("filter");
+    let mut filter = ui.data_mut(|data| data.get_temp::<String>(filter_id).unwrap_or_default());
+    ui.horizontal(|ui| {
+        ui.label("Filter");
+        ui.text_edit_singleline(&mut filter);
+    });
+    ui.data_mut(|data| data.insert_temp(filter_id, filter.clone()));
+    let filter = filter.to_lowercase();
+
+    let mut world = RestrictedWorldView::new(world);
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        if entries.is_empty() {
+            ui.weak("No images loaded");
+        }
+        for (path, handle) in entries
+            .into_iter()
+            .filter(|(path, _)| path.to_lowercase().contains(&filter))
+        {
+            let response = ui
+                .dnd_drag_source(id.with(handle.id()), handle.clone(), |ui| {
+                    ui.horizontal(|ui| {
+                        image_thumbnail(&handle, &mut world, ui, THUMBNAIL_SIZE);
+                        ui.label(&path);
+                    });
+                })
+                .response;
+            response.on_hover_text("Drag onto an image field");
+        }
+    });
+}
@@ -0,0 +1,228 @@
+//! A log of the [`AssetEvent`]s of asset types, together with the hot-reloading status of the [`AssetServer`].
+//!
+//! Events are only recorded for asset types which have a [`log_asset_events`] system, which is added for
+//! the common bevy asset types and those passed to
+//! [`AssetEventLogPlugin::with_asset`](crate::quick::AssetEventLogPlugin::with_asset) by the quick plugin.
+//!
+//! ```no_run
+//! use bevy_app::{App, Update};
+//! use bevy_asset::Asset;
+//! use bevy_ecs::prelude::*;
+//! use bevy_reflect::TypePath;
+//! use bevy_inspector_egui::bevy_inspector::asset_events::{self, AssetEventLog};
+//!
+//! #[derive(Asset, TypePath)]
+//! struct Level;
+//!
+//! # let mut app = App::new();
+//! app.init_resource::<AssetEventLog>()
+//!     .add_systems(Update, asset_events::log_asset_events::<Level>);
+//!
+//! fn asset_event_ui(world: &mut World, ui: &mut egui::Ui) {
+//!     asset_events::ui_for_asset_event_log(world, ui);
+//! }
+//! ```
+
+use std::{any::TypeId, collections::VecDeque, time::Duration};
+
+use bevy_asset::{Asset, AssetEvent, AssetServer, ReflectAsset, UntypedAssetId};
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use bevy_utils::HashSet;
+
+use super::errors::resource_does_not_exist;
+use crate::{theme::InspectorTheme, utils::pretty_type_name};
+
+/// The kind of an [`AssetEventEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetEventKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// A single recorded [`AssetEvent`]
+#[derive(Debug, Clone)]
+pub struct AssetEventEntry {
+    /// Time since startup at which the event was read
+    pub time: Duration,
+    pub kind: AssetEventKind,
+    pub id: UntypedAssetId,
+    /// Short name of the asset type
+    pub type_name: String,
+}
+
+/// The events displayed by [`ui_for_asset_event_log`]
+#[derive(Resource, Debug, Clone)]
+pub struct AssetEventLog {
+    pub entries: VecDeque<AssetEventEntry>,
+    /// Maximum number of entries, older ones are dropped first
+    pub capacity: usize,
+    /// While paused, new events are ignored
+    pub paused: bool,
+    logged_types: HashSet<TypeId>,
+}
+
+impl Default for AssetEventLog {
+    fn default() -> Self {
+        AssetEventLog {
+            entries: VecDeque::new(),
+            capacity: 500,
+            paused: false,
+            logged_types: HashSet::default(),
+        }
+    }
+}
+
+impl AssetEventLog {
+    /// Records an event, dropping the oldest entry if the log is full
+    pub fn push(&mut self, entry: AssetEventEntry) {
+        if self.paused {
+            return;
+        }
+        while self.entries.len() >= self.capacity.max(1) {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Whether events of the asset type with the [`TypeId`] `asset_type` are recorded
+    pub fn is_logged(&self, asset_type: TypeId) -> bool {
+        self.logged_types.contains(&asset_type)
+    }
+}
+
+/// Records the created, modified and removed events of `A` in the [`AssetEventLog`]
+pub fn log_asset_events<A: Asset>(
+    mut events: EventReader<AssetEvent<A>>,
+    mut log: ResMut<AssetEventLog>,
+    time: Option<Res<Time<Real>>>,
+) {
+    log.logged_types.insert(TypeId::of::<A>());
+
+    let time = time.map_or(Duration::ZERO, |time| time.elapsed());
+    for event in events.read() {
+        let (kind, id) = match *event {
+            AssetEvent::Added { id } => (AssetEventKind::Created, id),
+            AssetEvent::Modified { id } => (AssetEventKind::Modified, id),
+            AssetEvent::Removed { id } => (AssetEventKind::Removed, id),
+            AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => continue,
+        };
+        log.push(AssetEventEntry {
+            time,
+            kind,
+            id: id.untyped(),
+            type_name: A::short_type_path().to_owned(),
+        });
+    }
+}
+
+/// Display the [`AssetEventLog`], with a button to reload each asset that was loaded from a path
+pub fn ui_for_asset_event_log(world: &mut World, ui: &mut egui::Ui) {
+    let Some(asset_server) = world.get_resource::<AssetServer>().cloned() else {
+        resource_does_not_exist(ui, &pretty_type_name::<AssetServer>());
+        return;
+    };
+    let unlogged_types = unlogged_asset_types(world);
+    let Some(mut log) = world.get_resource_mut::<AssetEventLog>() else {
+        resource_does_not_exist(ui, &pretty_type_name::<AssetEventLog>());
+        return;
+    };
+    let log = log.bypass_change_detection();
+
+    ui.horizontal(|ui| {
+        match asset_server.watching_for_changes() {
+            true => ui.label("Hot reloading: on"),
+            false => ui.weak("Hot reloading: off").on_hover_text(
+                "Enable the `file_watcher` feature of bevy and set `AssetPlugin::watch_for_changes_override`",
+            ),
+        };
+        ui.separator();
+        ui.checkbox(&mut log.paused, "Pause");
+        if ui.button("Clear").clicked() {
+            log.entries.clear();
+        }
+    });
+
+    if !unlogged_types.is_empty() {
+        ui.collapsing(
+            format!("{} asset types are not logged", unlogged_types.len()),
+            |ui| {
+                for name in unlogged_types {
+                    ui.weak(name);
+                }
+            },
+        );
+    }
+
+    ui.separator();
+
+    if log.entries.is_empty() {
+        ui.weak("No asset events yet");
+        return;
+    }
+
+    let success_color = InspectorTheme::current(ui.ctx()).success_color;
+    egui::ScrollArea::vertical()
+        .stick_to_bottom(true)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            egui::Grid::new("asset_event_log")
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    for entry in &log.entries {
+                        ui.monospace(format!("{:>8.2}s", entry.time.as_secs_f32()));
+                        let (text, color) = match entry.kind {
+                            AssetEventKind::Created => ("Created", success_color),
+                            AssetEventKind::Modified => ("Modified", egui::Color32::LIGHT_BLUE),
+                            AssetEventKind::Removed => ("Removed", ui.visuals().error_fg_color),
+                        };
+                        ui.colored_label(color, text);
+                        ui.label(&entry.type_name);
+
+                        let path = asset_server.get_path(entry.id);
+                        match &path {
+                            Some(path) => ui.label(path.to_string()),
+                            None => ui.weak(format!("{:?}", entry.id)),
+                        };
+                        match path {
+                            Some(path) if entry.kind != AssetEventKind::Removed => {
+                                if ui.small_button("⟳").on_hover_text("Reload").clicked() {
+                                    asset_server.reload(path.into_owned());
+                                }
+                            }
+                            _ => {
+                                ui.label("");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// Names of the asset types in the type registry whose events aren't recorded
+fn unlogged_asset_types(world: &World) -> Vec<String> {
+    let (Some(type_registry), Some(log)) = (
+        world.get_resource::<AppTypeRegistry>(),
+        world.get_resource::<AssetEventLog>(),
+    ) else {
+        return Vec::new();
+    };
+    let type_registry = type_registry.read();
+    let mut names: Vec<String> = type_registry
+        .iter_with_data::<ReflectAsset>()
+        .map(|(registration, _)| registration)
+        .filter(|registration| !log.is_logged(registration.type_id()))
+        .map(|registration| {
+            registration
+                .type_info()
+                .type_path_table()
+                .short_path()
+                .to_owned()
+        })
+        .collect();
+    names.sort();
+    names
+}
@@ -0,0 +1,152 @@
+//! Opening the entities which match a search, so that the results are visible without opening their ancestors by hand.
+//!
+//! Auto-expansion is configured by the [`AutoExpandSettings`] resource, which is inserted (disabled) by the
+//! [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin). It applies to the [`Hierarchy`](super::hierarchy::Hierarchy),
+//! which opens the ancestors of matching entities, and to the entity list of [`ui_for_world`](super::ui_for_world),
+//! which also opens the matching entities themselves so that their [matching components](super::EntityFilter::matching_components) are shown.
+//!
+//! Entities are opened once when they start matching, and can be closed again while the search stays the same.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::auto_expand::AutoExpandSettings;
+//!
+//! fn setup(mut settings: ResMut<AutoExpandSettings>) {
+//!     settings.enabled = true;
+//!     settings.max_depth = 4;
+//! }
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::Parent;
+use bevy_utils::HashSet;
+
+/// How far the entities matching a search are opened, see the [module docs](self)
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AutoExpandSettings {
+    pub enabled: bool,
+    /// Number of hierarchy levels which are opened, counting from the root entities
+    pub max_depth: usize,
+    /// Maximum number of entities opened for a search, so that broad searches stay responsive.
+    /// Matches whose path would exceed it are left closed.
+    pub max_nodes: usize,
+}
+
+impl Default for AutoExpandSettings {
+    fn default() -> Self {
+        AutoExpandSettings {
+            enabled: false,
+            max_depth: 3,
+            max_nodes: 20,
+        }
+    }
+}
+
+impl AutoExpandSettings {
+    /// The ancestors of the `matched` entities up to the [`max_depth`](Self::max_depth), including the matched entities themselves if `include_matched` is set
+    pub fn entities_to_expand(
+        &self,
+        world: &World,
+        matched: &HashSet<Entity>,
+        include_matched: bool,
+    ) -> HashSet<Entity> {
+        let mut expand = HashSet::default();
+        if !self.enabled {
+            return expand;
+        }
+
+        let mut matched: Vec<Entity> = matched.iter().copied().collect();
+        matched.sort();
+        for entity in matched {
+            if expand.len() >= self.max_nodes {
+                break;
+            }
+            let mut path: Vec<Entity> = std::iter::successors(Some(entity), |&entity| {
+                world.get::<Parent>(entity).map(Parent::get)
+            })
+            .skip(usize::from(!include_matched))
+            .collect();
+            path.reverse();
+            path.truncate(self.max_depth);
+
+            let new = path
+                .iter()
+                .filter(|entity| !expand.contains(*entity))
+                .count();
+            if expand.len() + new <= self.max_nodes {
+                expand.extend(path);
+            }
+        }
+        expand
+    }
+}
+
+/// Opens the collapsing header `header_id` once it should be expanded, so that it can still be closed afterwards
+pub(crate) fn expand_once(ui: &mut egui::Ui, header_id: egui::Id, expand: bool) {
+    let expanded_id = header_id.with("expanded_by_search");
+    let expanded = ui.data(|data| data.get_temp::<bool>(expanded_id).unwrap_or(false));
+    if expand && !expanded {
+        let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+            ui.ctx(),
+            header_id,
+            false,
+        );
+        state.set_open(true);
+        state.store(ui.ctx());
+        ui.data_mut(|data| data.insert_temp(expanded_id, true));
+    } else if !expand && expanded {
+        ui.data_mut(|data| data.remove::<bool>(expanded_id));
+    }
+}
+
+/// The entities to open in a view of `world` filtered by `filter`
+pub(crate) fn entities_to_expand<F: super::EntityFilter>(
+    world: &mut World,
+    filter: &F,
+    type_registry: &bevy_reflect::TypeRegistry,
+    include_matched: bool,
+) -> HashSet<Entity> {
+    let Some(settings) = world
+        .get_resource::<AutoExpandSettings>()
+        .filter(|settings| settings.enabled)
+        .cloned()
+    else {
+        return HashSet::default();
+    };
+    let matched = filter.matching_entities(world, type_registry);
+    settings.entities_to_expand(world, &matched, include_matched)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+    use bevy_utils::HashSet;
+
+    use super::AutoExpandSettings;
+
+    #[test]
+    fn expansion_is_limited_by_depth_and_nodes() {
+        let mut world = World::new();
+        let root = world.spawn_empty().id();
+        let child = world.spawn_empty().set_parent(root).id();
+        let grandchild = world.spawn_empty().set_parent(child).id();
+        let other = world.spawn_empty().id();
+
+        let settings = AutoExpandSettings {
+            enabled: true,
+            max_depth: 2,
+            max_nodes: 2,
+        };
+        let matched = HashSet::from_iter([grandchild]);
+        assert_eq!(
+            settings.entities_to_expand(&world, &matched, false),
+            HashSet::from_iter([root, child])
+        );
+
+        // the path of `other` would exceed the node limit
+        let matched = HashSet::from_iter([grandchild, other]);
+        let expand = settings.entities_to_expand(&world, &matched, true);
+        assert_eq!(expand, HashSet::from_iter([root, child]));
+    }
+}
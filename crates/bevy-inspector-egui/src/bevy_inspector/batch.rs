@@ -0,0 +1,332 @@
+//! Structural changes applied to all [selected](SelectedEntities) entities at once.
+//!
+//! [`ui_for_batch_operations`] displays a toolbar with the [`BatchOperation`]s. They are pushed to a
+//! command queue which is applied once the toolbar is done, and despawned entities are removed from the selection.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::{self, batch, hierarchy::SelectedEntities};
+//!
+//! fn inspector_ui(world: &mut World, ui: &mut egui::Ui, selected: &mut SelectedEntities) {
+//!     if selected.len() > 1 {
+//!         batch::ui_for_batch_operations(world, ui, selected);
+//!     }
+//!     bevy_inspector::ui_for_entities_shared_components(world, selected.as_slice(), ui);
+//! }
+//! ```
+
+use std::any::TypeId;
+
+use bevy_core::Name;
+use bevy_ecs::{
+    component::ComponentId,
+    prelude::*,
+    reflect::{ReflectComponent, ReflectFromWorld},
+    world::CommandQueue,
+};
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt, Parent};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypeRegistry};
+use bevy_transform::components::Transform;
+
+use super::hierarchy::SelectedEntities;
+use crate::{dropdown::DropDownBox, utils::pretty_name_cache::pretty_component_name};
+
+/// A structural change applied to every selected entity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOperation {
+    /// Insert the registered component, constructed using its `ReflectDefault` or `ReflectFromWorld`
+    AddComponent(TypeId),
+    /// Remove the component from every entity which has it
+    RemoveComponent(ComponentId),
+    /// Name the entities `{prefix} 1`, `{prefix} 2`, ... in the order of the selection
+    NamePrefix(String),
+    /// Despawn the entities with their children
+    DespawnAll,
+    /// Spawn an empty entity and make it the parent of all entities.
+    ///
+    /// If the entities share a parent, the new entity is added as its child.
+    ParentUnderNew,
+}
+
+impl BatchOperation {
+    /// Applies the operation to those `entities` which still exist
+    pub fn apply(&self, world: &mut World, entities: &[Entity]) {
+        let entities: Vec<Entity> = entities
+            .iter()
+            .copied()
+            .filter(|&entity| world.get_entity(entity).is_ok())
+            .collect();
+
+        match self {
+            BatchOperation::AddComponent(type_id) => add_component(world, &entities, *type_id),
+            BatchOperation::RemoveComponent(component_id) => {
+                for &entity in &entities {
+                    world.entity_mut(entity).remove_by_id(*component_id);
+                }
+            }
+            BatchOperation::NamePrefix(prefix) => {
+                for (i, &entity) in entities.iter().enumerate() {
+                    world
+                        .entity_mut(entity)
+                        .insert(Name::new(format!("{prefix} {}", i + 1)));
+                }
+            }
+            BatchOperation::DespawnAll => {
+                for entity in entities {
+                    // the entity may have been a descendant of a previously despawned one
+                    if let Ok(entity) = world.get_entity_mut(entity) {
+                        entity.despawn_recursive();
+                    }
+                }
+            }
+            BatchOperation::ParentUnderNew => {
+                let mut parents = entities
+                    .iter()
+                    .map(|&entity| world.get::<Parent>(entity).map(Parent::get));
+                let shared_parent = parents.next().flatten().filter(|&first| {
+                    parents.all(|parent| parent == Some(first)) && !entities.contains(&first)
+                });
+                let has_transform = entities
+                    .iter()
+                    .any(|&entity| world.get::<Transform>(entity).is_some());
+
+                let mut group = world.spawn(Name::new("Group"));
+                if has_transform {
+                    group.insert(Transform::default());
+                }
+                if let Some(parent) = shared_parent {
+                    group.set_parent(parent);
+                }
+                let group = group.id();
+                for entity in entities {
+                    world.entity_mut(entity).set_parent(group);
+                }
+            }
+        }
+    }
+}
+
+fn add_component(world: &mut World, entities: &[Entity], type_id: TypeId) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let Some(registration) = type_registry.get(type_id) else {
+        return;
+    };
+    let type_path = registration.type_info().type_path();
+    let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+        bevy_log::warn!("Can't insert `{type_path}`, it doesn't `#[reflect(Component)]`");
+        return;
+    };
+
+    for &entity in entities {
+        let value: Box<dyn Reflect> = if let Some(default) = registration.data::<ReflectDefault>() {
+            default.default()
+        } else if let Some(from_world) = registration.data::<ReflectFromWorld>() {
+            from_world.from_world(world)
+        } else {
+            bevy_log::warn!(
+                "Can't insert `{type_path}`, it has no `ReflectDefault` or `ReflectFromWorld`"
+            );
+            return;
+        };
+        reflect_component.insert(
+            &mut world.entity_mut(entity),
+            value.as_partial_reflect(),
+            &type_registry,
+        );
+    }
+}
+
+/// Components which can be constructed by [`BatchOperation::AddComponent`], sorted by name
+fn addable_components(type_registry: &TypeRegistry) -> Vec<(String, TypeId)> {
+    let mut components: Vec<_> = type_registry
+        .iter()
+        .filter(|registration| {
+            registration.data::<ReflectComponent>().is_some()
+                && (registration.data::<ReflectDefault>().is_some()
+                    || registration.data::<ReflectFromWorld>().is_some())
+        })
+        .map(|registration| {
+            let name = registration.type_info().type_path_table().short_path();
+            (name.to_owned(), registration.type_id())
+        })
+        .collect();
+    components.sort();
+    components
+}
+
+/// Components of at least one of the `entities`, sorted by name
+fn present_components(world: &World, entities: &[Entity]) -> Vec<(String, ComponentId)> {
+    let mut components: Vec<(String, ComponentId)> = Vec::new();
+    for entity in entities
+        .iter()
+        .filter_map(|&entity| world.get_entity(entity).ok())
+    {
+        for component_id in entity.archetype().components() {
+            if components.iter().any(|(_, id)| *id == component_id) {
+                continue;
+            }
+            if let Some(name) =
+                pretty_component_name(world.as_unsafe_world_cell_readonly(), component_id)
+            {
+                components.push((name.to_string(), component_id));
+            }
+        }
+    }
+    components.sort();
+    components
+}
+
+#[derive(Clone, Default)]
+struct BatchState {
+    add_search: String,
+    remove_search: String,
+    prefix: String,
+}
+
+/// Display a toolbar with the [`BatchOperation`]s for the `selected` entities.
+///
+/// The operations are applied after the toolbar is displayed, and despawned entities are removed from `selected`.
+/// Returns `true` if an operation was applied.
+pub fn ui_for_batch_operations(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    selected: &mut SelectedEntities,
+) -> bool {
+    let id = egui::Id::new("batch_operations");
+    let mut state = ui.data_mut(|data| data.get_temp::<BatchState>(id).unwrap_or_default());
+    let entities: Vec<Entity> = selected.iter().collect();
+
+    let mut operation = None;
+    ui.label(format!("{} entities selected", entities.len()));
+
+    ui.horizontal(|ui| {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let addable = addable_components(&type_registry);
+        let dropdown = DropDownBox::from_iter(
+            addable.iter().map(|(name, _)| name),
+            id.with("add"),
+            &mut state.add_search,
+            |ui, name| {
+                let response = ui.selectable_label(false, name);
+                if response.clicked() {
+                    operation = addable
+                        .iter()
+                        .find(|(component_name, _)| component_name == name)
+                        .map(|&(_, type_id)| BatchOperation::AddComponent(type_id));
+                }
+                response
+            },
+        )
+        .hint_text("Add component to all");
+        ui.add(dropdown);
+
+        let present = present_components(world, &entities);
+        let dropdown = DropDownBox::from_iter(
+            present.iter().map(|(name, _)| name),
+            id.with("remove"),
+            &mut state.remove_search,
+            |ui, name| {
+                let response = ui.selectable_label(false, name);
+                if response.clicked() {
+                    operation = present
+                        .iter()
+                        .find(|(component_name, _)| component_name == name)
+                        .map(|&(_, component_id)| BatchOperation::RemoveComponent(component_id));
+                }
+                response
+            },
+        )
+        .hint_text("Remove component from all");
+        ui.add(dropdown);
+    });
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut state.prefix)
+                .hint_text("Name prefix")
+                .desired_width(120.0),
+        );
+        let set_prefix = ui
+            .add_enabled(!state.prefix.is_empty(), egui::Button::new("Set names"))
+            .on_hover_text("Name the entities `{prefix} 1`, `{prefix} 2`, ...");
+        if set_prefix.clicked() {
+            operation = Some(BatchOperation::NamePrefix(state.prefix.clone()));
+        }
+        if ui
+            .button("Parent under new")
+            .on_hover_text("Spawn an empty entity and make it the parent of all selected entities")
+            .clicked()
+        {
+            operation = Some(BatchOperation::ParentUnderNew);
+        }
+        if ui.button("Despawn all").clicked() {
+            operation = Some(BatchOperation::DespawnAll);
+        }
+    });
+    ui.separator();
+
+    if operation.is_some() {
+        state.add_search.clear();
+        state.remove_search.clear();
+    }
+    ui.data_mut(|data| data.insert_temp(id, state));
+
+    let Some(operation) = operation else {
+        return false;
+    };
+    let mut queue = CommandQueue::default();
+    queue.push(move |world: &mut World| operation.apply(world, &entities));
+    queue.apply(world);
+    selected.retain(|entity| world.get_entity(entity).is_ok());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::{BuildChildren, Children, Parent};
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+    use super::BatchOperation;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Marker;
+
+    #[test]
+    fn operations_apply_to_all_entities() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Marker>();
+        let root = world.spawn_empty().id();
+        let a = world.spawn_empty().set_parent(root).id();
+        let b = world.spawn_empty().set_parent(root).id();
+        let entities = [a, b];
+
+        BatchOperation::AddComponent(std::any::TypeId::of::<Marker>()).apply(&mut world, &entities);
+        assert!(world.get::<Marker>(a).is_some() && world.get::<Marker>(b).is_some());
+
+        let marker = world.component_id::<Marker>().unwrap();
+        BatchOperation::RemoveComponent(marker).apply(&mut world, &entities);
+        assert!(world.get::<Marker>(a).is_none() && world.get::<Marker>(b).is_none());
+
+        BatchOperation::NamePrefix("Enemy".into()).apply(&mut world, &entities);
+        assert_eq!(world.get::<Name>(b).unwrap().as_str(), "Enemy 2");
+
+        BatchOperation::ParentUnderNew.apply(&mut world, &entities);
+        let group = world.get::<Parent>(a).unwrap().get();
+        assert_eq!(world.get::<Parent>(b).unwrap().get(), group);
+        assert_eq!(world.get::<Parent>(group).unwrap().get(), root);
+        assert_eq!(world.get::<Children>(group).unwrap().len(), 2);
+
+        BatchOperation::DespawnAll.apply(&mut world, &[group, a]);
+        assert!(world.get_entity(a).is_err() && world.get_entity(b).is_err());
+        assert!(world.get_entity(root).is_ok());
+    }
+}
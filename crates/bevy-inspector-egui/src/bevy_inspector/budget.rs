@@ -0,0 +1,98 @@
+//! A frame budget for the entity list of the world inspector.
+//!
+//! If the [`InspectorPerformanceSettings`] resource has a [`frame_budget`](InspectorPerformanceSettings::frame_budget),
+//! entities which are collapsed or scrolled out of view are deferred to the next frame once the entity list took longer
+//! than the budget. Deferred entities keep the height they had when they were last displayed, and visible ones show a
+//! "…" until they are displayed again.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use bevy_app::App;
+//! use bevy_inspector_egui::bevy_inspector::budget::InspectorPerformanceSettings;
+//!
+//! App::new().insert_resource(InspectorPerformanceSettings {
+//!     frame_budget: Some(Duration::from_millis(4)),
+//! });
+//! ```
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::Instant;
+
+/// Settings for limiting the time spent on the inspector UI
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InspectorPerformanceSettings {
+    /// Time the entity list may take per frame before collapsed and offscreen entities are deferred.
+    ///
+    /// `None` displays every entity every frame.
+    pub frame_budget: Option<Duration>,
+}
+
+/// Tracks the time of a list of sections which may be deferred to the next frame, see the [module docs](self)
+pub(crate) struct FrameBudget {
+    deadline: Option<Instant>,
+}
+
+impl FrameBudget {
+    pub(crate) fn start(world: &World) -> FrameBudget {
+        let budget = world
+            .get_resource::<InspectorPerformanceSettings>()
+            .and_then(|settings| settings.frame_budget);
+        FrameBudget {
+            deadline: budget.map(|budget| Instant::now() + budget),
+        }
+    }
+
+    fn exceeded(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Displays a placeholder instead of the section `id` and returns `true`, if the budget is exceeded and the
+    /// section is collapsed or offscreen. `collapsing_id` is the id of the collapsing header of the section.
+    ///
+    /// A section is deferred at most once in a row, so that it is displayed in the next frame.
+    pub(crate) fn defer(&self, ui: &mut egui::Ui, id: egui::Id, collapsing_id: egui::Id) -> bool {
+        let deferred_id = id.with("deferred");
+        if ui.data_mut(|data| data.remove_temp::<bool>(deferred_id).is_some()) || !self.exceeded() {
+            return false;
+        }
+
+        let height = ui
+            .data(|data| data.get_temp::<f32>(id.with("height")))
+            .unwrap_or(ui.spacing().interact_size.y);
+        let rect =
+            egui::Rect::from_min_size(ui.cursor().min, egui::vec2(ui.available_width(), height));
+        let visible = ui.is_rect_visible(rect);
+        let open = egui::collapsing_header::CollapsingState::load(ui.ctx(), collapsing_id)
+            .is_some_and(|state| state.is_open());
+        if visible && open {
+            return false;
+        }
+
+        let (rect, response) = ui.allocate_exact_size(rect.size(), egui::Sense::hover());
+        if visible {
+            ui.painter().text(
+                rect.left_center(),
+                egui::Align2::LEFT_CENTER,
+                "…",
+                egui::TextStyle::Body.resolve(ui.style()),
+                ui.visuals().weak_text_color(),
+            );
+            response.on_hover_text("Deferred to the next frame to stay within the frame budget");
+        }
+        ui.data_mut(|data| data.insert_temp(deferred_id, true));
+        ui.ctx().request_repaint();
+        true
+    }
+
+    /// Remembers the height of the section `id` which started at `top`, used for its placeholder while deferred
+    pub(crate) fn record_height(&self, ui: &mut egui::Ui, id: egui::Id, top: f32) {
+        if self.deadline.is_none() {
+            return;
+        }
+        let height = ui.cursor().min.y - top - ui.spacing().item_spacing.y;
+        ui.data_mut(|data| data.insert_temp(id.with("height"), height.max(0.0)));
+    }
+}
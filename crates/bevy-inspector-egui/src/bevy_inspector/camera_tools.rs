@@ -0,0 +1,281 @@
+//! Tools displayed below the [`Camera`] component of an entity.
+//!
+//! - a small preview of what the camera sees, rendered by a child camera into an image.
+//!   The [`CameraPreview`] camera keeps rendering until the preview is unchecked again.
+//! - editing the [`Viewport`] of the camera by dragging a rectangle on that preview
+//! - with the `editor` feature, aligning the camera with the [`EditorCamera`](crate::editor::EditorCamera) and the other way around
+
+use bevy_asset::{Assets, Handle};
+use bevy_core::Name;
+use bevy_core_pipeline::{core_2d::Camera2d, core_3d::Camera3d};
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_egui::EguiUserTextures;
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_image::Image;
+use bevy_math::{UVec2, Vec2};
+use bevy_render::{
+    camera::{Camera, OrthographicProjection, Projection, RenderTarget, Viewport},
+    view::RenderLayers,
+};
+use bevy_transform::components::Transform;
+
+use crate::{game_view, restricted_world_view::RestrictedWorldView};
+
+/// Marks the cameras spawned to render the preview of their parent camera
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CameraPreview;
+
+#[derive(Clone)]
+struct PreviewState {
+    preview: Entity,
+    image: Handle<Image>,
+    texture_id: egui::TextureId,
+}
+
+const PREVIEW_WIDTH: f32 = 256.0;
+
+/// Displays the camera tools for the `camera` of `entity`, returning whether the camera was changed.
+pub(crate) fn camera_tools_ui(
+    camera: &mut Camera,
+    entity: Entity,
+    world: &mut RestrictedWorldView<'_>,
+    queue: Option<&mut CommandQueue>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    let Some(queue) = queue else {
+        return false;
+    };
+
+    let state_id = id.with("camera_preview");
+    let state = ui
+        .data(|data| data.get_temp::<PreviewState>(state_id))
+        .filter(|state| world.contains_entity(state.preview));
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        let mut show_preview = state.is_some();
+        if ui.checkbox(&mut show_preview, "Preview").changed() {
+            match (&state, show_preview) {
+                (None, true) => spawn_preview(camera, entity, world, queue, ui, state_id),
+                (Some(state), false) => despawn_preview(state.clone(), queue, ui, state_id),
+                _ => {}
+            }
+        }
+        if camera.viewport.is_some() && ui.button("Reset viewport").clicked() {
+            camera.viewport = None;
+            changed = true;
+        }
+        #[cfg(feature = "editor")]
+        align_buttons(entity, queue, ui);
+    });
+
+    if let Some(state) = state {
+        changed |= preview_ui(camera, &state, world, ui);
+    }
+
+    changed
+}
+
+fn spawn_preview(
+    camera: &Camera,
+    entity: Entity,
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    ui: &egui::Ui,
+    state_id: egui::Id,
+) {
+    let Ok(mut images) = world.get_resource_mut::<Assets<Image>>() else {
+        return;
+    };
+    let image = images.add(game_view::render_target_image());
+    let Ok(mut textures) = world.get_resource_mut::<EguiUserTextures>() else {
+        return;
+    };
+    let texture_id = textures.add_image(image.clone());
+
+    let preview_camera = Camera {
+        target: RenderTarget::Image(image.clone()),
+        viewport: None,
+        is_active: true,
+        ..camera.clone()
+    };
+    let ctx = ui.ctx().clone();
+    queue.push(move |world: &mut World| {
+        let Ok(source) = world.get_entity(entity) else {
+            return;
+        };
+        let camera_3d = source.get::<Camera3d>().cloned();
+        let camera_2d = source.get::<Camera2d>().cloned();
+        let projection = source.get::<Projection>().cloned();
+        let orthographic = source.get::<OrthographicProjection>().cloned();
+        let render_layers = source.get::<RenderLayers>().cloned();
+
+        let mut preview = world.spawn((
+            Name::new("Camera Preview"),
+            CameraPreview,
+            Transform::IDENTITY,
+        ));
+        if let Some(camera_3d) = camera_3d {
+            preview.insert(camera_3d);
+        }
+        if let Some(camera_2d) = camera_2d {
+            preview.insert(camera_2d);
+        }
+        if let Some(projection) = projection {
+            preview.insert(projection);
+        }
+        if let Some(orthographic) = orthographic {
+            preview.insert(orthographic);
+        }
+        if let Some(render_layers) = render_layers {
+            preview.insert(render_layers);
+        }
+        let preview = preview.insert(preview_camera).id();
+        world.entity_mut(entity).add_child(preview);
+
+        ctx.data_mut(|data| {
+            data.insert_temp(
+                state_id,
+                PreviewState {
+                    preview,
+                    image,
+                    texture_id,
+                },
+            )
+        });
+    });
+}
+
+fn despawn_preview(
+    state: PreviewState,
+    queue: &mut CommandQueue,
+    ui: &egui::Ui,
+    state_id: egui::Id,
+) {
+    ui.data_mut(|data| data.remove::<PreviewState>(state_id));
+    queue.push(move |world: &mut World| {
+        if let Ok(preview) = world.get_entity_mut(state.preview) {
+            preview.despawn_recursive();
+        }
+        if let Some(mut textures) = world.get_resource_mut::<EguiUserTextures>() {
+            textures.remove_image(&state.image);
+        }
+        if let Some(mut images) = world.get_resource_mut::<Assets<Image>>() {
+            images.remove(&state.image);
+        }
+    });
+}
+
+/// Shows the preview image, on which a new viewport can be dragged out
+fn preview_ui(
+    camera: &mut Camera,
+    state: &PreviewState,
+    world: &mut RestrictedWorldView<'_>,
+    ui: &mut egui::Ui,
+) -> bool {
+    let target_size = camera.physical_target_size().map(|size| size.as_vec2());
+    let aspect = target_size
+        .filter(|size| size.y > 0.0)
+        .map_or(16.0 / 9.0, |size| size.x / size.y);
+    let width = ui.available_width().min(PREVIEW_WIDTH);
+    let size = egui::vec2(width, width / aspect);
+
+    if let Ok(mut images) = world.get_resource_mut::<Assets<Image>>() {
+        game_view::resize_image(
+            &mut images,
+            &state.image,
+            size * ui.ctx().pixels_per_point(),
+        );
+    }
+
+    let response = ui.add(
+        egui::Image::new(egui::load::SizedTexture::new(state.texture_id, size))
+            .sense(egui::Sense::drag()),
+    );
+    let Some(target_size) = target_size else {
+        return false;
+    };
+    let rect = response.rect;
+    let to_screen = |physical: Vec2| {
+        rect.min + egui::vec2(physical.x, physical.y) * rect.width() / target_size.x
+    };
+    let to_physical = |pos: egui::Pos2| {
+        let pos = ((pos - rect.min) * target_size.x / rect.width()).max(egui::Vec2::ZERO);
+        Vec2::new(pos.x, pos.y).min(target_size)
+    };
+
+    if let Some(viewport) = &camera.viewport {
+        let min = viewport.physical_position.as_vec2();
+        let max = min + viewport.physical_size.as_vec2();
+        ui.painter().rect_stroke(
+            egui::Rect::from_min_max(to_screen(min), to_screen(max)),
+            0.0,
+            egui::Stroke::new(1.0, ui.visuals().selection.stroke.color),
+        );
+    }
+
+    if !response.dragged() {
+        return false;
+    }
+    let (Some(start), Some(current)) =
+        ui.input(|input| (input.pointer.press_origin(), input.pointer.interact_pos()))
+    else {
+        return false;
+    };
+    let (a, b) = (to_physical(start), to_physical(current));
+    let min = a.min(b).as_uvec2();
+    let size = (a.max(b).as_uvec2() - min).max(UVec2::ONE);
+    camera.viewport = Some(Viewport {
+        physical_position: min,
+        physical_size: size,
+        depth: camera
+            .viewport
+            .as_ref()
+            .map_or(0.0..1.0, |viewport| viewport.depth.clone()),
+    });
+    true
+}
+
+#[cfg(feature = "editor")]
+fn align_buttons(entity: Entity, queue: &mut CommandQueue, ui: &mut egui::Ui) {
+    use crate::editor::EditorCamera;
+
+    fn editor_camera(world: &mut World, except: Entity) -> Option<Entity> {
+        world
+            .query_filtered::<Entity, With<EditorCamera>>()
+            .iter(world)
+            .find(|&editor_camera| editor_camera != except)
+    }
+    fn copy_transform(world: &mut World, from: Entity, to: Entity) {
+        let Some(transform) = world.get::<Transform>(from).copied() else {
+            return;
+        };
+        if let Some(mut target) = world.get_mut::<Transform>(to) {
+            *target = transform;
+        }
+    }
+
+    if ui
+        .button("Align camera to view")
+        .on_hover_text("Move this camera to the editor camera")
+        .clicked()
+    {
+        queue.push(move |world: &mut World| {
+            if let Some(view) = editor_camera(world, entity) {
+                copy_transform(world, view, entity);
+            }
+        });
+    }
+    if ui
+        .button("Align view to camera")
+        .on_hover_text("Move the editor camera to this camera")
+        .clicked()
+    {
+        queue.push(move |world: &mut World| {
+            if let Some(view) = editor_camera(world, entity) {
+                copy_transform(world, entity, view);
+            }
+        });
+    }
+}
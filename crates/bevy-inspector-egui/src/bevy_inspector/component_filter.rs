@@ -0,0 +1,348 @@
+//! Hiding components from the entity inspector, or showing them read-only, and how the remaining ones are sorted and grouped.
+//!
+//! The [`ComponentFilterSettings`] resource is consulted whenever the components of an entity are displayed.
+//! Components can also be hidden or made read-only at runtime by right clicking their header,
+//! and [`ui_for_component_filter_settings`] lists all registered components to toggle them again.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::component_filter::ComponentFilterSettings;
+//! # #[derive(Component)] struct Handle;
+//! # #[derive(Component)] struct Transform;
+//!
+//! // the resource is inserted by the `DefaultInspectorConfigPlugin`
+//! fn setup(mut settings: ResMut<ComponentFilterSettings>) {
+//!     settings.ignore::<Handle>().read_only::<Transform>();
+//! }
+//! ```
+
+use std::{any::TypeId, collections::HashSet};
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+
+/// Which components are displayed by the entity inspector, and which of them can be edited
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ComponentFilterSettings {
+    /// Components which aren't displayed
+    pub ignored: HashSet<TypeId>,
+    /// Components which are displayed, but can't be edited
+    pub read_only: HashSet<TypeId>,
+    /// If set, only these components are displayed
+    pub allowed: Option<HashSet<TypeId>>,
+    pub order: ComponentOrder,
+    pub grouping: ComponentGrouping,
+}
+
+/// Order of the components of an entity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ComponentOrder {
+    /// Sorted by their name
+    #[default]
+    Alphabetical,
+    /// In the order the component types were first added to the world
+    Insertion,
+}
+
+/// Collapsible sections the components of an entity are grouped into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ComponentGrouping {
+    #[default]
+    None,
+    /// One section per crate, like `bevy_transform` or `my_game`
+    Crate,
+    /// Sections for each [`ComponentCategory`]
+    Category,
+}
+
+impl ComponentGrouping {
+    pub(crate) fn group_of(self, type_path: &str) -> String {
+        match self {
+            ComponentGrouping::None => String::new(),
+            ComponentGrouping::Crate => crate_of(type_path).to_owned(),
+            ComponentGrouping::Category => ComponentCategory::of(type_path).label().to_owned(),
+        }
+    }
+
+    /// Position of a section created by [`ComponentGrouping::Category`], so that they are displayed in a fixed order
+    pub(crate) fn category_position(group: &str) -> usize {
+        ComponentCategory::ALL
+            .iter()
+            .position(|category| category.label() == group)
+            .unwrap_or(ComponentCategory::ALL.len())
+    }
+}
+
+/// Rough category of a component, guessed from the crate it is defined in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComponentCategory {
+    Transform,
+    Render,
+    Physics,
+    /// Components defined outside of bevy and physics crates
+    Game,
+    /// Other bevy components, like `Name` or `Children`
+    Other,
+}
+
+impl ComponentCategory {
+    pub const ALL: [ComponentCategory; 5] = [
+        ComponentCategory::Transform,
+        ComponentCategory::Render,
+        ComponentCategory::Physics,
+        ComponentCategory::Game,
+        ComponentCategory::Other,
+    ];
+
+    pub fn of(type_path: &str) -> ComponentCategory {
+        const RENDER_CRATES: &[&str] = &[
+            "bevy_render",
+            "bevy_core_pipeline",
+            "bevy_pbr",
+            "bevy_sprite",
+            "bevy_ui",
+            "bevy_text",
+            "bevy_gizmos",
+            "bevy_image",
+            "bevy_mesh",
+            "bevy_animation",
+        ];
+        const PHYSICS_CRATES: &[&str] = &["bevy_rapier2d", "bevy_rapier3d", "avian2d", "avian3d"];
+
+        let krate = crate_of(type_path);
+        if krate == "bevy_transform" {
+            ComponentCategory::Transform
+        } else if RENDER_CRATES.contains(&krate) {
+            ComponentCategory::Render
+        } else if PHYSICS_CRATES.contains(&krate) {
+            ComponentCategory::Physics
+        } else if krate.starts_with("bevy_") {
+            ComponentCategory::Other
+        } else {
+            ComponentCategory::Game
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ComponentCategory::Transform => "Transform",
+            ComponentCategory::Render => "Render",
+            ComponentCategory::Physics => "Physics",
+            ComponentCategory::Game => "Game",
+            ComponentCategory::Other => "Other",
+        }
+    }
+}
+
+pub(crate) fn crate_of(type_path: &str) -> &str {
+    type_path.split("::").next().unwrap_or(type_path)
+}
+
+impl ComponentFilterSettings {
+    /// Don't display the component `C`
+    pub fn ignore<C: Component>(&mut self) -> &mut Self {
+        self.ignored.insert(TypeId::of::<C>());
+        self
+    }
+
+    /// Display the component `C` without allowing edits
+    pub fn read_only<C: Component>(&mut self) -> &mut Self {
+        self.read_only.insert(TypeId::of::<C>());
+        self
+    }
+
+    /// Add `C` to the components which are displayed, hiding all others which weren't allowed
+    pub fn allow<C: Component>(&mut self) -> &mut Self {
+        self.allowed
+            .get_or_insert_with(HashSet::new)
+            .insert(TypeId::of::<C>());
+        self
+    }
+
+    pub fn is_visible(&self, type_id: TypeId) -> bool {
+        !self.ignored.contains(&type_id)
+            && self
+                .allowed
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(&type_id))
+    }
+
+    pub fn is_read_only(&self, type_id: TypeId) -> bool {
+        self.read_only.contains(&type_id)
+    }
+
+    fn set_visible(&mut self, type_id: TypeId, visible: bool) {
+        if visible {
+            self.ignored.remove(&type_id);
+            if let Some(allowed) = &mut self.allowed {
+                allowed.insert(type_id);
+            }
+        } else {
+            self.ignored.insert(type_id);
+        }
+    }
+
+    fn set_read_only(&mut self, type_id: TypeId, read_only: bool) {
+        if read_only {
+            self.read_only.insert(type_id);
+        } else {
+            self.read_only.remove(&type_id);
+        }
+    }
+}
+
+/// Context menu of a component header, for hiding the component or making it read-only
+pub(crate) fn component_header_menu(
+    header: &egui::Response,
+    component: TypeId,
+    value: &dyn bevy_reflect::PartialReflect,
+    read_only: bool,
+    queue: Option<&mut bevy_ecs::world::CommandQueue>,
+) {
+    header.context_menu(|ui| {
+        super::rust_code::copy_buttons(ui, value);
+        let Some(queue) = queue else {
+            return;
+        };
+        super::notes::note_button_queued(
+            queue,
+            super::notes::NoteTarget::component(value.reflect_type_path()),
+            ui,
+        );
+        ui.separator();
+        if ui.button("Hide in inspector").clicked() {
+            queue.push(move |world: &mut World| {
+                world
+                    .get_resource_or_init::<ComponentFilterSettings>()
+                    .set_visible(component, false);
+            });
+            ui.close_menu();
+        }
+        let label = if read_only {
+            "Allow editing"
+        } else {
+            "Make read-only"
+        };
+        if ui.button(label).clicked() {
+            queue.push(move |world: &mut World| {
+                world
+                    .get_resource_or_init::<ComponentFilterSettings>()
+                    .set_read_only(component, !read_only);
+            });
+            ui.close_menu();
+        }
+    });
+}
+
+/// Display all registered components with checkboxes for whether they are shown and editable
+pub fn ui_for_component_filter_settings(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let mut settings = world.get_resource_or_init::<ComponentFilterSettings>();
+
+    let id = egui::Id::new("component_filter_settings");
+    let mut search = ui.data_mut(|data| data.get_temp::<String>(id).unwrap_or_default());
+    ui.add(egui::TextEdit::singleline(&mut search).hint_text("Search"));
+    ui.data_mut(|data| data.insert_temp(id, search.clone()));
+    let search = search.to_lowercase();
+
+    ui.horizontal(|ui| {
+        ui.label("Order");
+        ui.selectable_value(
+            &mut settings.order,
+            ComponentOrder::Alphabetical,
+            "Alphabetical",
+        );
+        ui.selectable_value(&mut settings.order, ComponentOrder::Insertion, "Insertion");
+    });
+    ui.horizontal(|ui| {
+        ui.label("Group by");
+        ui.selectable_value(&mut settings.grouping, ComponentGrouping::None, "None");
+        ui.selectable_value(&mut settings.grouping, ComponentGrouping::Crate, "Crate");
+        ui.selectable_value(
+            &mut settings.grouping,
+            ComponentGrouping::Category,
+            "Category",
+        );
+    });
+
+    let components = registered_components(&type_registry);
+    egui::Grid::new(id).striped(true).show(ui, |ui| {
+        ui.strong("Component");
+        ui.strong("Visible");
+        ui.strong("Read-only");
+        ui.end_row();
+
+        for (name, type_id) in components {
+            if !name.to_lowercase().contains(&search) {
+                continue;
+            }
+            ui.label(name);
+
+            let mut visible = settings.is_visible(type_id);
+            if ui.checkbox(&mut visible, "").changed() {
+                settings.set_visible(type_id, visible);
+            }
+            let mut read_only = settings.is_read_only(type_id);
+            if ui.checkbox(&mut read_only, "").changed() {
+                settings.set_read_only(type_id, read_only);
+            }
+            ui.end_row();
+        }
+    });
+}
+
+fn registered_components(type_registry: &TypeRegistry) -> Vec<(&str, TypeId)> {
+    let mut components: Vec<_> = type_registry
+        .iter()
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(|registration| {
+            (
+                registration.type_info().type_path_table().short_path(),
+                registration.type_id(),
+            )
+        })
+        .collect();
+    components.sort_by_key(|(name, _)| *name);
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct A;
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn visibility() {
+        let mut settings = ComponentFilterSettings::default();
+        assert!(settings.is_visible(TypeId::of::<A>()));
+
+        settings.ignore::<A>();
+        assert!(!settings.is_visible(TypeId::of::<A>()));
+        assert!(settings.is_visible(TypeId::of::<B>()));
+
+        settings.set_visible(TypeId::of::<A>(), true);
+        settings.allow::<A>();
+        assert!(settings.is_visible(TypeId::of::<A>()));
+        assert!(!settings.is_visible(TypeId::of::<B>()));
+    }
+
+    #[test]
+    fn categories() {
+        let category = |type_path| ComponentCategory::of(type_path);
+        assert_eq!(
+            category("bevy_transform::components::transform::Transform"),
+            ComponentCategory::Transform
+        );
+        assert_eq!(
+            category("bevy_pbr::light::point_light::PointLight"),
+            ComponentCategory::Render
+        );
+        assert_eq!(category("bevy_core::name::Name"), ComponentCategory::Other);
+        assert_eq!(category("my_game::Player"), ComponentCategory::Game);
+    }
+}
@@ -0,0 +1,567 @@
+//! Detection of common setup problems, for when the inspector doesn't show up or doesn't show what you expect.
+//!
+//! Besides the problems found by [`diagnose`], types which couldn't be displayed while inspecting are collected
+//! into the [`InspectorErrors`] resource, and listed by [`ui_for_doctor`] together with how to fix them.
+//! [`unregistered_components`] lists the component types of the world which are missing from the type registry.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::doctor;
+//!
+//! fn log_problems(world: &mut World) {
+//!     for problem in doctor::diagnose(world) {
+//!         bevy_log::warn!("{}: {}", problem.description, problem.fix);
+//!     }
+//! }
+//! ```
+
+use std::{any::TypeId, fmt};
+
+use bevy_ecs::{
+    archetype::ArchetypeGeneration, prelude::*, reflect::ReflectComponent, world::WorldId,
+};
+use bevy_egui::{EguiContext, EguiGlobalSettings};
+use bevy_reflect::{TypeInfo, TypeRegistry};
+use bevy_window::PrimaryWindow;
+use egui::RichText;
+
+use crate::{
+    inspector_egui_impls::InspectorEguiImpl,
+    inspector_options::ReflectInspectorOptions,
+    restricted_world_view::{self, RestrictedWorldView},
+    utils::pretty_type_name_str,
+};
+
+/// A setup problem found by [`diagnose`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// What is wrong
+    pub description: String,
+    /// How to fix it
+    pub fix: String,
+}
+
+impl Problem {
+    fn new(description: impl Into<String>, fix: impl Into<String>) -> Self {
+        Problem {
+            description: description.into(),
+            fix: fix.into(),
+        }
+    }
+}
+
+/// Checks the world for common setup problems:
+/// - the `EguiPlugin` is missing
+/// - the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) is missing, so builtin types have no UI
+/// - there are egui contexts, but none on the [`PrimaryWindow`], which is where the [`quick`](crate::quick) plugins draw
+/// - registered types with [`InspectorOptions`](crate::InspectorOptions) have fields whose types were never registered
+///
+/// Types which aren't registered at all carry no trace of their `InspectorOptions` at runtime, so they can't be
+/// told apart here. The component types among them are found by [`unregistered_components`].
+pub fn diagnose(world: &mut World) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    if !world.contains_resource::<EguiGlobalSettings>() {
+        problems.push(Problem::new(
+            "`EguiPlugin` is not added, so no UI can be drawn",
+            "app.add_plugins(EguiPlugin)",
+        ));
+    }
+
+    let mut contexts = world.query_filtered::<Has<PrimaryWindow>, With<EguiContext>>();
+    let (n_contexts, has_primary) = contexts
+        .iter(world)
+        .fold((0, false), |(n, has_primary), primary| {
+            (n + 1, has_primary || primary)
+        });
+    if n_contexts > 0 && !has_primary {
+        problems.push(Problem::new(
+            format!("there are {n_contexts} egui contexts, but none on the `PrimaryWindow`, so the quick plugins won't show up"),
+            "add the `PrimaryWindow` component to the window the inspector should be displayed in",
+        ));
+    }
+
+    let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+        problems.push(Problem::new(
+            "there is no `AppTypeRegistry`",
+            "add the inspector plugins after `DefaultPlugins`",
+        ));
+        return problems;
+    };
+    let type_registry = type_registry.read();
+
+    if type_registry
+        .get_type_data::<InspectorEguiImpl>(TypeId::of::<f32>())
+        .is_none()
+    {
+        problems.push(Problem::new(
+            "`DefaultInspectorConfigPlugin` is not added, so builtin types like `f32` have no UI",
+            "app.add_plugins(DefaultInspectorConfigPlugin)",
+        ));
+    }
+
+    unregistered_field_types(&type_registry, &mut problems);
+
+    problems
+}
+
+/// Field types of the registered types with [`ReflectInspectorOptions`] which are missing from the registry
+fn unregistered_field_types(type_registry: &TypeRegistry, problems: &mut Vec<Problem>) {
+    let mut unregistered: Vec<(&str, &str)> = Vec::new();
+
+    for registration in type_registry.iter() {
+        if registration.data::<ReflectInspectorOptions>().is_none() {
+            continue;
+        }
+
+        let info = registration.type_info();
+        let mut check = |field_type_id: TypeId, field_type_path: &'static str| {
+            if !type_registry.contains(field_type_id) {
+                unregistered.push((info.type_path(), field_type_path));
+            }
+        };
+        match info {
+            TypeInfo::Struct(info) => info
+                .iter()
+                .for_each(|field| check(field.type_id(), field.type_path())),
+            TypeInfo::TupleStruct(info) => info
+                .iter()
+                .for_each(|field| check(field.type_id(), field.type_path())),
+            TypeInfo::Enum(info) => info.iter().for_each(|variant| match variant {
+                bevy_reflect::VariantInfo::Struct(variant) => variant
+                    .iter()
+                    .for_each(|field| check(field.type_id(), field.type_path())),
+                bevy_reflect::VariantInfo::Tuple(variant) => variant
+                    .iter()
+                    .for_each(|field| check(field.type_id(), field.type_path())),
+                bevy_reflect::VariantInfo::Unit(_) => {}
+            }),
+            _ => {}
+        }
+    }
+
+    unregistered.sort_unstable();
+    unregistered.dedup();
+    problems.extend(
+        unregistered
+            .into_iter()
+            .map(|(type_path, field_type_path)| {
+                let field_type = pretty_type_name_str(field_type_path);
+                Problem::new(
+                    format!(
+                        "`{}` has a field of type `{field_type}`, which is not registered",
+                        pretty_type_name_str(type_path),
+                    ),
+                    format!("app.register_type::<{field_type}>()"),
+                )
+            }),
+    );
+}
+
+/// A component type of the world which the inspector can't display, found by [`unregistered_components`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnregisteredComponent {
+    /// The crate the type is defined in
+    pub krate: String,
+    pub type_path: String,
+    /// Whether the type is registered, but without `ReflectComponent`
+    pub registered: bool,
+}
+
+impl UnregisteredComponent {
+    /// How to make the component displayable
+    pub fn fix(&self) -> String {
+        let type_name = pretty_type_name_str(&self.type_path);
+        if self.registered {
+            format!("#[reflect(Component)] on {type_name}")
+        } else {
+            format!(".register_type::<{type_name}>()")
+        }
+    }
+}
+
+/// Finds the component types of the world which aren't in the `TypeRegistry` or lack `ReflectComponent`,
+/// sorted by crate and type path.
+///
+/// Resources and components without a rust type are skipped.
+pub fn unregistered_components(world: &World) -> Vec<UnregisteredComponent> {
+    let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+        return Vec::new();
+    };
+    let type_registry = type_registry.read();
+    let storages = world.storages();
+
+    let mut components: Vec<_> = world
+        .components()
+        .iter()
+        .filter(|info| {
+            storages.resources.get(info.id()).is_none()
+                && storages.non_send_resources.get(info.id()).is_none()
+        })
+        .filter_map(|info| {
+            let type_id = info.type_id()?;
+            let registered = match type_registry.get(type_id) {
+                Some(registration) if registration.data::<ReflectComponent>().is_some() => {
+                    return None
+                }
+                Some(_) => true,
+                None => false,
+            };
+            Some(UnregisteredComponent {
+                krate: super::component_filter::crate_of(info.name()).to_owned(),
+                type_path: info.name().to_owned(),
+                registered,
+            })
+        })
+        .collect();
+    components.sort_by(|a, b| (&a.krate, &a.type_path).cmp(&(&b.krate, &b.type_path)));
+    components
+}
+
+/// Display the [`unregistered_components`] grouped by crate, with the `register_type` calls that are missing.
+///
+/// Components of bevy crates are only listed if enabled with a checkbox, since many of them are internal.
+pub fn ui_for_registration_assistant(world: &mut World, ui: &mut egui::Ui) {
+    let id = egui::Id::new("registration_assistant");
+    let mut show_bevy = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(id));
+    if ui.checkbox(&mut show_bevy, "Include bevy crates").changed() {
+        ui.data_mut(|data| data.insert_temp(id, show_bevy));
+    }
+
+    let mut components = unregistered_components(world);
+    if !show_bevy {
+        components
+            .retain(|component| component.krate != "bevy" && !component.krate.starts_with("bevy_"));
+    }
+    if components.is_empty() {
+        ui.label("All components can be displayed");
+        return;
+    }
+
+    for krate in components.chunk_by(|a, b| a.krate == b.krate) {
+        let name = &krate[0].krate;
+        egui::CollapsingHeader::new(format!("{name} ({})", krate.len()))
+            .id_salt(id.with(name))
+            .show(ui, |ui| {
+                let missing: Vec<_> = krate
+                    .iter()
+                    .filter(|component| !component.registered)
+                    .map(UnregisteredComponent::fix)
+                    .collect();
+                if !missing.is_empty() && ui.button("Copy register_type calls").clicked() {
+                    crate::utils::copy_text(ui.ctx(), missing.join("\n"));
+                }
+                egui::Grid::new(id.with((name, "grid")))
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for component in krate {
+                            ui.label(pretty_type_name_str(&component.type_path));
+                            ui.label(RichText::new(component.fix()).monospace());
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
+
+/// Why a type couldn't be displayed by the inspector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InspectorError {
+    /// The type is not registered in the `TypeRegistry`
+    NotRegistered { type_name: String },
+    /// The type is missing type data like `ReflectComponent`
+    MissingTypeData {
+        type_name: String,
+        type_data: &'static str,
+    },
+    /// No value of the type can be constructed, e.g. for adding it to a list
+    NoDefault { type_name: String },
+    /// The type is opaque to reflection, but has no [`InspectorEguiImpl`]
+    NoInspectorImpl { type_name: String },
+}
+
+impl InspectorError {
+    pub fn type_name(&self) -> &str {
+        match self {
+            InspectorError::NotRegistered { type_name }
+            | InspectorError::MissingTypeData { type_name, .. }
+            | InspectorError::NoDefault { type_name }
+            | InspectorError::NoInspectorImpl { type_name } => type_name,
+        }
+    }
+
+    /// How to fix the error, usually as code to add to the app or the type
+    pub fn fix(&self) -> String {
+        match self {
+            InspectorError::NotRegistered { type_name } => {
+                format!("app.register_type::<{type_name}>()")
+            }
+            InspectorError::MissingTypeData {
+                type_name,
+                type_data: "ReflectAsset" | "ReflectHandle",
+            } => format!("app.register_asset_reflect::<{type_name}>()"),
+            InspectorError::MissingTypeData {
+                type_name,
+                type_data,
+            } => format!(
+                "#[reflect({})] on {type_name}",
+                type_data.trim_start_matches("Reflect")
+            ),
+            InspectorError::NoDefault { type_name } => {
+                format!("#[derive(Default)] and #[reflect(Default)] on {type_name}")
+            }
+            InspectorError::NoInspectorImpl { type_name } => format!(
+                "app.register_type_data::<{type_name}, InspectorEguiImpl>(), or add the `DefaultInspectorConfigPlugin` for builtin types"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for InspectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InspectorError::NotRegistered { type_name } => {
+                write!(f, "`{type_name}` is not registered in the `TypeRegistry`")
+            }
+            InspectorError::MissingTypeData {
+                type_name,
+                type_data,
+            } => write!(f, "`{type_name}` has no `{type_data}` type data"),
+            InspectorError::NoDefault { type_name } => write!(
+                f,
+                "`{type_name}` has no `ReflectDefault` or `ReflectFromReflect`, so no value of it can be constructed"
+            ),
+            InspectorError::NoInspectorImpl { type_name } => write!(
+                f,
+                "`{type_name}` is opaque to reflection, but has no `InspectorEguiImpl`"
+            ),
+        }
+    }
+}
+
+/// The [`InspectorError`]s encountered while inspecting, without duplicates.
+///
+/// Added by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) and displayed by [`ui_for_doctor`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct InspectorErrors {
+    errors: Vec<InspectorError>,
+}
+
+impl InspectorErrors {
+    /// Records `error`, unless it was already recorded
+    pub fn report(&mut self, error: InspectorError) {
+        if !self.errors.contains(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    pub fn contains(&self, error: &InspectorError) -> bool {
+        self.errors.contains(error)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &InspectorError> {
+        self.errors.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.errors.clear();
+    }
+}
+
+/// Records `error` into the [`InspectorErrors`], if the resource exists and the world has access to it
+pub(crate) fn report(world: &mut RestrictedWorldView<'_>, error: InspectorError) {
+    let Ok(mut errors) = world.get_resource_mut::<InspectorErrors>() else {
+        return;
+    };
+    if !errors.contains(&error) {
+        errors.report(error);
+    }
+}
+
+/// Records the [`InspectorError`] behind a failed access through a [`RestrictedWorldView`],
+/// if it was caused by how the type is registered
+pub(crate) fn report_access_error(
+    world: &mut RestrictedWorldView<'_>,
+    error: &restricted_world_view::Error,
+    type_name: &str,
+) {
+    let type_name = type_name.to_owned();
+    let error = match *error {
+        restricted_world_view::Error::NoTypeRegistration(_) => {
+            InspectorError::NotRegistered { type_name }
+        }
+        restricted_world_view::Error::NoTypeData(_, type_data) => InspectorError::MissingTypeData {
+            type_name,
+            type_data,
+        },
+        _ => return,
+    };
+    report(world, error);
+}
+
+/// The [`Problem`]s found by [`diagnose`], and the state of the world they were found in
+#[derive(Clone)]
+struct Diagnosis {
+    world: WorldId,
+    generation: ArchetypeGeneration,
+    n_components: usize,
+    problems: Vec<Problem>,
+}
+
+/// Display the [`Problem`]s found by [`diagnose`] and the collected [`InspectorErrors`], together with how to fix them,
+/// followed by the [registration assistant](ui_for_registration_assistant).
+///
+/// The world is only diagnosed again when new archetypes or components were added, or when clicking "Re-run".
+pub fn ui_for_doctor(world: &mut World, ui: &mut egui::Ui) {
+    let id = egui::Id::new("inspector_doctor");
+    let rerun = ui.button("Re-run").clicked();
+    let generation = world.archetypes().generation();
+    let n_components = world.components().len();
+    let cached = ui
+        .data(|data| data.get_temp::<Diagnosis>(id))
+        .filter(|diagnosis| {
+            !rerun
+                && diagnosis.world == world.id()
+                && diagnosis.generation == generation
+                && diagnosis.n_components == n_components
+        });
+    let problems = match cached {
+        Some(diagnosis) => diagnosis.problems,
+        None => {
+            let problems = diagnose(world);
+            let diagnosis = Diagnosis {
+                world: world.id(),
+                generation,
+                n_components,
+                problems: problems.clone(),
+            };
+            ui.data_mut(|data| data.insert_temp(id, diagnosis));
+            problems
+        }
+    };
+    let has_errors = world
+        .get_resource::<InspectorErrors>()
+        .is_some_and(|errors| !errors.is_empty());
+    if problems.is_empty() && !has_errors {
+        ui.label("No problems found");
+    }
+
+    for problem in problems {
+        ui.label(&problem.description);
+        ui.label(RichText::new(&problem.fix).monospace());
+        ui.separator();
+    }
+
+    if has_errors {
+        ui_for_inspector_errors(world, ui);
+    }
+
+    egui::CollapsingHeader::new("Component registrations").show(ui, |ui| {
+        ui_for_registration_assistant(world, ui);
+    });
+}
+
+/// Display the collected [`InspectorErrors`], together with how to fix them.
+pub fn ui_for_inspector_errors(world: &mut World, ui: &mut egui::Ui) {
+    let Some(mut errors) = world.get_resource_mut::<InspectorErrors>() else {
+        super::errors::resource_does_not_exist(ui, "InspectorErrors");
+        return;
+    };
+
+    ui.horizontal(|ui| {
+        ui.strong("Types which couldn't be displayed");
+        if ui.button("Clear").clicked() {
+            errors.clear();
+        }
+    });
+    if errors.is_empty() {
+        ui.weak("None so far");
+        return;
+    }
+    for error in errors.iter() {
+        ui.label(error.to_string());
+        ui.label(RichText::new(error.fix()).monospace());
+        ui.separator();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use super::{unregistered_components, InspectorError, InspectorErrors};
+
+    #[derive(Component)]
+    struct Unregistered(#[allow(dead_code)] u32);
+
+    #[derive(Component, Reflect)]
+    struct WithoutReflectComponent;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Reflected;
+
+    #[derive(Resource)]
+    struct SomeResource;
+
+    #[test]
+    fn collects_unregistered_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<InspectorErrors>();
+        let entity = world.spawn(Unregistered(0)).id();
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                crate::bevy_inspector::ui_for_entity(&mut world, entity, ui);
+            });
+        });
+
+        let errors: Vec<_> = world
+            .resource::<InspectorErrors>()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(
+            errors,
+            vec![InspectorError::NotRegistered {
+                type_name: "Unregistered".to_owned()
+            }]
+        );
+        assert_eq!(errors[0].fix(), "app.register_type::<Unregistered>()");
+    }
+
+    #[test]
+    fn finds_unregistered_components() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<WithoutReflectComponent>();
+            type_registry.register::<Reflected>();
+        }
+        world.insert_resource(SomeResource);
+        world.spawn((Unregistered(0), WithoutReflectComponent, Reflected));
+
+        // bevy_ecs has unregistered components of its own, like `OnAdd`
+        let fixes: Vec<_> = unregistered_components(&world)
+            .into_iter()
+            .filter(|component| component.krate == "bevy_inspector_egui")
+            .map(|component| component.fix())
+            .collect();
+        assert_eq!(
+            fixes,
+            vec![
+                ".register_type::<Unregistered>()",
+                "#[reflect(Component)] on WithoutReflectComponent",
+            ]
+        );
+    }
+}
@@ -0,0 +1,199 @@
+//! Structured snapshots of entities, for logging, test assertions or exporting to other tools.
+//!
+//! This collects the same components the entity inspector displays, but returns them as data instead of drawing UI.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::dump::EntityDump;
+//!
+//! fn log_entity(world: &mut World, entity: Entity) {
+//!     if let Some(dump) = EntityDump::collect(world, entity) {
+//!         for (type_path, value) in &dump.components {
+//!             bevy_log::info!("{type_path}: {value:?}");
+//!         }
+//!     }
+//! }
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeRegistry};
+
+use super::archetype_components;
+use crate::{
+    restricted_world_view::{Error, RestrictedWorldView},
+    utils::guess_entity_name::guess_entity_name,
+};
+
+/// The reflected components of an entity at the time of [`EntityDump::collect`]
+#[derive(Debug)]
+pub struct EntityDump {
+    pub entity: Entity,
+    /// The name as displayed in the inspector, like `Light (6v1)`
+    pub name: String,
+    /// Type paths and values of the components
+    pub components: Vec<(String, Box<dyn PartialReflect>)>,
+    /// Names of the components which couldn't be reflected, and why
+    pub errors: Vec<(String, String)>,
+}
+
+impl EntityDump {
+    /// Collects the components of the entity, or returns `None` if it doesn't exist
+    pub fn collect(world: &mut World, entity: Entity) -> Option<EntityDump> {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+
+        let name = guess_entity_name(world, entity);
+        let mut world = RestrictedWorldView::new(world);
+        let mut dump = EntityDump {
+            entity,
+            name,
+            components: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        let cell = world.world();
+        let components = archetype_components(cell, cell.get_entity(entity)?.archetype());
+        for (name, _, component_type_id, _) in components.iter().cloned() {
+            let Some(component_type_id) = component_type_id else {
+                dump.errors
+                    .push((name.to_string(), "not backed by a rust type".to_owned()));
+                continue;
+            };
+            let value =
+                match world.get_entity_component_reflect(entity, component_type_id, &type_registry)
+                {
+                    Ok(value) => owned_value(value.as_partial_reflect(), &type_registry),
+                    Err(error) => {
+                        dump.errors.push((name.to_string(), describe_error(error)));
+                        continue;
+                    }
+                };
+            dump.components
+                .push((value.reflect_type_path().to_owned(), value));
+        }
+
+        Some(dump)
+    }
+
+    /// Serializes the components to JSON, using the same format as `bevy_remote`.
+    ///
+    /// Components which can't be serialized are moved to `errors`.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json(&self, type_registry: &TypeRegistry) -> serde_json::Value {
+        use bevy_reflect::serde::TypedReflectSerializer;
+
+        let mut components = serde_json::Map::new();
+        let mut errors: serde_json::Map<_, _> = self
+            .errors
+            .iter()
+            .map(|(name, error)| (name.clone(), error.clone().into()))
+            .collect();
+        for (type_path, value) in &self.components {
+            match serde_json::to_value(TypedReflectSerializer::new(&**value, type_registry)) {
+                Ok(value) => components.insert(type_path.clone(), value),
+                Err(error) => errors.insert(type_path.clone(), error.to_string().into()),
+            };
+        }
+
+        serde_json::json!({
+            "entity": self.entity.to_string(),
+            "name": self.name,
+            "components": components,
+            "errors": errors,
+        })
+    }
+
+    /// Serializes the components to RON, as a map from type path to value.
+    ///
+    /// Components which can't be serialized are listed as comments.
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self, type_registry: &TypeRegistry) -> String {
+        use std::fmt::Write;
+
+        let mut ron = format!("// {}\n{{\n", self.name);
+        let mut errors = self.errors.clone();
+        for (type_path, value) in &self.components {
+            match crate::utils::ron::serialize(&**value, type_registry, false) {
+                Ok(value) => {
+                    let _ = writeln!(ron, "    {type_path:?}: {value},");
+                }
+                Err(error) => errors.push((type_path.clone(), error)),
+            }
+        }
+        ron.push('}');
+        for (name, error) in errors {
+            let _ = write!(ron, "\n// {name}: {error}");
+        }
+        ron
+    }
+}
+
+/// Snapshot of the entity's components as JSON, see [`EntityDump::to_json`]
+#[cfg(feature = "serde_json")]
+pub fn dump_entity(world: &mut World, entity: Entity) -> Option<serde_json::Value> {
+    let dump = EntityDump::collect(world, entity)?;
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    Some(dump.to_json(&type_registry))
+}
+
+/// Snapshot of the entity's components as RON, see [`EntityDump::to_ron`]
+#[cfg(feature = "ron")]
+pub fn dump_entity_ron(world: &mut World, entity: Entity) -> Option<String> {
+    let dump = EntityDump::collect(world, entity)?;
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    Some(dump.to_ron(&type_registry))
+}
+
+/// Clones the value, as its concrete type if possible so that e.g. `ReflectSerialize` still applies
+fn owned_value(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> Box<dyn PartialReflect> {
+    value
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get_type_data::<ReflectFromReflect>(info.type_id()))
+        .and_then(|from_reflect| from_reflect.from_reflect(value))
+        .map(|value| value.into_partial_reflect())
+        .unwrap_or_else(|| value.clone_value())
+}
+
+fn describe_error(error: Error) -> String {
+    match error {
+        Error::NoTypeRegistration(_) => "not registered in the type registry".to_owned(),
+        Error::NoTypeData(_, data) => format!("missing type data {data}"),
+        error => format!("{error:?}"),
+    }
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Health(f32);
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[test]
+    fn dump_entity_json() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let entity = world.spawn((Health(10.0), Unregistered)).id();
+
+        let dump = super::dump_entity(&mut world, entity).unwrap();
+        let components = dump["components"].as_object().unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(
+            components.values().next().unwrap(),
+            &serde_json::json!(10.0)
+        );
+        assert_eq!(dump["errors"].as_object().unwrap().len(), 1);
+    }
+}
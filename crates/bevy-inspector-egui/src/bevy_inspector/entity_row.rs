@@ -0,0 +1,122 @@
+//! Inline controls at the right end of entity rows in the [hierarchy](super::hierarchy) and the world inspector.
+//!
+//! They are disabled by default, insert the [`EntityRowToggles`] resource (or use
+//! [`WorldInspectorPlugin::entity_row_toggles`](crate::quick::WorldInspectorPlugin::entity_row_toggles)) to enable them.
+//!
+//! ```rust
+//! # use bevy_app::App;
+//! use bevy_inspector_egui::bevy_inspector::entity_row::EntityRowToggles;
+//!
+//! # let mut app = App::new();
+//! app.insert_resource(EntityRowToggles {
+//!     visibility: true,
+//!     render_layers: false,
+//! });
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_render::view::{RenderLayers, Visibility};
+
+/// Which inline controls are shown next to entity names
+#[derive(Resource, Default, Debug, Clone)]
+pub struct EntityRowToggles {
+    /// An eye icon toggling the [`Visibility`] of entities between hidden and inherited
+    pub visibility: bool,
+    /// A badge with the [`RenderLayers`] of entities, which can be edited by clicking it
+    pub render_layers: bool,
+}
+
+impl EntityRowToggles {
+    /// Enable all toggles
+    pub fn all() -> Self {
+        EntityRowToggles {
+            visibility: true,
+            render_layers: true,
+        }
+    }
+}
+
+/// Number of layers offered in the render layers popup, in addition to the ones already used by the entity
+const OFFERED_LAYERS: usize = 8;
+
+/// Draws the enabled toggles for `entity` in its `header`, ending at `right`.
+pub(crate) fn entity_row_toggles(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    header: &egui::Response,
+    mut right: f32,
+) {
+    let Some(settings) = world.get_resource::<EntityRowToggles>().cloned() else {
+        return;
+    };
+
+    let spacing = ui.spacing().item_spacing.x;
+    let mut next_rect = |width: f32| {
+        let rect = egui::Rect::from_min_max(
+            egui::pos2(right - width, header.rect.top()),
+            egui::pos2(right, header.rect.bottom()),
+        );
+        right -= width + spacing;
+        rect
+    };
+
+    if settings.visibility {
+        if let Some(mut visibility) = world.get_mut::<Visibility>(entity) {
+            let visible = *visibility != Visibility::Hidden;
+            let response = ui
+                .put(next_rect(20.0), egui::SelectableLabel::new(visible, "👁"))
+                .on_hover_text(match visible {
+                    true => "Hide",
+                    false => "Show",
+                });
+            if response.clicked() {
+                *visibility = match visible {
+                    true => Visibility::Hidden,
+                    false => Visibility::Inherited,
+                };
+            }
+        }
+    }
+
+    if settings.render_layers && world.get::<Visibility>(entity).is_some() {
+        let current = world.get::<RenderLayers>(entity);
+        let explicit = current.is_some();
+        let layers = current.cloned().unwrap_or_default();
+
+        let badge = layers
+            .iter()
+            .map(|layer| layer.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut badge = egui::RichText::new(format!("L{badge}")).small();
+        if !explicit {
+            badge = badge.weak();
+        }
+
+        let mut new_layers = None;
+        ui.put(next_rect(48.0), |ui: &mut egui::Ui| {
+            ui.menu_button(badge, |ui| {
+                let max_layer = layers.iter().max().unwrap_or(0).max(OFFERED_LAYERS - 1);
+                for layer in 0..=max_layer {
+                    let mut enabled = layers.intersects(&RenderLayers::layer(layer));
+                    if ui
+                        .checkbox(&mut enabled, format!("Layer {layer}"))
+                        .changed()
+                    {
+                        new_layers = Some(match enabled {
+                            true => layers.clone().with(layer),
+                            false => layers.clone().without(layer),
+                        });
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Render layers")
+        });
+
+        if let Some(new_layers) = new_layers {
+            world.entity_mut(entity).insert(new_layers);
+        }
+    }
+}
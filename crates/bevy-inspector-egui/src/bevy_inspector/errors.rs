@@ -155,7 +155,17 @@ pub fn no_type_id(ui: &mut egui::Ui, component_name: &str) {
     ui.label(job);
 }
 
-pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<str> {
+pub fn invalid_reflect_path(ui: &mut egui::Ui, path: &str, error: &str) {
+    let job = layout_job(&[
+        (FontId::proportional(13.0), "Invalid path "),
+        (FontId::monospace(12.0), path),
+        (FontId::proportional(13.0), &format!(": {error}")),
+    ]);
+
+    ui.label(job);
+}
+
+pub fn name_of_type(type_id: TypeId, type_registry: &TypeRegistry) -> Cow<'_, str> {
     type_registry
         .get(type_id)
         .map(|registration| Cow::Borrowed(registration.type_info().type_path_table().short_path()))
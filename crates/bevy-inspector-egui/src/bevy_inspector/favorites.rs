@@ -0,0 +1,363 @@
+//! Entities, resources and assets pinned to the top of the [world inspector](super::ui_for_world).
+//!
+//! Rows in the world inspector get a star button while hovered, which adds them to the [`Favorites`] resource.
+//! Entities are remembered by the [`Name`]s of them and their ancestors, and assets by their path,
+//! so that favorites still point to the right value after the entity index or asset id was reused.
+//!
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::favorites::{Favorite, Favorites};
+//!
+//! fn pin_entity(world: &mut World, entity: Entity) {
+//!     let favorite = Favorite::entity(world, entity);
+//!     world.resource_mut::<Favorites>().insert(favorite);
+//! }
+//! ```
+
+use std::any::TypeId;
+
+use bevy_asset::{AssetPath, AssetServer, UntypedAssetId};
+use bevy_core::Name;
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::TypeRegistry;
+
+use super::{errors::name_of_type, InspectorChanges};
+use crate::utils::guess_entity_name::guess_entity_name;
+
+/// A bookmarked entity, resource or asset in [`Favorites`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Favorite {
+    Entity {
+        /// Names from the root entity down to the entity, if all of them are named
+        path: Option<Vec<String>>,
+        /// The entity the favorite resolved to the last time
+        entity: Entity,
+    },
+    Resource {
+        type_id: TypeId,
+    },
+    Asset {
+        type_id: TypeId,
+        /// The path the asset was loaded from, if any
+        path: Option<AssetPath<'static>>,
+        /// The asset the favorite resolved to the last time
+        id: UntypedAssetId,
+    },
+}
+
+impl Favorite {
+    /// A favorite for `entity`, remembered by its name path if possible
+    pub fn entity(world: &World, entity: Entity) -> Self {
+        Favorite::Entity {
+            path: name_path(world, entity),
+            entity,
+        }
+    }
+
+    pub fn resource(type_id: TypeId) -> Self {
+        Favorite::Resource { type_id }
+    }
+
+    /// A favorite for the asset `id`, remembered by its path if it was loaded from one
+    pub fn asset(world: &World, type_id: TypeId, id: UntypedAssetId) -> Self {
+        let path = world
+            .get_resource::<AssetServer>()
+            .and_then(|server| server.get_path(id))
+            .map(|path| path.into_owned());
+        Favorite::Asset { type_id, path, id }
+    }
+
+    /// Whether `self` and `other` point to the same value
+    fn is(&self, other: &Favorite) -> bool {
+        match (self, other) {
+            (Favorite::Entity { entity: a, .. }, Favorite::Entity { entity: b, .. }) => a == b,
+            (Favorite::Resource { type_id: a }, Favorite::Resource { type_id: b }) => a == b,
+            (Favorite::Asset { id: a, .. }, Favorite::Asset { id: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Looks up the entity or asset again, in case it was despawned and respawned or reloaded
+    fn resolve(&mut self, world: &World) {
+        match self {
+            Favorite::Entity {
+                path: Some(path),
+                entity,
+            } if name_path(world, *entity).as_ref() != Some(path) => {
+                if let Some(resolved) = entity_by_name_path(world, path) {
+                    *entity = resolved;
+                }
+            }
+            Favorite::Asset {
+                type_id,
+                path: Some(path),
+                id,
+            } => {
+                let Some(server) = world.get_resource::<AssetServer>() else {
+                    return;
+                };
+                if let Some(resolved) = server
+                    .get_path_ids(path.clone())
+                    .into_iter()
+                    .find(|id| id.type_id() == *type_id)
+                {
+                    *id = resolved;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the entity, resource or asset currently exists
+    fn exists(&self, world: &World) -> bool {
+        match self {
+            Favorite::Entity { path, entity } => {
+                world.get_entity(*entity).is_ok()
+                    && path
+                        .as_ref()
+                        .is_none_or(|path| name_path(world, *entity).as_ref() == Some(path))
+            }
+            Favorite::Resource { type_id } => world
+                .components()
+                .get_resource_id(*type_id)
+                .is_some_and(|id| world.storages().resources.get(id).is_some()),
+            Favorite::Asset { .. } => true,
+        }
+    }
+
+    fn label(&self, world: &World, type_registry: &TypeRegistry) -> String {
+        match self {
+            Favorite::Entity { entity, .. } => guess_entity_name(world, *entity),
+            Favorite::Resource { type_id } => name_of_type(*type_id, type_registry).into_owned(),
+            Favorite::Asset { type_id, path, id } => {
+                let name = name_of_type(*type_id, type_registry);
+                match path {
+                    Some(path) => format!("{name} {path}"),
+                    None => format!("{name} {id:?}"),
+                }
+            }
+        }
+    }
+}
+
+/// The [`Favorite`]s shown at the top of the world inspector, in the order they were added
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Favorites {
+    favorites: Vec<Favorite>,
+}
+
+impl Favorites {
+    pub fn insert(&mut self, favorite: Favorite) {
+        if !self.contains(&favorite) {
+            self.favorites.push(favorite);
+        }
+    }
+
+    pub fn remove(&mut self, favorite: &Favorite) {
+        self.favorites.retain(|other| !other.is(favorite));
+    }
+
+    /// Removes `favorite` if it is a favorite, and inserts it otherwise
+    pub fn toggle(&mut self, favorite: Favorite) {
+        if self.contains(&favorite) {
+            self.remove(&favorite);
+        } else {
+            self.insert(favorite);
+        }
+    }
+
+    pub fn contains(&self, favorite: &Favorite) -> bool {
+        self.favorites.iter().any(|other| other.is(favorite))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Favorite> {
+        self.favorites.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.favorites.is_empty()
+    }
+
+    /// Looks up the favorite entities and assets again, so that they point to the current ones
+    pub fn resolve(&mut self, world: &World) {
+        for favorite in &mut self.favorites {
+            favorite.resolve(world);
+        }
+    }
+}
+
+/// Names of `entity` and its ancestors, starting at the root, or `None` if one of them has no [`Name`]
+pub(crate) fn name_path(world: &World, entity: Entity) -> Option<Vec<String>> {
+    let mut path = Vec::new();
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        let entity_ref = world.get_entity(entity).ok()?;
+        path.push(entity_ref.get::<Name>()?.as_str().to_owned());
+        current = entity_ref.get::<Parent>().map(Parent::get);
+    }
+    path.reverse();
+    Some(path)
+}
+
+pub(crate) fn entity_by_name_path(world: &World, path: &[String]) -> Option<Entity> {
+    let (root, path) = path.split_first()?;
+    let mut entity = world.iter_entities().find_map(|entity_ref| {
+        (!entity_ref.contains::<Parent>()
+            && entity_ref
+                .get::<Name>()
+                .is_some_and(|name| name.as_str() == root))
+        .then_some(entity_ref.id())
+    })?;
+    for name in path {
+        let children = world.get::<Children>(entity)?;
+        entity = children.iter().copied().find(|&child| {
+            world
+                .get::<Name>(child)
+                .is_some_and(|child_name| child_name.as_str() == name)
+        })?;
+    }
+    Some(entity)
+}
+
+/// A star button at the right end of `header`, which toggles whether `favorite` is in the [`Favorites`] when clicked.
+///
+/// The star is only visible while the header is hovered, unless `favorite` is already a favorite.
+/// Returns the left edge of the button, so that other controls can be placed next to it,
+/// or the right edge of `header` if there is no [`Favorites`] resource.
+pub(crate) fn favorite_star(
+    world: &mut World,
+    favorite: impl FnOnce(&World) -> Favorite,
+    ui: &mut egui::Ui,
+    header: &egui::Response,
+) -> f32 {
+    if !world.contains_resource::<Favorites>() {
+        return header.rect.right();
+    }
+    let favorite = favorite(world);
+    world.resource_scope(|_, mut favorites: Mut<Favorites>| {
+        star(&mut favorites, favorite, ui, header)
+    })
+}
+
+fn star(
+    favorites: &mut Mut<Favorites>,
+    favorite: Favorite,
+    ui: &mut egui::Ui,
+    header: &egui::Response,
+) -> f32 {
+    let width = 20.0;
+    let rect = egui::Rect::from_min_max(
+        egui::pos2(header.rect.right() - width, header.rect.top()),
+        header.rect.right_bottom(),
+    );
+    let left = rect.left() - ui.spacing().item_spacing.x;
+
+    let is_favorite = favorites.contains(&favorite);
+    if !is_favorite && !ui.rect_contains_pointer(header.rect) {
+        return left;
+    }
+    let response = ui
+        .put(
+            rect,
+            egui::SelectableLabel::new(is_favorite, if is_favorite { "★" } else { "☆" }),
+        )
+        .on_hover_text(match is_favorite {
+            true => "Remove from favorites",
+            false => "Add to favorites",
+        });
+    if response.clicked() {
+        favorites.toggle(favorite);
+    }
+    left
+}
+
+/// Display the [`Favorites`], each in a collapsible section with a star to remove it again
+pub fn ui_for_favorites(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    favorites_ui(world, ui, &type_registry)
+}
+
+pub(crate) fn favorites_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+) -> InspectorChanges {
+    let mut changes = InspectorChanges::default();
+    if !world.contains_resource::<Favorites>() {
+        return changes;
+    }
+
+    world.resource_scope(|world, mut favorites: Mut<Favorites>| {
+        favorites.bypass_change_detection().resolve(world);
+
+        let id = egui::Id::new("favorites");
+        for favorite in favorites.favorites.clone() {
+            let label = favorite.label(world, type_registry);
+            let header_id = id.with(label.as_str());
+            let response = egui::CollapsingHeader::new(&label)
+                .id_salt(header_id)
+                .enabled(favorite.exists(world))
+                .show(ui, |ui| match favorite {
+                    Favorite::Entity { entity, .. } => {
+                        let mut queue = CommandQueue::default();
+                        changes.extend(super::ui_for_entity_components(
+                            &mut world.into(),
+                            Some(&mut queue),
+                            entity,
+                            ui,
+                            header_id,
+                            type_registry,
+                        ));
+                        queue.apply(world);
+                    }
+                    Favorite::Resource { type_id } => {
+                        if super::by_type_id::ui_for_resource(
+                            world,
+                            type_id,
+                            ui,
+                            &label,
+                            type_registry,
+                        ) {
+                            changes.push_resource(type_id);
+                        }
+                    }
+                    Favorite::Asset { type_id, id, .. } => {
+                        if super::by_type_id::ui_for_asset(world, type_id, id, ui, type_registry) {
+                            changes.push_asset(id);
+                        }
+                    }
+                });
+            star(&mut favorites, favorite, ui, &response.header_response);
+        }
+    });
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+
+    use super::{Favorite, Favorites};
+
+    #[test]
+    fn entity_favorites_survive_respawning() {
+        let mut world = World::new();
+        let root = world.spawn(Name::new("Level")).id();
+        let player = world.spawn(Name::new("Player")).set_parent(root).id();
+
+        let mut favorites = Favorites::default();
+        favorites.insert(Favorite::entity(&world, player));
+
+        world.entity_mut(player).despawn();
+        let respawned = world.spawn(Name::new("Player")).set_parent(root).id();
+        world.spawn(Name::new("Player"));
+
+        favorites.resolve(&world);
+        assert!(favorites.contains(&Favorite::entity(&world, respawned)));
+    }
+}
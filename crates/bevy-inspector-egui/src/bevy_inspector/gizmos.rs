@@ -0,0 +1,310 @@
+//! Gizmos drawn into the viewport for entities of the inspector.
+//!
+//! While an entity row in the [hierarchy](super::hierarchy) or the world inspector is hovered, the
+//! [`Aabb`]s of the entity and its descendants are outlined, or its axes are drawn if it has no bounds.
+//!
+//! While an entity is shown in the inspector, the frustum of cameras, the range of lights and the
+//! bounds of entities can be drawn as well. They are toggled per entity by buttons above its components,
+//! and configured by the [`DebugDrawSettings`] resource.
+//!
+//! The quick plugins and the editor add the [`InspectorGizmosPlugin`] automatically. Gizmos are only drawn
+//! if the `GizmoPlugin` of bevy is added as well.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::gizmos::InspectorGizmosPlugin;
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(InspectorGizmosPlugin);
+//! ```
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_gizmos::{config::GizmoConfigStore, gizmos::Gizmos};
+use bevy_hierarchy::{Children, HierarchyQueryExt};
+use bevy_math::Vec3;
+use bevy_render::{
+    camera::{Camera, CameraProjection, OrthographicProjection, Projection},
+    primitives::Aabb,
+};
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+use bevy_utils::{HashMap, HashSet};
+
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// Color of the outline of the hovered entity
+const HOVER_COLOR: Color = Color::srgb(1.0, 0.8, 0.0);
+/// Descendants beyond this number are not outlined, so that hovering large scenes stays cheap
+const MAX_OUTLINED_ENTITIES: usize = 256;
+
+/// The entity whose row is hovered in the inspector this frame
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct HoveredEntity(pub Option<Entity>);
+
+/// Outlines the entity hovered in the inspector
+pub struct InspectorGizmosPlugin;
+
+impl Plugin for InspectorGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HoveredEntity>()
+            .init_resource::<DebugDrawSettings>()
+            .add_systems(
+                PostUpdate,
+                (draw_hovered_entity, draw_inspected_entities)
+                    .after(TransformSystem::TransformPropagate)
+                    .run_if(resource_exists::<GizmoConfigStore>),
+            );
+    }
+}
+
+/// Records `entity` as the [`HoveredEntity`] if its row `header` is hovered
+pub(crate) fn track_hovered_entity(world: &mut World, entity: Entity, header: &egui::Response) {
+    if !header.hovered() {
+        return;
+    }
+    if let Some(mut hovered) = world.get_resource_mut::<HoveredEntity>() {
+        hovered.0 = Some(entity);
+    }
+}
+
+/// Outlines the bounds of the [`HoveredEntity`] and its descendants, and resets it for the next frame
+fn draw_hovered_entity(
+    mut hovered: ResMut<HoveredEntity>,
+    mut gizmos: Gizmos,
+    transforms: Query<(&GlobalTransform, Option<&Aabb>)>,
+    children: Query<&Children>,
+) {
+    let Some(entity) = hovered.bypass_change_detection().0.take() else {
+        return;
+    };
+    let Ok((root_transform, _)) = transforms.get(entity) else {
+        return;
+    };
+
+    let mut outlined = 0;
+    let entities = std::iter::once(entity).chain(children.iter_descendants(entity));
+    for (transform, aabb) in transforms.iter_many(entities).take(MAX_OUTLINED_ENTITIES) {
+        let Some(aabb) = aabb else {
+            continue;
+        };
+        draw_aabb(&mut gizmos, transform, aabb, HOVER_COLOR);
+        outlined += 1;
+    }
+
+    if outlined == 0 {
+        gizmos.axes(*root_transform, 1.0);
+    }
+}
+
+fn draw_aabb(gizmos: &mut Gizmos, transform: &GlobalTransform, aabb: &Aabb, color: Color) {
+    let bounds = GlobalTransform::from_translation(Vec3::from(aabb.center))
+        * GlobalTransform::from_scale(Vec3::from(aabb.half_extents) * 2.0);
+    gizmos.cuboid(*transform * bounds, color);
+}
+
+/// Which gizmos are drawn for an entity while it is inspected
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebugDraw {
+    /// The frustum of a [`Camera`]
+    pub frustum: bool,
+    /// The range of a point light, or the cone of a spot light
+    pub light: bool,
+    /// The [`Aabb`]
+    pub aabb: bool,
+}
+
+/// Settings for the gizmos drawn for the entities shown in the inspector
+#[derive(Resource, Debug, Clone)]
+pub struct DebugDrawSettings {
+    /// What is drawn for entities which weren't toggled in the inspector yet
+    pub default: DebugDraw,
+    /// Frustums are cut off at this distance, since the far plane is usually very far away
+    pub max_frustum_distance: f32,
+    pub color: Color,
+    entities: HashMap<Entity, DebugDraw>,
+    inspected: HashSet<Entity>,
+}
+
+impl Default for DebugDrawSettings {
+    fn default() -> Self {
+        DebugDrawSettings {
+            default: DebugDraw::default(),
+            max_frustum_distance: 20.0,
+            color: Color::srgb(0.3, 0.8, 1.0),
+            entities: HashMap::default(),
+            inspected: HashSet::default(),
+        }
+    }
+}
+
+impl DebugDrawSettings {
+    /// What is drawn for `entity` while it is inspected
+    pub fn get(&self, entity: Entity) -> DebugDraw {
+        self.entities.get(&entity).copied().unwrap_or(self.default)
+    }
+
+    pub fn set(&mut self, entity: Entity, draw: DebugDraw) {
+        self.entities.insert(entity, draw);
+    }
+}
+
+/// Buttons toggling the [`DebugDraw`] of `entity`, for the kinds of gizmos that apply to it.
+///
+/// Also marks `entity` as inspected for this frame, so that its gizmos are drawn.
+pub(crate) fn debug_draw_toggles(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+) {
+    let Some(entity_ref) = world.world().get_entity(entity) else {
+        return;
+    };
+    let is_camera = entity_ref.contains::<Camera>();
+    let has_aabb = entity_ref.contains::<Aabb>();
+    #[cfg(feature = "bevy_pbr")]
+    let is_light = entity_ref.contains::<bevy_pbr::PointLight>()
+        || entity_ref.contains::<bevy_pbr::SpotLight>()
+        || entity_ref.contains::<bevy_pbr::DirectionalLight>();
+    #[cfg(not(feature = "bevy_pbr"))]
+    let is_light = false;
+    if !is_camera && !has_aabb && !is_light {
+        return;
+    }
+
+    let Ok(mut settings) = world.get_resource_mut::<DebugDrawSettings>() else {
+        return;
+    };
+    let settings = settings.bypass_change_detection();
+    settings.inspected.insert(entity);
+
+    let mut draw = settings.get(entity);
+    ui.horizontal(|ui| {
+        ui.weak("Gizmos");
+        if is_camera {
+            ui.toggle_value(&mut draw.frustum, "Frustum");
+        }
+        if is_light {
+            ui.toggle_value(&mut draw.light, "Light");
+        }
+        if has_aabb {
+            ui.toggle_value(&mut draw.aabb, "Bounds");
+        }
+    });
+    if draw != settings.get(entity) {
+        settings.set(entity, draw);
+    }
+}
+
+/// Draws the enabled gizmos of the entities inspected this frame, and resets them for the next frame
+#[allow(clippy::type_complexity)]
+fn draw_inspected_entities(
+    mut settings: ResMut<DebugDrawSettings>,
+    mut gizmos: Gizmos,
+    transforms: Query<&GlobalTransform>,
+    cameras: Query<(Option<&Projection>, Option<&OrthographicProjection>), With<Camera>>,
+    aabbs: Query<&Aabb>,
+    #[cfg(feature = "bevy_pbr")] lights: Query<(
+        Option<&bevy_pbr::PointLight>,
+        Option<&bevy_pbr::SpotLight>,
+        Option<&bevy_pbr::DirectionalLight>,
+    )>,
+) {
+    let settings = settings.bypass_change_detection();
+    let color = settings.color;
+    for entity in std::mem::take(&mut settings.inspected) {
+        let draw = settings.get(entity);
+        let Ok(transform) = transforms.get(entity) else {
+            continue;
+        };
+
+        if draw.frustum {
+            if let Ok((projection, orthographic)) = cameras.get(entity) {
+                let max_far = settings.max_frustum_distance;
+                let corners = match (projection, orthographic) {
+                    (Some(Projection::Perspective(perspective)), _) => {
+                        Some(frustum_corners(perspective, perspective.near, max_far))
+                    }
+                    (Some(Projection::Orthographic(orthographic)), _)
+                    | (None, Some(orthographic)) => {
+                        Some(frustum_corners(orthographic, orthographic.near, max_far))
+                    }
+                    (None, None) => None,
+                };
+                if let Some(corners) = corners {
+                    draw_frustum(&mut gizmos, transform, corners, color);
+                }
+            }
+        }
+
+        if draw.aabb {
+            if let Ok(aabb) = aabbs.get(entity) {
+                draw_aabb(&mut gizmos, transform, aabb, color);
+            }
+        }
+
+        #[cfg(feature = "bevy_pbr")]
+        if draw.light {
+            if let Ok(light) = lights.get(entity) {
+                draw_light(&mut gizmos, transform, light, color);
+            }
+        }
+    }
+}
+
+/// Corners of the view frustum in view space, with the near and far planes at most `max_far` away
+fn frustum_corners(projection: &impl CameraProjection, near: f32, max_far: f32) -> [Vec3; 8] {
+    let near = near.max(-max_far);
+    let far = projection.far().min(max_far);
+    projection.get_frustum_corners(-near, -far).map(Vec3::from)
+}
+
+fn draw_frustum(
+    gizmos: &mut Gizmos,
+    transform: &GlobalTransform,
+    corners: [Vec3; 8],
+    color: Color,
+) {
+    let corners = corners.map(|corner| transform.transform_point(corner));
+    for i in 0..4 {
+        let next = (i + 1) % 4;
+        gizmos.line(corners[i], corners[next], color);
+        gizmos.line(corners[i + 4], corners[next + 4], color);
+        gizmos.line(corners[i], corners[i + 4], color);
+    }
+}
+
+#[cfg(feature = "bevy_pbr")]
+fn draw_light(
+    gizmos: &mut Gizmos,
+    transform: &GlobalTransform,
+    (point, spot, directional): (
+        Option<&bevy_pbr::PointLight>,
+        Option<&bevy_pbr::SpotLight>,
+        Option<&bevy_pbr::DirectionalLight>,
+    ),
+    color: Color,
+) {
+    let position = transform.translation();
+    let rotation = transform.compute_transform().rotation;
+    if let Some(point) = point {
+        gizmos.sphere(
+            bevy_math::Isometry3d::new(position, rotation),
+            point.range,
+            color,
+        );
+    }
+    if let Some(spot) = spot {
+        let distance = spot.range * spot.outer_angle.cos();
+        let radius = spot.range * spot.outer_angle.sin();
+        let center = position + rotation * Vec3::new(0.0, 0.0, -distance);
+        gizmos.circle(bevy_math::Isometry3d::new(center, rotation), radius, color);
+        for direction in [Vec3::X, Vec3::NEG_X, Vec3::Y, Vec3::NEG_Y] {
+            gizmos.line(position, center + rotation * (direction * radius), color);
+        }
+    }
+    if directional.is_some() {
+        gizmos.arrow(position, position + transform.forward() * 2.0, color);
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
-use crate::bevy_inspector::{EntityFilter, Filter};
+use crate::bevy_inspector::{auto_expand, EntityFilter, Filter};
 use crate::utils::guess_entity_name;
-use bevy_ecs::{prelude::*, query::QueryFilter};
+use bevy_core::Name;
+use bevy_ecs::{prelude::*, query::QueryFilter, world::CommandQueue};
 use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::TypeRegistry;
 use egui::{CollapsingHeader, RichText};
@@ -64,9 +65,23 @@ impl<T> Hierarchy<'_, T> {
     {
         let mut root_query = self.world.query_filtered::<Entity, (Without<Parent>, QF)>();
 
+        let reveal = ui.data(|data| data.get_temp::<RevealRequest>(reveal_id()));
+        let mut selected = false;
+        if let Some(RevealRequest {
+            entity,
+            select: true,
+        }) = reveal
+        {
+            if self.world.get_entity(entity).is_ok() {
+                self.selected.select_replace(entity);
+                selected = true;
+            }
+        }
+
         let always_open: HashSet<Entity> = self
             .selected
             .iter()
+            .chain(reveal.map(|reveal| reveal.entity))
             .flat_map(|selected| {
                 std::iter::successors(Some(selected), |&entity| {
                     self.world.get::<Parent>(entity).map(|parent| parent.get())
@@ -75,13 +90,28 @@ impl<T> Hierarchy<'_, T> {
             })
             .collect();
 
+        let auto_expand =
+            auto_expand::entities_to_expand(self.world, &filter, self.type_registry, false);
+
         let mut entities: Vec<_> = root_query.iter(self.world).collect();
-        filter.filter_entities(self.world, &mut entities);
+        filter.filter_entities_with_registry(self.world, &mut entities, self.type_registry);
         entities.sort();
 
-        let mut selected = false;
+        let mut queue = CommandQueue::default();
         for &entity in &entities {
-            selected |= self.entity_ui(ui, entity, &always_open, &entities, &filter);
+            selected |= self.entity_ui(
+                ui,
+                entity,
+                &always_open,
+                &auto_expand,
+                &entities,
+                &filter,
+                &mut queue,
+            );
+        }
+        queue.apply(self.world);
+        if reveal.is_some() {
+            ui.data_mut(|data| data.remove::<RevealRequest>(reveal_id()));
         }
         selected
     }
@@ -91,8 +121,10 @@ impl<T> Hierarchy<'_, T> {
         ui: &mut egui::Ui,
         entity: Entity,
         always_open: &HashSet<Entity>,
+        auto_expand: &bevy_utils::HashSet<Entity>,
         at_same_level: &[Entity],
         filter: &F,
+        queue: &mut CommandQueue,
     ) -> bool
     where
         F: EntityFilter,
@@ -101,15 +133,19 @@ impl<T> Hierarchy<'_, T> {
         let selected = self.selected.contains(entity);
 
         let entity_name = guess_entity_name::guess_entity_name(self.world, entity);
-        let mut name = RichText::new(entity_name);
+        let mut name = match guess_entity_name::guess_entity_icon(self.world, entity) {
+            Some(icon) => RichText::new(format!("{icon} {entity_name}")),
+            None => RichText::new(entity_name),
+        };
         if selected {
             name = name.strong();
         }
 
-        let has_children = self
+        let child_count = self
             .world
             .get::<Children>(entity)
-            .is_some_and(|children| children.len() > 0);
+            .map_or(0, |children| children.len());
+        let has_children = child_count > 0;
 
         let open = if !has_children {
             Some(false)
@@ -125,8 +161,37 @@ impl<T> Hierarchy<'_, T> {
             }
         }
 
+        if rename_ui(ui, entity, queue) {
+            return false;
+        }
+
+        let mut label = egui::text::LayoutJob::default();
+        name.append_to(
+            &mut label,
+            ui.style(),
+            egui::FontSelection::Default,
+            egui::Align::Center,
+        );
+        if has_children {
+            let header_id = ui.make_persistent_id(entity);
+            auto_expand::expand_once(ui, header_id, auto_expand.contains(&entity));
+
+            let is_open = open.unwrap_or_else(|| {
+                egui::collapsing_header::CollapsingState::load(ui.ctx(), header_id)
+                    .is_some_and(|state| state.is_open())
+            });
+            if !is_open {
+                RichText::new(format!(" ({child_count})")).weak().append_to(
+                    &mut label,
+                    ui.style(),
+                    egui::FontSelection::Default,
+                    egui::Align::Center,
+                );
+            }
+        }
+
         #[allow(deprecated)] // the suggested replacement doesn't really work
-        let response = CollapsingHeader::new(name)
+        let response = CollapsingHeader::new(label)
             .id_source(entity)
             .icon(move |ui, openness, response| {
                 if !has_children {
@@ -139,15 +204,48 @@ impl<T> Hierarchy<'_, T> {
                 let children = self.world.get::<Children>(entity);
                 if let Some(children) = children {
                     let mut children = children.to_vec();
-                    filter.filter_entities(self.world, &mut children);
+                    filter.filter_entities_with_registry(
+                        self.world,
+                        &mut children,
+                        self.type_registry,
+                    );
                     for &child in &children {
-                        new_selection |= self.entity_ui(ui, child, always_open, &children, filter);
+                        new_selection |= self.entity_ui(
+                            ui,
+                            child,
+                            always_open,
+                            auto_expand,
+                            &children,
+                            filter,
+                            queue,
+                        );
                     }
                 } else {
                     ui.label("No children");
                 }
             });
         let header_response = response.header_response;
+        #[cfg(feature = "bevy_render")]
+        super::entity_row::entity_row_toggles(
+            self.world,
+            entity,
+            ui,
+            &header_response,
+            header_response.rect.right(),
+        );
+        #[cfg(feature = "bevy_gizmos")]
+        super::gizmos::track_hovered_entity(self.world, entity, &header_response);
+
+        if ui
+            .data(|data| data.get_temp::<RevealRequest>(reveal_id()))
+            .is_some_and(|reveal| reveal.entity == entity)
+        {
+            header_response.scroll_to_me(Some(egui::Align::Center));
+        }
+
+        if header_response.double_clicked() {
+            start_rename(ui.ctx(), entity, self.world.get::<Name>(entity));
+        }
 
         if header_response.clicked() {
             let selection_mode = ui.input(|input| {
@@ -179,6 +277,136 @@ impl<T> Hierarchy<'_, T> {
     }
 }
 
+/// The entity whose [`Name`] is being edited in the hierarchy
+#[derive(Clone)]
+struct RenameState {
+    entity: Entity,
+    text: String,
+    focused: bool,
+}
+
+fn rename_id() -> egui::Id {
+    egui::Id::new("hierarchy_rename")
+}
+
+fn start_rename(ctx: &egui::Context, entity: Entity, name: Option<&Name>) {
+    let state = RenameState {
+        entity,
+        text: name
+            .map(|name| name.as_str().to_owned())
+            .unwrap_or_default(),
+        focused: false,
+    };
+    ctx.data_mut(|data| data.insert_temp(rename_id(), state));
+}
+
+/// Displays a text field instead of the header of `entity` while it is being renamed, and returns whether it did.
+///
+/// Enter or clicking elsewhere queues inserting the [`Name`], or removing it if the text is empty. Escape cancels.
+fn rename_ui(ui: &mut egui::Ui, entity: Entity, queue: &mut CommandQueue) -> bool {
+    let Some(mut state) = ui
+        .data(|data| data.get_temp::<RenameState>(rename_id()))
+        .filter(|state| state.entity == entity)
+    else {
+        return false;
+    };
+
+    let response = ui.add(egui::TextEdit::singleline(&mut state.text).hint_text("Name"));
+    if !state.focused {
+        response.request_focus();
+        state.focused = true;
+    }
+
+    if response.lost_focus() {
+        ui.data_mut(|data| data.remove::<RenameState>(rename_id()));
+        if !ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+            let text = state.text.trim().to_owned();
+            queue.push(move |world: &mut World| {
+                let Ok(mut entity) = world.get_entity_mut(entity) else {
+                    return;
+                };
+                match text.is_empty() {
+                    true => entity.remove::<Name>(),
+                    false => entity.insert(Name::new(text)),
+                };
+            });
+        }
+    } else {
+        ui.data_mut(|data| data.insert_temp(rename_id(), state));
+    }
+    true
+}
+
+#[derive(Clone, Copy)]
+struct RevealRequest {
+    entity: Entity,
+    select: bool,
+}
+
+fn reveal_id() -> egui::Id {
+    egui::Id::new("hierarchy_reveal")
+}
+
+/// Expand the [`Hierarchy`] down to `entity` and scroll it into view the next time it is displayed
+pub fn reveal_in_hierarchy(ctx: &egui::Context, entity: Entity) {
+    ctx.data_mut(|data| {
+        data.insert_temp(
+            reveal_id(),
+            RevealRequest {
+                entity,
+                select: false,
+            },
+        )
+    });
+}
+
+/// Select `entity` in the [`Hierarchy`] the next time it is displayed, and reveal it like [`reveal_in_hierarchy`]
+pub fn select_in_hierarchy(ctx: &egui::Context, entity: Entity) {
+    ctx.data_mut(|data| {
+        data.insert_temp(
+            reveal_id(),
+            RevealRequest {
+                entity,
+                select: true,
+            },
+        )
+    });
+}
+
+/// Display the path of ancestors of `entity` like `Level › Player › Sword`, followed by a button to [reveal it in the hierarchy](reveal_in_hierarchy).
+///
+/// Clicking an ancestor [selects it in the hierarchy](select_in_hierarchy), and returns it.
+pub fn breadcrumbs_ui(world: &World, entity: Entity, ui: &mut egui::Ui) -> Option<Entity> {
+    let mut ancestors: Vec<_> = std::iter::successors(Some(entity), |&entity| {
+        world.get::<Parent>(entity).map(Parent::get)
+    })
+    .skip(1)
+    .collect();
+    ancestors.reverse();
+
+    let mut clicked = None;
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        for ancestor in ancestors {
+            let name = guess_entity_name::guess_entity_name(world, ancestor);
+            if ui.link(name).on_hover_text("Select").clicked() {
+                select_in_hierarchy(ui.ctx(), ancestor);
+                clicked = Some(ancestor);
+            }
+            ui.weak("›");
+        }
+        ui.strong(guess_entity_name::guess_entity_name(world, entity));
+        if ui
+            .small_button("⌖")
+            .on_hover_text("Reveal in hierarchy")
+            .clicked()
+        {
+            reveal_in_hierarchy(ui.ctx(), entity);
+        }
+    });
+    clicked
+}
+
 fn paint_default_icon(ui: &mut egui::Ui, openness: f32, response: &egui::Response) {
     let visuals = ui.style().interact(response);
     let stroke = visuals.fg_stroke;
@@ -331,3 +559,72 @@ impl SelectedEntities {
         self.entities.as_slice()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+
+    use super::SelectedEntities;
+
+    #[test]
+    fn select_in_hierarchy_selects_nested_entity() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let root = world.spawn(Name::new("Level")).id();
+        let player = world.spawn(Name::new("Player")).set_parent(root).id();
+        let sword = world.spawn(Name::new("Sword")).set_parent(player).id();
+
+        let ctx = egui::Context::default();
+        super::select_in_hierarchy(&ctx, sword);
+
+        let mut selected_entities = SelectedEntities::default();
+        let mut new_selection = false;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                new_selection = super::hierarchy_ui(&mut world, ui, &mut selected_entities);
+            });
+        });
+        assert!(new_selection);
+        assert_eq!(selected_entities.as_slice(), &[sword]);
+
+        // the request is only handled once
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                new_selection = super::hierarchy_ui(&mut world, ui, &mut selected_entities);
+            });
+        });
+        assert!(!new_selection);
+    }
+    #[test]
+    fn rename_inserts_name() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let entity = world.spawn_empty().id();
+
+        let ctx = egui::Context::default();
+        super::start_rename(&ctx, entity, None);
+
+        let mut selected_entities = SelectedEntities::default();
+        let enter = egui::Event::Key {
+            key: egui::Key::Enter,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        for events in [vec![], vec![egui::Event::Text("Sword".into())], vec![enter]] {
+            let input = egui::RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    super::hierarchy_ui(&mut world, ui, &mut selected_entities);
+                });
+            });
+        }
+        assert_eq!(world.get::<Name>(entity).unwrap().as_str(), "Sword");
+    }
+}
@@ -0,0 +1,247 @@
+//! Rolling history of numeric resource fields, plotted as a sparkline below the field.
+//!
+//! Recording is opt-in per field, by right clicking the field label in the resource inspector
+//! or by adding it to the [`ValueHistory`] directly.
+//! The [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin) records the values once per frame.
+//!
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_reflect::Reflect;
+//! use bevy_inspector_egui::bevy_inspector::history::ValueHistory;
+//!
+//! #[derive(Resource, Reflect)]
+//! #[reflect(Resource)]
+//! struct Physics {
+//!     gravity: f32,
+//! }
+//!
+//! fn record_gravity(mut history: ResMut<ValueHistory>) {
+//!     history.record::<Physics>(".gravity");
+//! }
+//! ```
+
+use std::{any::TypeId, collections::VecDeque};
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::{GetPath, PartialReflect};
+use bevy_time::{Real, Time};
+
+/// The recorded resource fields, and for how long their values are kept
+#[derive(Resource, Debug, Clone)]
+pub struct ValueHistory {
+    /// How many seconds of history are kept
+    pub duration: f64,
+    fields: Vec<RecordedField>,
+}
+
+impl Default for ValueHistory {
+    fn default() -> Self {
+        ValueHistory {
+            duration: 10.0,
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl ValueHistory {
+    /// Start recording the field at `path` of the resource `R`, e.g. `.gravity`
+    pub fn record<R: Resource>(&mut self, path: impl Into<String>) {
+        self.record_by_type_id(TypeId::of::<R>(), path);
+    }
+
+    /// Start recording the field at `path` of the resource with the given [`TypeId`]
+    pub fn record_by_type_id(&mut self, resource: TypeId, path: impl Into<String>) {
+        let path = path.into();
+        if self.get(resource, &path).is_none() {
+            self.fields.push(RecordedField {
+                resource,
+                path,
+                show_plot: true,
+                samples: VecDeque::new(),
+            });
+        }
+    }
+
+    /// Stop recording the field and forget its history
+    pub fn stop(&mut self, resource: TypeId, path: &str) {
+        self.fields
+            .retain(|field| field.resource != resource || field.path != path);
+    }
+
+    pub fn get(&self, resource: TypeId, path: &str) -> Option<&RecordedField> {
+        self.fields
+            .iter()
+            .find(|field| field.resource == resource && field.path == path)
+    }
+
+    pub fn get_mut(&mut self, resource: TypeId, path: &str) -> Option<&mut RecordedField> {
+        self.fields
+            .iter_mut()
+            .find(|field| field.resource == resource && field.path == path)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RecordedField> {
+        self.fields.iter()
+    }
+}
+
+/// A recorded field of a resource, with its samples of the last [`ValueHistory::duration`] seconds
+#[derive(Debug, Clone)]
+pub struct RecordedField {
+    /// The [`TypeId`] of the resource
+    pub resource: TypeId,
+    /// Path of the field inside the resource, e.g. `.gravity`
+    pub path: String,
+    /// Whether the [`sparkline`] is displayed below the field
+    pub show_plot: bool,
+    samples: VecDeque<(f64, f64)>,
+}
+
+impl RecordedField {
+    /// The recorded `(seconds, value)` pairs, oldest first
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = (f64, f64)> + '_ {
+        self.samples.iter().copied()
+    }
+}
+
+/// Records the fields of the [`ValueHistory`], using the real time since startup.
+///
+/// Fields which aren't numbers are skipped.
+pub fn record_value_history(world: &mut World) {
+    let Some(now) = world
+        .get_resource::<Time<Real>>()
+        .map(|time| time.elapsed_secs_f64())
+    else {
+        return;
+    };
+    if world
+        .get_resource::<ValueHistory>()
+        .is_none_or(|history| history.fields.is_empty())
+    {
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    world.resource_scope(|world, mut history: Mut<ValueHistory>| {
+        let history = history.bypass_change_detection();
+        let duration = history.duration;
+        for field in &mut history.fields {
+            let value = type_registry
+                .get_type_data::<ReflectResource>(field.resource)
+                .and_then(|reflect_resource| reflect_resource.reflect(world))
+                .and_then(|resource| resource.reflect_path(field.path.as_str()).ok())
+                .and_then(as_f64);
+            if let Some(value) = value {
+                field.samples.push_back((now, value));
+            }
+            while field
+                .samples
+                .front()
+                .is_some_and(|&(time, _)| now - time > duration)
+            {
+                field.samples.pop_front();
+            }
+        }
+    });
+}
+
+fn as_f64(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! numbers {
+        ($($ty:ty),*) => {
+            $(if let Some(value) = value.try_downcast_ref::<$ty>() {
+                return Some(*value as f64);
+            })*
+        };
+    }
+    numbers!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+/// Draws the samples of `field` as a line scaled to their minimum and maximum, with the values as tooltip
+pub fn sparkline(ui: &mut egui::Ui, field: &RecordedField) {
+    let width = ui.available_width().clamp(80.0, 200.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, 24.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    let (Some(&(start, _)), Some(&(end, last))) = (field.samples.front(), field.samples.back())
+    else {
+        return;
+    };
+    let (min, max) = field.samples.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), &(_, value)| (min.min(value), max.max(value)),
+    );
+    let time_span = (end - start).max(f64::EPSILON);
+    let value_span = (max - min).max(f64::EPSILON);
+
+    let rect = rect.shrink(2.0);
+    let points = field
+        .samples
+        .iter()
+        .map(|&(time, value)| {
+            egui::pos2(
+                rect.left() + ((time - start) / time_span) as f32 * rect.width(),
+                rect.bottom() - ((value - min) / value_span) as f32 * rect.height(),
+            )
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.0, ui.visuals().selection.stroke.color),
+    ));
+    response.on_hover_text(format!(
+        "last {last:.3}\nmin {min:.3}\nmax {max:.3}\nover {time_span:.1}s"
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+    use bevy_time::{Real, Time};
+
+    use super::ValueHistory;
+
+    #[derive(Resource, Reflect)]
+    #[reflect(Resource)]
+    struct Physics {
+        gravity: f32,
+        name: String,
+    }
+
+    #[test]
+    fn records_numeric_fields() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Physics>();
+        world.insert_resource(Time::<Real>::default());
+        world.insert_resource(Physics {
+            gravity: 9.8,
+            name: "earth".into(),
+        });
+        let mut history = ValueHistory::default();
+        history.record::<Physics>(".gravity");
+        history.record::<Physics>(".name");
+        world.insert_resource(history);
+
+        super::record_value_history(&mut world);
+        world.resource_mut::<Physics>().gravity = 1.6;
+        super::record_value_history(&mut world);
+
+        let history = world.resource::<ValueHistory>();
+        let physics = std::any::TypeId::of::<Physics>();
+        let gravity: Vec<_> = history
+            .get(physics, ".gravity")
+            .unwrap()
+            .samples()
+            .map(|(_, value)| value as f32)
+            .collect();
+        assert_eq!(gravity, vec![9.8, 1.6]);
+        assert_eq!(history.get(physics, ".name").unwrap().samples().len(), 0);
+    }
+}
@@ -0,0 +1,206 @@
+//! Inspectors locked to an entity, which keep displaying it while the selection changes.
+//!
+//! [`InspectorTabs`] keeps track of the locked entities, and [`ui_for_inspector_tabs`] displays a tab for the current
+//! [selection](SelectedEntities) next to a tab for each of them.
+//! The [editor](crate::editor) uses the same state, but opens a separate dock tab for every locked entity.
+//!
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::{
+//!     hierarchy::SelectedEntities,
+//!     inspector_tabs::{self, InspectorTabs},
+//! };
+//!
+//! fn inspector_ui(
+//!     world: &mut World,
+//!     ui: &mut egui::Ui,
+//!     selected: &SelectedEntities,
+//!     tabs: &mut InspectorTabs,
+//! ) {
+//!     inspector_tabs::ui_for_inspector_tabs(world, ui, selected, tabs);
+//! }
+//! ```
+
+use bevy_ecs::prelude::*;
+
+use super::{hierarchy::SelectedEntities, InspectorChanges};
+use crate::utils::guess_entity_name::guess_entity_name;
+
+/// The entities locked into their own inspector tab, and which tab is open
+#[derive(Debug, Default, Clone)]
+pub struct InspectorTabs {
+    locked: Vec<Entity>,
+    /// The locked entity whose tab is open, or `None` for the selection
+    active: Option<Entity>,
+}
+
+impl InspectorTabs {
+    /// Keep displaying `entity` in its own tab, and open that tab
+    pub fn lock(&mut self, entity: Entity) {
+        if !self.is_locked(entity) {
+            self.locked.push(entity);
+        }
+        self.active = Some(entity);
+    }
+
+    /// Close the tab of `entity`, going back to the selection if it was open
+    pub fn unlock(&mut self, entity: Entity) {
+        self.locked.retain(|&locked| locked != entity);
+        if self.active == Some(entity) {
+            self.active = None;
+        }
+    }
+
+    pub fn is_locked(&self, entity: Entity) -> bool {
+        self.locked.contains(&entity)
+    }
+
+    /// The locked entities, in the order they were locked
+    pub fn locked(&self) -> &[Entity] {
+        &self.locked
+    }
+
+    /// The locked entity whose tab is open, or `None` for the selection
+    pub fn active(&self) -> Option<Entity> {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: Option<Entity>) {
+        self.active = active.filter(|&entity| self.is_locked(entity));
+    }
+
+    /// Unlock the entities which were despawned
+    pub fn retain_existing(&mut self, world: &World) {
+        let despawned: Vec<_> = self
+            .locked
+            .iter()
+            .copied()
+            .filter(|&entity| world.get_entity(entity).is_err())
+            .collect();
+        for entity in despawned {
+            self.unlock(entity);
+        }
+    }
+}
+
+/// A toggle locking or unlocking `entity` in `tabs`
+pub fn lock_toggle(ui: &mut egui::Ui, tabs: &mut InspectorTabs, entity: Entity) {
+    let locked = tabs.is_locked(entity);
+    let response = ui
+        .selectable_label(locked, if locked { "🔒" } else { "🔓" })
+        .on_hover_text(match locked {
+            true => "Unlock",
+            false => "Keep displaying this entity when the selection changes",
+        });
+    if response.clicked() {
+        if locked {
+            tabs.unlock(entity);
+        } else {
+            tabs.lock(entity);
+        }
+    }
+}
+
+/// Display the selected entities, with a [`lock_toggle`] if a single entity is selected
+pub fn ui_for_selection(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    selected: &SelectedEntities,
+    tabs: &mut InspectorTabs,
+) -> InspectorChanges {
+    match selected.as_slice() {
+        [] => {
+            ui.weak("No entity selected");
+            InspectorChanges::default()
+        }
+        &[entity] => {
+            ui.horizontal(|ui| lock_toggle(ui, tabs, entity));
+            super::ui_for_entity_with_children(world, entity, ui)
+        }
+        entities => {
+            super::ui_for_entities_shared_components(world, entities, ui);
+            InspectorChanges::default()
+        }
+    }
+}
+
+/// Display the locked `entity`, with a [`lock_toggle`] to unlock it again
+pub fn ui_for_locked_entity(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    entity: Entity,
+    tabs: &mut InspectorTabs,
+) -> InspectorChanges {
+    if world.get_entity(entity).is_err() {
+        ui.weak(format!("Entity {entity} was despawned"));
+        return InspectorChanges::default();
+    }
+    ui.horizontal(|ui| lock_toggle(ui, tabs, entity));
+    super::ui_for_entity_with_children(world, entity, ui)
+}
+
+/// Display a tab bar with the selection and the locked entities, followed by the open tab
+pub fn ui_for_inspector_tabs(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    selected: &SelectedEntities,
+    tabs: &mut InspectorTabs,
+) -> InspectorChanges {
+    tabs.retain_existing(world);
+
+    ui.horizontal_wrapped(|ui| {
+        if ui
+            .selectable_label(tabs.active.is_none(), "Selection")
+            .clicked()
+        {
+            tabs.set_active(None);
+        }
+        for entity in tabs.locked.clone() {
+            let name = guess_entity_name(world, entity);
+            if ui
+                .selectable_label(tabs.active == Some(entity), format!("🔒 {name}"))
+                .clicked()
+            {
+                tabs.set_active(Some(entity));
+            }
+            if ui.small_button("×").on_hover_text("Unlock").clicked() {
+                tabs.unlock(entity);
+            }
+        }
+    });
+    ui.separator();
+
+    match tabs.active {
+        Some(entity) => ui_for_locked_entity(world, ui, entity, tabs),
+        None => ui_for_selection(world, ui, selected, tabs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::InspectorTabs;
+
+    #[test]
+    fn locked_tabs_outlive_selection_but_not_entity() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut tabs = InspectorTabs::default();
+        tabs.lock(a);
+        tabs.lock(b);
+        tabs.lock(a);
+        assert_eq!(tabs.locked(), &[a, b]);
+        assert_eq!(tabs.active(), Some(a));
+
+        world.despawn(a);
+        tabs.retain_existing(&world);
+        assert_eq!(tabs.locked(), &[b]);
+        assert_eq!(tabs.active(), None);
+
+        tabs.set_active(Some(a));
+        assert_eq!(tabs.active(), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! A console of the log events of the app, captured by a tracing layer into the [`LogBuffer`].
+//!
+//! The layer has to be installed through [`LogPlugin::custom_layer`](bevy_log::LogPlugin::custom_layer),
+//! since the global tracing subscriber can't be changed after the [`LogPlugin`](bevy_log::LogPlugin) was built.
+//!
+//! ```no_run
+//! use bevy::{log::LogPlugin, prelude::*};
+//! use bevy_inspector_egui::bevy_inspector::log_console;
+//!
+//! App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+//!     custom_layer: log_console::log_capture_layer,
+//!     ..default()
+//! }));
+//!
+//! fn log_ui(world: &mut World, ui: &mut egui::Ui) {
+//!     log_console::ui_for_log_console(world, ui);
+//! }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use bevy_log::{
+    tracing_subscriber::{layer::Context, Layer},
+    BoxedLayer,
+};
+use bevy_utils::{
+    tracing::{field::Field, field::Visit, Event, Level, Subscriber},
+    Instant,
+};
+
+use super::errors::resource_does_not_exist;
+use crate::{theme::InspectorTheme, utils::pretty_type_name};
+
+/// A single captured log event
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Time since the [`LogBuffer`] was created
+    pub time: Duration,
+    pub level: Level,
+    /// Module path the event was logged from
+    pub target: String,
+    /// The message, followed by the other fields of the event
+    pub message: String,
+}
+
+#[derive(Debug)]
+struct LogBufferInner {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    paused: bool,
+}
+
+/// Ring buffer of the events captured by the [`LogCaptureLayer`], displayed by [`ui_for_log_console`].
+///
+/// Clones share the same buffer, so the layer can keep writing into it from any thread.
+#[derive(Resource, Debug, Clone)]
+pub struct LogBuffer {
+    inner: Arc<Mutex<LogBufferInner>>,
+    start: Instant,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        LogBuffer::with_capacity(1000)
+    }
+}
+
+impl LogBuffer {
+    /// A buffer keeping the last `capacity` events
+    pub fn with_capacity(capacity: usize) -> Self {
+        LogBuffer {
+            inner: Arc::new(Mutex::new(LogBufferInner {
+                entries: VecDeque::new(),
+                capacity,
+                paused: false,
+            })),
+            start: Instant::now(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogBufferInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Records an event, dropping the oldest entry if the buffer is full
+    pub fn push(&self, entry: LogEntry) {
+        let mut inner = self.lock();
+        if inner.paused {
+            return;
+        }
+        while inner.entries.len() >= inner.capacity.max(1) {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry);
+    }
+
+    /// A copy of the currently buffered events, oldest first
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.lock().entries.iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// While paused, new events are ignored
+    pub fn set_paused(&self, paused: bool) {
+        self.lock().paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.lock().paused
+    }
+
+    /// A tracing layer writing into this buffer
+    pub fn layer(&self) -> LogCaptureLayer {
+        LogCaptureLayer {
+            buffer: self.clone(),
+        }
+    }
+}
+
+/// Tracing layer recording every event into a [`LogBuffer`]
+pub struct LogCaptureLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.buffer.push(LogEntry {
+            time: self.buffer.start.elapsed(),
+            level: *metadata.level(),
+            target: metadata.target().to_owned(),
+            message: visitor.message,
+        });
+    }
+}
+
+/// Formats the `message` field of an event, followed by the other fields as `name=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{value:?}{fields}");
+        } else {
+            let _ = write!(self.message, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Creates a [`LogBuffer`] resource and returns the layer capturing into it.
+///
+/// Meant to be used as the [`LogPlugin::custom_layer`](bevy_log::LogPlugin::custom_layer).
+pub fn log_capture_layer(app: &mut App) -> Option<BoxedLayer> {
+    let buffer = app
+        .world_mut()
+        .get_resource_or_insert_with(LogBuffer::default)
+        .clone();
+    Some(Box::new(buffer.layer()))
+}
+
+/// Filters of the console, stored in egui memory
+#[derive(Clone)]
+struct ConsoleState {
+    /// Most verbose level which is shown
+    max_level: Level,
+    filter: String,
+}
+
+impl Default for ConsoleState {
+    fn default() -> Self {
+        ConsoleState {
+            max_level: Level::INFO,
+            filter: String::new(),
+        }
+    }
+}
+
+fn level_color(level: Level, ui: &egui::Ui) -> egui::Color32 {
+    match level {
+        Level::ERROR => ui.visuals().error_fg_color,
+        Level::WARN => ui.visuals().warn_fg_color,
+        Level::INFO => InspectorTheme::current(ui.ctx()).success_color,
+        Level::DEBUG => egui::Color32::LIGHT_BLUE,
+        Level::TRACE => ui.visuals().weak_text_color(),
+    }
+}
+
+/// Display the [`LogBuffer`], filtered by level and by a text matched against the target and message
+pub fn ui_for_log_console(world: &mut World, ui: &mut egui::Ui) {
+    let Some(buffer) = world.get_resource::<LogBuffer>() else {
+        resource_does_not_exist(ui, &pretty_type_name::<LogBuffer>());
+        ui.weak(
+            "Set `LogPlugin::custom_layer` to `log_console::log_capture_layer` to capture logs",
+        );
+        return;
+    };
+
+    let id = egui::Id::new("log_console");
+    let mut state = ui.data_mut(|data| data.get_temp::<ConsoleState>(id).unwrap_or_default());
+
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt(id.with("level"))
+            .selected_text(state.max_level.as_str())
+            .show_ui(ui, |ui| {
+                for level in [
+                    Level::ERROR,
+                    Level::WARN,
+                    Level::INFO,
+                    Level::DEBUG,
+                    Level::TRACE,
+                ] {
+                    ui.selectable_value(&mut state.max_level, level, level.as_str());
+                }
+            });
+        ui.add(
+            egui::TextEdit::singleline(&mut state.filter)
+                .hint_text("Filter")
+                .desired_width(160.0),
+        );
+        let mut paused = buffer.is_paused();
+        if ui.checkbox(&mut paused, "Pause").changed() {
+            buffer.set_paused(paused);
+        }
+        if ui.button("Clear").clicked() {
+            buffer.clear();
+        }
+    });
+    ui.separator();
+
+    let filter = state.filter.to_lowercase();
+    let entries: Vec<LogEntry> = buffer
+        .entries()
+        .into_iter()
+        .filter(|entry| entry.level <= state.max_level)
+        .filter(|entry| {
+            filter.is_empty()
+                || entry.message.to_lowercase().contains(&filter)
+                || entry.target.to_lowercase().contains(&filter)
+        })
+        .collect();
+
+    if entries.is_empty() {
+        ui.weak("No log events");
+    } else {
+        egui::ScrollArea::vertical()
+            .stick_to_bottom(true)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new(id.with("entries"))
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for entry in &entries {
+                            ui.monospace(format!("{:>8.2}s", entry.time.as_secs_f32()));
+                            ui.colored_label(level_color(entry.level, ui), entry.level.as_str());
+                            ui.weak(&entry.target);
+                            ui.label(&entry.message);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, state));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogBuffer;
+    use bevy_log::tracing_subscriber::{layer::SubscriberExt, Registry};
+    use bevy_utils::tracing::{self, Level};
+
+    #[test]
+    fn captures_events_into_ring_buffer() {
+        let buffer = LogBuffer::with_capacity(2);
+        let subscriber = Registry::default().with(buffer.layer());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("first");
+            tracing::warn!(count = 3, "second");
+            tracing::error!("third");
+        });
+
+        let entries = buffer.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].level, Level::WARN);
+        assert_eq!(entries[0].message, "second count=3");
+        assert_eq!(entries[1].message, "third");
+    }
+}
@@ -37,27 +37,88 @@
 //! }
 //! ```
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
 use std::marker::PhantomData;
-
-use crate::utils::{pretty_type_name, pretty_type_name_str};
-use bevy_asset::{Asset, AssetServer, Assets, ReflectAsset, UntypedAssetId};
+use std::sync::Arc;
+
+use crate::localization;
+use crate::utils::{
+    pretty_name_cache::{pretty_component_info_name, pretty_type_name_cached},
+    pretty_type_name,
+};
+use bevy_asset::{
+    Asset, AssetServer, Assets, Handle, LoadState, ReflectAsset, UntypedAssetId, UntypedHandle,
+};
+use bevy_ecs::archetype::{Archetype, ArchetypeGeneration, ArchetypeId};
 use bevy_ecs::query::{QueryFilter, WorldQuery};
-use bevy_ecs::world::CommandQueue;
-use bevy_ecs::{component::ComponentId, prelude::*};
+use bevy_ecs::world::{unsafe_world_cell::UnsafeWorldCell, CommandQueue, WorldId};
+use bevy_ecs::{
+    component::{ComponentId, Tick},
+    prelude::*,
+};
 use bevy_hierarchy::{Children, Parent};
-use bevy_reflect::{Reflect, TypeRegistry};
+use bevy_reflect::{
+    std_traits::ReflectDefault, GetPath, PartialReflect, Reflect, ReflectRef, TypeRegistry,
+};
 use bevy_state::state::{FreelyMutableState, NextState, State};
+use bevy_utils::{HashMap, HashSet};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 
+pub mod arrange;
+#[cfg(feature = "bevy_image")]
+pub mod asset_browser;
+pub mod asset_events;
+pub mod auto_expand;
+pub mod batch;
+pub mod budget;
+#[cfg(all(feature = "bevy_render", feature = "bevy_image"))]
+pub mod camera_tools;
+pub mod component_filter;
+pub mod doctor;
+#[cfg(any(feature = "serde_json", feature = "ron"))]
+pub mod dump;
+#[cfg(feature = "bevy_render")]
+pub mod entity_row;
 pub(crate) mod errors;
+pub mod favorites;
+#[cfg(feature = "bevy_gizmos")]
+pub mod gizmos;
+pub mod history;
+pub mod inspector_tabs;
+#[cfg(feature = "log_console")]
+pub mod log_console;
+pub mod notes;
+pub mod query_playground;
+pub mod relationships;
+pub mod rust_code;
+#[cfg(feature = "bevy_scene")]
+pub mod scene_diff;
+#[cfg(feature = "stepping")]
+pub mod stepping;
+#[cfg(feature = "ron")]
+pub mod tweaks;
+#[cfg(feature = "bevy_ui")]
+pub mod ui_layout;
+pub mod watch;
+pub mod world_stats;
 
 /// UI for displaying the entity hierarchy
 pub mod hierarchy;
 
+#[cfg(feature = "serde_json")]
+pub use dump::dump_entity;
+#[cfg(feature = "ron")]
+pub use dump::dump_entity_ron;
+pub use world_stats::ui_for_world_stats;
+
+use crate::inspector_egui_impls::InspectorOverrides;
 use crate::reflect_inspector::{Context, InspectorUi};
 use crate::restricted_world_view::RestrictedWorldView;
+use budget::FrameBudget;
+use component_filter::{ComponentFilterSettings, ComponentGrouping, ComponentOrder};
+use favorites::{Favorite, Favorites};
 
 /// Display a single [`&mut dyn Reflect`](bevy_reflect::Reflect).
 ///
@@ -81,75 +142,296 @@ pub fn ui_for_value(value: &mut dyn Reflect, ui: &mut egui::Ui, world: &mut Worl
     changed
 }
 
-/// Display `Entities`, `Resources` and `Assets` using their respective functions inside headers
-pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) {
+/// What was edited through the inspector, returned by the functions displaying multiple values.
+///
+/// Editors can use it to e.g. mark a scene as dirty or to record undo entries.
+/// Structural changes like spawning or removing components are not included.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InspectorChanges {
+    /// Edited components, by entity and component type
+    pub components: Vec<(Entity, TypeId)>,
+    /// Types of the edited resources
+    pub resources: Vec<TypeId>,
+    pub assets: Vec<UntypedAssetId>,
+}
+
+impl InspectorChanges {
+    /// Whether nothing was edited
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.resources.is_empty() && self.assets.is_empty()
+    }
+
+    /// The entities with at least one edited component
+    pub fn entities(&self) -> Vec<Entity> {
+        let mut entities: Vec<_> = self.components.iter().map(|(entity, _)| *entity).collect();
+        entities.sort();
+        entities.dedup();
+        entities
+    }
+
+    pub fn extend(&mut self, other: InspectorChanges) {
+        for (entity, component) in other.components {
+            self.push_component(entity, component);
+        }
+        for resource in other.resources {
+            self.push_resource(resource);
+        }
+        for asset in other.assets {
+            self.push_asset(asset);
+        }
+    }
+
+    pub(crate) fn push_component(&mut self, entity: Entity, component: TypeId) {
+        if !self.components.contains(&(entity, component)) {
+            self.components.push((entity, component));
+        }
+    }
+
+    pub(crate) fn push_resource(&mut self, resource: TypeId) {
+        if !self.resources.contains(&resource) {
+            self.resources.push(resource);
+        }
+    }
+
+    pub(crate) fn push_asset(&mut self, asset: UntypedAssetId) {
+        if !self.assets.contains(&asset) {
+            self.assets.push(asset);
+        }
+    }
+}
+
+/// Display [`Favorites`], [notes](notes::InspectorNotes), `Entities`, `Resources`, `Assets` and [`Statistics`](ui_for_world_stats) using their respective functions inside headers
+pub fn ui_for_world(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let mut changes = InspectorChanges::default();
+    if world
+        .get_resource::<Favorites>()
+        .is_some_and(|favorites| !favorites.is_empty())
+    {
+        egui::CollapsingHeader::new("Favorites")
+            .default_open(true)
+            .show(ui, |ui| {
+                changes.extend(favorites::favorites_ui(world, ui, &type_registry));
+            });
+    }
+    if world
+        .get_resource::<notes::InspectorNotes>()
+        .is_some_and(|notes| !notes.is_empty())
+    {
+        egui::CollapsingHeader::new("Notes")
+            .default_open(true)
+            .show(ui, |ui| {
+                notes::notes_ui(world, ui, &type_registry);
+            });
+    }
+    egui::CollapsingHeader::new("Entities")
+        .default_open(true)
+        .show(ui, |ui| {
+            let filter = default_entities_filter(ui);
+            entity_count_ui(world, ui, &filter, &type_registry);
+            changes.extend(entities_ui(world, ui, true, &filter, false, &type_registry));
+        });
+    egui::CollapsingHeader::new("Resources").show(ui, |ui| {
+        changes.extend(resources_ui(world, ui, false, &type_registry));
+    });
+    egui::CollapsingHeader::new("Assets").show(ui, |ui| {
+        changes.extend(all_assets_ui(world, ui, false, &type_registry));
+    });
+    egui::CollapsingHeader::new("Statistics").show(ui, |ui| {
+        ui_for_world_stats(world, ui);
+    });
+    changes
+}
+
+/// Display all entities, resources and assets without allowing any of them to be changed
+pub fn ui_for_world_readonly(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
     egui::CollapsingHeader::new("Entities")
         .default_open(true)
         .show(ui, |ui| {
-            ui_for_entities(world, ui);
+            let filter = default_entities_filter(ui);
+            entity_count_ui(world, ui, &filter, &type_registry);
+            entities_ui(world, ui, true, &filter, true, &type_registry);
         });
     egui::CollapsingHeader::new("Resources").show(ui, |ui| {
-        ui_for_resources(world, ui);
+        resources_ui(world, ui, true, &type_registry);
     });
     egui::CollapsingHeader::new("Assets").show(ui, |ui| {
-        ui_for_all_assets(world, ui);
+        all_assets_ui(world, ui, true, &type_registry);
+    });
+    egui::CollapsingHeader::new("Statistics").show(ui, |ui| {
+        ui_for_world_stats(world, ui);
     });
 }
 
 /// Display all reflectable resources in the world
-pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) {
+pub fn ui_for_resources(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    resources_ui(world, ui, false, &type_registry)
+}
+
+/// Display all reflectable resources without allowing them to be changed
+pub fn ui_for_resources_readonly(world: &mut World, ui: &mut egui::Ui) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
+    resources_ui(world, ui, true, &type_registry);
+}
 
+fn resources_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    read_only: bool,
+    type_registry: &TypeRegistry,
+) -> InspectorChanges {
     let mut resources: Vec<_> = type_registry
         .iter()
         .filter(|registration| registration.data::<ReflectResource>().is_some())
         .map(|registration| {
+            let type_path_table = registration.type_info().type_path_table();
             (
-                registration.type_info().type_path_table().short_path(),
+                type_path_table.short_path(),
+                type_path_table.path(),
                 registration.type_id(),
             )
         })
         .collect();
-    resources.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
-    for (name, type_id) in resources {
-        ui.collapsing(name, |ui| {
-            by_type_id::ui_for_resource(world, type_id, ui, name, &type_registry);
+    resources.sort_by_key(|(name, ..)| *name);
+    let mut changes = InspectorChanges::default();
+    for (name, type_path, type_id) in resources {
+        let response = ui.collapsing(name, |ui| {
+            if read_only {
+                by_type_id::ui_for_resource_readonly(world, type_id, ui, name, type_registry);
+            } else if by_type_id::ui_for_resource(world, type_id, ui, name, type_registry) {
+                changes.push_resource(type_id);
+            }
+        });
+        response.header_response.context_menu(|ui| {
+            let value = type_registry
+                .get_type_data::<ReflectResource>(type_id)
+                .and_then(|reflect_resource| reflect_resource.reflect(world));
+            if let Some(value) = value {
+                rust_code::copy_buttons(ui, value.as_partial_reflect());
+            }
+            notes::note_button(world, notes::NoteTarget::resource(type_path), ui);
         });
+        if !read_only {
+            favorites::favorite_star(
+                world,
+                |_| Favorite::resource(type_id),
+                ui,
+                &response.header_response,
+            );
+        }
     }
+    changes
+}
+
+/// Display the resource `R`, and return whether it was changed
+pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) -> bool {
+    ui_for_resource_with_options::<R>(world, ui, &())
 }
 
-/// Display the resource `R`
-pub fn ui_for_resource<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+/// Display the resource `R` using `options` instead of the [`InspectorOptions`](crate::InspectorOptions) of its type.
+///
+/// `options` are passed to the UI of `R` like the derived options would be,
+/// so for structs they are usually [`InspectorOptions`](crate::InspectorOptions) with an entry per field.
+pub fn ui_for_resource_with_options<R: Resource + Reflect>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+) -> bool {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
     // create a context with access to the world except for the `R` resource
-    let Some((mut resource, world_view)) =
+    #[allow(unused_mut)]
+    let Some((mut resource, mut world_view)) =
         RestrictedWorldView::new(world).split_off_resource_typed::<R>()
     else {
         errors::resource_does_not_exist(ui, &pretty_type_name::<R>());
-        return;
+        return false;
     };
     let mut queue = CommandQueue::default();
+    #[cfg(feature = "ron")]
+    let before =
+        tweaks::is_recording(&mut world_view).then(|| resource.as_partial_reflect().clone_value());
     let mut cx = Context {
         world: Some(world_view),
         queue: Some(&mut queue),
     };
+    let mut watch = watch::FieldPath::resource(TypeId::of::<R>());
     let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+    env.watch = Some(&mut watch);
 
-    if env.ui_for_reflect(resource.bypass_change_detection(), ui) {
+    let changed = env.ui_for_reflect_with_options(
+        resource.bypass_change_detection(),
+        ui,
+        egui::Id::NULL,
+        options,
+    );
+    if changed {
         resource.set_changed();
     }
+    #[cfg(feature = "ron")]
+    if let (true, Some(before)) = (changed, before) {
+        tweaks::record(
+            &mut queue,
+            watch::FieldOwner::Resource(TypeId::of::<R>()),
+            before.as_ref(),
+            resource.as_partial_reflect(),
+            &type_registry,
+        );
+    }
 
     queue.apply(world);
+    changed
+}
+
+/// Display the resource `R` without allowing it to be changed
+pub fn ui_for_resource_readonly<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    // create a context with access to the world except for the `R` resource
+    let Some((resource, world_view)) =
+        RestrictedWorldView::new(world).split_off_resource_typed::<R>()
+    else {
+        errors::resource_does_not_exist(ui, &pretty_type_name::<R>());
+        return;
+    };
+    let mut cx = Context {
+        world: Some(world_view),
+        queue: None,
+    };
+    let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+    env.ui_for_reflect_readonly(resource.as_partial_reflect(), ui);
 }
 
 /// Display all reflectable assets
-pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
+pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    all_assets_ui(world, ui, false, &type_registry)
+}
+
+/// Display all reflectable assets without allowing them to be changed
+pub fn ui_for_all_assets_readonly(world: &mut World, ui: &mut egui::Ui) {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
+    all_assets_ui(world, ui, true, &type_registry);
+}
 
+fn all_assets_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    read_only: bool,
+    type_registry: &TypeRegistry,
+) -> InspectorChanges {
     let mut assets: Vec<_> = type_registry
         .iter()
         .filter(|registration| registration.data::<ReflectAsset>().is_some())
@@ -160,16 +442,153 @@ pub fn ui_for_all_assets(world: &mut World, ui: &mut egui::Ui) {
             )
         })
         .collect();
-    assets.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
+    assets.sort_by_key(|(name, ..)| *name);
+    let mut changes = InspectorChanges::default();
     for (name, type_id) in assets {
         ui.collapsing(name, |ui| {
-            by_type_id::ui_for_assets(world, type_id, ui, &type_registry);
+            if read_only {
+                by_type_id::ui_for_assets_readonly(world, type_id, ui, type_registry);
+            } else {
+                changes.extend(by_type_id::ui_for_assets(world, type_id, ui, type_registry));
+            }
         });
     }
+    changes
 }
 
 /// Display all assets of the specified asset type `A`
-pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    assets_ui::<A>(world, ui, "", AssetSort::Id)
+}
+
+/// How the assets in [`ui_for_assets_with_controls`] are ordered
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssetSort {
+    #[default]
+    Id,
+    /// By path, or by id for assets which weren't loaded from a file
+    Path,
+    /// Assets added at runtime first, followed by the ones not loaded yet, loading, loaded and failed
+    LoadState,
+}
+
+impl AssetSort {
+    fn label(self) -> &'static str {
+        match self {
+            AssetSort::Id => "Id",
+            AssetSort::Path => "Path",
+            AssetSort::LoadState => "Load state",
+        }
+    }
+}
+
+/// State of the toolbar of [`ui_for_assets_with_controls`]
+#[derive(Clone, Default)]
+struct AssetControls {
+    filter: String,
+    sort: AssetSort,
+    /// Assets created using the toolbar, kept alive until they are dismissed
+    created: Vec<UntypedHandle>,
+}
+
+/// Display all assets of the specified asset type `A` like [`ui_for_assets`], below a toolbar to filter them by path,
+/// sort them and [create new ones](create_default_asset)
+pub fn ui_for_assets_with_controls<A: Asset + Reflect>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+) -> InspectorChanges {
+    let id = egui::Id::new(("asset_controls", TypeId::of::<A>()));
+    let mut controls =
+        ui.data_mut(|data| data.get_temp_mut_or_default::<AssetControls>(id).clone());
+    let asset_server = world.get_resource::<AssetServer>().cloned();
+    let can_create = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectDefault>(TypeId::of::<A>())
+        .is_some();
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut controls.filter)
+                .hint_text("Filter by path")
+                .desired_width(120.0),
+        );
+        egui::ComboBox::from_id_salt(id.with("sort"))
+            .selected_text(controls.sort.label())
+            .show_ui(ui, |ui| {
+                for sort in [AssetSort::Id, AssetSort::Path, AssetSort::LoadState] {
+                    ui.selectable_value(&mut controls.sort, sort, sort.label());
+                }
+            });
+        let create = ui
+            .add_enabled(can_create, egui::Button::new("➕ New"))
+            .on_hover_text("Add a default value")
+            .on_disabled_hover_text(format!(
+                "{} doesn't register `ReflectDefault`",
+                pretty_type_name::<A>()
+            ));
+        if create.clicked() {
+            if let Some(handle) = create_default_asset::<A>(world) {
+                controls.created.push(handle.untyped());
+            }
+        }
+    });
+
+    controls.created.retain(|handle| {
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "Created {}",
+                handle_name(handle.id(), asset_server.as_ref())
+            ));
+            if ui
+                .small_button("📋")
+                .on_hover_text("Copy the asset id")
+                .clicked()
+            {
+                crate::utils::copy_text(ui.ctx(), format!("{:?}", handle.id().typed::<A>()));
+            }
+            !ui.small_button("✕")
+                .on_hover_text("Drop the handle, removing the asset unless it is used elsewhere")
+                .clicked()
+        })
+        .inner
+    });
+
+    let changes = assets_ui::<A>(world, ui, &controls.filter, controls.sort);
+    ui.data_mut(|data| data.insert_temp(id, controls));
+    changes
+}
+
+/// Add the default value of `A` to the [`Assets<A>`], using its registered [`ReflectDefault`].
+///
+/// Returns `None` if `A` doesn't register `ReflectDefault` or there is no `Assets<A>` resource.
+pub fn create_default_asset<A: Asset + Reflect>(world: &mut World) -> Option<Handle<A>> {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let value = type_registry
+        .read()
+        .get_type_data::<ReflectDefault>(TypeId::of::<A>())?
+        .default();
+    let asset = value.downcast::<A>().ok()?;
+    let mut assets = world.get_resource_mut::<Assets<A>>()?;
+    Some(assets.add(*asset))
+}
+
+fn load_state_rank(asset_server: Option<&AssetServer>, id: UntypedAssetId) -> u8 {
+    match asset_server.and_then(|server| server.get_load_state(id)) {
+        None => 0,
+        Some(LoadState::NotLoaded) => 1,
+        Some(LoadState::Loading) => 2,
+        Some(LoadState::Loaded) => 3,
+        Some(LoadState::Failed(_)) => 4,
+    }
+}
+
+fn assets_ui<A: Asset + Reflect>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &str,
+    sort: AssetSort,
+) -> InspectorChanges {
     let asset_server = world.get_resource::<AssetServer>().cloned();
 
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
@@ -180,7 +599,7 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
         RestrictedWorldView::new(world).split_off_resource_typed::<Assets<A>>()
     else {
         errors::resource_does_not_exist(ui, &pretty_type_name::<Assets<A>>());
-        return;
+        return InspectorChanges::default();
     };
 
     let mut queue = CommandQueue::default();
@@ -189,20 +608,68 @@ pub fn ui_for_assets<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
         queue: Some(&mut queue),
     };
 
-    let mut assets: Vec<_> = assets.iter_mut().collect();
-    assets.sort_by(|(a, _), (b, _)| a.cmp(b));
-    for (handle_id, asset) in assets {
+    let filter = filter.to_lowercase();
+    let mut assets: Vec<_> = assets
+        .iter_mut()
+        .map(|(id, asset)| (id, handle_name(id.untyped(), asset_server.as_ref()), asset))
+        .filter(|(_, name, _)| name.to_lowercase().contains(&filter))
+        .collect();
+    match sort {
+        AssetSort::Id => assets.sort_by_key(|(id, ..)| *id),
+        AssetSort::Path => assets.sort_by(|(_, a, _), (_, b, _)| a.cmp(b)),
+        AssetSort::LoadState => assets
+            .sort_by_key(|(id, ..)| (load_state_rank(asset_server.as_ref(), id.untyped()), *id)),
+    }
+    let mut changes = InspectorChanges::default();
+    for (handle_id, name, asset) in assets {
         let id = egui::Id::new(handle_id);
 
-        egui::CollapsingHeader::new(handle_name(handle_id.untyped(), asset_server.as_ref()))
+        egui::CollapsingHeader::new(name)
             .id_salt(id)
             .show(ui, |ui| {
                 let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
-                env.ui_for_reflect_with_options(asset, ui, id, &());
+                if env.ui_for_reflect_with_options(asset, ui, id, &()) {
+                    changes.push_asset(handle_id.untyped());
+                }
             });
     }
 
     queue.apply(world);
+    changes
+}
+
+/// Display all assets of the specified asset type `A` without allowing them to be changed
+pub fn ui_for_assets_readonly<A: Asset + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    let asset_server = world.get_resource::<AssetServer>().cloned();
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    // create a context with access to the world except for the `Assets<A>` resource
+    let Some((assets, world_view)) =
+        RestrictedWorldView::new(world).split_off_resource_typed::<Assets<A>>()
+    else {
+        errors::resource_does_not_exist(ui, &pretty_type_name::<Assets<A>>());
+        return;
+    };
+
+    let mut cx = Context {
+        world: Some(world_view),
+        queue: None,
+    };
+
+    let mut assets: Vec<_> = assets.iter().collect();
+    assets.sort_by_key(|(id, _)| *id);
+    for (handle_id, asset) in assets {
+        let id = egui::Id::new(handle_id);
+
+        egui::CollapsingHeader::new(handle_name(handle_id.untyped(), asset_server.as_ref()))
+            .id_salt(id)
+            .show(ui, |ui| {
+                let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+                env.ui_for_reflect_readonly_with_options(asset, ui, id, &());
+            });
+    }
 }
 
 /// Display state `T` and change state on edit
@@ -256,9 +723,37 @@ pub fn ui_for_world_entities_filtered<QF: WorldQuery + QueryFilter>(
 }
 
 /// Display all root entities.
-pub fn ui_for_entities(world: &mut World, ui: &mut egui::Ui) {
-    let filter: Filter = Filter::from_ui_fuzzy(ui, egui::Id::new("default_world_entities_filter"));
-    ui_for_entities_filtered(world, ui, true, &filter);
+pub fn ui_for_entities(world: &mut World, ui: &mut egui::Ui) -> InspectorChanges {
+    let filter = default_entities_filter(ui);
+    ui_for_entities_filtered(world, ui, true, &filter)
+}
+
+/// Display all root entities without allowing their components to be changed.
+pub fn ui_for_entities_readonly(world: &mut World, ui: &mut egui::Ui) {
+    let filter = default_entities_filter(ui);
+    ui_for_entities_filtered_readonly(world, ui, true, &filter);
+}
+
+fn default_entities_filter(ui: &mut egui::Ui) -> Filter {
+    Filter::from_ui_fuzzy(ui, egui::Id::new("default_world_entities_filter"))
+}
+
+/// The number of entities matching `filter` out of all entities, like `12 / 340 entities`
+fn entity_count_ui<F: EntityFilter>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    filter: &F,
+    type_registry: &TypeRegistry,
+) {
+    let total = world.entities().len();
+    let text = match filter.is_active() {
+        true => {
+            let matching = filter.matching_entities(world, type_registry).len();
+            format!("{matching} / {total} entities")
+        }
+        false => format!("{total} entities"),
+    };
+    ui.weak(text);
 }
 
 /// Display all entities matching the given [`EntityFilter`].
@@ -270,51 +765,117 @@ pub fn ui_for_entities_filtered<F>(
     ui: &mut egui::Ui,
     with_children: bool,
     filter: &F,
+) -> InspectorChanges
+where
+    F: EntityFilter,
+{
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    entities_ui(world, ui, with_children, filter, false, &type_registry)
+}
+
+/// Display all entities matching the given [`EntityFilter`] without allowing their components to be changed.
+///
+/// See [`ui_for_entities_filtered`].
+pub fn ui_for_entities_filtered_readonly<F>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    with_children: bool,
+    filter: &F,
 ) where
     F: EntityFilter,
 {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
+    entities_ui(world, ui, with_children, filter, true, &type_registry);
+}
 
+fn entities_ui<F>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    with_children: bool,
+    filter: &F,
+    read_only: bool,
+    type_registry: &TypeRegistry,
+) -> InspectorChanges
+where
+    F: EntityFilter,
+{
     let mut root_entities = world.query_filtered::<Entity, F::StaticFilter>();
     let mut entities = root_entities.iter(world).collect::<Vec<_>>();
 
-    filter.filter_entities(world, &mut entities);
+    filter.filter_entities_with_registry(world, &mut entities, type_registry);
 
     entities.sort();
 
+    let auto_expand = auto_expand::entities_to_expand(world, filter, type_registry, true);
     let id = egui::Id::new("world ui");
+    let mut changes = InspectorChanges::default();
+    let budget = FrameBudget::start(world);
     for entity in entities {
         let id = id.with(entity);
+        if budget.defer(ui, id, ui.make_persistent_id(id)) {
+            continue;
+        }
+        let top = ui.cursor().min.y;
 
         let entity_name = guess_entity_name(world, entity);
 
-        egui::CollapsingHeader::new(&entity_name)
+        let header_id = ui.make_persistent_id(id);
+        auto_expand::expand_once(ui, header_id, auto_expand.contains(&entity));
+        let response = egui::CollapsingHeader::new(&entity_name)
             .id_salt(id)
             .show(ui, |ui| {
                 if with_children {
-                    ui_for_entity_with_children_inner(
+                    changes.extend(ui_for_entity_with_children_inner(
                         world,
                         entity,
                         ui,
                         id,
-                        &type_registry,
+                        type_registry,
                         filter,
-                    );
+                        read_only,
+                        &auto_expand,
+                    ));
                 } else {
                     let mut queue = CommandQueue::default();
-                    ui_for_entity_components(
+                    let expanded = filter.matching_components(world, entity, type_registry);
+                    changes.extend(ui_for_entity_components_with_options(
                         &mut world.into(),
-                        Some(&mut queue),
+                        (!read_only).then_some(&mut queue),
                         entity,
                         ui,
                         id,
-                        &type_registry,
-                    );
+                        type_registry,
+                        &ComponentOptions {
+                            read_only,
+                            expanded,
+                            ..Default::default()
+                        },
+                    ));
                     queue.apply(world);
                 }
             });
+        response.header_response.context_menu(|ui| {
+            let target = notes::NoteTarget::entity(world, entity);
+            notes::note_button(world, target, ui);
+        });
+        if !read_only {
+            #[allow(unused_variables)]
+            let right = favorites::favorite_star(
+                world,
+                |world| Favorite::entity(world, entity),
+                ui,
+                &response.header_response,
+            );
+            #[cfg(feature = "bevy_render")]
+            entity_row::entity_row_toggles(world, entity, ui, &response.header_response, right);
+        }
+        #[cfg(feature = "bevy_gizmos")]
+        gizmos::track_hovered_entity(world, entity, &response.header_response);
+        budget.record_height(ui, id, top);
     }
+    changes
 }
 
 pub trait EntityFilter {
@@ -341,14 +902,85 @@ pub trait EntityFilter {
         entities.retain(|&entity| self.filter_entity(world, entity));
     }
 
+    /// Filters entities in place, for callers which already hold a read lock of the [`AppTypeRegistry`]
+    ///
+    /// default impl calls [`EntityFilter::filter_entities`]
+    fn filter_entities_with_registry(
+        &self,
+        world: &mut World,
+        entities: &mut Vec<Entity>,
+        _type_registry: &TypeRegistry,
+    ) {
+        self.filter_entities(world, entities);
+    }
+
     /// Returns true if entity matches the filter term
     fn filter_entity(&self, world: &mut World, entity: Entity) -> bool;
+
+    /// Returns the components of the entity which match the filter term, which are expanded when the entity is displayed
+    ///
+    /// default impl returns no components
+    fn matching_components(
+        &self,
+        _world: &mut World,
+        _entity: Entity,
+        _type_registry: &TypeRegistry,
+    ) -> Vec<TypeId> {
+        Vec::new()
+    }
+
+    /// Returns the entities which match the filter term themselves, and not only through a descendant.
+    /// They and their ancestors are opened by [auto-expansion](auto_expand).
+    ///
+    /// default impl returns no entities
+    fn matching_entities(
+        &self,
+        _world: &mut World,
+        _type_registry: &TypeRegistry,
+    ) -> Arc<HashSet<Entity>> {
+        Arc::default()
+    }
+}
+
+/// What the word of a [`Filter`] is matched against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FilterScope {
+    /// The names of entities
+    #[default]
+    Names,
+    /// The type names of the components of entities, and the text in their fields
+    Components,
+}
+
+impl FilterScope {
+    fn ui(&mut self, ui: &mut egui::Ui, id: egui::Id) {
+        let id = id.with("scope");
+        let mut scope = ui.data_mut(|data| *data.get_temp_mut_or_default::<FilterScope>(id));
+        egui::ComboBox::from_id_salt(id)
+            .selected_text(scope.label())
+            .width(0.0)
+            .show_ui(ui, |ui| {
+                for option in [FilterScope::Names, FilterScope::Components] {
+                    ui.selectable_value(&mut scope, option, option.label());
+                }
+            });
+        ui.data_mut(|data| data.insert_temp(id, scope));
+        *self = scope;
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FilterScope::Names => "Names",
+            FilterScope::Components => "Components",
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Filter<F: QueryFilter = Without<Parent>> {
     pub word: String,
     pub is_fuzzy: bool,
+    pub scope: FilterScope,
     pub marker: PhantomData<F>,
 }
 
@@ -357,6 +989,7 @@ impl<F: QueryFilter + Clone> Clone for Filter<F> {
         Self {
             word: self.word.clone(),
             is_fuzzy: self.is_fuzzy,
+            scope: self.scope,
             marker: PhantomData,
         }
     }
@@ -364,6 +997,17 @@ impl<F: QueryFilter + Clone> Clone for Filter<F> {
 
 impl<F: QueryFilter> Filter<F> {
     pub fn from_ui_fuzzy(ui: &mut egui::Ui, id: egui::Id) -> Self {
+        ui.horizontal(|ui| {
+            let mut scope = FilterScope::default();
+            scope.ui(ui, id);
+            let mut filter = Self::from_ui_fuzzy_word(ui, id);
+            filter.scope = scope;
+            filter
+        })
+        .inner
+    }
+
+    fn from_ui_fuzzy_word(ui: &mut egui::Ui, id: egui::Id) -> Self {
         let word = {
             let id = id.with("word");
             // filter, using eguis memory and provided id
@@ -383,12 +1027,15 @@ impl<F: QueryFilter> Filter<F> {
         Filter {
             word,
             is_fuzzy: true,
+            scope: FilterScope::default(),
             marker: PhantomData,
         }
     }
 
     pub fn from_ui(ui: &mut egui::Ui, id: egui::Id) -> Self {
         ui.horizontal(|ui| {
+            let mut scope = FilterScope::default();
+            scope.ui(ui, id);
             // filter kind
             let is_fuzzy = {
                 let id = id.with("is_fuzzy");
@@ -421,6 +1068,7 @@ impl<F: QueryFilter> Filter<F> {
             Filter {
                 word,
                 is_fuzzy,
+                scope,
                 marker: PhantomData,
             }
         })
@@ -432,6 +1080,7 @@ impl<F: QueryFilter> Filter<F> {
         Self {
             word: String::from(""),
             is_fuzzy: false,
+            scope: FilterScope::Names,
             marker: PhantomData,
         }
     }
@@ -444,46 +1093,408 @@ impl<F: QueryFilter> EntityFilter for Filter<F> {
         !self.word.is_empty()
     }
 
-    fn filter_entity(&self, world: &mut World, entity: Entity) -> bool {
-        self_or_children_satisfy_filter(world, entity, self.word.as_str(), self.is_fuzzy)
+    fn filter_entities(&self, world: &mut World, entities: &mut Vec<Entity>) {
+        if !self.is_active() {
+            return;
+        }
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+        self.filter_entities_with_registry(world, entities, &type_registry);
     }
-}
 
-fn self_or_children_satisfy_filter(
-    world: &mut World,
-    entity: Entity,
-    filter: &str,
-    is_fuzzy: bool,
-) -> bool {
-    let name = guess_entity_name(world, entity);
-    let self_matches = if is_fuzzy {
-        let matcher = SkimMatcherV2::default();
-        matcher.fuzzy_match(name.as_str(), filter).is_some()
-    } else {
-        name.to_lowercase().contains(filter)
-    };
-    self_matches || {
-        let Ok(children) = world
-            .query::<&Children>()
-            .get(world, entity)
-            .map(|children| children.to_vec())
-        else {
-            return false;
+    fn filter_entities_with_registry(
+        &self,
+        world: &mut World,
+        entities: &mut Vec<Entity>,
+        type_registry: &TypeRegistry,
+    ) {
+        if !self.is_active() {
+            return;
+        }
+        let (visible, _) = self.entity_matches(world, type_registry);
+        entities.retain(|entity| visible.contains(entity));
+    }
+
+    fn filter_entity(&self, world: &mut World, entity: Entity) -> bool {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+        self.entity_matches(world, &type_registry)
+            .0
+            .contains(&entity)
+    }
+
+    fn matching_components(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        type_registry: &TypeRegistry,
+    ) -> Vec<TypeId> {
+        if self.scope != FilterScope::Components || !self.is_active() {
+            return Vec::new();
+        }
+        let matcher = self.text_matcher();
+        matching_components(world, entity, type_registry, &|text| matcher.matches(text))
+    }
+
+    fn matching_entities(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+    ) -> Arc<HashSet<Entity>> {
+        if !self.is_active() {
+            return Arc::default();
+        }
+        self.entity_matches(world, type_registry).1
+    }
+}
+
+impl<F: QueryFilter> Filter<F> {
+    fn text_matcher(&self) -> TextMatcher<'_> {
+        match self.is_fuzzy {
+            true => TextMatcher::Fuzzy(Box::default(), &self.word),
+            false => TextMatcher::Contains(&self.word),
+        }
+    }
+
+    /// The entities which match the filter or have a matching descendant, and the entities which match it themselves.
+    ///
+    /// They are kept in the [`FilterMatchCache`] and updated incrementally: an entity is only matched again
+    /// after it moved to another archetype or its [`Name`](bevy_core::Name) changed, or any of its components
+    /// if those are searched or there are [`EntityNameRules`]. The ancestors of the matches are only collected
+    /// again after the matches or a [`Parent`] changed.
+    fn entity_matches(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+    ) -> (Arc<HashSet<Entity>>, Arc<HashSet<Entity>>) {
+        let key = FilterKey {
+            word: self.word.clone(),
+            is_fuzzy: self.is_fuzzy,
+            scope: self.scope,
         };
+        world.get_resource_or_init::<FilterMatchCache>();
+        world.resource_scope(|world, mut cache: Mut<FilterMatchCache>| {
+            let cache = cache.bypass_change_detection();
+            cache.uses += 1;
+            let uses = cache.uses;
+            if !cache.filters.contains_key(&key)
+                && cache.filters.len() >= FilterMatchCache::MAX_FILTERS
+            {
+                let least_recently_used = cache
+                    .filters
+                    .iter()
+                    .min_by_key(|(_, matches)| matches.used)
+                    .map(|(key, _)| key.clone());
+                if let Some(least_recently_used) = least_recently_used {
+                    cache.filters.remove(&least_recently_used);
+                }
+            }
+            let matches = cache.filters.entry(key).or_default();
+            matches.used = uses;
+            self.update_matches(world, type_registry, matches);
+            (matches.visible.clone(), matches.matched.clone())
+        })
+    }
 
-        children
+    fn update_matches(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+        matches: &mut FilterMatches,
+    ) {
+        // changes made after this, e.g. by the inspector itself, get a newer tick and are seen by the next update
+        let this_run = world.increment_change_tick();
+        let checked = matches.checked.replace(this_run);
+
+        // names from rules can depend on any component
+        let any_component = self.scope == FilterScope::Components
+            || world
+                .get_resource::<EntityNameRules>()
+                .is_some_and(|rules| !rules.is_empty());
+        let (dirty, hierarchy_changed) = match checked {
+            Some(checked) => world.last_change_tick_scope(checked, |world| {
+                let dirty: HashSet<Entity> = if any_component {
+                    entities_with_changes(world, checked)
+                } else {
+                    world
+                        .query_filtered::<Entity, Changed<bevy_core::Name>>()
+                        .iter(world)
+                        .collect()
+                };
+                let hierarchy_changed = world
+                    .query_filtered::<(), Changed<Parent>>()
+                    .iter(world)
+                    .next()
+                    .is_some();
+                (dirty, hierarchy_changed)
+            }),
+            None => (HashSet::default(), true),
+        };
+
+        let generation = world.archetypes().generation();
+        let structure_changed = matches.generation != Some(generation)
+            || !world
+                .archetypes()
+                .iter()
+                .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+                .eq(matches.entities.iter().copied());
+        if !structure_changed && dirty.is_empty() && !hierarchy_changed {
+            return;
+        }
+
+        let matcher = self.text_matcher();
+        let world = &*world;
+        let matches_entity = |entity: Entity| match self.scope {
+            FilterScope::Names => matcher.matches(&guess_entity_name(world, entity)),
+            FilterScope::Components => {
+                !matching_components(world, entity, type_registry, &|text| matcher.matches(text))
+                    .is_empty()
+            }
+        };
+        if structure_changed {
+            let mut entities = Vec::with_capacity(world.entities().len() as usize);
+            let mut by_entity = HashMap::default();
+            for archetype in world.archetypes().iter() {
+                for entity in archetype.entities() {
+                    let entity = entity.id();
+                    let matched = match matches.by_entity.get(&entity) {
+                        Some(&(previous_archetype, matched))
+                            if previous_archetype == archetype.id() && !dirty.contains(&entity) =>
+                        {
+                            matched
+                        }
+                        _ => matches_entity(entity),
+                    };
+                    entities.push(entity);
+                    by_entity.insert(entity, (archetype.id(), matched));
+                }
+            }
+            matches.generation = Some(generation);
+            matches.entities = entities;
+            matches.by_entity = by_entity;
+        } else {
+            for entity in dirty {
+                if let Some((_, matched)) = matches.by_entity.get_mut(&entity) {
+                    *matched = matches_entity(entity);
+                }
+            }
+        }
+
+        let matched: HashSet<Entity> = matches
+            .by_entity
             .iter()
-            .any(|child| self_or_children_satisfy_filter(world, *child, filter, is_fuzzy))
+            .filter(|(_, (_, matched))| *matched)
+            .map(|(entity, _)| *entity)
+            .collect();
+        if !structure_changed && !hierarchy_changed && matched == *matches.matched {
+            return;
+        }
+
+        let mut visible = HashSet::default();
+        for &entity in &matched {
+            // ancestors of an entity in the set are in it as well, so the walk can stop at the first one
+            if visible.insert(entity) {
+                let mut current = entity;
+                while let Some(parent) = world.get::<Parent>(current) {
+                    current = parent.get();
+                    if !visible.insert(current) {
+                        break;
+                    }
+                }
+            }
+        }
+        matches.visible = Arc::new(visible);
+        matches.matched = Arc::new(matched);
     }
 }
 
-/// Display the given entity with all its components and children
-pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+/// Entities with a component which changed after the `checked` tick
+fn entities_with_changes(world: &World, checked: Tick) -> HashSet<Entity> {
+    let this_run = world.read_change_tick();
+    world
+        .iter_entities()
+        .filter(|entity_ref| {
+            entity_ref.archetype().components().any(|component_id| {
+                entity_ref
+                    .get_change_ticks_by_id(component_id)
+                    .is_some_and(|ticks| ticks.is_changed(checked, this_run))
+            })
+        })
+        .map(|entity_ref| entity_ref.id())
+        .collect()
+}
+
+/// Matches text against the word of a [`Filter`], with the fuzzy matcher constructed only once
+enum TextMatcher<'a> {
+    Fuzzy(Box<SkimMatcherV2>, &'a str),
+    Contains(&'a str),
+}
+
+impl TextMatcher<'_> {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            TextMatcher::Fuzzy(matcher, word) => matcher.fuzzy_match(text, word).is_some(),
+            TextMatcher::Contains(word) => text.to_lowercase().contains(word),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct FilterKey {
+    word: String,
+    is_fuzzy: bool,
+    scope: FilterScope,
+}
+
+#[derive(Default)]
+struct FilterMatches {
+    /// Value of `FilterMatchCache::uses` when the filter was last used
+    used: u64,
+    /// The change tick up to which changes were taken into account
+    checked: Option<Tick>,
+    generation: Option<ArchetypeGeneration>,
+    /// All entities, in the order of their archetypes, to notice when entities are spawned, despawned or moved
+    entities: Vec<Entity>,
+    /// Whether each entity matched, and the archetype it was in at the time
+    by_entity: HashMap<Entity, (ArchetypeId, bool)>,
+    visible: Arc<HashSet<Entity>>,
+    matched: Arc<HashSet<Entity>>,
+}
+
+/// The matches of the most recently used [`Filter`]s of a world, see [`Filter::entity_matches`]
+#[derive(Resource, Default)]
+struct FilterMatchCache {
+    uses: u64,
+    filters: HashMap<FilterKey, FilterMatches>,
+}
+
+impl FilterMatchCache {
+    /// Every word typed into a search box is a new filter, so only the last few are kept
+    const MAX_FILTERS: usize = 8;
+}
+
+/// Components of `entity` whose type name or text in one of their fields `matches`
+fn matching_components(
+    world: &World,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+    matches: &dyn Fn(&str) -> bool,
+) -> Vec<TypeId> {
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return Vec::new();
+    };
+
+    world
+        .inspect_entity(entity)
+        .filter_map(|info| {
+            let type_id = info.type_id()?;
+            let value_matches = || {
+                type_registry
+                    .get_type_data::<ReflectComponent>(type_id)
+                    .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+                    .is_some_and(|value| text_matches(value.as_partial_reflect(), matches, 0))
+            };
+            (matches(&pretty_type_name_cached(type_id, info.name())) || value_matches())
+                .then_some(type_id)
+        })
+        .collect()
+}
+
+/// Whether `value` or one of its fields is text which `matches`
+fn text_matches(value: &dyn PartialReflect, matches: &dyn Fn(&str) -> bool, depth: usize) -> bool {
+    const MAX_DEPTH: usize = 8;
+    if depth > MAX_DEPTH {
+        return false;
+    }
+    if let Some(text) = value.try_downcast_ref::<String>() {
+        return matches(text);
+    }
+    if let Some(text) = value.try_downcast_ref::<std::borrow::Cow<'static, str>>() {
+        return matches(text);
+    }
+    if let Some(name) = value.try_downcast_ref::<bevy_core::Name>() {
+        return matches(name.as_str());
+    }
+
+    let mut fields: Box<dyn Iterator<Item = &dyn PartialReflect>> = match value.reflect_ref() {
+        ReflectRef::Struct(value) => Box::new(value.iter_fields()),
+        ReflectRef::TupleStruct(value) => Box::new(value.iter_fields()),
+        ReflectRef::Tuple(value) => Box::new(value.iter_fields()),
+        ReflectRef::List(value) => Box::new(value.iter()),
+        ReflectRef::Array(value) => Box::new(value.iter()),
+        ReflectRef::Map(value) => Box::new(value.iter().map(|(_, value)| value)),
+        ReflectRef::Set(value) => Box::new(value.iter()),
+        ReflectRef::Enum(value) => Box::new(value.iter_fields().map(|field| field.value())),
+        _ => return false,
+    };
+    fields.any(|field| text_matches(field, matches, depth + 1))
+}
+
+/// Display the component `C` of every entity having it, each below the name of its entity
+pub fn ui_for_components<C: Component + Reflect>(
+    world: &mut World,
+    ui: &mut egui::Ui,
+) -> InspectorChanges {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
+    let type_id = TypeId::of::<C>();
 
-    let entity_name = guess_entity_name(world, entity);
-    ui.label(entity_name);
+    let mut entities: Vec<Entity> = world
+        .query_filtered::<Entity, With<C>>()
+        .iter(world)
+        .collect();
+    entities.sort();
+
+    let mut changes = InspectorChanges::default();
+    let mut queue = CommandQueue::default();
+    for entity in entities {
+        let id = egui::Id::new((type_id, entity));
+        egui::CollapsingHeader::new(guess_entity_name(world, entity))
+            .id_salt(id)
+            .default_open(true)
+            .show(ui, |ui| {
+                // create a context with access to the world except for the component
+                let mut world_view = RestrictedWorldView::new(world);
+                let (mut component_view, world_view) =
+                    world_view.split_off_component((entity, type_id));
+                let mut cx = Context {
+                    world: Some(world_view),
+                    queue: Some(&mut queue),
+                };
+                let Ok(mut value) =
+                    component_view.get_entity_component_reflect(entity, type_id, &type_registry)
+                else {
+                    return;
+                };
+
+                let mut env = InspectorUi::for_bevy(&type_registry, &mut cx);
+                let changed = env.ui_for_reflect_with_options(
+                    value.bypass_change_detection().as_partial_reflect_mut(),
+                    ui,
+                    id,
+                    &(),
+                );
+                if changed {
+                    value.set_changed();
+                    changes.push_component(entity, type_id);
+                }
+            });
+    }
+
+    queue.apply(world);
+    changes
+}
+
+/// Display the given entity with all its components and children, below the [breadcrumbs](hierarchy::breadcrumbs_ui) of its ancestors
+pub fn ui_for_entity_with_children(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+) -> InspectorChanges {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    hierarchy::breadcrumbs_ui(world, entity, ui);
 
     let filter: Filter = Filter::all();
     ui_for_entity_with_children_inner(
@@ -493,9 +1504,31 @@ pub fn ui_for_entity_with_children(world: &mut World, entity: Entity, ui: &mut e
         egui::Id::new(entity),
         &type_registry,
         &filter,
+        false,
+        &HashSet::default(),
     )
 }
 
+/// Display the given entity with all its components and children without allowing them to be changed
+pub fn ui_for_entity_with_children_readonly(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    hierarchy::breadcrumbs_ui(world, entity, ui);
+
+    let filter: Filter = Filter::all();
+    ui_for_entity_with_children_inner(
+        world,
+        entity,
+        ui,
+        egui::Id::new(entity),
+        &type_registry,
+        &filter,
+        true,
+        &HashSet::default(),
+    );
+}
+
 fn ui_for_entity_with_children_inner<F>(
     world: &mut World,
     entity: Entity,
@@ -503,17 +1536,26 @@ fn ui_for_entity_with_children_inner<F>(
     id: egui::Id,
     type_registry: &TypeRegistry,
     filter: &F,
-) where
+    read_only: bool,
+    auto_expand: &HashSet<Entity>,
+) -> InspectorChanges
+where
     F: EntityFilter,
 {
     let mut queue = CommandQueue::default();
-    ui_for_entity_components(
+    let expanded = filter.matching_components(world, entity, type_registry);
+    let mut changes = ui_for_entity_components_with_options(
         &mut world.into(),
-        Some(&mut queue),
+        (!read_only).then_some(&mut queue),
         entity,
         ui,
         id,
         type_registry,
+        &ComponentOptions {
+            read_only,
+            expanded,
+            ..Default::default()
+        },
     );
 
     let children = world
@@ -521,35 +1563,121 @@ fn ui_for_entity_with_children_inner<F>(
         .map(|children| children.iter().copied().collect::<Vec<_>>());
     if let Some(mut children) = children {
         if !children.is_empty() {
-            filter.filter_entities(world, &mut children);
-            ui.label("Children");
+            filter.filter_entities_with_registry(world, &mut children, type_registry);
+            ui.label(localization::builtin(ui, "Children"));
             for child in children {
                 let id = id.with(child);
 
                 let child_entity_name = guess_entity_name(world, child);
-                egui::CollapsingHeader::new(&child_entity_name)
+                let header_id = ui.make_persistent_id(id);
+                auto_expand::expand_once(ui, header_id, auto_expand.contains(&child));
+                let response = egui::CollapsingHeader::new(&child_entity_name)
                     .id_salt(id)
                     .show(ui, |ui| {
                         ui.label(&child_entity_name);
 
-                        ui_for_entity_with_children_inner(
+                        changes.extend(ui_for_entity_with_children_inner(
                             world,
                             child,
                             ui,
                             id,
                             type_registry,
                             filter,
-                        );
+                            read_only,
+                            auto_expand,
+                        ));
                     });
+                if !read_only {
+                    #[allow(unused_variables)]
+                    let right = favorites::favorite_star(
+                        world,
+                        |world| Favorite::entity(world, child),
+                        ui,
+                        &response.header_response,
+                    );
+                    #[cfg(feature = "bevy_render")]
+                    entity_row::entity_row_toggles(
+                        world,
+                        child,
+                        ui,
+                        &response.header_response,
+                        right,
+                    );
+                }
+                #[cfg(feature = "bevy_gizmos")]
+                gizmos::track_hovered_entity(world, child, &response.header_response);
             }
         }
     }
 
     queue.apply(world);
+    changes
 }
 
 /// Display the components of the given entity
-pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) -> InspectorChanges {
+    ui_for_entity_with_options(world, entity, ui, &ComponentOptions::default())
+}
+
+/// Display the components of the given entity without allowing them to be changed
+pub fn ui_for_entity_readonly(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    ui_for_entity_with_options(
+        world,
+        entity,
+        ui,
+        &ComponentOptions {
+            read_only: true,
+            ..Default::default()
+        },
+    );
+}
+
+/// Options for the components of an entity, used by [`ui_for_entity_with_options`]
+/// instead of the [`InspectorOptions`](crate::InspectorOptions) of the component types.
+///
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_inspector_egui::{bevy_inspector::ComponentOptions, inspector_options::{std_options::NumberOptions, Target}, InspectorOptions};
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// let mut health_options = InspectorOptions::new();
+/// health_options.insert(Target::Field(0), NumberOptions::<f32>::between(0.0, 100.0));
+/// let options = ComponentOptions::default().with::<Health>(health_options);
+/// ```
+#[derive(Default)]
+pub struct ComponentOptions {
+    options: HashMap<TypeId, Box<dyn Any>>,
+    /// Display every component read-only, and don't offer any actions changing the entity
+    pub(crate) read_only: bool,
+    /// Components matching the search, which are expanded once
+    pub(crate) expanded: Vec<TypeId>,
+}
+
+impl ComponentOptions {
+    /// Use `options` for the component `C`
+    pub fn with<C: Component>(mut self, options: impl Any) -> Self {
+        self.insert::<C>(options);
+        self
+    }
+
+    /// Use `options` for the component `C`
+    pub fn insert<C: Component>(&mut self, options: impl Any) {
+        self.options.insert(TypeId::of::<C>(), Box::new(options));
+    }
+
+    pub fn get(&self, type_id: TypeId) -> Option<&dyn Any> {
+        self.options.get(&type_id).map(|options| &**options)
+    }
+}
+
+/// Display the components of the given entity, using `options` for the components that have an entry in it
+pub fn ui_for_entity_with_options(
+    world: &mut World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    options: &ComponentOptions,
+) -> InspectorChanges {
     let type_registry = world.resource::<AppTypeRegistry>().0.clone();
     let type_registry = type_registry.read();
 
@@ -557,139 +1685,668 @@ pub fn ui_for_entity(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
     ui.label(entity_name);
 
     let mut queue = CommandQueue::default();
-    ui_for_entity_components(
+    let changes = ui_for_entity_components_with_options(
         &mut world.into(),
-        Some(&mut queue),
+        (!options.read_only).then_some(&mut queue),
         entity,
         ui,
         egui::Id::new(entity),
         &type_registry,
+        options,
     );
     queue.apply(world);
+    changes
+}
+
+/// Display a single field of a component on the given entity, without the rest of the component.
+///
+/// The path starts with the name of the component, followed by a [reflect path](bevy_reflect::GetPath) into it,
+/// like `Transform.translation.x`. The component can be named by its short or full type path.
+/// Returns whether the value was changed.
+///
+/// ```rust
+/// # use bevy_ecs::prelude::*;
+/// use bevy_inspector_egui::bevy_inspector;
+///
+/// fn show_ui(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+///     bevy_inspector::ui_for_reflect_path(world, entity, "Transform.translation.x", ui);
+/// }
+/// ```
+pub fn ui_for_reflect_path(
+    world: &mut World,
+    entity: Entity,
+    path: &str,
+    ui: &mut egui::Ui,
+) -> bool {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let (component_name, field_path) = split_component_path(path);
+    let Some(registration) = type_registry
+        .get_with_short_type_path(component_name)
+        .or_else(|| type_registry.get_with_type_path(component_name))
+    else {
+        crate::reflect_inspector::errors::not_in_type_registry(ui, component_name);
+        return false;
+    };
+
+    let mut queue = CommandQueue::default();
+    let changed = ui_for_component_field(
+        &mut world.into(),
+        &mut queue,
+        entity,
+        registration.type_id(),
+        field_path,
+        ui,
+        &type_registry,
+    );
+    queue.apply(world);
+    changed
+}
+
+/// Splits `Transform.translation.x` into the component `Transform` and the path `.translation.x`,
+/// ignoring separators inside of generics like `Foo<Bar.Baz>`
+fn split_component_path(path: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    let split = path.char_indices().find(|&(_, c)| {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        depth == 0 && matches!(c, '.' | '[' | '#')
+    });
+    match split {
+        Some((i, _)) => path.split_at(i),
+        None => (path, ""),
+    }
+}
+
+/// Display the field at `path` inside of a component
+pub(crate) fn ui_for_component_field(
+    world: &mut RestrictedWorldView<'_>,
+    queue: &mut CommandQueue,
+    entity: Entity,
+    component: TypeId,
+    path: &str,
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let (mut component_view, world) = world.split_off_component((entity, component));
+    let mut value =
+        match component_view.get_entity_component_reflect(entity, component, type_registry) {
+            Ok(value) => value,
+            Err(e) => {
+                let name = errors::name_of_type(component, type_registry);
+                errors::show_error(e, ui, &name);
+                return false;
+            }
+        };
+
+    let field_value = match value.bypass_change_detection().reflect_path_mut(path) {
+        Ok(field_value) => field_value,
+        Err(e) => {
+            errors::invalid_reflect_path(ui, path, &e.to_string());
+            return false;
+        }
+    };
+
+    let mut cx = Context {
+        world: Some(world),
+        queue: Some(queue),
+    };
+    let changed = InspectorUi::for_bevy(type_registry, &mut cx).ui_for_reflect_with_options(
+        field_value,
+        ui,
+        egui::Id::new((entity, component, path)),
+        &(),
+    );
+    if changed {
+        value.set_changed();
+    }
+    changed
 }
 
 /// Display the components of the given entity
 pub(crate) fn ui_for_entity_components(
+    world: &mut RestrictedWorldView<'_>,
+    queue: Option<&mut CommandQueue>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) -> InspectorChanges {
+    ui_for_entity_components_with_options(
+        world,
+        queue,
+        entity,
+        ui,
+        id,
+        type_registry,
+        &ComponentOptions::default(),
+    )
+}
+
+/// Display the components of the given entity, using `options` for the components that have an entry in it
+pub(crate) fn ui_for_entity_components_with_options(
     world: &mut RestrictedWorldView<'_>,
     mut queue: Option<&mut CommandQueue>,
     entity: Entity,
     ui: &mut egui::Ui,
     id: egui::Id,
     type_registry: &TypeRegistry,
-) {
-    let Some(components) = components_of_entity(world, entity) else {
+    options: &ComponentOptions,
+) -> InspectorChanges {
+    let Some(components) = components_of_entity(ui, world, entity) else {
         errors::entity_does_not_exist(ui, entity);
-        return;
+        return InspectorChanges::default();
     };
+    let filter_settings = world
+        .get_resource_mut::<ComponentFilterSettings>()
+        .map(|settings| settings.clone())
+        .unwrap_or_default();
 
-    for (name, component_id, component_type_id, size) in components {
-        let id = id.with(component_id);
+    let mut components: Vec<ComponentEntry> = components
+        .iter()
+        .filter(|(_, _, type_id, _)| {
+            type_id.is_none_or(|type_id| filter_settings.is_visible(type_id))
+        })
+        .cloned()
+        .collect();
+    if filter_settings.order == ComponentOrder::Insertion {
+        components.sort_by_key(|(_, component_id, ..)| *component_id);
+    }
 
-        let header = egui::CollapsingHeader::new(&name).id_salt(id);
+    #[cfg(feature = "bevy_scene")]
+    let scene_source = world
+        .get_world_mut()
+        .and_then(|world| scene_diff::SceneSource::of(world, entity, type_registry));
+    #[cfg(feature = "bevy_scene")]
+    if let Some(scene_source) = &scene_source {
+        scene_diff::scene_source_ui(scene_source, entity, queue.as_deref_mut(), ui);
+    }
 
-        let Some(component_type_id) = component_type_id else {
-            header.show(ui, |ui| errors::no_type_id(ui, &name));
-            continue;
+    #[cfg(feature = "bevy_gizmos")]
+    gizmos::debug_draw_toggles(world, entity, ui);
+    let mut changes = relationships::related_entities_ui(
+        world,
+        queue.as_deref_mut(),
+        entity,
+        ui,
+        id,
+        type_registry,
+        options.read_only,
+    );
+
+    if filter_settings.grouping == ComponentGrouping::None {
+        for component in components {
+            let type_id = component.2;
+            let changed = ui_for_component(
+                world,
+                queue.as_deref_mut(),
+                entity,
+                ui,
+                id,
+                type_registry,
+                &filter_settings,
+                options,
+                #[cfg(feature = "bevy_scene")]
+                scene_source.as_ref(),
+                component,
+            );
+            if let (true, Some(type_id)) = (changed, type_id) {
+                changes.push_component(entity, type_id);
+            }
+        }
+        return changes;
+    }
+
+    let mut groups: Vec<(String, Vec<_>)> = Vec::new();
+    for component in components {
+        let type_path = world
+            .world()
+            .components()
+            .get_info(component.1)
+            .map_or("", |info| info.name());
+        let group = filter_settings.grouping.group_of(type_path);
+        match groups.iter_mut().find(|(other, _)| *other == group) {
+            Some((_, components)) => components.push(component),
+            None => groups.push((group, vec![component])),
+        }
+    }
+    if filter_settings.grouping == ComponentGrouping::Crate {
+        groups.sort_by(|(a, _), (b, _)| a.cmp(b));
+    } else {
+        groups.sort_by_key(|(group, _)| ComponentGrouping::category_position(group));
+    }
+
+    for (group, components) in groups {
+        egui::CollapsingHeader::new(&group)
+            .id_salt(id.with(("component_group", &group)))
+            .default_open(true)
+            .show(ui, |ui| {
+                for component in components {
+                    let type_id = component.2;
+                    let changed = ui_for_component(
+                        world,
+                        queue.as_deref_mut(),
+                        entity,
+                        ui,
+                        id,
+                        type_registry,
+                        &filter_settings,
+                        options,
+                        #[cfg(feature = "bevy_scene")]
+                        scene_source.as_ref(),
+                        component,
+                    );
+                    if let (true, Some(type_id)) = (changed, type_id) {
+                        changes.push_component(entity, type_id);
+                    }
+                }
+            });
+    }
+
+    changes
+}
+
+/// The [localized](localization) header of a component
+fn component_label<'a>(
+    ui: &egui::Ui,
+    type_registry: &'a TypeRegistry,
+    name: &'a str,
+    component_type_id: Option<TypeId>,
+) -> Cow<'a, str> {
+    let type_path = component_type_id
+        .and_then(|type_id| type_registry.get(type_id))
+        .map(|registration| registration.type_info().type_path());
+    localization::localize(ui.ctx(), localization::Label::Component { type_path, name })
+}
+
+/// Display a single component of the entity, as part of [`ui_for_entity_components`], and return whether it was changed
+fn ui_for_component(
+    world: &mut RestrictedWorldView<'_>,
+    mut queue: Option<&mut CommandQueue>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+    filter_settings: &ComponentFilterSettings,
+    options: &ComponentOptions,
+    #[cfg(feature = "bevy_scene")] scene_source: Option<&scene_diff::SceneSource>,
+    (name, component_id, component_type_id, size): ComponentEntry,
+) -> bool {
+    let id = id.with(component_id);
+
+    if let Some(component_type_id) = component_type_id {
+        let header_id = ui.make_persistent_id(id);
+        auto_expand::expand_once(ui, header_id, options.expanded.contains(&component_type_id));
+    }
+    let label = component_label(ui, type_registry, &name, component_type_id);
+    let header = egui::CollapsingHeader::new(label).id_salt(id);
+
+    let Some(component_type_id) = component_type_id else {
+        header.show(ui, |ui| errors::no_type_id(ui, &name));
+        return false;
+    };
+
+    if size == 0 {
+        header.show(ui, |_| {});
+        return false;
+    }
+
+    #[cfg(feature = "documentation")]
+    let type_docs = type_registry
+        .get_type_info(component_type_id)
+        .and_then(|info| info.docs());
+
+    // create a context with access to the world except for the currently viewed component
+    let (mut component_view, world) = world.split_off_component((entity, component_type_id));
+    let mut cx = Context {
+        world: Some(world),
+        #[allow(clippy::needless_option_as_deref)]
+        queue: queue.as_deref_mut(),
+    };
+
+    let mut value =
+        match component_view.get_entity_component_reflect(entity, component_type_id, type_registry)
+        {
+            Ok(value) => value,
+            Err(e) => {
+                if let Some(world) = cx.world.as_mut() {
+                    doctor::report_access_error(world, &e, &name);
+                }
+                header.show(ui, |ui| errors::show_error(e, ui, &name));
+                return false;
+            }
         };
 
-        if size == 0 {
-            header.show(ui, |_| {});
-            continue;
+    #[cfg(feature = "highlight_changes")]
+    {
+        let strength =
+            crate::egui_utils::change_highlight(ui, id, value.last_changed().get().into());
+        if strength > 0.0 {
+            set_highlight_style(ui, strength);
+        }
+    }
+
+    let read_only = options.read_only || filter_settings.is_read_only(component_type_id);
+    let options = options.get(component_type_id).unwrap_or(&());
+
+    #[cfg(feature = "ron")]
+    let before = (!read_only && cx.world.as_mut().is_some_and(tweaks::is_recording))
+        .then(|| value.as_partial_reflect().clone_value());
+
+    #[cfg(feature = "ron")]
+    let ron_id = id.with("raw_ron");
+    #[cfg(feature = "ron")]
+    let raw_ron = ui.data_mut(|data| data.get_temp::<bool>(ron_id).unwrap_or(false));
+
+    let _response = header.show(ui, |ui| {
+        ui.reset_style();
+
+        if read_only {
+            InspectorUi::for_bevy(type_registry, &mut cx).ui_for_reflect_readonly_with_options(
+                value.as_partial_reflect(),
+                ui,
+                id.with(component_id),
+                options,
+            );
+            return false;
+        }
+
+        #[cfg(feature = "ron")]
+        if raw_ron {
+            if ron_component_ui(
+                value.bypass_change_detection().as_partial_reflect_mut(),
+                component_type_id,
+                ui,
+                id,
+                type_registry,
+            ) {
+                value.set_changed();
+                return true;
+            }
+            return false;
+        }
+
+        let mut watch = watch::FieldPath::component(entity, component_type_id);
+        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+        env.watch = Some(&mut watch);
+        let inspector_changed = env.ui_for_reflect_with_options(
+            value.bypass_change_detection().as_partial_reflect_mut(),
+            ui,
+            id.with(component_id),
+            options,
+        );
+
+        #[allow(unused_mut)]
+        let mut changed = inspector_changed;
+        if inspector_changed {
+            value.set_changed();
+        }
+
+        #[cfg(all(feature = "bevy_render", feature = "bevy_image"))]
+        if let Some(camera) = value
+            .bypass_change_detection()
+            .downcast_mut::<bevy_render::camera::Camera>()
+        {
+            let Context { world, queue } = &mut cx;
+            if let Some(world) = world {
+                if camera_tools::camera_tools_ui(
+                    camera,
+                    entity,
+                    world,
+                    queue.as_deref_mut(),
+                    ui,
+                    id,
+                ) {
+                    value.set_changed();
+                    changed = true;
+                }
+            }
         }
 
-        #[cfg(feature = "documentation")]
-        let type_docs = type_registry
-            .get_type_info(component_type_id)
-            .and_then(|info| info.docs());
-
-        // create a context with access to the world except for the currently viewed component
-        let (mut component_view, world) = world.split_off_component((entity, component_type_id));
-        let mut cx = Context {
-            world: Some(world),
-            #[allow(clippy::needless_option_as_deref)]
-            queue: queue.as_deref_mut(),
-        };
+        #[cfg(feature = "bevy_ui")]
+        if let Some(node) = value.downcast_ref::<bevy_ui::Node>() {
+            let Context { world, queue } = &mut cx;
+            if let Some(world) = world {
+                ui_layout::node_layout_ui(
+                    node,
+                    entity,
+                    world,
+                    queue.as_deref_mut(),
+                    ui,
+                    id,
+                    type_registry,
+                );
+            }
+        }
 
-        let mut value = match component_view.get_entity_component_reflect(
-            entity,
+        changed
+    });
+    #[allow(unused_mut)]
+    let mut changed = _response.body_returned.unwrap_or(false);
+    #[cfg(feature = "ron")]
+    if !read_only {
+        ron_toggle_ui(ui, &_response.header_response, ron_id, raw_ron);
+    }
+    #[cfg(feature = "bevy_scene")]
+    if let Some(scene_source) = scene_source {
+        let revert = scene_diff::override_badge_ui(
+            ui,
+            &_response.header_response,
+            scene_source,
             component_type_id,
-            type_registry,
-        ) {
-            Ok(value) => value,
-            Err(e) => {
-                header.show(ui, |ui| errors::show_error(e, ui, &name));
-                continue;
+            value.as_partial_reflect(),
+        );
+        if revert && !read_only {
+            if let Some(source_value) = scene_source.source_value(component_type_id) {
+                if value
+                    .bypass_change_detection()
+                    .as_partial_reflect_mut()
+                    .try_apply(source_value)
+                    .is_ok()
+                {
+                    value.set_changed();
+                    changed = true;
+                }
             }
-        };
-
-        if value.is_changed() {
-            #[cfg(feature = "highlight_changes")]
-            set_highlight_style(ui);
         }
+    }
+    #[cfg(feature = "ron")]
+    if let (true, Some(before), Some(queue)) = (changed, before, queue.as_deref_mut()) {
+        tweaks::record(
+            queue,
+            watch::FieldOwner::Component {
+                entity,
+                component: component_type_id,
+            },
+            before.as_ref(),
+            value.as_partial_reflect(),
+            type_registry,
+        );
+    }
+    component_filter::component_header_menu(
+        &_response.header_response,
+        component_type_id,
+        value.as_partial_reflect(),
+        read_only,
+        queue,
+    );
+    #[cfg(feature = "documentation")]
+    crate::egui_utils::show_docs(_response.header_response, type_docs);
+    ui.reset_style();
 
-        let _response = header.show(ui, |ui| {
-            ui.reset_style();
+    changed
+}
 
-            let inspector_changed = InspectorUi::for_bevy(type_registry, &mut cx)
-                .ui_for_reflect_with_options(
-                    value.bypass_change_detection().as_partial_reflect_mut(),
-                    ui,
-                    id.with(component_id),
-                    &(),
-                );
+/// Button at the right end of a component header, switching between widgets and raw RON text
+#[cfg(feature = "ron")]
+fn ron_toggle_ui(ui: &mut egui::Ui, header: &egui::Response, ron_id: egui::Id, raw_ron: bool) {
+    let width = 36.0;
+    let rect = egui::Rect::from_min_max(
+        egui::pos2(header.rect.right() - width, header.rect.top()),
+        header.rect.right_bottom(),
+    );
+    let response = ui
+        .put(rect, egui::SelectableLabel::new(raw_ron, "RON"))
+        .on_hover_text(match raw_ron {
+            true => "Show widgets",
+            false => "Edit as RON text",
+        });
+    if response.clicked() {
+        ui.data_mut(|data| data.insert_temp(ron_id, !raw_ron));
+    }
+}
 
-            if inspector_changed {
-                value.set_changed();
+/// Multiline RON text view of a component, which is applied to the component whenever it parses
+#[cfg(feature = "ron")]
+fn ron_component_ui(
+    value: &mut dyn bevy_reflect::PartialReflect,
+    component_type_id: TypeId,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let Some(registration) = type_registry.get(component_type_id) else {
+        crate::reflect_inspector::errors::not_in_type_registry(
+            ui,
+            &crate::utils::pretty_type_name_str(value.reflect_type_path()),
+        );
+        return false;
+    };
+    let draft_id = id.with("ron_draft");
+    let error_id = id.with("ron_error");
+
+    let draft = ui.data_mut(|data| data.get_temp::<String>(draft_id));
+    let mut text = match draft {
+        Some(draft) => draft,
+        None => match crate::utils::ron::serialize(value, type_registry, true) {
+            Ok(text) => text,
+            Err(error) => {
+                crate::egui_utils::error_label(ui, error);
+                return false;
             }
+        },
+    };
+
+    let mut changed = false;
+    let response = ui.add(
+        egui::TextEdit::multiline(&mut text)
+            .font(egui::TextStyle::Monospace)
+            .code_editor()
+            .desired_width(f32::INFINITY),
+    );
+    if response.changed() {
+        let result = crate::utils::ron::deserialize(&text, registration, type_registry)
+            .and_then(|new| value.try_apply(new.as_ref()).map_err(|e| e.to_string()));
+        changed = result.is_ok();
+        ui.data_mut(|data| match result {
+            Ok(()) => data.remove::<String>(error_id),
+            Err(error) => data.insert_temp(error_id, error),
         });
-        #[cfg(feature = "documentation")]
-        crate::egui_utils::show_docs(_response.header_response, type_docs);
-        ui.reset_style();
     }
+
+    // keep the text while editing, so that it isn't reformatted on every keystroke
+    if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(draft_id, text));
+    } else {
+        ui.data_mut(|data| {
+            data.remove::<String>(draft_id);
+            data.remove::<String>(error_id);
+        });
+    }
+
+    if let Some(error) = ui.data_mut(|data| data.get_temp::<String>(error_id)) {
+        crate::egui_utils::error_label(ui, error);
+    }
+
+    changed
 }
 
 #[cfg(feature = "highlight_changes")]
-fn set_highlight_style(ui: &mut egui::Ui) {
-    let highlight_color = egui::Color32::GOLD;
+fn set_highlight_style(ui: &mut egui::Ui, strength: f32) {
+    let theme = crate::theme::InspectorTheme::current(ui.ctx());
+    let highlight_color = theme.highlight_color.gamma_multiply(strength);
+    let width = if theme.shape_cues { 2.0 } else { 1.0 };
 
     let visuals = &mut ui.style_mut().visuals;
     visuals.collapsing_header_frame = true;
     visuals.widgets.inactive.bg_stroke = egui::Stroke {
-        width: 1.0,
+        width,
         color: highlight_color,
     };
     visuals.widgets.active.bg_stroke = egui::Stroke {
-        width: 1.0,
+        width,
         color: highlight_color,
     };
     visuals.widgets.hovered.bg_stroke = egui::Stroke {
-        width: 1.0,
+        width,
         color: highlight_color,
     };
     visuals.widgets.noninteractive.bg_stroke = egui::Stroke {
-        width: 1.0,
+        width,
         color: highlight_color,
     };
 }
 
-fn components_of_entity(
-    world: &mut RestrictedWorldView<'_>,
-    entity: Entity,
-) -> Option<Vec<(String, ComponentId, Option<TypeId>, usize)>> {
-    let entity_ref = world.world().get_entity(entity)?;
+/// The pretty name, id, type id and size of a component
+type ComponentEntry = (Arc<str>, ComponentId, Option<TypeId>, usize);
+
+/// The components of each archetype sorted by name, which are kept in egui's temp data and reused
+/// until a new archetype is created
+#[derive(Clone, Default)]
+struct ComponentListCache {
+    world: Option<WorldId>,
+    generation: Option<ArchetypeGeneration>,
+    archetypes: HashMap<ArchetypeId, Arc<[ComponentEntry]>>,
+}
 
-    let archetype = entity_ref.archetype();
+/// The components of `archetype` sorted by name
+fn archetype_components(
+    world: UnsafeWorldCell<'_>,
+    archetype: &Archetype,
+) -> Arc<[ComponentEntry]> {
     let mut components: Vec<_> = archetype
         .components()
         .map(|component_id| {
-            let info = world.world().components().get_info(component_id).unwrap();
-            let name = pretty_type_name_str(info.name());
+            let info = world.components().get_info(component_id).unwrap();
+            let name = pretty_component_info_name(world.id(), info);
 
             (name, component_id, info.type_id(), info.layout().size())
         })
         .collect();
     components.sort_by(|(name_a, ..), (name_b, ..)| name_a.cmp(name_b));
-    Some(components)
+    components.into()
+}
+
+/// The [`archetype_components`] of the archetype of `entity`, cached in the temp data of `ui`
+fn components_of_entity(
+    ui: &egui::Ui,
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+) -> Option<Arc<[ComponentEntry]>> {
+    let world = world.world();
+    let archetype = world.get_entity(entity)?.archetype();
+    let generation = world.archetypes().generation();
+
+    ui.data_mut(|data| {
+        let cache =
+            data.get_temp_mut_or_default::<ComponentListCache>(egui::Id::new("component_lists"));
+        if cache.world != Some(world.id()) || cache.generation != Some(generation) {
+            *cache = ComponentListCache {
+                world: Some(world.id()),
+                generation: Some(generation),
+                archetypes: HashMap::default(),
+            };
+        }
+        let components = cache
+            .archetypes
+            .entry(archetype.id())
+            .or_insert_with(|| archetype_components(world, archetype));
+        Some(components.clone())
+    })
 }
 
 /// Display the given entity with all its components and children
@@ -705,16 +2362,37 @@ pub fn ui_for_entities_shared_components(
         return;
     };
 
-    let Some(mut components) = components_of_entity(&mut world.into(), first) else {
+    let Some(components) = components_of_entity(ui, &mut world.into(), first) else {
         return errors::entity_does_not_exist(ui, first);
     };
-
-    for &entity in entities.iter().skip(1) {
-        components.retain(|(_, id, _, _)| {
-            world
-                .get_entity(entity)
-                .map_or(true, |entity| entity.contains_id(*id))
+    let components: Vec<ComponentEntry> = components
+        .iter()
+        .filter(|(_, id, _, _)| {
+            entities.iter().skip(1).all(|&entity| {
+                world
+                    .get_entity(entity)
+                    .map_or(true, |entity| entity.contains_id(*id))
+            })
         })
+        .cloned()
+        .collect();
+
+    egui::CollapsingHeader::new("Relationships")
+        .id_salt("shared_relationships")
+        .show(ui, |ui| {
+            relationships::relationship_graph_ui(world, entities, ui, &type_registry);
+        });
+
+    let transform_id = world.component_id::<bevy_transform::components::Transform>();
+    if components
+        .iter()
+        .any(|(_, id, ..)| Some(*id) == transform_id)
+    {
+        egui::CollapsingHeader::new("Arrange")
+            .id_salt("shared_arrange")
+            .show(ui, |ui| {
+                arrange::ui_for_arrange(world, entities, ui);
+            });
     }
 
     let (resources_view, components_view) = RestrictedWorldView::resources_components(world);
@@ -728,61 +2406,66 @@ pub fn ui_for_entities_shared_components(
     let id = egui::Id::NULL;
     for (name, component_id, component_type_id, size) in components {
         let id = id.with(component_id);
-        egui::CollapsingHeader::new(&name)
-            .id_salt(id)
-            .show(ui, |ui| {
-                if size == 0 {
-                    return;
-                }
-                let Some(component_type_id) = component_type_id else {
-                    return errors::no_type_id(ui, &name);
-                };
+        egui::CollapsingHeader::new(component_label(
+            ui,
+            &type_registry,
+            &name,
+            component_type_id,
+        ))
+        .id_salt(id)
+        .show(ui, |ui| {
+            if size == 0 {
+                return;
+            }
+            let Some(component_type_id) = component_type_id else {
+                return errors::no_type_id(ui, &name);
+            };
 
-                let mut values = Vec::with_capacity(entities.len());
+            let mut values = Vec::with_capacity(entities.len());
 
-                for (i, &entity) in entities.iter().enumerate() {
-                    // skip duplicate entities
-                    if entities[0..i].contains(&entity) {
-                        continue;
-                    };
+            for (i, &entity) in entities.iter().enumerate() {
+                // skip duplicate entities
+                if entities[0..i].contains(&entity) {
+                    continue;
+                };
 
-                    // SAFETY: entities are distinct, env has a context with just resources
-                    match unsafe {
-                        components_view.get_entity_component_reflect_unchecked(
-                            entity,
-                            component_type_id,
-                            &type_registry,
-                        )
-                    } {
-                        Ok(value) => {
-                            values.push(value);
-                        }
-                        Err(error) => {
-                            errors::show_error(error, ui, &name);
-                            return;
-                        }
+                // SAFETY: entities are distinct, env has a context with just resources
+                match unsafe {
+                    components_view.get_entity_component_reflect_unchecked(
+                        entity,
+                        component_type_id,
+                        &type_registry,
+                    )
+                } {
+                    Ok(value) => {
+                        values.push(value);
+                    }
+                    Err(error) => {
+                        errors::show_error(error, ui, &name);
+                        return;
                     }
                 }
+            }
 
-                let mut values_reflect: Vec<_> = values
-                    .iter_mut()
-                    .map(|value| value.bypass_change_detection().as_partial_reflect_mut())
-                    .collect();
-                let changed = env.ui_for_reflect_many_with_options(
-                    component_type_id,
-                    &name,
-                    ui,
-                    id.with(component_id),
-                    &(),
-                    values_reflect.as_mut_slice(),
-                    &|a| a,
-                );
-                if changed {
-                    for value in values.iter_mut() {
-                        value.set_changed();
-                    }
+            let mut values_reflect: Vec<_> = values
+                .iter_mut()
+                .map(|value| value.bypass_change_detection().as_partial_reflect_mut())
+                .collect();
+            let changed = env.ui_for_reflect_many_with_options(
+                component_type_id,
+                &name,
+                ui,
+                id.with(component_id),
+                &(),
+                values_reflect.as_mut_slice(),
+                &|a| a,
+            );
+            if changed {
+                for value in values.iter_mut() {
+                    value.set_changed();
                 }
-            });
+            }
+        });
     }
 
     queue.apply(world);
@@ -802,36 +2485,51 @@ pub mod by_type_id {
 
     use super::{
         errors::{self, name_of_type},
-        handle_name,
+        favorites::{self, Favorite},
+        handle_name, InspectorChanges,
     };
 
-    /// Display the resource with the given [`TypeId`]
+    /// Display the resource with the given [`TypeId`], and return whether it was changed
     pub fn ui_for_resource(
         world: &mut World,
         resource_type_id: TypeId,
         ui: &mut egui::Ui,
         name_of_type: &str,
         type_registry: &TypeRegistry,
-    ) {
+    ) -> bool {
         let mut queue = CommandQueue::default();
 
-        {
+        let changed = {
             // create a context with access to the world except for the current resource
             let mut world_view = RestrictedWorldView::new(world);
-            let (mut resource_view, world_view) = world_view.split_off_resource(resource_type_id);
+            #[allow(unused_mut)]
+            let (mut resource_view, mut world_view) =
+                world_view.split_off_resource(resource_type_id);
+            #[cfg(feature = "ron")]
+            let recording = super::tweaks::is_recording(&mut world_view);
             let mut cx = Context {
                 world: Some(world_view),
                 queue: Some(&mut queue),
             };
+            let mut watch = super::watch::FieldPath::resource(resource_type_id);
             let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+            env.watch = Some(&mut watch);
 
             let mut resource = match resource_view
                 .get_resource_reflect_mut_by_id(resource_type_id, type_registry)
             {
                 Ok(resource) => resource,
-                Err(err) => return errors::show_error(err, ui, name_of_type),
+                Err(err) => {
+                    if let Some(world) = env.context.world.as_mut() {
+                        super::doctor::report_access_error(world, &err, name_of_type);
+                    }
+                    errors::show_error(err, ui, name_of_type);
+                    return false;
+                }
             };
 
+            #[cfg(feature = "ron")]
+            let before = recording.then(|| resource.as_partial_reflect().clone_value());
             let changed = env.ui_for_reflect(
                 resource.bypass_change_detection().as_partial_reflect_mut(),
                 ui,
@@ -839,9 +2537,54 @@ pub mod by_type_id {
             if changed {
                 resource.set_changed();
             }
-        }
+            #[cfg(feature = "ron")]
+            if let (true, Some(before)) = (changed, before) {
+                if let Some(queue) = env.context.queue.as_deref_mut() {
+                    super::tweaks::record(
+                        queue,
+                        super::watch::FieldOwner::Resource(resource_type_id),
+                        before.as_ref(),
+                        resource.as_partial_reflect(),
+                        type_registry,
+                    );
+                }
+            }
+            changed
+        };
 
         queue.apply(world);
+        changed
+    }
+
+    /// Display the resource with the given [`TypeId`] without allowing it to be changed
+    pub fn ui_for_resource_readonly(
+        world: &mut World,
+        resource_type_id: TypeId,
+        ui: &mut egui::Ui,
+        name_of_type: &str,
+        type_registry: &TypeRegistry,
+    ) {
+        // create a context with access to the world except for the current resource
+        let mut world_view = RestrictedWorldView::new(world);
+        let (mut resource_view, world_view) = world_view.split_off_resource(resource_type_id);
+        let mut cx = Context {
+            world: Some(world_view),
+            queue: None,
+        };
+        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+
+        let resource =
+            match resource_view.get_resource_reflect_mut_by_id(resource_type_id, type_registry) {
+                Ok(resource) => resource,
+                Err(err) => {
+                    if let Some(world) = env.context.world.as_mut() {
+                        super::doctor::report_access_error(world, &err, name_of_type);
+                    }
+                    return errors::show_error(err, ui, name_of_type);
+                }
+            };
+
+        env.ui_for_reflect_readonly(resource.as_partial_reflect(), ui);
     }
 
     /// Display all assets of the given asset [`TypeId`]
@@ -850,30 +2593,33 @@ pub mod by_type_id {
         asset_type_id: TypeId,
         ui: &mut egui::Ui,
         type_registry: &TypeRegistry,
-    ) {
+    ) -> InspectorChanges {
         let asset_server = world.get_resource::<AssetServer>().cloned();
 
         let Some(registration) = type_registry.get(asset_type_id) else {
-            return crate::reflect_inspector::errors::not_in_type_registry(
+            crate::reflect_inspector::errors::not_in_type_registry(
                 ui,
                 &name_of_type(asset_type_id, type_registry),
             );
+            return InspectorChanges::default();
         };
         let Some(reflect_asset) = registration.data::<ReflectAsset>() else {
-            return errors::no_type_data(
+            errors::no_type_data(
                 ui,
                 &name_of_type(asset_type_id, type_registry),
                 "ReflectAsset",
             );
+            return InspectorChanges::default();
         };
         let Some(reflect_handle) =
             type_registry.get_type_data::<ReflectHandle>(reflect_asset.handle_type_id())
         else {
-            return errors::no_type_data(
+            errors::no_type_data(
                 ui,
                 &name_of_type(reflect_asset.handle_type_id(), type_registry),
                 "ReflectHandle",
             );
+            return InspectorChanges::default();
         };
 
         let ids: Vec<_> = reflect_asset.ids(world).collect();
@@ -887,21 +2633,93 @@ pub mod by_type_id {
             queue: Some(&mut queue),
         };
 
+        let mut changes = InspectorChanges::default();
+        let mut headers = Vec::with_capacity(ids.len());
         for handle_id in ids {
             let id = egui::Id::new(handle_id);
             let mut handle = reflect_handle
                 .typed(UntypedHandle::Weak(handle_id))
                 .into_partial_reflect();
 
+            let response =
+                egui::CollapsingHeader::new(handle_name(handle_id, asset_server.as_ref()))
+                    .id_salt(id)
+                    .show(ui, |ui| {
+                        let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
+                        if env.ui_for_reflect_with_options(&mut *handle, ui, id, &()) {
+                            changes.push_asset(handle_id);
+                        }
+                    });
+            headers.push((handle_id, response.header_response));
+        }
+
+        queue.apply(world);
+        // the stars need the world, which is borrowed by the context while the assets are displayed
+        for (handle_id, header) in headers {
+            favorites::favorite_star(
+                world,
+                |world| Favorite::asset(world, asset_type_id, handle_id),
+                ui,
+                &header,
+            );
+        }
+        changes
+    }
+
+    /// Display all assets of the given asset [`TypeId`] without allowing them to be changed
+    pub fn ui_for_assets_readonly(
+        world: &mut World,
+        asset_type_id: TypeId,
+        ui: &mut egui::Ui,
+        type_registry: &TypeRegistry,
+    ) {
+        let asset_server = world.get_resource::<AssetServer>().cloned();
+
+        let Some(registration) = type_registry.get(asset_type_id) else {
+            return crate::reflect_inspector::errors::not_in_type_registry(
+                ui,
+                &name_of_type(asset_type_id, type_registry),
+            );
+        };
+        let Some(reflect_asset) = registration.data::<ReflectAsset>() else {
+            return errors::no_type_data(
+                ui,
+                &name_of_type(asset_type_id, type_registry),
+                "ReflectAsset",
+            );
+        };
+        let Some(reflect_handle) =
+            type_registry.get_type_data::<ReflectHandle>(reflect_asset.handle_type_id())
+        else {
+            return errors::no_type_data(
+                ui,
+                &name_of_type(reflect_asset.handle_type_id(), type_registry),
+                "ReflectHandle",
+            );
+        };
+
+        let ids: Vec<_> = reflect_asset.ids(world).collect();
+
+        // Displaying the `Handle<T>` will short circuit into displaying the T with a world view excluding Assets<T>.
+        let world_view = RestrictedWorldView::new(world);
+        let mut cx = Context {
+            world: Some(world_view),
+            queue: None,
+        };
+
+        for handle_id in ids {
+            let id = egui::Id::new(handle_id);
+            let handle = reflect_handle
+                .typed(UntypedHandle::Weak(handle_id))
+                .into_partial_reflect();
+
             egui::CollapsingHeader::new(handle_name(handle_id, asset_server.as_ref()))
                 .id_salt(id)
                 .show(ui, |ui| {
                     let mut env = InspectorUi::for_bevy(type_registry, &mut cx);
-                    env.ui_for_reflect_with_options(&mut *handle, ui, id, &());
+                    env.ui_for_reflect_readonly_with_options(&*handle, ui, id, &());
                 });
         }
-
-        queue.apply(world)
     }
 
     /// Display a given asset by handle and asset [`TypeId`]
@@ -987,13 +2805,21 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         type_registry: &'a TypeRegistry,
         context: &'a mut Context<'c>,
     ) -> InspectorUi<'a, 'c> {
-        InspectorUi::new(
+        let overrides = context.world.as_mut().and_then(|world| {
+            world
+                .get_resource_mut::<InspectorOverrides>()
+                .ok()
+                .map(|overrides| overrides.clone())
+        });
+        let mut env = InspectorUi::new(
             type_registry,
             context,
             Some(short_circuit::short_circuit),
             Some(short_circuit::short_circuit_readonly),
             Some(short_circuit::short_circuit_many),
-        )
+        );
+        env.overrides = overrides;
+        env
     }
 }
 
@@ -1074,6 +2900,8 @@ pub mod short_circuit {
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
                 short_circuit_many: env.short_circuit_many,
+                watch: None,
+                overrides: env.overrides.clone(),
             };
             return Some(restricted_env.ui_for_reflect_with_options(
                 asset_value.as_partial_reflect_mut(),
@@ -1171,6 +2999,8 @@ pub mod short_circuit {
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
                 short_circuit_many: env.short_circuit_many,
+                watch: None,
+                overrides: env.overrides.clone(),
             };
             return Some(restricted_env.ui_for_reflect_many_with_options(
                 reflect_handle.asset_type_id(),
@@ -1253,6 +3083,8 @@ pub mod short_circuit {
                 short_circuit: env.short_circuit,
                 short_circuit_readonly: env.short_circuit_readonly,
                 short_circuit_many: env.short_circuit_many,
+                watch: None,
+                overrides: env.overrides.clone(),
             };
             restricted_env.ui_for_reflect_readonly_with_options(
                 asset_value,
@@ -1267,4 +3099,307 @@ pub mod short_circuit {
     }
 }
 
-pub use crate::utils::guess_entity_name::guess_entity_name;
+pub use crate::utils::guess_entity_name::{
+    guess_entity_icon, guess_entity_name, EntityIcons, EntityNameRules,
+};
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+    use super::{EntityFilter, InspectorChanges};
+    use crate::inspector_egui_impls::InspectorOverrides;
+
+    #[derive(Resource, Reflect, Clone, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn run_ui(world: &mut World, mut f: impl FnMut(&mut World, &mut egui::Ui)) {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| f(world, ui));
+        });
+    }
+
+    #[test]
+    fn readonly_resource_ui_does_not_change_resource() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Score>();
+        let mut overrides = InspectorOverrides::default();
+        overrides.insert::<Score>(|value, _, _, _| {
+            value.0 += 1;
+            true
+        });
+        world.insert_resource(overrides);
+        world.insert_resource(Score(0));
+
+        run_ui(&mut world, super::ui_for_resource_readonly::<Score>);
+        run_ui(&mut world, super::ui_for_resources_readonly);
+        assert_eq!(world.resource::<Score>().0, 0);
+
+        let mut changed = false;
+        run_ui(&mut world, |world, ui| {
+            changed = super::ui_for_resource::<Score>(world, ui);
+        });
+        assert!(changed);
+        assert_eq!(world.resource::<Score>().0, 1);
+    }
+
+    #[test]
+    fn inspector_changes_are_deduplicated() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let score = std::any::TypeId::of::<Score>();
+
+        let mut changes = InspectorChanges::default();
+        assert!(changes.is_empty());
+        changes.push_component(b, score);
+        changes.push_component(a, score);
+        changes.push_resource(score);
+
+        let mut other = InspectorChanges::default();
+        other.push_component(a, score);
+        other.push_resource(score);
+        changes.extend(other);
+
+        assert_eq!(changes.components, vec![(b, score), (a, score)]);
+        assert_eq!(changes.resources, vec![score]);
+        assert_eq!(changes.entities(), vec![a, b]);
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Dialogue {
+        lines: Vec<String>,
+    }
+
+    #[test]
+    fn filter_matches_component_names_and_text() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Dialogue>();
+        let entity = world
+            .spawn(Dialogue {
+                lines: vec!["Hello there".to_owned()],
+            })
+            .id();
+
+        let filter = |word: &str, scope| super::Filter::<()> {
+            word: word.to_owned(),
+            is_fuzzy: false,
+            scope,
+            marker: std::marker::PhantomData,
+        };
+        let dialogue = std::any::TypeId::of::<Dialogue>();
+
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+
+        let by_text = filter("hello", super::FilterScope::Components);
+        assert!(by_text.filter_entity(&mut world, entity));
+        assert_eq!(
+            by_text.matching_components(&mut world, entity, &type_registry),
+            vec![dialogue]
+        );
+
+        let by_type = filter("dialog", super::FilterScope::Components);
+        assert_eq!(
+            by_type.matching_components(&mut world, entity, &type_registry),
+            vec![dialogue]
+        );
+
+        assert!(
+            !filter("goodbye", super::FilterScope::Components).filter_entity(&mut world, entity)
+        );
+        assert!(!filter("hello", super::FilterScope::Names).filter_entity(&mut world, entity));
+    }
+
+    #[test]
+    fn filter_keeps_ancestors_and_notices_renames() {
+        use bevy_core::Name;
+        use bevy_hierarchy::BuildChildren;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let root = world.spawn(Name::new("Level")).id();
+        let other_root = world.spawn(Name::new("Menu")).id();
+        let parent = world.spawn(Name::new("Room")).set_parent(root).id();
+        let needle = world.spawn(Name::new("Needle")).set_parent(parent).id();
+
+        let filter = super::Filter::<()> {
+            word: "needle".to_owned(),
+            is_fuzzy: true,
+            scope: super::FilterScope::Names,
+            marker: std::marker::PhantomData,
+        };
+        let mut entities = vec![root, other_root, parent, needle];
+        filter.filter_entities(&mut world, &mut entities);
+        assert_eq!(entities, [root, parent, needle]);
+
+        world.increment_change_tick();
+        world.entity_mut(needle).insert(Name::new("Haystack"));
+        assert!(!filter.filter_entity(&mut world, root));
+    }
+
+    #[test]
+    fn filter_matches_are_kept_until_something_changes() {
+        use bevy_core::Name;
+        use bevy_hierarchy::BuildChildren;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let root = world.spawn(Name::new("Level")).id();
+        let other_root = world.spawn(Name::new("Menu")).id();
+        let needle = world.spawn(Name::new("Needle")).set_parent(root).id();
+
+        let filter = super::Filter::<()> {
+            word: "needle".to_owned(),
+            is_fuzzy: false,
+            scope: super::FilterScope::Names,
+            marker: std::marker::PhantomData,
+        };
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let (visible, matched) = filter.entity_matches(&mut world, &registry);
+        let (visible_again, matched_again) = filter.entity_matches(&mut world, &registry);
+        assert!(std::sync::Arc::ptr_eq(&visible, &visible_again));
+        assert!(std::sync::Arc::ptr_eq(&matched, &matched_again));
+
+        world.entity_mut(needle).set_parent(other_root);
+        let (visible, _) = filter.entity_matches(&mut world, &registry);
+        assert!(visible.contains(&other_root) && !visible.contains(&root));
+
+        // a new entity in the same archetype as a despawned one
+        world.despawn(needle);
+        let respawned = world.spawn(Name::new("Needle")).set_parent(root).id();
+        let (visible, matched) = filter.entity_matches(&mut world, &registry);
+        assert_eq!(*matched, [respawned].into_iter().collect());
+        assert!(visible.contains(&root) && !visible.contains(&other_root));
+    }
+
+    #[derive(Component)]
+    struct Enemy {
+        kind: &'static str,
+    }
+
+    #[test]
+    fn name_rules_are_used_and_filtered() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .get_resource_or_init::<super::EntityNameRules>()
+            .add(|entity| Some(format!("Enemy ({})", entity.get::<Enemy>()?.kind)));
+        let goblin = world.spawn(Enemy { kind: "goblin" }).id();
+        assert_eq!(
+            super::guess_entity_name(&world, goblin),
+            format!("Enemy (goblin) ({goblin})")
+        );
+
+        let filter = super::Filter::<()> {
+            word: "orc".to_owned(),
+            is_fuzzy: false,
+            scope: super::FilterScope::Names,
+            marker: std::marker::PhantomData,
+        };
+        assert!(!filter.filter_entity(&mut world, goblin));
+
+        // the component isn't tracked by the name cache of the filter
+        world.increment_change_tick();
+        world.get_mut::<Enemy>(goblin).unwrap().kind = "orc";
+        assert!(filter.filter_entity(&mut world, goblin));
+    }
+
+    #[derive(bevy_asset::Asset, Reflect, Default)]
+    #[reflect(Default)]
+    struct Level {
+        name: String,
+    }
+
+    #[test]
+    fn creates_default_assets() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<bevy_asset::Assets<Level>>();
+        assert!(super::create_default_asset::<Level>(&mut world).is_none());
+
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Level>();
+        let handle = super::create_default_asset::<Level>(&mut world).unwrap();
+        let assets = world.resource::<bevy_asset::Assets<Level>>();
+        assert_eq!(assets.get(&handle).unwrap().name, "");
+    }
+
+    #[derive(Component, Reflect, Clone)]
+    struct Stats {
+        speed: f32,
+    }
+
+    #[test]
+    fn component_ui_shows_every_entity() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stats>();
+        let mut overrides = InspectorOverrides::default();
+        overrides.insert::<Stats>(|value, _, _, _| {
+            value.speed += 1.0;
+            true
+        });
+        world.insert_resource(overrides);
+        let a = world.spawn(Stats { speed: 1.0 }).id();
+        let b = world.spawn(Stats { speed: 2.0 }).id();
+        world.spawn_empty();
+
+        let mut changes = InspectorChanges::default();
+        run_ui(&mut world, |world, ui| {
+            changes = super::ui_for_components::<Stats>(world, ui);
+        });
+        assert_eq!(changes.entities(), vec![a, b]);
+        assert_eq!(world.get::<Stats>(a).unwrap().speed, 2.0);
+        assert_eq!(world.get::<Stats>(b).unwrap().speed, 3.0);
+    }
+
+    #[derive(Component)]
+    struct Beta;
+    #[derive(Component)]
+    struct Alpha;
+
+    #[test]
+    fn components_of_entity_are_sorted_and_updated() {
+        let mut world = World::new();
+        let entity = world.spawn(Beta).id();
+        let ctx = egui::Context::default();
+        let names = |world: &mut World| -> Vec<String> {
+            let mut names = Vec::new();
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    names = super::components_of_entity(ui, &mut world.into(), entity)
+                        .unwrap()
+                        .iter()
+                        .map(|(name, ..)| name.to_string())
+                        .collect();
+                });
+            });
+            names
+        };
+        assert_eq!(names(&mut world), ["Beta"]);
+
+        world.entity_mut(entity).insert(Alpha);
+        assert_eq!(names(&mut world), ["Alpha", "Beta"]);
+        world.spawn(Alpha);
+        assert_eq!(names(&mut world), ["Alpha", "Beta"]);
+    }
+}
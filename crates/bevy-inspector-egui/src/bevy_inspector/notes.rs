@@ -0,0 +1,366 @@
+//! Free-text notes attached to entities, component types and resources, for keeping track of things during long debugging sessions.
+//!
+//! The context menus of entities, components and resources in the inspector have a "📝 Note" entry, which adds a note
+//! to the [`InspectorNotes`] resource. The notes are listed and edited in [`ui_for_notes`], which is also shown
+//! at the top of the [world inspector](super::ui_for_world) while there are any.
+//!
+//! Entities are identified by the [`Name`](bevy_core::Name)s of them and their ancestors, and components and resources by their type path,
+//! so that with the `ron` feature the notes can be [persisted](InspectorNotes::persisted) to a file and shown again after restarting the app.
+//!
+//! ```rust
+//! # use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::notes::{InspectorNotes, NoteTarget};
+//!
+//! fn annotate(world: &mut World, entity: Entity) {
+//!     let target = NoteTarget::entity(world, entity);
+//!     world
+//!         .resource_mut::<InspectorNotes>()
+//!         .set(target, "falls through the floor after respawning");
+//! }
+//! ```
+
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_reflect::TypeRegistry;
+
+use super::favorites;
+use crate::utils::guess_entity_name::guess_entity_name;
+
+/// The entity, component type or resource a note is attached to
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ron", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteTarget {
+    Entity {
+        /// Names of the entity and its ancestors, starting at the root, or `None` if one of them is unnamed
+        names: Option<Vec<String>>,
+        /// The entity at the time the note was added, used if it has no `names`
+        entity: u64,
+    },
+    Component {
+        /// Type path of the component
+        component: String,
+    },
+    Resource {
+        /// Type path of the resource
+        resource: String,
+    },
+}
+
+impl NoteTarget {
+    /// The target for `entity`, remembered by its name path if possible
+    pub fn entity(world: &World, entity: Entity) -> Self {
+        NoteTarget::Entity {
+            names: favorites::name_path(world, entity),
+            entity: entity.to_bits(),
+        }
+    }
+
+    pub fn component(type_path: impl Into<String>) -> Self {
+        NoteTarget::Component {
+            component: type_path.into(),
+        }
+    }
+
+    pub fn resource(type_path: impl Into<String>) -> Self {
+        NoteTarget::Resource {
+            resource: type_path.into(),
+        }
+    }
+
+    /// Whether `self` and `other` point to the same entity or type
+    fn is(&self, other: &NoteTarget) -> bool {
+        match (self, other) {
+            (
+                NoteTarget::Entity { names: Some(a), .. },
+                NoteTarget::Entity { names: Some(b), .. },
+            ) => a == b,
+            (
+                NoteTarget::Entity {
+                    names: None,
+                    entity: a,
+                },
+                NoteTarget::Entity {
+                    names: None,
+                    entity: b,
+                },
+            ) => a == b,
+            (NoteTarget::Component { component: a }, NoteTarget::Component { component: b }) => {
+                a == b
+            }
+            (NoteTarget::Resource { resource: a }, NoteTarget::Resource { resource: b }) => a == b,
+            _ => false,
+        }
+    }
+
+    /// The entity the note is attached to, if it currently exists
+    pub fn find_entity(&self, world: &World) -> Option<Entity> {
+        match self {
+            NoteTarget::Entity {
+                names: Some(names), ..
+            } => favorites::entity_by_name_path(world, names),
+            NoteTarget::Entity {
+                names: None,
+                entity,
+            } => Entity::try_from_bits(*entity)
+                .ok()
+                .filter(|&entity| world.get_entity(entity).is_ok()),
+            _ => None,
+        }
+    }
+
+    fn label(&self, world: &World, type_registry: &TypeRegistry) -> String {
+        let short_path = |type_path: &str| {
+            type_registry.get_with_type_path(type_path).map_or_else(
+                || type_path.to_owned(),
+                |registration| {
+                    registration
+                        .type_info()
+                        .type_path_table()
+                        .short_path()
+                        .to_owned()
+                },
+            )
+        };
+        match self {
+            NoteTarget::Entity { names, entity } => match self.find_entity(world) {
+                Some(entity) => guess_entity_name(world, entity),
+                None => match names {
+                    Some(names) => names.join("/"),
+                    None => format!("Entity {entity}"),
+                },
+            },
+            NoteTarget::Component { component } => short_path(component),
+            NoteTarget::Resource { resource } => short_path(resource),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "ron", derive(serde::Serialize, serde::Deserialize))]
+struct Note {
+    target: NoteTarget,
+    text: String,
+}
+
+/// The notes shown in [`ui_for_notes`], in the order they were added
+#[derive(Resource, Debug, Default, Clone)]
+#[cfg_attr(feature = "ron", derive(serde::Serialize, serde::Deserialize))]
+pub struct InspectorNotes {
+    notes: Vec<Note>,
+    /// The note which gets the keyboard focus the next time the notes are displayed
+    #[cfg_attr(feature = "ron", serde(skip))]
+    editing: Option<NoteTarget>,
+    /// The file the notes are saved to, see [`InspectorNotes::persisted`]
+    #[cfg(all(feature = "ron", not(target_arch = "wasm32")))]
+    #[serde(skip)]
+    file: Option<std::path::PathBuf>,
+}
+
+impl InspectorNotes {
+    /// Sets the note of `target`, removing it if `text` is empty
+    pub fn set(&mut self, target: NoteTarget, text: impl Into<String>) {
+        let text = text.into();
+        if text.is_empty() {
+            self.remove(&target);
+            return;
+        }
+        match self.notes.iter_mut().find(|note| note.target.is(&target)) {
+            Some(note) => note.text = text,
+            None => self.notes.push(Note { target, text }),
+        }
+    }
+
+    pub fn get(&self, target: &NoteTarget) -> Option<&str> {
+        self.notes
+            .iter()
+            .find(|note| note.target.is(target))
+            .map(|note| note.text.as_str())
+    }
+
+    pub fn remove(&mut self, target: &NoteTarget) {
+        self.notes.retain(|note| !note.target.is(target));
+    }
+
+    /// Adds an empty note for `target` if it has none, and focuses it the next time the notes are displayed
+    pub fn edit(&mut self, target: NoteTarget) {
+        if self.get(&target).is_none() {
+            self.notes.push(Note {
+                target: target.clone(),
+                text: String::new(),
+            });
+        }
+        self.editing = Some(target);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&NoteTarget, &str)> {
+        self.notes
+            .iter()
+            .map(|note| (&note.target, note.text.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+    }
+
+    #[cfg(feature = "ron")]
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Loads the notes from the RON `file`, or starts without notes if it doesn't exist yet.
+    ///
+    /// The notes are saved back to the file whenever one is edited or removed in [`ui_for_notes`].
+    #[cfg(all(feature = "ron", not(target_arch = "wasm32")))]
+    pub fn persisted(file: impl Into<std::path::PathBuf>) -> Result<Self, String> {
+        let file = file.into();
+        let mut notes = match std::fs::read_to_string(&file) {
+            Ok(text) => InspectorNotes::from_ron(&text)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => InspectorNotes::default(),
+            Err(error) => return Err(format!("{}: {error}", file.display())),
+        };
+        notes.file = Some(file);
+        Ok(notes)
+    }
+
+    /// Writes the notes to the file they were [loaded from](InspectorNotes::persisted), if any
+    pub fn save(&self) -> Result<(), String> {
+        #[cfg(all(feature = "ron", not(target_arch = "wasm32")))]
+        if let Some(file) = &self.file {
+            let ron = self.to_ron()?;
+            std::fs::write(file, ron).map_err(|error| format!("{}: {error}", file.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// A context menu button which adds a note for `target` to the [`InspectorNotes`]
+pub(crate) fn note_button(world: &mut World, target: NoteTarget, ui: &mut egui::Ui) {
+    if ui.button("📝 Note").clicked() {
+        world.get_resource_or_init::<InspectorNotes>().edit(target);
+        ui.close_menu();
+    }
+}
+
+/// Like [`note_button`], for context menus which can only access the world through a [`CommandQueue`]
+pub(crate) fn note_button_queued(queue: &mut CommandQueue, target: NoteTarget, ui: &mut egui::Ui) {
+    if ui.button("📝 Note").clicked() {
+        queue.push(move |world: &mut World| {
+            world.get_resource_or_init::<InspectorNotes>().edit(target);
+        });
+        ui.close_menu();
+    }
+}
+
+/// Display the [`InspectorNotes`], each with a text field for editing it and a button to remove it
+pub fn ui_for_notes(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    notes_ui(world, ui, &type_registry);
+}
+
+pub(crate) fn notes_ui(world: &mut World, ui: &mut egui::Ui, type_registry: &TypeRegistry) {
+    if !world.contains_resource::<InspectorNotes>() {
+        ui.label("No InspectorNotes resource. Add the DefaultInspectorConfigPlugin.");
+        return;
+    }
+
+    world.resource_scope(|world, mut notes: Mut<InspectorNotes>| {
+        if notes.is_empty() {
+            ui.weak("No notes. Add one from the context menu of an entity, component or resource.");
+            return;
+        }
+
+        let editing = notes.bypass_change_detection().editing.take();
+        let id = egui::Id::new("inspector_notes");
+        let mut remove = None;
+        let mut changed = false;
+        let mut save = false;
+        for (i, note) in notes.bypass_change_detection().notes.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                let label = note.target.label(world, type_registry);
+                let exists = match note.target {
+                    NoteTarget::Entity { .. } => note.target.find_entity(world).is_some(),
+                    _ => true,
+                };
+                ui.add_enabled(
+                    exists,
+                    egui::Label::new(egui::RichText::new(label).strong()),
+                );
+                if ui.small_button("🗑").on_hover_text("Remove note").clicked() {
+                    remove = Some(i);
+                }
+            });
+            let response = ui.add(
+                egui::TextEdit::multiline(&mut note.text)
+                    .id(id.with(i))
+                    .hint_text("Note")
+                    .desired_rows(2)
+                    .desired_width(f32::INFINITY),
+            );
+            if editing
+                .as_ref()
+                .is_some_and(|target| target.is(&note.target))
+            {
+                response.request_focus();
+            }
+            if response.lost_focus() {
+                save = true;
+                if note.text.is_empty() {
+                    remove = Some(i);
+                }
+            }
+            if response.changed() {
+                changed = true;
+            }
+        }
+        if let Some(i) = remove {
+            notes.notes.remove(i);
+            save = true;
+        } else if changed {
+            notes.set_changed();
+        }
+
+        if save {
+            if let Err(e) = notes.save() {
+                bevy_log::error!("Could not save inspector notes: {e}");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+
+    use super::{InspectorNotes, NoteTarget};
+
+    #[test]
+    fn entity_notes_survive_respawning() {
+        let mut world = World::new();
+        let root = world.spawn(Name::new("Level")).id();
+        let player = world.spawn(Name::new("Player")).set_parent(root).id();
+        let unnamed = world.spawn_empty().id();
+
+        let mut notes = InspectorNotes::default();
+        notes.set(NoteTarget::entity(&world, player), "clips into walls");
+        notes.set(NoteTarget::entity(&world, unnamed), "spawned twice?");
+        notes.set(NoteTarget::resource("game::Score"), "resets on pause");
+
+        world.entity_mut(player).despawn();
+        let respawned = world.spawn(Name::new("Player")).set_parent(root).id();
+        let target = NoteTarget::entity(&world, respawned);
+        assert_eq!(notes.get(&target), Some("clips into walls"));
+        assert_eq!(target.find_entity(&world), Some(respawned));
+
+        notes.set(NoteTarget::entity(&world, unnamed), "");
+        assert_eq!(notes.get(&NoteTarget::entity(&world, unnamed)), None);
+        assert_eq!(notes.iter().count(), 2);
+    }
+}
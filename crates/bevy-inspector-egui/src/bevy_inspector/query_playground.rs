@@ -0,0 +1,275 @@
+//! A read-only query inspector, where a query is built at runtime from component names.
+//!
+//! Every term of the query is a component with one of the [`TermKind`]s. The matching entities are
+//! listed live and can be clicked to select them in the hierarchy.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::{hierarchy::SelectedEntities, query_playground};
+//!
+//! fn show_ui(world: &mut World, ui: &mut egui::Ui, selected: &mut SelectedEntities) {
+//!     if query_playground::query_playground_ui(world, ui, selected) {
+//!         // an entity was clicked
+//!     }
+//! }
+//! ```
+
+use bevy_ecs::{component::ComponentId, component::Tick, prelude::*};
+use bevy_utils::HashSet;
+
+use super::hierarchy::SelectedEntities;
+use crate::{
+    dropdown::DropDownBox,
+    utils::{
+        guess_entity_name::guess_entity_name, pretty_name_cache::pretty_component_name,
+        pretty_type_name_str,
+    },
+};
+
+/// Maximum number of matching entities listed by [`query_playground_ui`]
+const MAX_LISTED_ENTITIES: usize = 200;
+
+/// How a component is used in a [`QueryTerm`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermKind {
+    /// `With<T>`
+    With,
+    /// `Without<T>`
+    Without,
+    /// `Changed<T>`, since the previous evaluation of the query
+    Changed,
+}
+
+/// A single component filter of a [`PlaygroundQuery`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryTerm {
+    pub component: ComponentId,
+    pub kind: TermKind,
+}
+
+/// A query built at runtime, matching the entities which fulfill every [`QueryTerm`]
+#[derive(Debug, Clone, Default)]
+pub struct PlaygroundQuery {
+    pub terms: Vec<QueryTerm>,
+    /// Change tick of the previous evaluation, for [`TermKind::Changed`]
+    last_run: Option<Tick>,
+}
+
+impl PlaygroundQuery {
+    /// The entities matching all terms.
+    ///
+    /// Like in a system, [`TermKind::Changed`] is relative to the previous call of this method.
+    pub fn matching_entities(&mut self, world: &World) -> Vec<Entity> {
+        let this_run = world.read_change_tick();
+        let last_run = self.last_run.replace(this_run).unwrap_or(Tick::new(0));
+
+        let required: Vec<ComponentId> = self
+            .terms
+            .iter()
+            .filter(|term| term.kind != TermKind::Without)
+            .map(|term| term.component)
+            .collect();
+        let excluded: Vec<ComponentId> = self
+            .terms
+            .iter()
+            .filter(|term| term.kind == TermKind::Without)
+            .map(|term| term.component)
+            .collect();
+        let changed: Vec<ComponentId> = self
+            .terms
+            .iter()
+            .filter(|term| term.kind == TermKind::Changed)
+            .map(|term| term.component)
+            .collect();
+
+        world
+            .archetypes()
+            .iter()
+            .filter(|archetype| {
+                required.iter().all(|&id| archetype.contains(id))
+                    && !excluded.iter().any(|&id| archetype.contains(id))
+            })
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+            .filter(|&entity| {
+                changed.iter().all(|&id| {
+                    world
+                        .entity(entity)
+                        .get_change_ticks_by_id(id)
+                        .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Names of all components which are part of an archetype, sorted by name
+fn spawned_components(world: &World) -> Vec<(String, ComponentId)> {
+    let ids: HashSet<ComponentId> = world
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| archetype.components())
+        .collect();
+    let mut components: Vec<(String, ComponentId)> = ids
+        .into_iter()
+        .filter_map(|id| {
+            let name = pretty_component_name(world.as_unsafe_world_cell_readonly(), id)?;
+            Some((name.to_string(), id))
+        })
+        .collect();
+    components.sort();
+    components
+}
+
+#[derive(Clone, Default)]
+struct PlaygroundState {
+    query: PlaygroundQuery,
+    search: String,
+}
+
+/// Display the query playground: a picker to add components to the query, the terms of the query,
+/// and the matching entities.
+///
+/// The query is stored in egui memory. Returns `true` if an entity was clicked and added to `selected`.
+pub fn query_playground_ui(
+    world: &mut World,
+    ui: &mut egui::Ui,
+    selected: &mut SelectedEntities,
+) -> bool {
+    let id = egui::Id::new("query_playground");
+    let mut state = ui.data_mut(|data| data.get_temp::<PlaygroundState>(id).unwrap_or_default());
+
+    let components = spawned_components(world);
+    let component_name = |component: ComponentId| {
+        components
+            .iter()
+            .find(|(_, id)| *id == component)
+            .map(|(name, _)| name.clone())
+            .or_else(|| {
+                world
+                    .components()
+                    .get_name(component)
+                    .map(pretty_type_name_str)
+            })
+            .unwrap_or_else(|| format!("{component:?}"))
+    };
+
+    let mut added = None;
+    let dropdown = DropDownBox::from_iter(
+        components.iter().map(|(name, _)| name),
+        id.with("component_picker"),
+        &mut state.search,
+        |ui, name| {
+            let response = ui.selectable_label(false, name);
+            if response.clicked() {
+                added = components
+                    .iter()
+                    .find(|(component_name, _)| component_name == name)
+                    .map(|(_, id)| *id);
+            }
+            response
+        },
+    )
+    .hint_text("Add component")
+    .desired_width(f32::INFINITY);
+    ui.add(dropdown);
+    if let Some(component) = added {
+        state.query.terms.push(QueryTerm {
+            component,
+            kind: TermKind::With,
+        });
+        state.search.clear();
+    }
+
+    let mut removed = None;
+    for (i, term) in state.query.terms.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut term.kind, TermKind::With, "With");
+            ui.selectable_value(&mut term.kind, TermKind::Without, "Without");
+            ui.selectable_value(&mut term.kind, TermKind::Changed, "Changed");
+            ui.label(component_name(term.component));
+            if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                removed = Some(i);
+            }
+        });
+    }
+    if let Some(i) = removed {
+        state.query.terms.remove(i);
+    }
+
+    ui.separator();
+
+    let mut clicked = false;
+    if state.query.terms.is_empty() {
+        ui.weak("Add a component to query for entities");
+    } else {
+        let entities = state.query.matching_entities(world);
+        ui.label(format!("{} matching entities", entities.len()));
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for &entity in entities.iter().take(MAX_LISTED_ENTITIES) {
+                    let response = ui.selectable_label(
+                        selected.contains(entity),
+                        guess_entity_name(world, entity),
+                    );
+                    if response.clicked() {
+                        let add = ui.input(|input| input.modifiers.ctrl);
+                        selected.select_maybe_add(entity, add);
+                        clicked = true;
+                    }
+                }
+                if entities.len() > MAX_LISTED_ENTITIES {
+                    ui.weak(format!("and {} more", entities.len() - MAX_LISTED_ENTITIES));
+                }
+            });
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, state));
+    clicked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlaygroundQuery, QueryTerm, TermKind};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Component)]
+    struct A(u32);
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn terms_filter_entities() {
+        let mut world = World::new();
+        let a = world.spawn(A(0)).id();
+        let ab = world.spawn((A(0), B)).id();
+        world.spawn(B);
+        let component_a = world.register_component::<A>();
+        let component_b = world.register_component::<B>();
+
+        let mut query = PlaygroundQuery {
+            terms: vec![
+                QueryTerm {
+                    component: component_a,
+                    kind: TermKind::With,
+                },
+                QueryTerm {
+                    component: component_b,
+                    kind: TermKind::Without,
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(query.matching_entities(&world), vec![a]);
+
+        query.terms[1].kind = TermKind::With;
+        assert_eq!(query.matching_entities(&world), vec![ab]);
+
+        query.terms[0].kind = TermKind::Changed;
+        world.increment_change_tick();
+        assert!(query.matching_entities(&world).is_empty());
+        world.increment_change_tick();
+        world.get_mut::<A>(ab).unwrap().0 = 1;
+        assert_eq!(query.matching_entities(&world), vec![ab]);
+    }
+}
@@ -0,0 +1,465 @@
+//! Relationships between entities other than the hierarchy.
+//!
+//! Components are treated as relationships if their type is registered with [`ReflectComponent`] and
+//! [`ReflectMapEntities`], which is required for entity references to survive scene spawning.
+//! The referenced entities are found by walking the reflected component value.
+//!
+//! The entity inspector shows a "Related entities" section with the relations from and to the entity,
+//! and [`relationship_graph_ui`] draws the relations between several entities.
+
+use std::{any::TypeId, sync::Arc};
+
+use bevy_ecs::{
+    archetype::ArchetypeGeneration,
+    component::ComponentId,
+    entity::Entity,
+    prelude::*,
+    reflect::{ReflectComponent, ReflectMapEntities},
+    world::{unsafe_world_cell::UnsafeWorldCell, CommandQueue, WorldId},
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{PartialReflect, ReflectRef, TypeRegistry};
+
+use super::InspectorChanges;
+use crate::restricted_world_view::RestrictedWorldView;
+use crate::utils::guess_entity_name::guess_entity_name_restricted;
+
+/// A component of `source` which references `target`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relation {
+    pub source: Entity,
+    pub target: Entity,
+    pub component: TypeId,
+    /// Short name of the component type
+    pub component_name: String,
+}
+
+/// A component type which can reference other entities, with its [`ComponentId`] and short name
+type RelationshipComponent = (TypeId, ComponentId, String);
+
+/// The relationship components of a world, which are kept in egui's temp data and reused
+/// until a new archetype is created or more types are registered
+#[derive(Clone)]
+struct RelationshipComponentCache {
+    world: WorldId,
+    generation: ArchetypeGeneration,
+    registered_types: usize,
+    components: Arc<[RelationshipComponent]>,
+}
+
+/// Component types which can reference other entities, with their [`ComponentId`], if an archetype has them.
+///
+/// [`Children`] is left out, since it mirrors [`Parent`].
+fn relationship_components(
+    world: UnsafeWorldCell<'_>,
+    type_registry: &TypeRegistry,
+) -> Arc<[RelationshipComponent]> {
+    type_registry
+        .iter()
+        .filter(|registration| {
+            registration.data::<ReflectComponent>().is_some()
+                && registration.data::<ReflectMapEntities>().is_some()
+                && registration.type_id() != TypeId::of::<Children>()
+        })
+        .filter_map(|registration| {
+            let component_id = world.components().get_id(registration.type_id())?;
+            let spawned = world
+                .archetypes()
+                .iter()
+                .any(|archetype| archetype.contains(component_id));
+            if !spawned {
+                return None;
+            }
+            let name = registration
+                .type_info()
+                .type_path_table()
+                .short_path()
+                .to_owned();
+            Some((registration.type_id(), component_id, name))
+        })
+        .collect()
+}
+
+/// [`relationship_components`], cached in the temp data of `ui`
+fn cached_relationship_components(
+    ui: &egui::Ui,
+    world: UnsafeWorldCell<'_>,
+    type_registry: &TypeRegistry,
+) -> Arc<[RelationshipComponent]> {
+    let id = egui::Id::new("relationship_components");
+    let generation = world.archetypes().generation();
+    let registered_types = type_registry.iter().count();
+    let cached = ui.data(|data| data.get_temp::<RelationshipComponentCache>(id));
+    match cached {
+        Some(cache)
+            if cache.world == world.id()
+                && cache.generation == generation
+                && cache.registered_types == registered_types =>
+        {
+            cache.components
+        }
+        _ => {
+            let components = relationship_components(world, type_registry);
+            ui.data_mut(|data| {
+                data.insert_temp(
+                    id,
+                    RelationshipComponentCache {
+                        world: world.id(),
+                        generation,
+                        registered_types,
+                        components: components.clone(),
+                    },
+                );
+            });
+            components
+        }
+    }
+}
+
+/// The `components` except for [`Parent`], whose relations are shown by the hierarchy
+fn without_hierarchy(components: &[RelationshipComponent]) -> Vec<RelationshipComponent> {
+    components
+        .iter()
+        .filter(|(type_id, ..)| *type_id != TypeId::of::<Parent>())
+        .cloned()
+        .collect()
+}
+
+/// Collects all [`Entity`] values in `value`
+fn collect_entities(value: &dyn PartialReflect, entities: &mut Vec<Entity>) {
+    if let Some(entity) = value.try_downcast_ref::<Entity>() {
+        if *entity != Entity::PLACEHOLDER {
+            entities.push(*entity);
+        }
+        return;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value
+            .iter_fields()
+            .for_each(|field| collect_entities(field, entities)),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .for_each(|field| collect_entities(field, entities)),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .for_each(|field| collect_entities(field, entities)),
+        ReflectRef::List(value) => value
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Array(value) => value
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Set(value) => value
+            .iter()
+            .for_each(|item| collect_entities(item, entities)),
+        ReflectRef::Map(value) => value.iter().for_each(|(key, value)| {
+            collect_entities(key, entities);
+            collect_entities(value, entities);
+        }),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .for_each(|field| collect_entities(field.value(), entities)),
+        _ => {}
+    }
+}
+
+fn relations_of(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    components: &[RelationshipComponent],
+    type_registry: &TypeRegistry,
+) -> Vec<Relation> {
+    let mut relations = Vec::new();
+    for (type_id, _, name) in components {
+        let Ok(value) = world.get_entity_component_reflect(entity, *type_id, type_registry) else {
+            continue;
+        };
+        let mut targets = Vec::new();
+        collect_entities(value.as_partial_reflect(), &mut targets);
+        relations.extend(targets.into_iter().map(|target| Relation {
+            source: entity,
+            target,
+            component: *type_id,
+            component_name: name.clone(),
+        }));
+    }
+    relations
+}
+
+/// The relations from `entity` to other entities, except for its [`Parent`]
+pub fn outgoing_relations(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Vec<Relation> {
+    let components = without_hierarchy(&relationship_components(world.world(), type_registry));
+    relations_of(world, entity, &components, type_registry)
+}
+
+/// The relations from other entities to `entity`, except for its children.
+///
+/// This looks at every entity with a relationship component, so it should only be called on demand.
+pub fn incoming_relations(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Vec<Relation> {
+    let components = without_hierarchy(&relationship_components(world.world(), type_registry));
+    incoming_relations_of(world, entity, &components, type_registry)
+}
+
+fn incoming_relations_of(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    components: &[RelationshipComponent],
+    type_registry: &TypeRegistry,
+) -> Vec<Relation> {
+    let sources: Vec<Entity> = world
+        .world()
+        .archetypes()
+        .iter()
+        .filter(|archetype| {
+            components
+                .iter()
+                .any(|(_, component_id, _)| archetype.contains(*component_id))
+        })
+        .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+        .filter(|source| *source != entity)
+        .collect();
+
+    sources
+        .into_iter()
+        .flat_map(|source| relations_of(world, source, components, type_registry))
+        .filter(|relation| relation.target == entity)
+        .collect()
+}
+
+/// "Related entities" section of [`ui_for_entity_components`](super::ui_for_entity_components).
+///
+/// Every related entity can be expanded to show its components. The incoming relations are only searched while their header is open.
+pub(crate) fn related_entities_ui(
+    world: &mut RestrictedWorldView<'_>,
+    mut queue: Option<&mut CommandQueue>,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+    read_only: bool,
+) -> InspectorChanges {
+    let mut changes = InspectorChanges::default();
+    let components = without_hierarchy(&cached_relationship_components(
+        ui,
+        world.world(),
+        type_registry,
+    ));
+    if components.is_empty() {
+        return changes;
+    }
+    let outgoing = relations_of(world, entity, &components, type_registry);
+
+    egui::CollapsingHeader::new(format!("Related entities ({})", outgoing.len()))
+        .id_salt(id.with("related_entities"))
+        .show(ui, |ui| {
+            for (i, relation) in outgoing.iter().enumerate() {
+                changes.extend(relation_row_ui(
+                    world,
+                    queue.as_deref_mut(),
+                    &relation.component_name,
+                    "→",
+                    relation.target,
+                    ui,
+                    id.with(("outgoing", i)),
+                    type_registry,
+                    read_only,
+                ));
+            }
+            egui::CollapsingHeader::new("Referenced by")
+                .id_salt(id.with("incoming_relations"))
+                .show(ui, |ui| {
+                    let incoming = incoming_relations_of(world, entity, &components, type_registry);
+                    if incoming.is_empty() {
+                        ui.weak("No other entity references this one");
+                    }
+                    for (i, relation) in incoming.iter().enumerate() {
+                        changes.extend(relation_row_ui(
+                            world,
+                            queue.as_deref_mut(),
+                            &relation.component_name,
+                            "←",
+                            relation.source,
+                            ui,
+                            id.with(("incoming", i)),
+                            type_registry,
+                            read_only,
+                        ));
+                    }
+                });
+        });
+
+    changes
+}
+
+#[allow(clippy::too_many_arguments)]
+fn relation_row_ui(
+    world: &mut RestrictedWorldView<'_>,
+    queue: Option<&mut CommandQueue>,
+    component_name: &str,
+    arrow: &str,
+    other: Entity,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+    read_only: bool,
+) -> InspectorChanges {
+    let name = guess_entity_name_restricted(world, other);
+    egui::CollapsingHeader::new(format!("{component_name} {arrow} {name}"))
+        .id_salt(id)
+        .show(ui, |ui| {
+            super::ui_for_entity_components_with_options(
+                world,
+                queue,
+                other,
+                ui,
+                id,
+                type_registry,
+                &super::ComponentOptions {
+                    read_only,
+                    ..Default::default()
+                },
+            )
+        })
+        .body_returned
+        .unwrap_or_default()
+}
+
+/// Draws `entities` in a circle, with an arrow for every relation between them, including [`Parent`]
+pub fn relationship_graph_ui(
+    world: &mut World,
+    entities: &[Entity],
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+) {
+    let mut world = RestrictedWorldView::new(world);
+    let components = cached_relationship_components(ui, world.world(), type_registry);
+    let relations: Vec<Relation> = entities
+        .iter()
+        .flat_map(|&entity| relations_of(&mut world, entity, &components, type_registry))
+        .filter(|relation| entities.contains(&relation.target))
+        .collect();
+    if relations.is_empty() {
+        ui.weak("There are no relations between the selected entities");
+        return;
+    }
+
+    let size = ui.available_width().min(320.0);
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    let font = egui::FontId::proportional(11.0);
+
+    let radius = size / 2.0 - 40.0;
+    let position = |entity: Entity| {
+        let index = entities.iter().position(|e| *e == entity).unwrap_or(0);
+        let angle = std::f32::consts::TAU * index as f32 / entities.len() as f32;
+        rect.center() + radius * egui::vec2(angle.cos(), angle.sin())
+    };
+
+    for relation in &relations {
+        let (from, to) = (position(relation.source), position(relation.target));
+        let direction = (to - from).normalized();
+        let (from, to) = (from + direction * 8.0, to - direction * 8.0);
+        painter.arrow(
+            from,
+            to - from,
+            egui::Stroke::new(1.5, visuals.selection.stroke.color),
+        );
+        painter.text(
+            from + (to - from) / 2.0,
+            egui::Align2::CENTER_BOTTOM,
+            &relation.component_name,
+            font.clone(),
+            visuals.weak_text_color(),
+        );
+    }
+    for &entity in entities {
+        let position = position(entity);
+        painter.circle_filled(position, 5.0, visuals.text_color());
+        painter.text(
+            position + egui::vec2(0.0, 8.0),
+            egui::Align2::CENTER_TOP,
+            guess_entity_name_restricted(&mut world, entity),
+            font.clone(),
+            visuals.text_color(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cached_relationship_components, incoming_relations, outgoing_relations};
+    use crate::restricted_world_view::RestrictedWorldView;
+    use bevy_ecs::{
+        entity::{EntityMapper, MapEntities},
+        prelude::*,
+        reflect::ReflectMapEntities,
+    };
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component, MapEntities)]
+    struct Targets(Vec<Entity>);
+
+    impl MapEntities for Targets {
+        fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+            for entity in &mut self.0 {
+                *entity = entity_mapper.map_entity(*entity);
+            }
+        }
+    }
+
+    #[test]
+    fn relations_in_both_directions() {
+        let mut type_registry = TypeRegistry::new();
+        type_registry.register::<Targets>();
+
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let source = world.spawn(Targets(vec![a, b])).id();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let outgoing = outgoing_relations(&mut world, source, &type_registry);
+        let targets: Vec<Entity> = outgoing.iter().map(|relation| relation.target).collect();
+        assert_eq!(targets, vec![a, b]);
+        assert_eq!(outgoing[0].component_name, "Targets");
+
+        let incoming = incoming_relations(&mut world, b, &type_registry);
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0].source, source);
+    }
+
+    #[test]
+    fn cache_notices_registered_types() {
+        let mut world = World::new();
+        world.spawn(Targets(Vec::new()));
+        let world = RestrictedWorldView::new(&mut world);
+        let mut type_registry = TypeRegistry::new();
+
+        let ctx = egui::Context::default();
+        let mut lengths = Vec::new();
+        for register in [false, true] {
+            if register {
+                type_registry.register::<Targets>();
+            }
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let components =
+                        cached_relationship_components(ui, world.world(), &type_registry);
+                    lengths.push(components.len());
+                });
+            });
+        }
+        assert_eq!(lengths.first(), Some(&0));
+        assert_eq!(lengths.last(), Some(&1));
+    }
+}
@@ -0,0 +1,281 @@
+//! Rust source code for reflected values, so that values tuned in the inspector can be pasted back into the code.
+//!
+//! [`to_rust_literal`] produces an expression like `Player { speed: 2.5, name: "Ferris".to_string() }`,
+//! [`to_default_impl`] wraps it in an `impl Default`.
+//! Both are available in the context menu of component and resource headers.
+//!
+//! ```rust
+//! use bevy_reflect::Reflect;
+//! use bevy_inspector_egui::bevy_inspector::rust_code::to_rust_literal;
+//!
+//! #[derive(Reflect)]
+//! struct Player {
+//!     speed: f32,
+//!     lives: Option<u8>,
+//! }
+//!
+//! let player = Player { speed: 2.5, lives: Some(3) };
+//! assert_eq!(
+//!     to_rust_literal(&player),
+//!     "Player {\n    speed: 2.5,\n    lives: Some(3),\n}"
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use bevy_reflect::{PartialReflect, ReflectRef, VariantType};
+
+const INDENT: &str = "    ";
+
+/// An expression constructing `value`.
+///
+/// Values which can't be written as a literal, like asset handles, are replaced by `Default::default()`
+/// with their debug representation as a comment.
+pub fn to_rust_literal(value: &dyn PartialReflect) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value, 0);
+    out
+}
+
+/// An `impl Default` returning `value`
+pub fn to_default_impl(value: &dyn PartialReflect) -> String {
+    let mut body = String::new();
+    write_value(&mut body, value, 2);
+    format!(
+        "impl Default for {} {{\n{INDENT}fn default() -> Self {{\n{INDENT}{INDENT}{body}\n{INDENT}}}\n}}",
+        short_type_name(value)
+    )
+}
+
+/// Buttons copying `value` as a [literal](to_rust_literal) or [`Default` impl](to_default_impl) to the clipboard,
+/// for use in context menus
+pub(crate) fn copy_buttons(ui: &mut egui::Ui, value: &dyn PartialReflect) {
+    if ui.button("Copy as Rust").clicked() {
+        crate::utils::copy_text(ui.ctx(), to_rust_literal(value));
+        ui.close_menu();
+    }
+    if ui.button("Copy as Default impl").clicked() {
+        crate::utils::copy_text(ui.ctx(), to_default_impl(value));
+        ui.close_menu();
+    }
+}
+
+fn short_type_name(value: &dyn PartialReflect) -> &str {
+    match value.get_represented_type_info() {
+        Some(info) => info.type_path_table().short_path(),
+        None => value.reflect_short_type_path(),
+    }
+}
+
+/// The short type name without generics, which are usually inferred
+fn constructor_name(value: &dyn PartialReflect) -> &str {
+    let name = short_type_name(value);
+    name.split('<').next().unwrap_or(name)
+}
+
+fn write_value(out: &mut String, value: &dyn PartialReflect, indent: usize) {
+    // the fields of `Quat` are private
+    if let Some(quat) = value.try_downcast_ref::<bevy_math::Quat>() {
+        let [x, y, z, w] = quat.to_array();
+        let _ = write!(out, "Quat::from_xyzw({x:?}, {y:?}, {z:?}, {w:?})");
+        return;
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) if value.field_len() == 0 => {
+            out.push_str(constructor_name(value.as_partial_reflect()));
+        }
+        ReflectRef::Struct(value) => {
+            out.push_str(constructor_name(value.as_partial_reflect()));
+            out.push_str(" {\n");
+            for i in 0..value.field_len() {
+                let (Some(name), Some(field)) = (value.name_at(i), value.field_at(i)) else {
+                    continue;
+                };
+                push_indent(out, indent + 1);
+                let _ = write!(out, "{name}: ");
+                write_value(out, field, indent + 1);
+                out.push_str(",\n");
+            }
+            push_indent(out, indent);
+            out.push('}');
+        }
+        ReflectRef::TupleStruct(value) => {
+            out.push_str(constructor_name(value.as_partial_reflect()));
+            write_list(out, "(", value.iter_fields(), ")", indent);
+        }
+        ReflectRef::Tuple(value) => write_list(out, "(", value.iter_fields(), ")", indent),
+        ReflectRef::List(value) => write_list(out, "vec![", value.iter(), "]", indent),
+        ReflectRef::Array(value) => write_list(out, "[", value.iter(), "]", indent),
+        ReflectRef::Set(value) => {
+            out.push_str(constructor_name(value.as_partial_reflect()));
+            write_list(out, "::from([", value.iter(), "])", indent);
+        }
+        ReflectRef::Map(value) => {
+            out.push_str(constructor_name(value.as_partial_reflect()));
+            out.push_str("::from([");
+            for (i, (key, entry)) in value.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                out.push('(');
+                write_value(out, key, indent);
+                out.push_str(", ");
+                write_value(out, entry, indent);
+                out.push(')');
+            }
+            out.push_str("])");
+        }
+        ReflectRef::Enum(value) => {
+            let type_path = match value.get_represented_type_info() {
+                Some(info) => info.type_path(),
+                None => value.reflect_type_path(),
+            };
+            if !type_path.starts_with("core::option::Option<") {
+                out.push_str(constructor_name(value.as_partial_reflect()));
+                out.push_str("::");
+            }
+            out.push_str(value.variant_name());
+            match value.variant_type() {
+                VariantType::Unit => {}
+                VariantType::Tuple => write_list(
+                    out,
+                    "(",
+                    value.iter_fields().map(|field| field.value()),
+                    ")",
+                    indent,
+                ),
+                VariantType::Struct => {
+                    out.push_str(" {\n");
+                    for field in value.iter_fields() {
+                        push_indent(out, indent + 1);
+                        let _ = write!(out, "{}: ", field.name().unwrap_or_default());
+                        write_value(out, field.value(), indent + 1);
+                        out.push_str(",\n");
+                    }
+                    push_indent(out, indent);
+                    out.push('}');
+                }
+            }
+        }
+        _ => write_opaque(out, value),
+    }
+}
+
+fn write_list<'a>(
+    out: &mut String,
+    open: &str,
+    items: impl Iterator<Item = &'a dyn PartialReflect>,
+    close: &str,
+    indent: usize,
+) {
+    out.push_str(open);
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_value(out, item, indent);
+    }
+    out.push_str(close);
+}
+
+fn write_opaque(out: &mut String, value: &dyn PartialReflect) {
+    macro_rules! debug {
+        ($($ty:ty),*) => {
+            $(if let Some(value) = value.try_downcast_ref::<$ty>() {
+                let _ = write!(out, "{value:?}");
+                return;
+            })*
+        };
+    }
+    macro_rules! float {
+        ($($ty:ident),*) => {
+            $(if let Some(value) = value.try_downcast_ref::<$ty>() {
+                let _ = match value {
+                    value if value.is_nan() => write!(out, "{}::NAN", stringify!($ty)),
+                    value if value.is_infinite() && *value > 0.0 => write!(out, "{}::INFINITY", stringify!($ty)),
+                    value if value.is_infinite() => write!(out, "{}::NEG_INFINITY", stringify!($ty)),
+                    value => write!(out, "{value:?}"),
+                };
+                return;
+            })*
+        };
+    }
+    debug!(
+        bool,
+        char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    );
+    float!(f32, f64);
+
+    if let Some(value) = value.try_downcast_ref::<String>() {
+        let _ = write!(out, "{value:?}.to_string()");
+    } else if let Some(value) = value.try_downcast_ref::<std::borrow::Cow<'static, str>>() {
+        let _ = write!(out, "{value:?}.into()");
+    } else {
+        let debug = format!("{value:?}").replace("*/", "* /");
+        let _ = write!(out, "Default::default() /* {debug} */");
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize) {
+    for _ in 0..indent {
+        out.push_str(INDENT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{Quat, Vec3};
+    use bevy_reflect::Reflect;
+
+    use super::{to_default_impl, to_rust_literal};
+
+    #[derive(Reflect)]
+    enum Shape {
+        Circle { radius: f32 },
+        Square(f32),
+        Point,
+    }
+
+    #[derive(Reflect)]
+    struct Level {
+        name: String,
+        spawn: Vec3,
+        rotation: Quat,
+        shapes: Vec<Shape>,
+        gravity: (f32, f32),
+    }
+
+    #[test]
+    fn generates_struct_literals() {
+        let level = Level {
+            name: "intro".into(),
+            spawn: Vec3::new(1.0, 0.5, -2.0),
+            rotation: Quat::IDENTITY,
+            shapes: vec![
+                Shape::Circle { radius: 2.0 },
+                Shape::Square(1.0),
+                Shape::Point,
+            ],
+            gravity: (0.0, f32::NEG_INFINITY),
+        };
+        assert_eq!(
+            to_rust_literal(&level),
+            "Level {
+    name: \"intro\".to_string(),
+    spawn: Vec3 {
+        x: 1.0,
+        y: 0.5,
+        z: -2.0,
+    },
+    rotation: Quat::from_xyzw(0.0, 0.0, 0.0, 1.0),
+    shapes: vec![Shape::Circle {
+        radius: 2.0,
+    }, Shape::Square(1.0), Shape::Point],
+    gravity: (0.0, f32::NEG_INFINITY),
+}"
+        );
+        assert!(to_default_impl(&Shape::Square(1.0)).starts_with(
+            "impl Default for Shape {\n    fn default() -> Self {\n        Shape::Square(1.0)\n"
+        ));
+    }
+}
@@ -0,0 +1,527 @@
+//! Which scene instance an entity belongs to, and which of its components differ from the scene (overrides).
+//!
+//! The [`SceneSpawner`] doesn't expose which scene entity an instance entity was spawned from,
+//! so entities are matched to the entities of the [`Scene`] or [`DynamicScene`] by their [`Name`]s
+//! and position in the hierarchy below the [`SceneRoot`] or [`DynamicSceneRoot`].
+//!
+//! Components referencing other entities (like [`Parent`]) and components computed from others (like [`GlobalTransform`])
+//! are never reported as overridden.
+//!
+//! The overrides of an entity can be written back to the scene asset with [`apply_overrides`],
+//! and with the `scene_file` feature a [`DynamicScene`] can be saved back to its RON file with [`save_scene_file`],
+//! which downloads the file on the web.
+
+use std::any::TypeId;
+
+use bevy_asset::{AssetId, AssetServer, Assets, UntypedAssetId};
+use bevy_core::Name;
+use bevy_ecs::{
+    entity::EntityHashSet, prelude::*, reflect::ReflectMapEntities, world::CommandQueue,
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::{FromReflect, PartialReflect, ReflectFromReflect, TypeRegistry};
+use bevy_scene::{DynamicScene, DynamicSceneRoot, Scene, SceneInstance, SceneRoot, SceneSpawner};
+use bevy_transform::components::GlobalTransform;
+
+/// The scene an entity was spawned from, together with the component values of its scene entity
+pub struct SceneSource {
+    /// The entity with the [`SceneRoot`] or [`DynamicSceneRoot`] which spawned the scene
+    pub root: Entity,
+    /// The asset path of the scene, or its id if it wasn't loaded from a file
+    pub scene: String,
+    asset: SceneAsset,
+    source_entity: Entity,
+    components: Vec<(TypeId, Box<dyn PartialReflect>)>,
+}
+
+#[derive(Clone, Copy)]
+enum SceneAsset {
+    Scene(AssetId<Scene>),
+    Dynamic(AssetId<DynamicScene>),
+}
+
+impl SceneSource {
+    /// Finds the scene instance `entity` belongs to and the matching entity in the scene asset.
+    ///
+    /// Returns `None` if the entity isn't part of a scene instance, or the scene asset isn't loaded anymore.
+    pub fn of(world: &World, entity: Entity, type_registry: &TypeRegistry) -> Option<SceneSource> {
+        let spawner = world.get_resource::<SceneSpawner>()?;
+
+        let mut ancestor = entity;
+        let (root, instance) = loop {
+            ancestor = world.get::<Parent>(ancestor)?.get();
+            let Some(instance) = world.get::<SceneInstance>(ancestor) else {
+                continue;
+            };
+            let instance: EntityHashSet = spawner.iter_instance_entities(**instance).collect();
+            if instance.contains(&entity) {
+                break (ancestor, instance);
+            }
+        };
+        let path = instance_path(world, entity, root, &instance)?;
+
+        let (asset, source_entity, components) =
+            if let Some(scene_root) = world.get::<SceneRoot>(root) {
+                let scene = world.get_resource::<Assets<Scene>>()?.get(&scene_root.0)?;
+                let source = find_source(&scene_nodes(scene), &path)?;
+                let components = scene_components(scene, source, type_registry);
+                (SceneAsset::Scene(scene_root.0.id()), source, components)
+            } else {
+                let scene_root = world.get::<DynamicSceneRoot>(root)?;
+                let scene = world
+                    .get_resource::<Assets<DynamicScene>>()?
+                    .get(&scene_root.0)?;
+                let source = find_source(&dynamic_scene_nodes(scene), &path)?;
+                let components = dynamic_scene_components(scene, source, type_registry);
+                (SceneAsset::Dynamic(scene_root.0.id()), source, components)
+            };
+        let scene_id = match asset {
+            SceneAsset::Scene(id) => id.untyped(),
+            SceneAsset::Dynamic(id) => id.untyped(),
+        };
+
+        Some(SceneSource {
+            root,
+            scene: scene_label(world, scene_id),
+            asset,
+            source_entity,
+            components: components
+                .into_iter()
+                .filter(|(type_id, _)| !is_ignored(*type_id, type_registry))
+                .collect(),
+        })
+    }
+
+    /// The value of the component in the scene, if the scene entity has it
+    pub fn source_value(&self, component: TypeId) -> Option<&dyn PartialReflect> {
+        self.components
+            .iter()
+            .find(|(type_id, _)| *type_id == component)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    /// Whether `value` differs from the value of the component in the scene.
+    ///
+    /// Returns `None` if the component isn't part of the scene entity, or the values can't be compared.
+    pub fn is_overridden(&self, component: TypeId, value: &dyn PartialReflect) -> Option<bool> {
+        let source = self.source_value(component)?;
+        value.reflect_partial_eq(source).map(|equal| !equal)
+    }
+}
+
+/// Writes the overridden components of `entity` into the scene asset it was spawned from,
+/// returning the number of components that were written.
+///
+/// Modifying a [`DynamicScene`] respawns its instances, so they pick up the new values as well.
+pub fn apply_overrides(world: &mut World, entity: Entity) -> Result<usize, String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    let source = SceneSource::of(world, entity, &type_registry)
+        .ok_or_else(|| format!("{entity} is not part of a loaded scene"))?;
+    let entity_ref = world
+        .get_entity(entity)
+        .map_err(|_| format!("{entity} does not exist"))?;
+    let overrides: Vec<(TypeId, Box<dyn PartialReflect>)> = source
+        .components
+        .iter()
+        .filter_map(|(type_id, _)| {
+            let reflect_component = type_registry.get_type_data::<ReflectComponent>(*type_id)?;
+            let value = reflect_component.reflect(entity_ref)?.as_partial_reflect();
+            (source.is_overridden(*type_id, value) == Some(true))
+                .then(|| (*type_id, clone_component(value, *type_id, &type_registry)))
+        })
+        .collect();
+
+    let missing_scene = || format!("the scene `{}` is not loaded", source.scene);
+    match source.asset {
+        SceneAsset::Scene(id) => {
+            let mut scenes = world.resource_mut::<Assets<Scene>>();
+            let scene = scenes.get_mut(id).ok_or_else(missing_scene)?;
+            let mut scene_entity = scene.world.entity_mut(source.source_entity);
+            for (type_id, value) in &overrides {
+                if let Some(reflect_component) =
+                    type_registry.get_type_data::<ReflectComponent>(*type_id)
+                {
+                    reflect_component.apply(&mut scene_entity, value.as_ref());
+                }
+            }
+        }
+        SceneAsset::Dynamic(id) => {
+            let mut scenes = world.resource_mut::<Assets<DynamicScene>>();
+            let scene = scenes.get_mut(id).ok_or_else(missing_scene)?;
+            let scene_entity = scene
+                .entities
+                .iter_mut()
+                .find(|other| other.entity == source.source_entity)
+                .ok_or_else(missing_scene)?;
+            for (type_id, value) in overrides.iter() {
+                let component = scene_entity.components.iter_mut().find(|component| {
+                    component
+                        .get_represented_type_info()
+                        .is_some_and(|info| info.type_id() == *type_id)
+                });
+                if let Some(component) = component {
+                    *component = value.clone_value();
+                }
+            }
+        }
+    }
+
+    Ok(overrides.len())
+}
+
+/// Serializes the [`DynamicScene`] `entity` was spawned from back into its RON file in the `assets` directory,
+/// returning the path of the written file.
+///
+/// On the web, the file is downloaded instead, and only its name is returned.
+#[cfg(feature = "scene_file")]
+pub fn save_scene_file(world: &World, entity: Entity) -> Result<std::path::PathBuf, String> {
+    let (path, ron) = serialize_scene(world, entity)?;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let file = bevy_asset::io::file::FileAssetReader::get_base_path()
+            .join("assets")
+            .join(path.path());
+        std::fs::write(&file, ron).map_err(|error| format!("{}: {error}", file.display()))?;
+        Ok(file)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let file_name = path
+            .path()
+            .file_name()
+            .map_or_else(|| "scene.scn.ron".into(), |name| name.to_owned());
+        crate::web::download(&file_name.to_string_lossy(), &ron, "text/plain")?;
+        Ok(file_name.into())
+    }
+}
+
+/// The path of the [`DynamicScene`] `entity` was spawned from, and the scene serialized as RON
+#[cfg(feature = "scene_file")]
+fn serialize_scene(
+    world: &World,
+    entity: Entity,
+) -> Result<(bevy_asset::AssetPath<'static>, String), String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let source = SceneSource::of(world, entity, &type_registry)
+        .ok_or_else(|| format!("{entity} is not part of a loaded scene"))?;
+    let SceneAsset::Dynamic(id) = source.asset else {
+        return Err(format!("`{}` is not a dynamic scene", source.scene));
+    };
+
+    let path = world
+        .get_resource::<AssetServer>()
+        .and_then(|asset_server| asset_server.get_path(id))
+        .ok_or_else(|| format!("`{}` was not loaded from a file", source.scene))?;
+    let scene = world
+        .resource::<Assets<DynamicScene>>()
+        .get(id)
+        .ok_or_else(|| format!("the scene `{}` is not loaded", source.scene))?;
+    let ron = scene
+        .serialize(&type_registry)
+        .map_err(|error| error.to_string())?;
+    Ok((path.into_owned(), ron))
+}
+
+fn scene_label(world: &World, id: UntypedAssetId) -> String {
+    world
+        .get_resource::<AssetServer>()
+        .and_then(|asset_server| asset_server.get_path(id))
+        .map_or_else(|| format!("{id:?}"), |path| path.to_string())
+}
+
+/// Components which always differ between the scene and its instances
+fn is_ignored(type_id: TypeId, type_registry: &TypeRegistry) -> bool {
+    #[cfg(feature = "bevy_render")]
+    if type_id == TypeId::of::<bevy_render::view::InheritedVisibility>()
+        || type_id == TypeId::of::<bevy_render::view::ViewVisibility>()
+    {
+        return true;
+    }
+    type_id == TypeId::of::<GlobalTransform>()
+        || type_registry
+            .get_type_data::<ReflectMapEntities>(type_id)
+            .is_some()
+}
+
+fn name_of(world: &World, entity: Entity) -> Option<&str> {
+    world.get::<Name>(entity).map(Name::as_str)
+}
+
+/// The names of the entities from the top of the scene down to `entity`, each with the index among its siblings of the same name
+fn instance_path(
+    world: &World,
+    entity: Entity,
+    root: Entity,
+    instance: &EntityHashSet,
+) -> Option<Vec<(Option<String>, usize)>> {
+    let mut path = Vec::new();
+    let mut current = entity;
+    while current != root {
+        let parent = world.get::<Parent>(current)?.get();
+        let name = name_of(world, current);
+        let index = world
+            .get::<Children>(parent)?
+            .iter()
+            .filter(|sibling| instance.contains(*sibling) && name_of(world, **sibling) == name)
+            .position(|sibling| *sibling == current)?;
+        path.push((name.map(str::to_owned), index));
+        current = parent;
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// The hierarchy information of an entity in a scene asset
+struct SourceNode {
+    entity: Entity,
+    parent: Option<Entity>,
+    children: Option<Vec<Entity>>,
+    name: Option<String>,
+}
+
+fn find_source(nodes: &[SourceNode], path: &[(Option<String>, usize)]) -> Option<Entity> {
+    let mut parent = None;
+    for (name, index) in path {
+        let siblings: Vec<&SourceNode> =
+            match parent.and_then(|parent| nodes.iter().find(|node| node.entity == parent)) {
+                Some(SourceNode {
+                    children: Some(children),
+                    ..
+                }) => children
+                    .iter()
+                    .filter_map(|child| nodes.iter().find(|node| node.entity == *child))
+                    .collect(),
+                _ => nodes.iter().filter(|node| node.parent == parent).collect(),
+            };
+        let node = siblings
+            .into_iter()
+            .filter(|node| node.name == *name)
+            .nth(*index)?;
+        parent = Some(node.entity);
+    }
+    parent
+}
+
+fn scene_nodes(scene: &Scene) -> Vec<SourceNode> {
+    scene
+        .world
+        .iter_entities()
+        .map(|entity| SourceNode {
+            entity: entity.id(),
+            parent: entity.get::<Parent>().map(Parent::get),
+            children: entity.get::<Children>().map(|children| children.to_vec()),
+            name: entity.get::<Name>().map(|name| name.as_str().to_owned()),
+        })
+        .collect()
+}
+
+fn dynamic_scene_nodes(scene: &DynamicScene) -> Vec<SourceNode> {
+    fn component<T: FromReflect>(components: &[Box<dyn PartialReflect>]) -> Option<T> {
+        components
+            .iter()
+            .find(|component| {
+                component
+                    .get_represented_type_info()
+                    .is_some_and(|info| info.type_id() == TypeId::of::<T>())
+            })
+            .and_then(|component| T::from_reflect(component.as_ref()))
+    }
+
+    scene
+        .entities
+        .iter()
+        .map(|entity| SourceNode {
+            entity: entity.entity,
+            parent: component::<Parent>(&entity.components).map(|parent| parent.get()),
+            children: component::<Children>(&entity.components).map(|children| children.to_vec()),
+            name: component::<Name>(&entity.components).map(|name| name.as_str().to_owned()),
+        })
+        .collect()
+}
+
+/// Clones `value`, into its concrete type if possible so that it can be compared with the components of the instance
+fn clone_component(
+    value: &dyn PartialReflect,
+    type_id: TypeId,
+    type_registry: &TypeRegistry,
+) -> Box<dyn PartialReflect> {
+    type_registry
+        .get_type_data::<ReflectFromReflect>(type_id)
+        .and_then(|from_reflect| from_reflect.from_reflect(value))
+        .map_or_else(|| value.clone_value(), |value| value.into_partial_reflect())
+}
+
+fn scene_components(
+    scene: &Scene,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Vec<(TypeId, Box<dyn PartialReflect>)> {
+    let Ok(entity) = scene.world.get_entity(entity) else {
+        return Vec::new();
+    };
+    entity
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = scene.world.components().get_info(component_id)?.type_id()?;
+            let reflect_component = type_registry.get_type_data::<ReflectComponent>(type_id)?;
+            let value = reflect_component.reflect(entity)?;
+            Some((
+                type_id,
+                clone_component(value.as_partial_reflect(), type_id, type_registry),
+            ))
+        })
+        .collect()
+}
+
+fn dynamic_scene_components(
+    scene: &DynamicScene,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Vec<(TypeId, Box<dyn PartialReflect>)> {
+    let Some(entity) = scene.entities.iter().find(|other| other.entity == entity) else {
+        return Vec::new();
+    };
+    entity
+        .components
+        .iter()
+        .filter_map(|component| {
+            let type_id = component.get_represented_type_info()?.type_id();
+            Some((
+                type_id,
+                clone_component(component.as_ref(), type_id, type_registry),
+            ))
+        })
+        .collect()
+}
+
+/// A line above the components of an entity, naming the scene it was spawned from,
+/// with an action to apply the overrides of the entity to the scene
+pub(crate) fn scene_source_ui(
+    source: &SceneSource,
+    entity: Entity,
+    queue: Option<&mut CommandQueue>,
+    ui: &mut egui::Ui,
+) {
+    ui.horizontal(|ui| {
+        ui.weak("Scene");
+        ui.label(&source.scene)
+            .on_hover_text(format!("Spawned by {}", source.root));
+
+        let Some(queue) = queue else {
+            return;
+        };
+        ui.menu_button("…", |ui| {
+            if ui
+                .button("Apply overrides to scene")
+                .on_hover_text(
+                    "Write the overridden components of this entity into the scene asset",
+                )
+                .clicked()
+            {
+                queue.push(move |world: &mut World| {
+                    if let Err(error) = apply_overrides(world, entity) {
+                        bevy_log::warn!("Failed to apply overrides to scene: {error}");
+                    }
+                });
+                ui.close_menu();
+            }
+            #[cfg(feature = "scene_file")]
+            if matches!(source.asset, SceneAsset::Dynamic(_))
+                && ui
+                    .button("Apply overrides and save scene file")
+                    .on_hover_text(match cfg!(target_arch = "wasm32") {
+                        true => "Also download the scene as a RON file",
+                        false => "Also write the scene back to its RON file",
+                    })
+                    .clicked()
+            {
+                queue.push(move |world: &mut World| {
+                    let result =
+                        apply_overrides(world, entity).and_then(|_| save_scene_file(world, entity));
+                    match result {
+                        Ok(file) => bevy_log::info!("Saved scene to {}", file.display()),
+                        Err(error) => bevy_log::warn!("Failed to save scene file: {error}"),
+                    }
+                });
+                ui.close_menu();
+            }
+        });
+    });
+}
+
+/// An "override" badge left of the end of a component header, if the component differs from the scene.
+///
+/// Returns whether "Revert to scene value" was clicked.
+pub(crate) fn override_badge_ui(
+    ui: &mut egui::Ui,
+    header: &egui::Response,
+    source: &SceneSource,
+    component: TypeId,
+    value: &dyn PartialReflect,
+) -> bool {
+    if source.is_overridden(component, value) != Some(true) {
+        return false;
+    }
+
+    let width = 56.0;
+    let right = header.rect.right() - 40.0;
+    let rect = egui::Rect::from_min_max(
+        egui::pos2(right - width, header.rect.top()),
+        egui::pos2(right, header.rect.bottom()),
+    );
+    let badge = egui::RichText::new("override")
+        .small()
+        .color(ui.visuals().warn_fg_color);
+
+    let mut revert = false;
+    ui.put(rect, |ui: &mut egui::Ui| {
+        ui.menu_button(badge, |ui| {
+            if ui.button("Revert to scene value").clicked() {
+                revert = true;
+                ui.close_menu();
+            }
+        })
+        .response
+        .on_hover_text(format!("Differs from the scene `{}`", source.scene))
+    });
+    revert
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_source, SourceNode};
+    use bevy_ecs::entity::Entity;
+
+    fn node(index: u32, parent: Option<u32>, name: Option<&str>) -> SourceNode {
+        SourceNode {
+            entity: Entity::from_raw(index),
+            parent: parent.map(Entity::from_raw),
+            children: None,
+            name: name.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn source_matched_by_names_and_sibling_index() {
+        let nodes = [
+            node(0, None, Some("Root")),
+            node(1, Some(0), Some("Wheel")),
+            node(2, Some(0), None),
+            node(3, Some(0), Some("Wheel")),
+            node(4, Some(3), None),
+        ];
+
+        let path = [(Some("Root".to_owned()), 0), (Some("Wheel".to_owned()), 1)];
+        assert_eq!(find_source(&nodes, &path), Some(Entity::from_raw(3)));
+
+        let path = [(Some("Root".to_owned()), 0), (None, 0)];
+        assert_eq!(find_source(&nodes, &path), Some(Entity::from_raw(2)));
+
+        let path = [(Some("Root".to_owned()), 0), (Some("Wheel".to_owned()), 2)];
+        assert_eq!(find_source(&nodes, &path), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Controls for the [`Stepping`] of systems: enabling it per schedule, running one system or the rest
+//! of the frame, and setting breakpoints on single systems.
+//!
+//! The schedule the UI is drawn in must not be stepped, otherwise the controls stop updating.
+//! The quick plugins draw in their own schedule after `Update`.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::stepping;
+//!
+//! fn stepping_ui(world: &mut World, ui: &mut egui::Ui) {
+//!     stepping::ui_for_stepping(world, ui);
+//! }
+//! ```
+
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, NodeId, Schedules, Stepping},
+};
+use bevy_utils::HashSet;
+
+use super::errors::resource_does_not_exist;
+use crate::utils::{pretty_type_name, pretty_type_name_str};
+
+/// Breakpoints set from the UI, since [`Stepping`] doesn't expose them
+type Breakpoints = HashSet<(InternedScheduleLabel, NodeId)>;
+
+/// Display the stepping controls, with the systems of every stepped schedule.
+///
+/// The system which runs next is marked, and clicking the dot in front of a system toggles a breakpoint on it.
+pub fn ui_for_stepping(world: &mut World, ui: &mut egui::Ui) {
+    if !world.contains_resource::<Stepping>() {
+        resource_does_not_exist(ui, &pretty_type_name::<Stepping>());
+        if ui.button("Insert Stepping").clicked() {
+            world.insert_resource(Stepping::new());
+        }
+        return;
+    }
+
+    let schedule_labels: Vec<InternedScheduleLabel> = world
+        .resource::<Schedules>()
+        .iter()
+        .map(|(_, schedule)| schedule.label())
+        .collect();
+
+    let breakpoints_id = egui::Id::new("stepping_breakpoints");
+    let mut breakpoints: Breakpoints =
+        ui.data_mut(|data| data.get_temp(breakpoints_id).unwrap_or_default());
+
+    world.resource_scope(|world, mut stepping: Mut<Stepping>| {
+        let stepping = stepping.bypass_change_detection();
+
+        ui.horizontal(|ui| {
+            let mut enabled = stepping.is_enabled();
+            if ui.checkbox(&mut enabled, "Stepping").changed() {
+                match enabled {
+                    true => stepping.enable(),
+                    false => stepping.disable(),
+                };
+            }
+            ui.add_enabled_ui(enabled, |ui| {
+                if ui
+                    .button("Step system")
+                    .on_hover_text("Run the next system")
+                    .clicked()
+                {
+                    stepping.step_frame();
+                }
+                if ui
+                    .button("Step frame")
+                    .on_hover_text("Run the remaining systems of the frame, or until a breakpoint")
+                    .clicked()
+                {
+                    stepping.continue_frame();
+                }
+            });
+        });
+
+        let stepped: Vec<InternedScheduleLabel> = stepping.schedules().cloned().unwrap_or_default();
+        let cursor = stepping.cursor();
+
+        match cursor {
+            Some((label, node)) => {
+                let name = world
+                    .resource::<Schedules>()
+                    .get(label)
+                    .and_then(|schedule| system_name(schedule, node));
+                ui.label(format!(
+                    "Next: {} in {label:?}",
+                    name.as_deref().unwrap_or("?")
+                ));
+            }
+            None if stepping.is_enabled() => {
+                ui.weak("Waiting for the next frame");
+            }
+            None => {}
+        }
+
+        ui.menu_button("Add schedule", |ui| {
+            for label in &schedule_labels {
+                if stepped.contains(label) {
+                    continue;
+                }
+                if ui.button(format!("{label:?}")).clicked() {
+                    stepping.add_schedule(*label);
+                    ui.close_menu();
+                }
+            }
+        });
+
+        let schedules = world.resource::<Schedules>();
+        for label in &stepped {
+            let header = egui::CollapsingHeader::new(format!("{label:?}"))
+                .id_salt(("stepping_schedule", label))
+                .default_open(cursor.is_some_and(|(cursor, _)| cursor == *label));
+            let response = header.show(ui, |ui| {
+                let Some(schedule) = schedules.get(*label) else {
+                    ui.weak("The schedule is currently running or doesn't exist");
+                    return;
+                };
+                let Ok(systems) = schedule.systems() else {
+                    ui.weak("The schedule has not run yet");
+                    return;
+                };
+                for (node, system) in systems {
+                    ui.horizontal(|ui| {
+                        let key = (*label, node);
+                        let breakpoint = breakpoints.contains(&key);
+                        let dot = egui::RichText::new("●").color(match breakpoint {
+                            true => ui.visuals().error_fg_color,
+                            false => ui.visuals().weak_text_color(),
+                        });
+                        if ui
+                            .add(egui::Button::new(dot).frame(false))
+                            .on_hover_text("Toggle breakpoint")
+                            .clicked()
+                        {
+                            if breakpoint {
+                                stepping.clear_breakpoint_node(*label, node);
+                                breakpoints.remove(&key);
+                            } else {
+                                stepping.set_breakpoint_node(*label, node);
+                                breakpoints.insert(key);
+                            }
+                        }
+
+                        let name = pretty_type_name_str(&system.name());
+                        match cursor == Some(key) {
+                            true => ui.strong(format!("▶ {name}")),
+                            false => ui.label(name),
+                        };
+                    });
+                }
+            });
+            response.header_response.context_menu(|ui| {
+                if ui.button("Stop stepping this schedule").clicked() {
+                    stepping.remove_schedule(*label);
+                    breakpoints.retain(|(schedule, _)| schedule != label);
+                    ui.close_menu();
+                }
+            });
+        }
+        if stepped.is_empty() {
+            ui.weak("No schedule is stepped yet");
+        }
+    });
+
+    ui.data_mut(|data| data.insert_temp(breakpoints_id, breakpoints));
+}
+
+fn system_name(schedule: &Schedule, node: NodeId) -> Option<String> {
+    let (_, system) = schedule.systems().ok()?.find(|(id, _)| *id == node)?;
+    Some(pretty_type_name_str(&system.name()))
+}
@@ -0,0 +1,476 @@
+//! Record the changes made in the inspector, export them to RON and apply them again on a later run.
+//!
+//! While the [`TweakSession`] is recording, every field changed in the component or resource inspector is logged
+//! with its path, old and new value and the time of the change.
+//! Entities are identified by the [`Name`](bevy_core::Name)s of them and their ancestors, so that a session can be replayed
+//! after restarting the app.
+//!
+//! ```rust
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::tweaks::TweakSession;
+//!
+//! fn replay(world: &mut World, ron: &str) {
+//!     let session = TweakSession::from_ron(ron).unwrap();
+//!     for error in session.apply(world) {
+//!         bevy_log::warn!("{error}");
+//!     }
+//! }
+//! ```
+
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_reflect::{GetPath, PartialReflect, ReflectRef, TypeRegistry, VariantType};
+use bevy_time::{Real, Time};
+use serde::{Deserialize, Serialize};
+
+use super::{favorites, watch::FieldOwner};
+use crate::restricted_world_view::RestrictedWorldView;
+
+/// The changes made in the inspector while [`recording`](TweakSession::recording)
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TweakSession {
+    /// Whether changes made in the inspector are added to the session
+    #[serde(skip)]
+    pub recording: bool,
+    pub tweaks: Vec<Tweak>,
+}
+
+/// A single changed field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tweak {
+    pub target: TweakTarget,
+    /// Path of the field inside the component or resource, e.g. `.translation.x`, or empty for the whole value
+    pub path: String,
+    /// The value before the change, as RON
+    pub old: String,
+    /// The value after the change, as RON
+    pub new: String,
+    /// Real time since startup in seconds
+    pub time: f64,
+}
+
+/// The component or resource a [`Tweak`] was made to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TweakTarget {
+    Component {
+        /// Names of the entity and its ancestors, starting at the root, or `None` if one of them is unnamed
+        names: Option<Vec<String>>,
+        /// The entity at the time of recording, used if it has no `names`
+        entity: u64,
+        /// Type path of the component
+        component: String,
+    },
+    Resource {
+        /// Type path of the resource
+        resource: String,
+    },
+}
+
+impl TweakSession {
+    pub fn start(&mut self) {
+        self.recording = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.tweaks.clear();
+    }
+
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Apply the new values of the tweaks in the order they were recorded, and return the ones which failed
+    pub fn apply(&self, world: &mut World) -> Vec<String> {
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+        self.tweaks
+            .iter()
+            .filter_map(|tweak| {
+                apply_tweak(world, tweak, &type_registry)
+                    .err()
+                    .map(|e| format!("{}{}: {e}", tweak.target, tweak.path))
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for TweakTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TweakTarget::Component {
+                names: Some(names),
+                component,
+                ..
+            } => write!(f, "{}/{component}", names.join("/")),
+            TweakTarget::Component {
+                names: None,
+                entity,
+                component,
+            } => match Entity::try_from_bits(*entity) {
+                Ok(entity) => write!(f, "{entity}/{component}"),
+                Err(_) => write!(f, "{entity}/{component}"),
+            },
+            TweakTarget::Resource { resource } => f.write_str(resource),
+        }
+    }
+}
+
+fn apply_tweak(
+    world: &mut World,
+    tweak: &Tweak,
+    type_registry: &TypeRegistry,
+) -> Result<(), String> {
+    let mut value = match &tweak.target {
+        TweakTarget::Component {
+            names,
+            entity,
+            component,
+        } => {
+            let entity = match names {
+                Some(names) => favorites::entity_by_name_path(world, names)
+                    .ok_or_else(|| "no entity with these names".to_owned())?,
+                None => Entity::try_from_bits(*entity).map_err(|e| e.to_string())?,
+            };
+            let reflect_component = type_registry
+                .get_with_type_path(component)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+                .ok_or_else(|| "component is not registered".to_owned())?;
+            let entity = world.get_entity_mut(entity).map_err(|e| e.to_string())?;
+            reflect_component
+                .reflect_mut(entity)
+                .ok_or_else(|| "entity doesn't have the component".to_owned())?
+        }
+        TweakTarget::Resource { resource } => {
+            let reflect_resource = type_registry
+                .get_with_type_path(resource)
+                .and_then(|registration| registration.data::<ReflectResource>())
+                .ok_or_else(|| "resource is not registered".to_owned())?;
+            reflect_resource
+                .reflect_mut(world)
+                .ok_or_else(|| "resource doesn't exist".to_owned())?
+        }
+    };
+    let field = match tweak.path.as_str() {
+        "" => value.as_partial_reflect_mut(),
+        path => value.reflect_path_mut(path).map_err(|e| e.to_string())?,
+    };
+    let registration = field
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+        .ok_or_else(|| "field type is not registered".to_owned())?;
+    let new = crate::utils::ron::deserialize(&tweak.new, registration, type_registry)?;
+    field.try_apply(new.as_ref()).map_err(|e| e.to_string())
+}
+
+/// Whether changes should be recorded, i.e. the [`TweakSession`] exists and is recording
+pub(crate) fn is_recording(world: &mut RestrictedWorldView<'_>) -> bool {
+    world
+        .get_resource_mut::<TweakSession>()
+        .is_ok_and(|session| session.recording)
+}
+
+/// Queues adding the fields which differ between `before` and `after` to the [`TweakSession`]
+pub(crate) fn record(
+    queue: &mut CommandQueue,
+    owner: FieldOwner,
+    before: &dyn PartialReflect,
+    after: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) {
+    let mut changes = Vec::new();
+    diff(
+        &mut String::new(),
+        before,
+        after,
+        type_registry,
+        &mut changes,
+    );
+    if changes.is_empty() {
+        return;
+    }
+    let type_path = |type_id| {
+        type_registry
+            .get(type_id)
+            .map(|registration| registration.type_info().type_path().to_owned())
+    };
+    let Some(target) = (match owner {
+        FieldOwner::Component { entity, component } => {
+            type_path(component).map(|component| (Some(entity), component))
+        }
+        FieldOwner::Resource(resource) => type_path(resource).map(|resource| (None, resource)),
+    }) else {
+        return;
+    };
+
+    queue.push(move |world: &mut World| {
+        let target = match target {
+            (Some(entity), component) => TweakTarget::Component {
+                names: favorites::name_path(world, entity),
+                entity: entity.to_bits(),
+                component,
+            },
+            (None, resource) => TweakTarget::Resource { resource },
+        };
+        let time = world
+            .get_resource::<Time<Real>>()
+            .map_or(0.0, |time| time.elapsed_secs_f64());
+        if let Some(mut session) = world.get_resource_mut::<TweakSession>() {
+            session
+                .tweaks
+                .extend(changes.into_iter().map(|(path, old, new)| Tweak {
+                    target: target.clone(),
+                    path,
+                    old,
+                    new,
+                    time,
+                }));
+        }
+    });
+}
+
+/// Collects the paths and RON values of the innermost fields which differ
+fn diff(
+    path: &mut String,
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    changes: &mut Vec<(String, String, String)>,
+) {
+    let len = path.len();
+    let mut field = |path: &mut String, access: std::fmt::Arguments, old, new| {
+        use std::fmt::Write;
+        let _ = path.write_fmt(access);
+        diff(path, old, new, type_registry, changes);
+        path.truncate(len);
+    };
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            for i in 0..old.field_len() {
+                if let (Some(name), Some(old), Some(new)) =
+                    (old.name_at(i), old.field_at(i), new.field_at(i))
+                {
+                    field(path, format_args!(".{name}"), old, new);
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            for (i, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                field(path, format_args!(".{i}"), old, new);
+            }
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            for (i, (old, new)) in old.iter_fields().zip(new.iter_fields()).enumerate() {
+                field(path, format_args!(".{i}"), old, new);
+            }
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                field(path, format_args!("[{i}]"), old, new);
+            }
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new.iter()).enumerate() {
+                field(path, format_args!("[{i}]"), old, new);
+            }
+        }
+        (ReflectRef::Enum(old), ReflectRef::Enum(new))
+            if old.variant_name() == new.variant_name()
+                && old.variant_type() != VariantType::Unit =>
+        {
+            for i in 0..old.field_len() {
+                let (Some(old_field), Some(new_field)) = (old.field_at(i), new.field_at(i)) else {
+                    continue;
+                };
+                match old.name_at(i) {
+                    Some(name) => field(path, format_args!(".{name}"), old_field, new_field),
+                    None => field(path, format_args!(".{i}"), old_field, new_field),
+                }
+            }
+        }
+        _ => {
+            if old.reflect_partial_eq(new) == Some(true) {
+                return;
+            }
+            let serialize = |value| crate::utils::ron::serialize(value, type_registry, false);
+            if let (Ok(old), Ok(new)) = (serialize(old), serialize(new)) {
+                if old != new {
+                    changes.push((path.clone(), old, new));
+                }
+            }
+        }
+    }
+}
+
+/// Display the [`TweakSession`], with buttons to start and stop recording, copy the session as RON and
+/// apply a pasted session
+pub fn ui_for_tweak_session(world: &mut World, ui: &mut egui::Ui) {
+    let Some(session) = world.get_resource::<TweakSession>() else {
+        ui.label("No TweakSession resource. Add the DefaultInspectorConfigPlugin.");
+        return;
+    };
+    let recording = session.recording;
+    let id = ui.id().with("tweak_session_import");
+
+    ui.horizontal(|ui| {
+        let label = match recording {
+            true => "⏹ Stop recording",
+            false => "⏺ Record",
+        };
+        if ui.button(label).clicked() {
+            let mut session = world.resource_mut::<TweakSession>();
+            session.recording = !recording;
+        }
+        let session = world.resource::<TweakSession>();
+        let has_tweaks = !session.tweaks.is_empty();
+        if ui
+            .add_enabled(has_tweaks, egui::Button::new("📋 Copy as RON"))
+            .clicked()
+        {
+            match session.to_ron() {
+                Ok(ron) => crate::utils::copy_text(ui.ctx(), ron),
+                Err(e) => bevy_log::error!("Could not export tweak session: {e}"),
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        if ui
+            .add_enabled(has_tweaks, egui::Button::new("⬇ Download"))
+            .clicked()
+        {
+            let result = session
+                .to_ron()
+                .and_then(|ron| crate::web::download("tweak_session.ron", &ron, "text/plain"));
+            if let Err(e) = result {
+                bevy_log::error!("Could not export tweak session: {e}");
+            }
+        }
+        if ui
+            .add_enabled(has_tweaks, egui::Button::new("Clear"))
+            .clicked()
+        {
+            world.resource_mut::<TweakSession>().clear();
+        }
+    });
+
+    let session = world.resource::<TweakSession>();
+    if session.tweaks.is_empty() {
+        ui.weak("No tweaks recorded");
+    }
+    egui::ScrollArea::vertical()
+        .max_height(240.0)
+        .id_salt(id.with("list"))
+        .show(ui, |ui| {
+            for tweak in &session.tweaks {
+                ui.horizontal(|ui| {
+                    ui.weak(format!("{:.1}s", tweak.time));
+                    ui.label(format!("{}{}", tweak.target, tweak.path));
+                    ui.weak(format!("{} → {}", tweak.old, tweak.new));
+                });
+            }
+        });
+
+    ui.separator();
+    let mut import = ui.data_mut(|data| data.get_temp::<String>(id).unwrap_or_default());
+    ui.add(
+        egui::TextEdit::multiline(&mut import)
+            .hint_text("Paste a tweak session to apply it")
+            .desired_rows(3)
+            .code_editor(),
+    );
+    if ui
+        .add_enabled(!import.is_empty(), egui::Button::new("Apply"))
+        .clicked()
+    {
+        match TweakSession::from_ron(&import) {
+            Ok(session) => {
+                for error in session.apply(world) {
+                    bevy_log::warn!("Could not apply tweak {error}");
+                }
+            }
+            Err(e) => bevy_log::error!("Invalid tweak session: {e}"),
+        }
+    }
+    ui.data_mut(|data| data.insert_temp(id, import));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_core::Name;
+    use bevy_ecs::{prelude::*, world::CommandQueue};
+    use bevy_hierarchy::BuildChildren;
+    use bevy_reflect::{PartialReflect, Reflect};
+
+    use super::{TweakSession, TweakTarget};
+    use crate::bevy_inspector::watch::FieldOwner;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        speed: f32,
+        jumps: Vec<u8>,
+    }
+
+    #[test]
+    fn records_and_replays_tweaks() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Stats>();
+        world.init_resource::<TweakSession>();
+        let parent = world.spawn(Name::new("player")).id();
+        let entity = world.spawn((Name::new("body"), Stats::default())).id();
+        world.entity_mut(entity).set_parent(parent);
+
+        let before = world.get::<Stats>(entity).unwrap().clone_value();
+        let after = Stats {
+            speed: 2.5,
+            jumps: vec![],
+        };
+        let mut queue = CommandQueue::default();
+        let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+        super::record(
+            &mut queue,
+            FieldOwner::Component {
+                entity,
+                component: std::any::TypeId::of::<Stats>(),
+            },
+            before.as_ref(),
+            after.as_partial_reflect(),
+            &type_registry.read(),
+        );
+        queue.apply(&mut world);
+
+        let session = world.resource::<TweakSession>().clone();
+        assert_eq!(session.tweaks.len(), 1);
+        let tweak = &session.tweaks[0];
+        assert_eq!(tweak.path, ".speed");
+        assert_eq!((tweak.old.as_str(), tweak.new.as_str()), ("0.0", "2.5"));
+        assert!(matches!(
+            &tweak.target,
+            TweakTarget::Component { names: Some(names), .. } if names == &["player", "body"]
+        ));
+
+        let session = TweakSession::from_ron(&session.to_ron().unwrap()).unwrap();
+        let mut fresh = World::new();
+        fresh.insert_resource(world.resource::<AppTypeRegistry>().clone());
+        let parent = fresh.spawn(Name::new("player")).id();
+        let entity = fresh.spawn((Name::new("body"), Stats::default())).id();
+        fresh.entity_mut(entity).set_parent(parent);
+
+        assert!(session.apply(&mut fresh).is_empty());
+        assert_eq!(fresh.get::<Stats>(entity).unwrap().speed, 2.5);
+    }
+}
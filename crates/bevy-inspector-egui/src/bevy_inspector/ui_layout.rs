@@ -0,0 +1,309 @@
+//! Layout debugging shown below the [`Node`] component of UI entities:
+//!
+//! - the computed size, position, border and padding of the node, from its [`ComputedNode`] and [`GlobalTransform`]
+//! - a box model diagram and a sketch of where the children were laid out, with the flex direction or grid tracks
+//! - a button flashing an [`Outline`] around the node in the game viewport.
+//!   This needs the [`flash_outlines`] system, which is added by the [`DefaultInspectorConfigPlugin`](crate::DefaultInspectorConfigPlugin).
+
+use std::{any::TypeId, time::Duration};
+
+use bevy_color::Color;
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_hierarchy::Children;
+use bevy_math::Vec2;
+use bevy_reflect::{FromReflect, TypeRegistry};
+use bevy_time::{Real, Time, Timer, TimerMode};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{ComputedNode, Display, FlexDirection, Node, Outline, Val};
+
+use crate::restricted_world_view::RestrictedWorldView;
+
+const FLASH_DURATION: Duration = Duration::from_millis(1500);
+const FLASH_COLOR: Color = Color::srgb(1.0, 0.0, 1.0);
+
+/// An [`Outline`] blinking around a UI node, after which the previous outline is restored
+#[derive(Component)]
+pub struct OutlineFlash {
+    timer: Timer,
+    previous: Option<Outline>,
+}
+
+/// Blinks the outlines of nodes with an [`OutlineFlash`], and restores their previous outline afterwards
+pub fn flash_outlines(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut flashes: Query<(Entity, &mut OutlineFlash, &mut Outline)>,
+) {
+    for (entity, mut flash, mut outline) in &mut flashes {
+        flash.timer.tick(time.delta());
+        if !flash.timer.finished() {
+            let visible = ((flash.timer.elapsed_secs() * 6.0) as u32).is_multiple_of(2);
+            outline.color = match visible {
+                true => FLASH_COLOR,
+                false => Color::NONE,
+            };
+            continue;
+        }
+
+        let mut entity = commands.entity(entity);
+        entity.remove::<OutlineFlash>();
+        match flash.previous {
+            Some(previous) => *outline = previous,
+            None => {
+                entity.remove::<Outline>();
+            }
+        }
+    }
+}
+
+fn flash_outline(world: &mut World, entity: Entity) {
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let previous = match entity.get::<OutlineFlash>() {
+        Some(flash) => flash.previous,
+        None => entity.get::<Outline>().copied(),
+    };
+    entity.insert((
+        OutlineFlash {
+            timer: Timer::new(FLASH_DURATION, TimerMode::Once),
+            previous,
+        },
+        Outline::new(Val::Px(2.0), Val::Px(1.0), FLASH_COLOR),
+    ));
+}
+
+/// The computed layout of a node
+#[derive(Clone, Copy)]
+struct NodeLayout {
+    computed: ComputedNode,
+    /// The area of the node in logical pixels, with the origin at the top left of the window
+    rect: egui::Rect,
+}
+
+fn component<T: FromReflect>(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Option<T> {
+    let value = world
+        .get_entity_component_reflect(entity, TypeId::of::<T>(), type_registry)
+        .ok()?;
+    T::from_reflect(value.as_partial_reflect())
+}
+
+fn node_layout(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Option<NodeLayout> {
+    let computed = component::<ComputedNode>(world, entity, type_registry)?;
+    let center = component::<GlobalTransform>(world, entity, type_registry)
+        .map_or(Vec2::ZERO, |transform| transform.translation().truncate());
+    // the layout is computed in physical pixels
+    let scale = computed.inverse_scale_factor();
+    let size = computed.size() * scale;
+    let min = center * scale - size / 2.0;
+    Some(NodeLayout {
+        computed,
+        rect: egui::Rect::from_min_size(egui::pos2(min.x, min.y), egui::vec2(size.x, size.y)),
+    })
+}
+
+/// Displays the layout debugging section for the `node` of `entity`
+pub(crate) fn node_layout_ui(
+    node: &Node,
+    entity: Entity,
+    world: &mut RestrictedWorldView<'_>,
+    queue: Option<&mut CommandQueue>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) {
+    egui::CollapsingHeader::new("Layout")
+        .id_salt(id.with("ui_layout"))
+        .show(ui, |ui| {
+            let Some(layout) = node_layout(world, entity, type_registry) else {
+                ui.weak("The node has not been laid out yet");
+                return;
+            };
+            let children: Vec<NodeLayout> = component::<Children>(world, entity, type_registry)
+                .map(|children| {
+                    children
+                        .iter()
+                        .filter_map(|child| node_layout(world, *child, type_registry))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            computed_values_ui(&layout, ui, id);
+            box_model_ui(&layout.computed, ui);
+            children_ui(node, &layout, &children, ui);
+
+            if let Some(queue) = queue {
+                if ui
+                    .button("Flash outline")
+                    .on_hover_text("Blink an outline around the node in the game viewport")
+                    .clicked()
+                {
+                    queue.push(move |world: &mut World| flash_outline(world, entity));
+                }
+            }
+        });
+}
+
+fn computed_values_ui(layout: &NodeLayout, ui: &mut egui::Ui, id: egui::Id) {
+    let rect = layout.rect;
+    egui::Grid::new(id.with("computed_layout"))
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("size");
+            ui.label(format!("{:.1} × {:.1}", rect.width(), rect.height()));
+            ui.end_row();
+            ui.label("position");
+            ui.label(format!("{:.1}, {:.1}", rect.left(), rect.top()));
+            ui.end_row();
+            ui.label("stack index");
+            ui.label(layout.computed.stack_index().to_string());
+            ui.end_row();
+        });
+}
+
+/// Nested border, padding and content boxes, labelled with their sizes in logical pixels
+fn box_model_ui(computed: &ComputedNode, ui: &mut egui::Ui) {
+    let scale = computed.inverse_scale_factor();
+    let border = computed.border();
+    let padding = computed.padding();
+    let content = (computed.size()
+        - Vec2::new(
+            border.left + border.right + padding.left + padding.right,
+            border.top + border.bottom + padding.top + padding.bottom,
+        ))
+    .max(Vec2::ZERO)
+        * scale;
+
+    let width = ui.available_width().min(240.0);
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, 110.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    let font = egui::FontId::monospace(10.0);
+    let text_color = visuals.text_color();
+    let inset = 18.0;
+
+    let boxes = [
+        ("border", border, visuals.warn_fg_color),
+        ("padding", padding, visuals.selection.bg_fill),
+    ];
+    let mut current = rect;
+    for (label, sides, color) in boxes {
+        painter.rect_stroke(current, 0.0, egui::Stroke::new(1.0, color));
+        painter.text(
+            current.left_top() + egui::vec2(3.0, 2.0),
+            egui::Align2::LEFT_TOP,
+            label,
+            font.clone(),
+            color,
+        );
+        let inner = current.shrink(inset);
+        for (value, pos, align) in [
+            (
+                sides.top,
+                egui::pos2(current.center().x, current.top() + 2.0),
+                egui::Align2::CENTER_TOP,
+            ),
+            (
+                sides.bottom,
+                egui::pos2(current.center().x, current.bottom() - 2.0),
+                egui::Align2::CENTER_BOTTOM,
+            ),
+            (
+                sides.left,
+                egui::pos2(current.left() + 3.0, current.center().y),
+                egui::Align2::LEFT_CENTER,
+            ),
+            (
+                sides.right,
+                egui::pos2(current.right() - 3.0, current.center().y),
+                egui::Align2::RIGHT_CENTER,
+            ),
+        ] {
+            painter.text(
+                pos,
+                align,
+                format!("{}", (value * scale).round()),
+                font.clone(),
+                text_color,
+            );
+        }
+        current = inner;
+    }
+    painter.rect_stroke(current, 0.0, visuals.widgets.noninteractive.fg_stroke);
+    painter.text(
+        current.center(),
+        egui::Align2::CENTER_CENTER,
+        format!("{:.0} × {:.0}", content.x, content.y),
+        font,
+        text_color,
+    );
+}
+
+/// The node scaled down with the rectangles of its children, and the flex direction or grid tracks
+fn children_ui(node: &Node, layout: &NodeLayout, children: &[NodeLayout], ui: &mut egui::Ui) {
+    match node.display {
+        Display::Flex => ui.label(format!(
+            "Flex {:?}, wrap {:?}, justify {:?}, align {:?}",
+            node.flex_direction, node.flex_wrap, node.justify_content, node.align_items
+        )),
+        Display::Grid => ui.label(format!(
+            "Grid with {} column and {} row templates, flow {:?}",
+            node.grid_template_columns.len(),
+            node.grid_template_rows.len(),
+            node.grid_auto_flow
+        )),
+        display => ui.label(format!("{display:?}")),
+    };
+
+    let node_rect = layout.rect;
+    if node_rect.width() <= 0.0 || node_rect.height() <= 0.0 {
+        return;
+    }
+    let width = ui.available_width().min(240.0);
+    let height = (width * node_rect.height() / node_rect.width()).clamp(24.0, 160.0);
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+
+    let to_preview = egui::emath::RectTransform::from_to(node_rect, rect);
+    painter.rect_stroke(rect, 0.0, visuals.widgets.noninteractive.fg_stroke);
+    for (i, child) in children.iter().enumerate() {
+        let child_rect = to_preview.transform_rect(child.rect);
+        painter.rect(
+            child_rect,
+            0.0,
+            visuals.selection.bg_fill.gamma_multiply(0.4),
+            egui::Stroke::new(1.0, visuals.selection.stroke.color),
+        );
+        painter.text(
+            child_rect.center(),
+            egui::Align2::CENTER_CENTER,
+            i.to_string(),
+            egui::FontId::monospace(10.0),
+            visuals.text_color(),
+        );
+    }
+
+    if node.display == Display::Flex {
+        let direction = match node.flex_direction {
+            FlexDirection::Row => egui::vec2(1.0, 0.0),
+            FlexDirection::RowReverse => egui::vec2(-1.0, 0.0),
+            FlexDirection::Column => egui::vec2(0.0, 1.0),
+            FlexDirection::ColumnReverse => egui::vec2(0.0, -1.0),
+        };
+        let length = 0.4 * rect.size().dot(direction.abs());
+        painter.arrow(
+            rect.center() - direction * length / 2.0,
+            direction * length,
+            egui::Stroke::new(2.0, visuals.warn_fg_color),
+        );
+    }
+}
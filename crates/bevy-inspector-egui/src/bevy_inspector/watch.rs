@@ -0,0 +1,165 @@
+//! Watch window for individual fields of components, which stay visible while the rest of the entity is collapsed.
+//!
+//! Fields can be pinned by right clicking their label in the entity inspector, or by adding them to the [`WatchList`] directly.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_reflect::Reflect;
+//! use bevy_inspector_egui::bevy_inspector::watch::{self, WatchList, WatchedField};
+//!
+//! #[derive(Component, Reflect)]
+//! struct Health {
+//!     current: f32,
+//!     max: f32,
+//! }
+//!
+//! fn pin_health(mut watch_list: ResMut<WatchList>, query: Query<Entity, With<Health>>) {
+//!     for entity in &query {
+//!         watch_list.pin(WatchedField::new::<Health>(entity, "current"));
+//!     }
+//! }
+//!
+//! fn watch_ui(world: &mut World, ui: &mut egui::Ui) {
+//!     watch::ui_for_watch_list(world, ui);
+//! }
+//! ```
+
+use std::any::TypeId;
+
+use bevy_ecs::{prelude::*, world::CommandQueue};
+
+use super::errors;
+use crate::{
+    restricted_world_view::RestrictedWorldView, utils::guess_entity_name::guess_entity_name,
+};
+
+/// The fields displayed by [`ui_for_watch_list`]
+#[derive(Resource, Default, Debug, Clone)]
+pub struct WatchList {
+    pub fields: Vec<WatchedField>,
+}
+
+impl WatchList {
+    /// Adds the field to the watch list, if it isn't already in it
+    pub fn pin(&mut self, field: WatchedField) {
+        if !self.fields.contains(&field) {
+            self.fields.push(field);
+        }
+    }
+
+    /// Removes the field from the watch list
+    pub fn unpin(&mut self, field: &WatchedField) {
+        self.fields.retain(|other| other != field);
+    }
+}
+
+/// A field of a component on an entity, identified by a [reflect path](bevy_reflect::GetPath)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WatchedField {
+    pub entity: Entity,
+    /// The [`TypeId`] of the component
+    pub component: TypeId,
+    /// Path of the field inside the component, e.g. `translation.x`
+    pub path: String,
+}
+
+impl WatchedField {
+    pub fn new<C: Component>(entity: Entity, path: impl Into<String>) -> Self {
+        WatchedField {
+            entity,
+            component: TypeId::of::<C>(),
+            path: path.into(),
+        }
+    }
+}
+
+/// The path of the field currently displayed by the [`InspectorUi`](crate::reflect_inspector::InspectorUi),
+/// used by the context menus of field labels
+#[derive(Debug, Clone)]
+pub(crate) struct FieldPath {
+    pub(crate) owner: FieldOwner,
+    pub(crate) path: String,
+}
+
+impl FieldPath {
+    pub(crate) fn component(entity: Entity, component: TypeId) -> Self {
+        FieldPath {
+            owner: FieldOwner::Component { entity, component },
+            path: String::new(),
+        }
+    }
+
+    pub(crate) fn resource(resource: TypeId) -> Self {
+        FieldPath {
+            owner: FieldOwner::Resource(resource),
+            path: String::new(),
+        }
+    }
+
+    /// The entity whose component is displayed, if any
+    pub(crate) fn entity(&self) -> Option<Entity> {
+        match self.owner {
+            FieldOwner::Component { entity, .. } => Some(entity),
+            FieldOwner::Resource(_) => None,
+        }
+    }
+}
+
+/// What a [`FieldPath`] is relative to
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum FieldOwner {
+    /// Component fields can be pinned to the [`WatchList`]
+    Component { entity: Entity, component: TypeId },
+    /// Resource fields can be recorded in the [`ValueHistory`](super::history::ValueHistory)
+    Resource(TypeId),
+}
+
+/// Display the fields of the [`WatchList`], which can be edited and unpinned
+pub fn ui_for_watch_list(world: &mut World, ui: &mut egui::Ui) {
+    let fields = world
+        .get_resource::<WatchList>()
+        .map(|watch_list| watch_list.fields.clone())
+        .unwrap_or_default();
+    if fields.is_empty() {
+        ui.label("No pinned fields. Right click a field in the inspector to pin it.");
+        return;
+    }
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+
+    let mut unpin = None;
+    egui::Grid::new("watch_list").show(ui, |ui| {
+        for field in &fields {
+            let component_name = errors::name_of_type(field.component, &type_registry);
+            let label = ui.label(format!(
+                "{} {component_name}.{}",
+                guess_entity_name(world, field.entity),
+                field.path.trim_start_matches('.'),
+            ));
+            label.context_menu(|ui| {
+                if ui.button("Unpin").clicked() {
+                    unpin = Some(field.clone());
+                    ui.close_menu();
+                }
+            });
+
+            let mut queue = CommandQueue::default();
+            super::ui_for_component_field(
+                &mut RestrictedWorldView::new(world),
+                &mut queue,
+                field.entity,
+                field.component,
+                &field.path,
+                ui,
+                &type_registry,
+            );
+            queue.apply(world);
+            ui.end_row();
+        }
+    });
+
+    if let Some(field) = unpin {
+        world.resource_mut::<WatchList>().unpin(&field);
+    }
+}
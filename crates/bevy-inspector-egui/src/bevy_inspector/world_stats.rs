@@ -0,0 +1,334 @@
+//! Statistics about the [`World`], for spotting leaks of entities, archetypes or assets.
+//!
+//! [`ui_for_world_stats`] collects a new [`WorldStats`] snapshot every few seconds and shows
+//! how each number changed since the previous one. The refresh interval can be changed in the UI.
+//!
+//! ```no_run
+//! use bevy_ecs::prelude::*;
+//! use bevy_inspector_egui::bevy_inspector::world_stats::{self, WorldStats};
+//!
+//! fn show_stats(world: &mut World, ui: &mut egui::Ui) {
+//!     world_stats::ui_for_world_stats(world, ui);
+//! }
+//!
+//! fn log_entity_count(world: &mut World) {
+//!     let stats = WorldStats::collect(world);
+//!     bevy_log::info!("{} entities in {} archetypes", stats.entities, stats.archetypes.len());
+//! }
+//! ```
+
+use std::cmp::Reverse;
+
+use bevy_asset::ReflectAsset;
+use bevy_ecs::{component::ComponentId, prelude::*};
+use bevy_utils::HashSet;
+
+use crate::utils::pretty_type_name_str;
+
+/// Default number of seconds between two snapshots in [`ui_for_world_stats`]
+const DEFAULT_REFRESH_INTERVAL: f64 = 1.0;
+/// Number of archetypes listed in [`ui_for_world_stats`], sorted by their entity count
+const MAX_LISTED_ARCHETYPES: usize = 50;
+
+/// A single archetype of the [`WorldStats`]
+#[derive(Debug, Clone)]
+pub struct ArchetypeStats {
+    pub entities: usize,
+    /// Short names of the components of the archetype
+    pub components: Vec<String>,
+}
+
+/// A snapshot of the size of the [`World`]
+#[derive(Debug, Clone)]
+pub struct WorldStats {
+    pub entities: usize,
+    pub archetypes: Vec<ArchetypeStats>,
+    /// Estimated bytes of the component tables, including unused capacity and change ticks
+    pub table_bytes: usize,
+    /// Estimated bytes of the components stored in sparse sets
+    pub sparse_set_bytes: usize,
+    pub resources: usize,
+    /// Number of assets per registered asset type, sorted by name
+    pub assets: Vec<(String, usize)>,
+}
+
+/// Size of the added and changed ticks stored next to every component
+const TICKS_SIZE: usize = 2 * size_of::<u32>();
+
+fn component_size(world: &World, component_id: ComponentId) -> usize {
+    world
+        .components()
+        .get_info(component_id)
+        .map_or(0, |info| info.layout().size())
+}
+
+impl WorldStats {
+    /// Collects the statistics of `world`
+    pub fn collect(world: &World) -> Self {
+        let archetypes = world
+            .archetypes()
+            .iter()
+            .map(|archetype| ArchetypeStats {
+                entities: archetype.len(),
+                components: archetype
+                    .components()
+                    .filter_map(|id| world.components().get_name(id))
+                    .map(pretty_type_name_str)
+                    .collect(),
+            })
+            .collect();
+
+        let mut seen_tables = HashSet::<usize>::default();
+        let table_bytes = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| seen_tables.insert(archetype.table_id().as_usize()))
+            .map(|archetype| {
+                let capacity = world
+                    .storages()
+                    .tables
+                    .get(archetype.table_id())
+                    .map_or(0, |table| table.entity_capacity());
+                let row_size: usize = archetype
+                    .table_components()
+                    .map(|id| component_size(world, id) + TICKS_SIZE)
+                    .sum();
+                capacity * row_size
+            })
+            .sum();
+
+        let sparse_set_bytes = world
+            .storages()
+            .sparse_sets
+            .iter()
+            .map(|(id, set)| set.len() * (component_size(world, id) + TICKS_SIZE))
+            .sum();
+
+        let mut assets: Vec<(String, usize)> = match world.get_resource::<AppTypeRegistry>() {
+            Some(type_registry) => {
+                let type_registry = type_registry.read();
+                type_registry
+                    .iter_with_data::<ReflectAsset>()
+                    .map(|(registration, reflect_asset)| {
+                        let name = registration
+                            .type_info()
+                            .type_path_table()
+                            .short_path()
+                            .to_owned();
+                        (name, reflect_asset.len(world))
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        assets.sort();
+
+        WorldStats {
+            entities: world.entities().len() as usize,
+            archetypes,
+            table_bytes,
+            sparse_set_bytes,
+            resources: world.iter_resources().count(),
+            assets,
+        }
+    }
+}
+
+/// The snapshots shown by [`ui_for_world_stats`], stored in egui memory
+#[derive(Clone)]
+struct StatsState {
+    current: WorldStats,
+    previous: Option<WorldStats>,
+    collected_at: f64,
+    refresh_interval: f64,
+}
+
+/// Display the [`WorldStats`], refreshed on an interval which can be changed in the UI,
+/// with the change of every number since the previous refresh.
+pub fn ui_for_world_stats(world: &mut World, ui: &mut egui::Ui) {
+    let id = egui::Id::new("world_stats");
+    let now = ui.input(|input| input.time);
+    let mut state = ui.data_mut(|data| data.get_temp::<StatsState>(id));
+
+    let mut refresh = false;
+    ui.horizontal(|ui| {
+        let mut interval = state
+            .as_ref()
+            .map_or(DEFAULT_REFRESH_INTERVAL, |state| state.refresh_interval);
+        ui.label("Refresh every");
+        ui.add(
+            egui::DragValue::new(&mut interval)
+                .range(0.1..=60.0)
+                .speed(0.1)
+                .suffix(" s"),
+        );
+        if let Some(state) = &mut state {
+            state.refresh_interval = interval;
+        }
+        refresh = ui.button("Refresh now").clicked();
+    });
+
+    let state = match state {
+        Some(state) if !refresh && now - state.collected_at < state.refresh_interval => state,
+        previous => {
+            let refresh_interval = previous
+                .as_ref()
+                .map_or(DEFAULT_REFRESH_INTERVAL, |state| state.refresh_interval);
+            StatsState {
+                current: WorldStats::collect(world),
+                previous: previous.map(|state| state.current),
+                collected_at: now,
+                refresh_interval,
+            }
+        }
+    };
+    ui.data_mut(|data| data.insert_temp(id, state.clone()));
+
+    let stats = &state.current;
+    let previous = state.previous.as_ref();
+
+    egui::Grid::new(id.with("totals"))
+        .num_columns(3)
+        .show(ui, |ui| {
+            count_row(
+                ui,
+                "Entities",
+                stats.entities,
+                previous.map(|stats| stats.entities),
+            );
+            count_row(
+                ui,
+                "Archetypes",
+                stats.archetypes.len(),
+                previous.map(|stats| stats.archetypes.len()),
+            );
+            count_row(
+                ui,
+                "Resources",
+                stats.resources,
+                previous.map(|stats| stats.resources),
+            );
+
+            ui.label("Table memory");
+            ui.label(format_bytes(stats.table_bytes));
+            ui.label("");
+            ui.end_row();
+            ui.label("Sparse set memory");
+            ui.label(format_bytes(stats.sparse_set_bytes));
+            ui.label("");
+            ui.end_row();
+        });
+
+    egui::CollapsingHeader::new(format!("Archetypes ({})", stats.archetypes.len()))
+        .id_salt(id.with("archetypes"))
+        .show(ui, |ui| {
+            let mut archetypes: Vec<&ArchetypeStats> = stats.archetypes.iter().collect();
+            archetypes.sort_by_key(|archetype| Reverse(archetype.entities));
+            egui::Grid::new(id.with("archetype_grid"))
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Entities");
+                    ui.strong("Components");
+                    ui.strong("");
+                    ui.end_row();
+                    for archetype in archetypes.iter().take(MAX_LISTED_ARCHETYPES) {
+                        ui.label(archetype.entities.to_string());
+                        ui.label(archetype.components.len().to_string());
+                        ui.weak(archetype.components.join(", "));
+                        ui.end_row();
+                    }
+                });
+            if archetypes.len() > MAX_LISTED_ARCHETYPES {
+                ui.weak(format!(
+                    "and {} more",
+                    archetypes.len() - MAX_LISTED_ARCHETYPES
+                ));
+            }
+        });
+
+    egui::CollapsingHeader::new("Assets")
+        .id_salt(id.with("assets"))
+        .show(ui, |ui| {
+            if stats.assets.is_empty() {
+                ui.weak("No asset types are registered");
+                return;
+            }
+            egui::Grid::new(id.with("asset_grid"))
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (name, count) in &stats.assets {
+                        let before = previous.and_then(|previous| {
+                            previous
+                                .assets
+                                .iter()
+                                .find(|(previous_name, _)| previous_name == name)
+                                .map(|(_, count)| *count)
+                        });
+                        ui.label(name);
+                        ui.label(count.to_string());
+                        delta_label(ui, *count, before);
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+fn count_row(ui: &mut egui::Ui, name: &str, value: usize, before: Option<usize>) {
+    ui.label(name);
+    ui.label(value.to_string());
+    delta_label(ui, value, before);
+    ui.end_row();
+}
+
+/// The change from `before` to `value`, with growth highlighted since it may be a leak
+fn delta_label(ui: &mut egui::Ui, value: usize, before: Option<usize>) {
+    match before.map(|before| value as i64 - before as i64) {
+        Some(delta) if delta > 0 => {
+            ui.colored_label(ui.visuals().warn_fg_color, format!("+{delta}"));
+        }
+        Some(delta) if delta < 0 => {
+            ui.weak(delta.to_string());
+        }
+        _ => {
+            ui.label("");
+        }
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{value:.1} {}", UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, WorldStats};
+    use bevy_ecs::prelude::*;
+
+    #[derive(Component)]
+    struct Marker(#[allow(dead_code)] u64);
+
+    #[test]
+    fn counts_entities_and_table_memory() {
+        let mut world = World::new();
+        let before = WorldStats::collect(&world);
+        world.spawn_batch((0..10).map(Marker));
+        let after = WorldStats::collect(&world);
+
+        assert_eq!(after.entities, before.entities + 10);
+        assert_eq!(after.archetypes.len(), before.archetypes.len() + 1);
+        assert!(after.table_bytes >= before.table_bytes + 10 * size_of::<u64>());
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+    }
+}
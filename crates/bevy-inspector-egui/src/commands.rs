@@ -0,0 +1,364 @@
+//! Named inspector actions for console crates like `bevy_minibuffer` or `bevy_console`.
+//!
+//! The [`InspectorCommandsPlugin`] registers the builtin [`InspectorCommand`]s in the [`InspectorCommands`] resource:
+//! - `inspect_entity <entity>`: open or close a window for an entity, given as `4v1`, its index or its [`Name`]
+//! - `inspect_resource <resource>`: open or close a window for a resource, given by its short or full type path
+//! - `toggle_world_inspector`: show or hide a world inspector window
+//!
+//! Console integrations only need to forward the input to [`InspectorCommands::run_line`],
+//! and can list the available commands using [`InspectorCommands::iter`].
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::commands::{InspectorCommands, InspectorCommandsPlugin};
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(InspectorCommandsPlugin)
+//!         .add_systems(Startup, |world: &mut World| {
+//!             InspectorCommands::run_line(world, "toggle_world_inspector").unwrap();
+//!         })
+//!         .run();
+//! }
+//! ```
+
+use std::any::TypeId;
+
+use bevy_app::{App, Plugin};
+use bevy_core::Name;
+use bevy_ecs::prelude::*;
+use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_window::PrimaryWindow;
+
+use crate::{
+    bevy_inspector,
+    quick::{check_default_plugins, Inspect, InspectSchedulePlugin},
+    utils::guess_entity_name::guess_entity_name,
+    DefaultInspectorConfigPlugin,
+};
+
+const DEFAULT_SIZE: (f32, f32) = (320., 160.);
+
+/// An action which can be invoked by name, e.g. from a console
+pub trait InspectorCommand: Send + Sync + 'static {
+    /// The name the command is invoked by, like `inspect_entity`
+    fn name(&self) -> &str;
+    /// Short explanation of what the command does and which arguments it takes
+    fn description(&self) -> &str;
+    /// Runs the command with the given arguments, returning a message if they are invalid
+    fn run(&self, world: &mut World, args: &[&str]) -> Result<(), String>;
+}
+
+/// The [`InspectorCommand`]s available to console integrations
+#[derive(Resource, Default)]
+pub struct InspectorCommands {
+    commands: Vec<Box<dyn InspectorCommand>>,
+}
+
+impl InspectorCommands {
+    /// Adds a command, replacing an existing one with the same name
+    pub fn add(&mut self, command: impl InspectorCommand) {
+        self.commands.retain(|other| other.name() != command.name());
+        self.commands.push(Box::new(command));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn InspectorCommand> {
+        self.commands
+            .iter()
+            .find(|command| command.name() == name)
+            .map(|command| &**command)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn InspectorCommand> {
+        self.commands.iter().map(|command| &**command)
+    }
+
+    /// Runs the command called `name` from the [`InspectorCommands`] resource
+    pub fn run(world: &mut World, name: &str, args: &[&str]) -> Result<(), String> {
+        world.resource_scope(|world, commands: Mut<InspectorCommands>| {
+            let command = commands
+                .get(name)
+                .ok_or_else(|| format!("unknown command `{name}`"))?;
+            command.run(world, args)
+        })
+    }
+
+    /// Runs a line like `inspect_entity 4v1`, with the command name and arguments separated by whitespace
+    pub fn run_line(world: &mut World, line: &str) -> Result<(), String> {
+        let mut words = line.split_whitespace();
+        let name = words.next().ok_or("no command given")?;
+        let args: Vec<&str> = words.collect();
+        InspectorCommands::run(world, name, &args)
+    }
+}
+
+/// The windows opened by the builtin commands
+#[derive(Resource, Default, Debug, Clone)]
+pub struct InspectorWindows {
+    pub world_inspector: bool,
+    pub entities: Vec<Entity>,
+    pub resources: Vec<TypeId>,
+}
+
+/// Plugin adding the [`InspectorCommands`] resource with the builtin commands,
+/// and displaying the [`InspectorWindows`] they open.
+///
+/// The world inspector window is independent of the [`WorldInspectorPlugin`](crate::quick::WorldInspectorPlugin).
+pub struct InspectorCommandsPlugin;
+
+impl Plugin for InspectorCommandsPlugin {
+    fn build(&self, app: &mut App) {
+        check_default_plugins(app, "InspectorCommandsPlugin");
+
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<InspectSchedulePlugin>() {
+            app.add_plugins(InspectSchedulePlugin);
+        }
+
+        let mut commands = InspectorCommands::default();
+        commands.add(InspectEntity);
+        commands.add(InspectResource);
+        commands.add(ToggleWorldInspector);
+        app.insert_resource(commands)
+            .init_resource::<InspectorWindows>()
+            .add_systems(Inspect, inspector_windows_ui);
+    }
+}
+
+/// `inspect_entity <entity>`
+pub struct InspectEntity;
+
+impl InspectorCommand for InspectEntity {
+    fn name(&self) -> &str {
+        "inspect_entity"
+    }
+
+    fn description(&self) -> &str {
+        "inspect_entity <entity>: open or close a window for an entity, given as `4v1`, its index or its name"
+    }
+
+    fn run(&self, world: &mut World, args: &[&str]) -> Result<(), String> {
+        let [arg] = args else {
+            return Err("expected one argument: <entity>".into());
+        };
+        let entity = parse_entity(world, arg)?;
+        toggle(
+            &mut world.resource_mut::<InspectorWindows>().entities,
+            entity,
+        );
+        Ok(())
+    }
+}
+
+/// `inspect_resource <resource>`
+pub struct InspectResource;
+
+impl InspectorCommand for InspectResource {
+    fn name(&self) -> &str {
+        "inspect_resource"
+    }
+
+    fn description(&self) -> &str {
+        "inspect_resource <resource>: open or close a window for a resource, given by its type path"
+    }
+
+    fn run(&self, world: &mut World, args: &[&str]) -> Result<(), String> {
+        let [arg] = args else {
+            return Err("expected one argument: <resource>".into());
+        };
+        let type_id = {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            let registration = type_registry
+                .get_with_short_type_path(arg)
+                .or_else(|| type_registry.get_with_type_path(arg))
+                .ok_or_else(|| format!("`{arg}` is not registered"))?;
+            if registration.data::<ReflectResource>().is_none() {
+                return Err(format!("`{arg}` is not a reflected resource"));
+            }
+            registration.type_id()
+        };
+        toggle(
+            &mut world.resource_mut::<InspectorWindows>().resources,
+            type_id,
+        );
+        Ok(())
+    }
+}
+
+/// `toggle_world_inspector`
+pub struct ToggleWorldInspector;
+
+impl InspectorCommand for ToggleWorldInspector {
+    fn name(&self) -> &str {
+        "toggle_world_inspector"
+    }
+
+    fn description(&self) -> &str {
+        "toggle_world_inspector: show or hide the world inspector window"
+    }
+
+    fn run(&self, world: &mut World, args: &[&str]) -> Result<(), String> {
+        if !args.is_empty() {
+            return Err("expected no arguments".into());
+        }
+        let mut windows = world.resource_mut::<InspectorWindows>();
+        windows.world_inspector = !windows.world_inspector;
+        Ok(())
+    }
+}
+
+fn toggle<T: PartialEq>(list: &mut Vec<T>, value: T) {
+    match list.iter().position(|other| *other == value) {
+        Some(i) => drop(list.remove(i)),
+        None => list.push(value),
+    }
+}
+
+/// Parses `4v1`, `4` (the live entity with index 4, whatever its generation) or a [`Name`]
+fn parse_entity(world: &mut World, arg: &str) -> Result<Entity, String> {
+    let entity = match arg.split_once('v') {
+        Some((index, generation)) => index
+            .parse::<u32>()
+            .ok()
+            .zip(generation.parse::<u32>().ok())
+            .and_then(|(index, generation)| {
+                Entity::try_from_bits(u64::from(generation) << 32 | u64::from(index)).ok()
+            }),
+        None => arg
+            .parse::<u32>()
+            .ok()
+            .and_then(|index| world.entities().resolve_from_id(index)),
+    };
+    let entity = match entity {
+        Some(entity) => Some(entity),
+        None => world
+            .query::<(Entity, &Name)>()
+            .iter(world)
+            .find(|(_, name)| name.as_str() == arg)
+            .map(|(entity, _)| entity),
+    };
+
+    entity
+        .filter(|&entity| world.get_entity(entity).is_ok())
+        .ok_or_else(|| format!("no entity `{arg}`"))
+}
+
+fn inspector_windows_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let ctx = egui_context.get_mut();
+
+    let mut windows = world.resource::<InspectorWindows>().clone();
+
+    if windows.world_inspector {
+        egui::Window::new("World Inspector")
+            .default_size(DEFAULT_SIZE)
+            .open(&mut windows.world_inspector)
+            .show(ctx, |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    bevy_inspector::ui_for_world(world, ui);
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+    }
+
+    windows.entities.retain(|&entity| {
+        let mut open = world.get_entity(entity).is_ok();
+        egui::Window::new(guess_entity_name(world, entity))
+            .id(egui::Id::new(("inspect_entity", entity)))
+            .default_size(DEFAULT_SIZE)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    bevy_inspector::ui_for_entity(world, entity, ui);
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        open
+    });
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    windows.resources.retain(|&type_id| {
+        let mut open = true;
+        let name = bevy_inspector::errors::name_of_type(type_id, &type_registry);
+        egui::Window::new(&*name)
+            .id(egui::Id::new(("inspect_resource", type_id)))
+            .default_size(DEFAULT_SIZE)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    bevy_inspector::by_type_id::ui_for_resource(
+                        world,
+                        type_id,
+                        ui,
+                        &name,
+                        &type_registry,
+                    );
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        open
+    });
+
+    *world.resource_mut::<InspectorWindows>() = windows;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_entities() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Name::new("player")).id();
+
+        assert_eq!(parse_entity(&mut world, &a.to_string()), Ok(a));
+        assert_eq!(parse_entity(&mut world, &b.index().to_string()), Ok(b));
+        assert_eq!(parse_entity(&mut world, "player"), Ok(b));
+        assert!(parse_entity(&mut world, "enemy").is_err());
+        assert!(parse_entity(&mut world, "100").is_err());
+
+        world.despawn(a);
+        let recycled = world.spawn_empty().id();
+        assert_eq!(recycled.index(), a.index());
+        assert_eq!(
+            parse_entity(&mut world, &a.index().to_string()),
+            Ok(recycled)
+        );
+        assert!(parse_entity(&mut world, &a.to_string()).is_err());
+    }
+
+    #[test]
+    fn run_line() {
+        let mut world = World::new();
+        world.init_resource::<InspectorWindows>();
+        let mut commands = InspectorCommands::default();
+        commands.add(InspectEntity);
+        commands.add(ToggleWorldInspector);
+        world.insert_resource(commands);
+        let entity = world.spawn_empty().id();
+
+        InspectorCommands::run_line(&mut world, "toggle_world_inspector").unwrap();
+        InspectorCommands::run_line(&mut world, &format!("inspect_entity {entity}")).unwrap();
+        let windows = world.resource::<InspectorWindows>();
+        assert!(windows.world_inspector);
+        assert_eq!(windows.entities, [entity]);
+
+        assert!(InspectorCommands::run_line(&mut world, "inspect_entity").is_err());
+        assert!(InspectorCommands::run_line(&mut world, "unknown").is_err());
+    }
+}
@@ -0,0 +1,595 @@
+//! A docked editor layout using [`egui_dock`], behind the `editor` feature.
+//!
+//! The [`EditorDockPlugin`] fills the primary window with **Hierarchy**, **Inspector**, **Resources**, **Assets** and **Game View** tabs,
+//! which can be rearranged by dragging them around.
+//! The lock toggle in the inspector opens the selected entity in its own tab, which keeps displaying it when the selection changes.
+//! The cameras marked with [`EditorCamera`] (or all cameras rendering to the primary window, if there are none) only render into the game view.
+//! Alternatively, a camera set up using [`game_view::setup_game_view`] is displayed in the game view as a texture.
+//!
+//! Additional tabs can be added using [`EditorDockPlugin::with_tab`] or [`EditorDock::add_tab`].
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::editor::{EditorCamera, EditorDockPlugin};
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(EditorDockPlugin::new().with_tab("Stats", |world: &mut World, ui: &mut egui::Ui| {
+//!             ui.label(format!("{} entities", world.entities().len()));
+//!         }))
+//!         .add_systems(Startup, setup)
+//!         .run();
+//! }
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((Camera3d::default(), EditorCamera));
+//! }
+//! ```
+
+use std::{any::TypeId, sync::Mutex};
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{ReflectAsset, UntypedAssetId};
+use bevy_ecs::{prelude::*, schedule::BoxedCondition};
+use bevy_egui::{EguiContext, EguiContextSettings, EguiPlugin};
+use bevy_math::UVec2;
+use bevy_reflect::TypeRegistry;
+use bevy_render::camera::{Camera, NormalizedRenderTarget, Viewport};
+use bevy_window::{PrimaryWindow, Window};
+pub use egui_dock;
+use egui_dock::{DockArea, DockState, NodeIndex, Style};
+
+use crate::{
+    bevy_inspector::{
+        self,
+        hierarchy::{Hierarchy, SelectedEntities},
+        inspector_tabs::{self, InspectorTabs},
+    },
+    game_view::{self, GameView},
+    quick::{check_default_plugins, Inspect, InspectSchedulePlugin},
+    DefaultInspectorConfigPlugin,
+};
+
+type TabUi = Box<dyn FnMut(&mut World, &mut egui::Ui) + Send + Sync>;
+
+/// Plugin displaying the [`EditorDock`] in the primary window
+///
+/// You can use [`EditorDockPlugin::run_if`] to control when the editor is shown.
+#[derive(Default)]
+pub struct EditorDockPlugin {
+    condition: Mutex<Option<BoxedCondition>>,
+    layout: Mutex<Option<DockState<EditorTab>>>,
+    tabs: Mutex<Vec<(String, TabUi)>>,
+}
+
+impl EditorDockPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only show the editor if the condition is fulfilled.
+    ///
+    /// While hidden, the cameras keep the viewport of the game view, see [`reset_camera_viewports`].
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        let condition_system = IntoSystem::into_system(condition);
+        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+        self
+    }
+
+    /// Replace the default layout, see [`EditorDock::default_layout`]
+    pub fn with_layout(mut self, layout: DockState<EditorTab>) -> Self {
+        self.layout = Mutex::new(Some(layout));
+        self
+    }
+
+    /// Add a tab called `name`, see [`EditorDock::add_tab`]
+    pub fn with_tab(
+        self,
+        name: impl Into<String>,
+        ui: impl FnMut(&mut World, &mut egui::Ui) + Send + Sync + 'static,
+    ) -> Self {
+        self.tabs.lock().unwrap().push((name.into(), Box::new(ui)));
+        self
+    }
+}
+
+impl Plugin for EditorDockPlugin {
+    fn build(&self, app: &mut App) {
+        check_default_plugins(app, "EditorDockPlugin");
+
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<InspectSchedulePlugin>() {
+            app.add_plugins(InspectSchedulePlugin);
+        }
+        #[cfg(feature = "bevy_gizmos")]
+        if !app.is_plugin_added::<bevy_inspector::gizmos::InspectorGizmosPlugin>() {
+            app.add_plugins(bevy_inspector::gizmos::InspectorGizmosPlugin);
+        }
+
+        let mut dock = EditorDock::new(
+            self.layout
+                .lock()
+                .unwrap()
+                .take()
+                .unwrap_or_else(EditorDock::default_layout),
+        );
+        for (name, ui) in self.tabs.lock().unwrap().drain(..) {
+            dock.add_tab_boxed(name, ui);
+        }
+        app.insert_resource(dock);
+
+        let condition = self.condition.lock().unwrap().take();
+        let mut system = (editor_dock_ui, set_camera_viewports)
+            .chain()
+            .into_configs();
+        if let Some(condition) = condition {
+            system.run_if_dyn(condition);
+        }
+        app.add_systems(Inspect, system);
+    }
+}
+
+/// Marks the cameras rendering into the game view of the [`EditorDock`]
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct EditorCamera;
+
+/// A tab of the [`EditorDock`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorTab {
+    GameView,
+    Hierarchy,
+    Inspector,
+    Resources,
+    Assets,
+    /// The [asset browser](bevy_inspector::asset_browser), from which images can be dragged onto texture fields
+    AssetBrowser,
+    /// The [query playground](bevy_inspector::query_playground), whose entities can be clicked to select them
+    QueryPlayground,
+    /// The [notes](bevy_inspector::notes) attached to entities, components and resources
+    Notes,
+    /// The [stepping controls](bevy_inspector::stepping)
+    #[cfg(feature = "stepping")]
+    Stepping,
+    /// The [log console](bevy_inspector::log_console)
+    #[cfg(feature = "log_console")]
+    Log,
+    /// An inspector locked to an entity using the lock toggle in the [`EditorTab::Inspector`], see [`InspectorTabs`]
+    LockedInspector(Entity),
+    /// A tab added using [`EditorDock::add_tab`]
+    Custom(String),
+}
+
+/// What is displayed in the [`EditorTab::Inspector`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorSelection {
+    /// The [`EditorDock::selected_entities`]
+    Entities,
+    Resource(TypeId, String),
+    Asset(TypeId, String, UntypedAssetId),
+}
+
+/// State of the editor layout and selection
+#[derive(Resource)]
+pub struct EditorDock {
+    pub state: DockState<EditorTab>,
+    pub selected_entities: SelectedEntities,
+    pub selection: EditorSelection,
+    /// The locked entities, each displayed in an [`EditorTab::LockedInspector`]
+    pub inspector_tabs: InspectorTabs,
+    game_view_rect: Option<egui::Rect>,
+    custom_tabs: Vec<(String, TabUi)>,
+}
+
+impl EditorDock {
+    pub fn new(state: DockState<EditorTab>) -> Self {
+        EditorDock {
+            state,
+            selected_entities: SelectedEntities::default(),
+            selection: EditorSelection::Entities,
+            inspector_tabs: InspectorTabs::default(),
+            game_view_rect: None,
+            custom_tabs: Vec::new(),
+        }
+    }
+
+    /// The game view in the middle, with the hierarchy to the left, the inspector to the right and resources and assets below
+    pub fn default_layout() -> DockState<EditorTab> {
+        let mut state = DockState::new(vec![EditorTab::GameView]);
+        let tree = state.main_surface_mut();
+        let [game, _inspector] =
+            tree.split_right(NodeIndex::root(), 0.75, vec![EditorTab::Inspector]);
+        let [game, _hierarchy] = tree.split_left(game, 0.2, vec![EditorTab::Hierarchy]);
+        #[allow(unused_mut)]
+        let mut bottom = vec![
+            EditorTab::Resources,
+            EditorTab::Assets,
+            EditorTab::AssetBrowser,
+            EditorTab::QueryPlayground,
+            EditorTab::Notes,
+        ];
+        #[cfg(feature = "stepping")]
+        bottom.push(EditorTab::Stepping);
+        #[cfg(feature = "log_console")]
+        bottom.push(EditorTab::Log);
+        let [_game, _bottom] = tree.split_below(game, 0.8, bottom);
+        state
+    }
+
+    /// Adds a tab called `name` displaying `ui`, replacing an existing tab with the same name.
+    ///
+    /// If the tab isn't already part of the layout, it is opened next to the focused tab.
+    pub fn add_tab(
+        &mut self,
+        name: impl Into<String>,
+        ui: impl FnMut(&mut World, &mut egui::Ui) + Send + Sync + 'static,
+    ) {
+        self.add_tab_boxed(name.into(), Box::new(ui));
+    }
+
+    fn add_tab_boxed(&mut self, name: String, ui: TabUi) {
+        let tab = EditorTab::Custom(name.clone());
+        if self.state.find_tab(&tab).is_none() {
+            self.state.push_to_focused_leaf(tab);
+        }
+        self.custom_tabs.retain(|(other, _)| *other != name);
+        self.custom_tabs.push((name, ui));
+    }
+
+    /// The area of the game view in logical egui points, if it was visible last frame
+    pub fn game_view_rect(&self) -> Option<egui::Rect> {
+        self.game_view_rect
+    }
+
+    /// Display the dock area, filling the whole `ctx`
+    pub fn ui(&mut self, world: &mut World, ctx: &mut egui::Context) {
+        self.game_view_rect = None;
+        self.inspector_tabs.retain_existing(world);
+        self.sync_locked_tabs();
+
+        let mut tab_viewer = EditorTabViewer {
+            world,
+            selected_entities: &mut self.selected_entities,
+            selection: &mut self.selection,
+            inspector_tabs: &mut self.inspector_tabs,
+            game_view_rect: &mut self.game_view_rect,
+            custom_tabs: &mut self.custom_tabs,
+        };
+        DockArea::new(&mut self.state)
+            .style(Style::from_egui(ctx.style().as_ref()))
+            .show(ctx, &mut tab_viewer);
+    }
+
+    /// Opens an [`EditorTab::LockedInspector`] next to the inspector for every newly locked entity,
+    /// and closes the ones which were unlocked.
+    fn sync_locked_tabs(&mut self) {
+        let inspector_tabs = &self.inspector_tabs;
+        self.state.retain_tabs(|tab| match tab {
+            EditorTab::LockedInspector(entity) => inspector_tabs.is_locked(*entity),
+            _ => true,
+        });
+
+        for &entity in self.inspector_tabs.locked() {
+            let tab = EditorTab::LockedInspector(entity);
+            if self.state.find_tab(&tab).is_some() {
+                continue;
+            }
+            match self.state.find_tab(&EditorTab::Inspector) {
+                Some((surface, node, _)) => self.state[surface][node].append_tab(tab),
+                None => self.state.push_to_focused_leaf(tab),
+            }
+        }
+    }
+}
+
+/// Let the [`EditorCamera`]s render to their whole target again, e.g. after hiding the editor
+pub fn reset_camera_viewports(world: &mut World) {
+    let mut cameras = world.query_filtered::<&mut Camera, With<EditorCamera>>();
+    for mut camera in cameras.iter_mut(world) {
+        camera.viewport = None;
+    }
+}
+
+/// The [`egui_dock::TabViewer`] used by [`EditorDock::ui`].
+///
+/// If you need more control than [`EditorDock::add_tab`] gives you, you can implement your own
+/// [`TabViewer`](egui_dock::TabViewer) and forward the builtin tabs to this one.
+pub struct EditorTabViewer<'a> {
+    pub world: &'a mut World,
+    pub selected_entities: &'a mut SelectedEntities,
+    pub selection: &'a mut EditorSelection,
+    pub inspector_tabs: &'a mut InspectorTabs,
+    /// Set to the clip rect of the game view while it is displayed
+    pub game_view_rect: &'a mut Option<egui::Rect>,
+    custom_tabs: &'a mut Vec<(String, TabUi)>,
+}
+
+impl egui_dock::TabViewer for EditorTabViewer<'_> {
+    type Tab = EditorTab;
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        let type_registry = self.world.resource::<AppTypeRegistry>().0.clone();
+        let type_registry = type_registry.read();
+
+        match tab {
+            EditorTab::GameView => {
+                let game_view_camera = self
+                    .world
+                    .query_filtered::<Entity, With<GameView>>()
+                    .iter(self.world)
+                    .next();
+                match game_view_camera {
+                    Some(camera) => drop(game_view::game_view_ui(self.world, camera, ui)),
+                    None => *self.game_view_rect = Some(ui.clip_rect()),
+                }
+            }
+            EditorTab::Hierarchy => {
+                let selected = Hierarchy {
+                    world: self.world,
+                    type_registry: &type_registry,
+                    selected: self.selected_entities,
+                    context_menu: Some(&mut |ui: &mut egui::Ui, entity, world: &mut World, _| {
+                        let target = bevy_inspector::notes::NoteTarget::entity(world, entity);
+                        bevy_inspector::notes::note_button(world, target, ui);
+                    }),
+                    shortcircuit_entity: None,
+                    extra_state: &mut (),
+                }
+                .show::<()>(ui);
+                if selected {
+                    *self.selection = EditorSelection::Entities;
+                }
+            }
+            EditorTab::Resources => select_resource(ui, &type_registry, self.selection),
+            EditorTab::Assets => select_asset(ui, &type_registry, self.world, self.selection),
+            EditorTab::AssetBrowser => {
+                drop(type_registry);
+                bevy_inspector::asset_browser::asset_browser_ui(self.world, ui);
+            }
+            #[cfg(feature = "stepping")]
+            EditorTab::Stepping => {
+                drop(type_registry);
+                bevy_inspector::stepping::ui_for_stepping(self.world, ui);
+            }
+            #[cfg(feature = "log_console")]
+            EditorTab::Log => {
+                drop(type_registry);
+                bevy_inspector::log_console::ui_for_log_console(self.world, ui);
+            }
+            EditorTab::Notes => {
+                drop(type_registry);
+                bevy_inspector::notes::ui_for_notes(self.world, ui);
+            }
+            EditorTab::QueryPlayground => {
+                drop(type_registry);
+                let selected = bevy_inspector::query_playground::query_playground_ui(
+                    self.world,
+                    ui,
+                    self.selected_entities,
+                );
+                if selected {
+                    *self.selection = EditorSelection::Entities;
+                }
+            }
+            EditorTab::Inspector => match *self.selection {
+                EditorSelection::Entities => {
+                    drop(type_registry);
+                    if self.selected_entities.len() > 1 {
+                        bevy_inspector::batch::ui_for_batch_operations(
+                            self.world,
+                            ui,
+                            self.selected_entities,
+                        );
+                    }
+                    inspector_tabs::ui_for_selection(
+                        self.world,
+                        ui,
+                        self.selected_entities,
+                        self.inspector_tabs,
+                    );
+                }
+                EditorSelection::Resource(type_id, ref name) => {
+                    ui.label(name);
+                    bevy_inspector::by_type_id::ui_for_resource(
+                        self.world,
+                        type_id,
+                        ui,
+                        name,
+                        &type_registry,
+                    );
+                }
+                EditorSelection::Asset(type_id, ref name, handle) => {
+                    ui.label(name);
+                    bevy_inspector::by_type_id::ui_for_asset(
+                        self.world,
+                        type_id,
+                        handle,
+                        ui,
+                        &type_registry,
+                    );
+                }
+            },
+            EditorTab::LockedInspector(entity) => {
+                drop(type_registry);
+                inspector_tabs::ui_for_locked_entity(self.world, ui, *entity, self.inspector_tabs);
+            }
+            EditorTab::Custom(name) => {
+                drop(type_registry);
+                match self.custom_tabs.iter_mut().find(|(other, _)| other == name) {
+                    Some((_, tab_ui)) => tab_ui(self.world, ui),
+                    None => {
+                        ui.label(format!("No UI registered for tab `{name}`"));
+                    }
+                }
+            }
+        }
+    }
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        match tab {
+            EditorTab::GameView => "Game View".into(),
+            EditorTab::AssetBrowser => "Asset Browser".into(),
+            EditorTab::QueryPlayground => "Query Playground".into(),
+            EditorTab::LockedInspector(entity) => format!(
+                "🔒 {}",
+                bevy_inspector::guess_entity_name(self.world, *entity)
+            )
+            .into(),
+            EditorTab::Custom(name) => name.as_str().into(),
+            _ => format!("{tab:?}").into(),
+        }
+    }
+
+    fn on_close(&mut self, tab: &mut Self::Tab) -> bool {
+        if let EditorTab::LockedInspector(entity) = tab {
+            self.inspector_tabs.unlock(*entity);
+        }
+        true
+    }
+
+    fn clear_background(&self, tab: &Self::Tab) -> bool {
+        !matches!(tab, EditorTab::GameView)
+    }
+}
+
+fn select_resource(
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+    selection: &mut EditorSelection,
+) {
+    let mut resources: Vec<_> = type_registry
+        .iter()
+        .filter(|registration| registration.data::<ReflectResource>().is_some())
+        .map(|registration| {
+            (
+                registration.type_info().type_path_table().short_path(),
+                registration.type_id(),
+            )
+        })
+        .collect();
+    resources.sort_by_key(|(name, _)| *name);
+
+    for (resource_name, type_id) in resources {
+        let selected = match *selection {
+            EditorSelection::Resource(selected, _) => selected == type_id,
+            _ => false,
+        };
+
+        if ui.selectable_label(selected, resource_name).clicked() {
+            *selection = EditorSelection::Resource(type_id, resource_name.to_string());
+        }
+    }
+}
+
+fn select_asset(
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+    world: &World,
+    selection: &mut EditorSelection,
+) {
+    let mut assets: Vec<_> = type_registry
+        .iter()
+        .filter_map(|registration| {
+            let reflect_asset = registration.data::<ReflectAsset>()?;
+            Some((
+                registration.type_info().type_path_table().short_path(),
+                registration.type_id(),
+                reflect_asset,
+            ))
+        })
+        .collect();
+    assets.sort_by_key(|(name, ..)| *name);
+
+    for (asset_name, asset_type_id, reflect_asset) in assets {
+        let handles: Vec<_> = reflect_asset.ids(world).collect();
+
+        ui.collapsing(format!("{asset_name} ({})", handles.len()), |ui| {
+            for handle in handles {
+                let selected = match *selection {
+                    EditorSelection::Asset(_, _, selected_id) => selected_id == handle,
+                    _ => false,
+                };
+
+                if ui
+                    .selectable_label(selected, format!("{handle:?}"))
+                    .clicked()
+                {
+                    *selection =
+                        EditorSelection::Asset(asset_type_id, asset_name.to_string(), handle);
+                }
+            }
+        });
+    }
+}
+
+fn editor_dock_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    world.resource_scope(|world, mut dock: Mut<EditorDock>| {
+        dock.ui(world, egui_context.get_mut());
+    });
+}
+
+/// Restricts the cameras to the game view, so that they aren't obstructed by the other tabs
+fn set_camera_viewports(
+    dock: Res<EditorDock>,
+    primary_window: Query<(Entity, &Window, &EguiContextSettings), With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Camera, Has<EditorCamera>)>,
+) {
+    let Some(game_view_rect) = dock.game_view_rect else {
+        return;
+    };
+    let Ok((window_entity, window, egui_settings)) = primary_window.get_single() else {
+        return;
+    };
+
+    let scale_factor = window.scale_factor() * egui_settings.scale_factor;
+    let viewport_pos = game_view_rect.left_top().to_vec2() * scale_factor;
+    let viewport_size = game_view_rect.size() * scale_factor;
+
+    let physical_position = UVec2::new(viewport_pos.x as u32, viewport_pos.y as u32);
+    let physical_size = UVec2::new(viewport_size.x as u32, viewport_size.y as u32);
+
+    // wgpu panics if the viewport extends past the render target, which can happen for a frame while resizing
+    let window_size = window.physical_size();
+    let rect = physical_position + physical_size;
+    if physical_size.x == 0
+        || physical_size.y == 0
+        || rect.x > window_size.x
+        || rect.y > window_size.y
+    {
+        return;
+    }
+
+    let any_marked = cameras.iter().any(|(_, marked)| marked);
+    for (mut camera, marked) in &mut cameras {
+        let renders_to_window = matches!(
+            camera.target.normalize(Some(window_entity)),
+            Some(NormalizedRenderTarget::Window(window_ref)) if window_ref.entity() == window_entity
+        );
+        if (any_marked && !marked) || (!any_marked && !renders_to_window) {
+            continue;
+        }
+
+        camera.viewport = Some(Viewport {
+            physical_position,
+            physical_size,
+            depth: camera
+                .viewport
+                .as_ref()
+                .map_or(0.0..1.0, |viewport| viewport.depth.clone()),
+        });
+    }
+}
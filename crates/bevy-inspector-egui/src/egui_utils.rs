@@ -1,5 +1,7 @@
 use egui::FontId;
 
+use crate::theme::InspectorTheme;
+
 pub fn layout_job(text: &[(FontId, &str)]) -> egui::epaint::text::LayoutJob {
     let mut job = egui::epaint::text::LayoutJob::default();
     for (font_id, text) in text {
@@ -79,6 +81,23 @@ impl IconButton {
         self.line([self.rect.right_center(), self.rect.center_bottom()]);
         self.response
     }
+    fn reset_button(self) -> egui::Response {
+        // paints an open circle, going counterclockwise from the top
+        let center = self.rect.center();
+        let radius = self.rect.width() / 2.0;
+        let points = (0..=12)
+            .map(|i| {
+                let angle = -std::f32::consts::FRAC_PI_2 - i as f32 * std::f32::consts::PI / 8.0;
+                center + radius * egui::Vec2::angled(angle)
+            })
+            .collect();
+        self.painter.add(egui::Shape::line(points, self.stroke));
+        // paints an arrow head pointing left at the top
+        let tip = self.rect.center_top();
+        self.line([tip, tip + egui::vec2(radius / 3.0, -radius / 3.0)]);
+        self.line([tip, tip + egui::vec2(radius / 3.0, radius / 3.0)]);
+        self.response
+    }
 }
 
 pub fn add_button(ui: &mut egui::Ui) -> egui::Response {
@@ -97,6 +116,38 @@ pub fn down_button(ui: &mut egui::Ui) -> egui::Response {
     IconButton::new(ui).down_button()
 }
 
+pub fn reset_button(ui: &mut egui::Ui) -> egui::Response {
+    IconButton::new(ui).reset_button()
+}
+
+pub fn set_error_style(ui: &mut egui::Ui) {
+    let color = ui.visuals().error_fg_color;
+    let width = if InspectorTheme::current(ui.ctx()).shape_cues {
+        2.0
+    } else {
+        1.0
+    };
+    let visuals = ui.visuals_mut();
+    visuals.override_text_color = Some(color);
+    for widget in [
+        &mut visuals.widgets.inactive,
+        &mut visuals.widgets.hovered,
+        &mut visuals.widgets.active,
+    ] {
+        widget.bg_stroke = egui::Stroke::new(width, color);
+    }
+}
+
+/// An error message in the error color, prefixed with ⚠ if the [`InspectorTheme`] has shape cues
+#[cfg(any(feature = "ron", feature = "remote"))]
+pub fn error_label(ui: &mut egui::Ui, text: impl Into<String>) -> egui::Response {
+    let mut text = text.into();
+    if InspectorTheme::current(ui.ctx()).shape_cues {
+        text.insert_str(0, "⚠ ");
+    }
+    ui.colored_label(ui.visuals().error_fg_color, text)
+}
+
 pub fn show_docs(response: egui::Response, docs: Option<&str>) {
     if let Some(docs) = docs {
         let mut end_idx = docs.len();
@@ -293,7 +344,7 @@ mod easymark {
             // TODO(emilk): add Style here so empty heading still uses up the right amount of space.
             Newline,
 
-            ///
+            /// Text with a style
             Text(Style, &'a str),
 
             /// title, url
@@ -353,7 +404,7 @@ mod easymark {
         /// See the module-level documentation for details.
         ///
         /// # Example:
-        /// ```
+        /// ```ignore
         /// # use egui_demo_lib::easy_mark::parser::Parser;
         /// for item in Parser::new("Hello *world*!") {
         /// }
@@ -621,3 +672,62 @@ mod easymark {
         }
     }
 }
+
+/// How long it takes for the `highlight_changes` highlight to fade out
+#[cfg(feature = "highlight_changes")]
+const HIGHLIGHT_DURATION_SECONDS: f64 = 2.0;
+
+/// Remembers when the `fingerprint` of a value last changed, and returns how strongly it should be highlighted,
+/// fading from `1.0` right after the change to `0.0` after [`HIGHLIGHT_DURATION_SECONDS`]
+#[cfg(feature = "highlight_changes")]
+pub fn change_highlight(ui: &egui::Ui, id: egui::Id, fingerprint: u64) -> f32 {
+    let id = id.with("change_highlight");
+    let now = ui.input(|input| input.time);
+    let (last_fingerprint, last_change) = ui
+        .data(|data| data.get_temp::<(u64, f64)>(id))
+        .unwrap_or((fingerprint, f64::NEG_INFINITY));
+    let last_change = if last_fingerprint != fingerprint {
+        now
+    } else {
+        last_change
+    };
+    ui.data_mut(|data| data.insert_temp(id, (fingerprint, last_change)));
+
+    let strength = 1.0 - (now - last_change) / HIGHLIGHT_DURATION_SECONDS;
+    if strength > 0.0 {
+        ui.ctx().request_repaint();
+        strength as f32
+    } else {
+        0.0
+    }
+}
+
+/// Label for a field in a grid, tinted while the field's value was changed recently
+pub fn field_label(
+    ui: &mut egui::Ui,
+    text: &str,
+    _field: &dyn bevy_reflect::PartialReflect,
+    _id: egui::Id,
+) -> egui::Response {
+    #[cfg(feature = "highlight_changes")]
+    {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        format!("{_field:?}").hash(&mut hasher);
+        let strength = change_highlight(ui, _id, hasher.finish());
+        if strength > 0.0 {
+            let theme = InspectorTheme::current(ui.ctx());
+            let color = ui
+                .visuals()
+                .text_color()
+                .lerp_to_gamma(theme.highlight_color, strength);
+            let mut text = egui::RichText::new(text).color(color);
+            if theme.shape_cues {
+                text = text.underline();
+            }
+            return ui.label(text);
+        }
+    }
+    ui.label(text)
+}
@@ -0,0 +1,188 @@
+//! Rendering a camera into an egui widget, e.g. to display the game inside a dock layout.
+//!
+//! [`setup_game_view`] makes the camera render into an image instead of the window,
+//! and [`game_view_ui`] displays that image, resizing it to the available space.
+//! Because egui covers the window, game systems should use the [`GameView::cursor_position`]
+//! and the [`game_view_hovered`] and [`game_view_focused`] run conditions instead of the window's cursor and input.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::bevy_egui::{EguiContext, EguiPlugin};
+//! use bevy_inspector_egui::game_view::{self, GameView};
+//! use bevy_window::PrimaryWindow;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(EguiPlugin)
+//!         .add_systems(Startup, setup)
+//!         .add_systems(Update, ui)
+//!         .add_systems(Update, jump.run_if(game_view::game_view_focused))
+//!         .run();
+//! }
+//!
+//! fn setup(world: &mut World) {
+//!     let camera = world.spawn(Camera3d::default()).id();
+//!     game_view::setup_game_view(world, camera);
+//! }
+//!
+//! fn ui(world: &mut World) {
+//!     let mut egui_context = world
+//!         .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+//!         .single(world)
+//!         .clone();
+//!     let camera = world.query_filtered::<Entity, With<GameView>>().single(world);
+//!
+//!     egui::Window::new("Game").show(egui_context.get_mut(), |ui| {
+//!         game_view::game_view_ui(world, camera, ui);
+//!     });
+//! }
+//!
+//! fn jump(input: Res<ButtonInput<KeyCode>>) {
+//!     if input.just_pressed(KeyCode::Space) {
+//!         info!("jump");
+//!     }
+//! }
+//! ```
+
+use bevy_asset::{Assets, Handle, RenderAssetUsages};
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiUserTextures;
+use bevy_image::Image;
+use bevy_math::Vec2;
+use bevy_render::{
+    camera::{Camera, RenderTarget},
+    render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+};
+
+/// A camera rendering into an image, which is displayed by [`game_view_ui`]
+#[derive(Component, Debug, Clone)]
+pub struct GameView {
+    pub image: Handle<Image>,
+    pub texture_id: egui::TextureId,
+    /// Position of the cursor in the image in physical pixels, like the window cursor position for [`Camera::viewport_to_world`]
+    pub cursor_position: Option<Vec2>,
+    pub hovered: bool,
+    /// Set when the game view is clicked, and cleared when something else is clicked
+    pub focused: bool,
+}
+
+/// Makes the `camera` render into a new image, which can be displayed using [`game_view_ui`].
+///
+/// Requires the `EguiPlugin`.
+pub fn setup_game_view(world: &mut World, camera: Entity) -> egui::TextureId {
+    let image = world
+        .resource_mut::<Assets<Image>>()
+        .add(render_target_image());
+    let texture_id = world
+        .resource_mut::<EguiUserTextures>()
+        .add_image(image.clone());
+
+    let mut entity = world.entity_mut(camera);
+    if let Some(mut camera) = entity.get_mut::<Camera>() {
+        camera.target = RenderTarget::Image(image.clone());
+    }
+    entity.insert(GameView {
+        image,
+        texture_id,
+        cursor_position: None,
+        hovered: false,
+        focused: false,
+    });
+
+    texture_id
+}
+
+/// An empty image which cameras can render into, to be resized before displaying it
+pub(crate) fn render_target_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+/// Display the image rendered by the `camera` set up using [`setup_game_view`], filling the available space.
+///
+/// Returns `None` if the camera has no [`GameView`].
+pub fn game_view_ui(
+    world: &mut World,
+    camera: Entity,
+    ui: &mut egui::Ui,
+) -> Option<egui::Response> {
+    let mut game_view = world.get::<GameView>(camera)?.clone();
+
+    let size = ui.available_size().max(egui::Vec2::splat(1.0));
+    let pixels_per_point = ui.ctx().pixels_per_point();
+    resize_image(
+        &mut world.resource_mut::<Assets<Image>>(),
+        &game_view.image,
+        size * pixels_per_point,
+    );
+
+    let response = ui.add(
+        egui::Image::new(egui::load::SizedTexture::new(game_view.texture_id, size))
+            .sense(egui::Sense::click_and_drag()),
+    );
+
+    game_view.hovered = response.hovered();
+    game_view.cursor_position = response
+        .hover_pos()
+        .map(|pos| (pos - response.rect.min) * pixels_per_point)
+        .map(|pos| Vec2::new(pos.x, pos.y));
+    if response.clicked() || response.drag_started() {
+        game_view.focused = true;
+        // keyboard input should reach the game, not a previously focused text field
+        ui.memory_mut(|memory| {
+            if let Some(focused) = memory.focused() {
+                memory.surrender_focus(focused);
+            }
+        });
+    } else if response.clicked_elsewhere() {
+        game_view.focused = false;
+    }
+
+    *world.get_mut::<GameView>(camera)? = game_view;
+
+    Some(response)
+}
+
+/// Resizes the `image` to the `physical_size`, unless it already has that size
+pub(crate) fn resize_image(
+    images: &mut Assets<Image>,
+    image: &Handle<Image>,
+    physical_size: egui::Vec2,
+) {
+    let physical_size = Extent3d {
+        width: (physical_size.x as u32).max(1),
+        height: (physical_size.y as u32).max(1),
+        depth_or_array_layers: 1,
+    };
+    if let Some(image) = images.get(image) {
+        if image.texture_descriptor.size == physical_size {
+            return;
+        }
+    }
+    if let Some(image) = images.get_mut(image) {
+        image.resize(physical_size);
+    }
+}
+
+/// Run condition which is true while the cursor is over a [`GameView`]
+pub fn game_view_hovered(game_views: Query<&GameView>) -> bool {
+    game_views.iter().any(|game_view| game_view.hovered)
+}
+
+/// Run condition which is true while a [`GameView`] is focused
+pub fn game_view_focused(game_views: Query<&GameView>) -> bool {
+    game_views.iter().any(|game_view| game_view.focused)
+}
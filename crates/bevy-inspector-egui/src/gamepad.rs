@@ -0,0 +1,116 @@
+//! Driving the inspector with a gamepad, for console or dev-kit builds without a mouse or keyboard.
+//!
+//! [`InspectorGamepadPlugin`] translates gamepad buttons into the keys egui uses for keyboard navigation,
+//! as configured by the [`InspectorGamepadMapping`] resource. By default
+//! - the d-pad up and down move the focus to the previous and next widget, left and right move sliders,
+//! - A (south) clicks the focused button or toggles the focused checkbox or header, B (east) removes the focus,
+//! - the right and left triggers increase and decrease the focused number.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::{gamepad::InspectorGamepadPlugin, quick::WorldInspectorPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins((WorldInspectorPlugin::new(), InspectorGamepadPlugin));
+//! ```
+
+use std::time::Duration;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_egui::{input::EguiInputEvent, EguiContext, EguiInputSet, EguiPlugin};
+use bevy_input::gamepad::{Gamepad, GamepadButton};
+use bevy_time::{Real, Time};
+use bevy_utils::HashMap;
+use bevy_window::PrimaryWindow;
+
+/// Time a button has to be held before its key is repeated
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+/// Time between repeated keys while a button is held
+const REPEAT_INTERVAL: Duration = Duration::from_millis(80);
+
+/// Plugin sending the keys of the [`InspectorGamepadMapping`] to the egui context of the primary window,
+/// see the [module docs](self)
+pub struct InspectorGamepadPlugin;
+
+impl Plugin for InspectorGamepadPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        app.init_resource::<InspectorGamepadMapping>();
+        app.add_systems(
+            PreUpdate,
+            send_gamepad_keys.in_set(EguiInputSet::ReadBevyEvents),
+        );
+    }
+}
+
+/// The key (with modifiers) sent to egui for each gamepad button
+#[derive(Resource, Debug, Clone)]
+pub struct InspectorGamepadMapping {
+    pub bindings: Vec<(GamepadButton, egui::Key, egui::Modifiers)>,
+}
+
+impl Default for InspectorGamepadMapping {
+    fn default() -> Self {
+        use egui::{Key, Modifiers};
+        InspectorGamepadMapping {
+            bindings: vec![
+                (GamepadButton::DPadDown, Key::Tab, Modifiers::NONE),
+                (GamepadButton::DPadUp, Key::Tab, Modifiers::SHIFT),
+                (GamepadButton::DPadLeft, Key::ArrowLeft, Modifiers::NONE),
+                (GamepadButton::DPadRight, Key::ArrowRight, Modifiers::NONE),
+                (GamepadButton::South, Key::Space, Modifiers::NONE),
+                (GamepadButton::East, Key::Escape, Modifiers::NONE),
+                (GamepadButton::RightTrigger2, Key::ArrowUp, Modifiers::NONE),
+                (GamepadButton::LeftTrigger2, Key::ArrowDown, Modifiers::NONE),
+            ],
+        }
+    }
+}
+
+fn send_gamepad_keys(
+    mapping: Res<InspectorGamepadMapping>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    context: Query<Entity, (With<PrimaryWindow>, With<EguiContext>)>,
+    time: Res<Time<Real>>,
+    mut next_repeat: Local<HashMap<(Entity, GamepadButton), Duration>>,
+    mut events: EventWriter<EguiInputEvent>,
+) {
+    let Ok(context) = context.get_single() else {
+        return;
+    };
+    let now = time.elapsed();
+
+    for (gamepad_entity, gamepad) in &gamepads {
+        for &(button, key, modifiers) in &mapping.bindings {
+            let held = (gamepad_entity, button);
+            let (pressed, repeat) = if gamepad.just_pressed(button) {
+                next_repeat.insert(held, now + REPEAT_DELAY);
+                (true, false)
+            } else if gamepad.just_released(button) {
+                next_repeat.remove(&held);
+                (false, false)
+            } else {
+                match next_repeat.get_mut(&held) {
+                    Some(next) if *next <= now && gamepad.pressed(button) => {
+                        *next = now + REPEAT_INTERVAL;
+                        (true, true)
+                    }
+                    _ => continue,
+                }
+            };
+
+            let event = egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat,
+                modifiers,
+            };
+            events.send(EguiInputEvent { context, event });
+        }
+    }
+}
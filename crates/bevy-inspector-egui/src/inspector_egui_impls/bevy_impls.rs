@@ -2,13 +2,22 @@ use bevy_color::{Color, Hsla, Hsva, Lcha, LinearRgba, Srgba};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::world::CommandQueue;
 use bevy_ecs::world::World;
+use bevy_hierarchy::Parent;
+use bevy_math::{EulerRot, Quat, Vec3};
+use bevy_reflect::PartialReflect;
+use bevy_transform::components::{GlobalTransform, Transform};
 use egui::Color32;
-use std::any::Any;
+use std::any::{Any, TypeId};
 
+#[cfg(any(feature = "bevy_render", feature = "bevy_animation"))]
+use bevy_asset::{Assets, Handle};
 #[cfg(feature = "bevy_render")]
-use ::{
-    bevy_asset::Assets, bevy_asset::Handle, bevy_render::mesh::Mesh,
-    bevy_render::view::RenderLayers,
+use ::{bevy_render::mesh::Mesh, bevy_render::view::RenderLayers};
+
+#[cfg(feature = "bevy_animation")]
+use bevy_animation::{
+    graph::{AnimationGraph, AnimationGraphHandle, AnimationNodeIndex, AnimationNodeType},
+    AnimationClip, AnimationPlayer, RepeatAnimation,
 };
 
 #[cfg(feature = "bevy_render")]
@@ -16,19 +25,391 @@ use crate::bevy_inspector::errors::{dead_asset_handle, show_error};
 use crate::{
     bevy_inspector::errors::no_world_in_context,
     egui_utils,
-    inspector_options::std_options::{EntityDisplay, EntityOptions},
-    reflect_inspector::{Context, InspectorUi},
+    inspector_options::std_options::{EntityDisplay, EntityOptions, TransformOptions},
+    reflect_inspector::{Context, InspectorUi, ProjectorReflect},
+    restricted_world_view::Error as RestrictedWorldViewError,
 };
 
-use super::InspectorPrimitive;
+use super::{math_impls::link_axes, multiedit, InspectorPrimitive};
 
-impl InspectorPrimitive for uuid::Uuid {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        ui.label(self.to_string());
+fn snap_translation(translation: &mut Vec3, snap: f32) {
+    if snap > 0.0 {
+        *translation = (*translation / snap).round() * snap;
+    }
+}
+
+fn snap_rotation(rotation: &mut Quat, snap_degrees: f32) {
+    if snap_degrees > 0.0 {
+        let snap = snap_degrees.to_radians();
+        let (x, y, z) = rotation.to_euler(EulerRot::XYZ);
+        let [x, y, z] = [x, y, z].map(|angle| (angle / snap).round() * snap);
+        *rotation = Quat::from_euler(EulerRot::XYZ, x, y, z);
+    }
+}
+
+fn transform_label(ui: &mut egui::Ui, name: &str, options: &TransformOptions) {
+    let response = ui.label(name);
+    if options.show_space {
+        response.on_hover_text("In local space, relative to the parent entity");
+    }
+}
+
+/// Shows the toggle for editing all scale axes together, remembering the state per `id`
+fn uniform_scale_toggle(ui: &mut egui::Ui, id: egui::Id, options: &TransformOptions) -> bool {
+    let linked_id = id.with("uniform_scale");
+    let mut linked = ui.data(|data| data.get_temp(linked_id).unwrap_or(options.uniform_scale));
+    let toggle = ui
+        .toggle_value(&mut linked, "🔗")
+        .on_hover_text("Scale all axes together");
+    if toggle.changed() {
+        ui.data_mut(|data| data.insert_temp(linked_id, linked));
+    }
+    linked
+}
+
+impl InspectorPrimitive for Transform {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<TransformOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            transform_label(ui, "translation", &options);
+            ui.horizontal(|ui| {
+                if env.ui_for_reflect_with_options(
+                    &mut self.translation,
+                    ui,
+                    id.with("translation"),
+                    &(),
+                ) {
+                    if let Some(snap) = options.translation_snap {
+                        snap_translation(&mut self.translation, snap);
+                    }
+                    changed = true;
+                }
+                if options.show_space {
+                    ui.weak("local");
+                }
+            });
+            ui.end_row();
+
+            transform_label(ui, "rotation", &options);
+            if env.ui_for_reflect_with_options(&mut self.rotation, ui, id.with("rotation"), &()) {
+                if let Some(snap) = options.rotation_snap {
+                    snap_rotation(&mut self.rotation, snap);
+                }
+                changed = true;
+            }
+            ui.end_row();
+
+            transform_label(ui, "scale", &options);
+            ui.horizontal(|ui| {
+                let before = self.scale.to_array();
+                if env.ui_for_reflect_with_options(&mut self.scale, ui, id.with("scale"), &()) {
+                    changed = true;
+                    if uniform_scale_toggle(ui, id, &options) {
+                        let mut after = self.scale.to_array();
+                        link_axes(&before, &mut after);
+                        self.scale = Vec3::from_array(after);
+                    }
+                } else {
+                    uniform_scale_toggle(ui, id, &options);
+                }
+            });
+            ui.end_row();
+        });
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<TransformOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            transform_label(ui, "translation", &options);
+            env.ui_for_reflect_readonly(&self.translation, ui);
+            ui.end_row();
+            transform_label(ui, "rotation", &options);
+            env.ui_for_reflect_readonly(&self.rotation, ui);
+            ui.end_row();
+            transform_label(ui, "scale", &options);
+            env.ui_for_reflect_readonly(&self.scale, ui);
+            ui.end_row();
+        });
+    }
+}
+
+fn as_transform(value: &mut dyn PartialReflect) -> &mut Transform {
+    value.try_downcast_mut::<Transform>().unwrap()
+}
+
+/// How one axis of multiple values was edited by [`axis_ui_many`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AxisChange {
+    Set(f32),
+    Offset(f32),
+}
+
+impl AxisChange {
+    fn apply(self, value: &mut f32) {
+        match self {
+            AxisChange::Set(new) => *value = new,
+            AxisChange::Offset(offset) => *value += offset,
+        }
+    }
+}
+
+/// Edits one axis of multiple values.
+///
+/// If the values are equal, editing sets all of them. Differing values are displayed as "—",
+/// and dragging offsets each of them, like every edit in `relative` mode.
+fn axis_ui_many(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    axis: &[f32],
+    relative: bool,
+    speed: f32,
+) -> Option<AxisChange> {
+    let same = crate::inspector_egui_impls::iter_all_eq(axis.iter().copied());
+    match same.filter(|_| !relative) {
+        Some(mut value) => {
+            let changed = ui
+                .add(egui::DragValue::new(&mut value).speed(speed))
+                .changed();
+            changed.then_some(AxisChange::Set(value))
+        }
+        None => {
+            let old_total = ui.data(|data| data.get_temp::<f32>(id).unwrap_or(0.0));
+            let mut total = old_total;
+            let widget = egui::DragValue::new(&mut total).speed(speed);
+            let widget = match relative {
+                true => widget.prefix("±"),
+                false => multiedit::mixed_drag_value(widget, true),
+            };
+            let response = ui.add(widget);
+            if response.drag_stopped() || response.lost_focus() {
+                ui.data_mut(|data| data.remove::<f32>(id));
+            } else {
+                ui.data_mut(|data| data.insert_temp(id, total));
+            }
+            (response.changed() && total != old_total)
+                .then_some(AxisChange::Offset(total - old_total))
+        }
+    }
+}
+
+/// Displays the axes of a [`Vec3`] of every transform, applying edits only to the edited axis
+fn vec3_ui_many(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    values: &mut [&mut dyn PartialReflect],
+    project: impl Fn(&mut Transform) -> &mut Vec3,
+    relative: bool,
+    speed: f32,
+) -> bool {
+    let mut changed = false;
+    for axis in 0..3 {
+        let current: Vec<f32> = values
+            .iter_mut()
+            .map(|value| project(as_transform(*value))[axis])
+            .collect();
+        if let Some(change) = axis_ui_many(ui, id.with(axis), &current, relative, speed) {
+            for value in values.iter_mut() {
+                change.apply(&mut project(as_transform(*value))[axis]);
+            }
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Multi-edits the transforms axis by axis, with rotations as euler angles in degrees.
+///
+/// Axes with differing values are displayed as "—" and only the edited axis is written,
+/// so the other axes keep their differences. The "±" toggle offsets translations instead of setting them.
+pub fn transform_ui_many(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    let options = options
+        .downcast_ref::<TransformOptions>()
+        .cloned()
+        .unwrap_or_default();
+    let mut transforms: Vec<&mut dyn PartialReflect> =
+        values.iter_mut().map(|value| projector(*value)).collect();
+    let transforms = transforms.as_mut_slice();
+
+    let mut changed = false;
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        transform_label(ui, "translation", &options);
+        ui.horizontal(|ui| {
+            let relative_id = id.with("relative_translation");
+            let mut relative = ui.data(|data| data.get_temp(relative_id).unwrap_or(false));
+            if vec3_ui_many(
+                ui,
+                id.with("translation"),
+                transforms,
+                |transform| &mut transform.translation,
+                relative,
+                0.1,
+            ) {
+                if let Some(snap) = options.translation_snap {
+                    for value in transforms.iter_mut() {
+                        snap_translation(&mut as_transform(*value).translation, snap);
+                    }
+                }
+                changed = true;
+            }
+            if ui
+                .toggle_value(&mut relative, "±")
+                .on_hover_text("Move all translations by the dragged amount")
+                .changed()
+            {
+                ui.data_mut(|data| data.insert_temp(relative_id, relative));
+            }
+        });
+        ui.end_row();
+
+        transform_label(ui, "rotation", &options);
+        ui.horizontal(|ui| {
+            let eulers: Vec<[f32; 3]> = transforms
+                .iter_mut()
+                .map(|value| {
+                    let (x, y, z) = as_transform(*value).rotation.to_euler(EulerRot::XYZ);
+                    [x, y, z].map(f32::to_degrees)
+                })
+                .collect();
+            for axis in 0..3 {
+                let current: Vec<f32> = eulers.iter().map(|euler| euler[axis]).collect();
+                let Some(change) =
+                    axis_ui_many(ui, id.with(("rotation", axis)), &current, false, 1.0)
+                else {
+                    continue;
+                };
+                for (value, euler) in transforms.iter_mut().zip(&eulers) {
+                    let mut euler = *euler;
+                    change.apply(&mut euler[axis]);
+                    let [x, y, z] = euler.map(f32::to_radians);
+                    let rotation = &mut as_transform(*value).rotation;
+                    *rotation = Quat::from_euler(EulerRot::XYZ, x, y, z);
+                    if let Some(snap) = options.rotation_snap {
+                        snap_rotation(rotation, snap);
+                    }
+                }
+                changed = true;
+            }
+        });
+        ui.end_row();
+
+        transform_label(ui, "scale", &options);
+        ui.horizontal(|ui| {
+            changed |= vec3_ui_many(
+                ui,
+                id.with("scale"),
+                transforms,
+                |transform| &mut transform.scale,
+                false,
+                0.1,
+            );
+        });
+        ui.end_row();
+    });
+    changed
+}
+
+/// The entity currently being inspected, if it has no [`Parent`] so that its [`GlobalTransform`] equals its [`Transform`].
+/// Returns [`None`] if that is not known, e.g. because the value isn't displayed as part of an entity.
+fn inspected_root_entity(env: &mut InspectorUi<'_, '_>) -> Option<Entity> {
+    let entity = env.watch.as_ref()?.entity()?;
+    let world = env.context.world.as_mut()?;
+    match world.get_entity_component_reflect(entity, TypeId::of::<Parent>(), env.type_registry) {
+        Err(
+            RestrictedWorldViewError::ComponentDoesNotExist(_)
+            | RestrictedWorldViewError::NoComponentId(_),
+        ) => Some(entity),
+        _ => None,
+    }
+}
+
+fn global_transform_ui(
+    value: &GlobalTransform,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) {
+    let (scale, rotation, translation) = value.to_scale_rotation_translation();
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        ui.label("translation");
+        env.ui_for_reflect_readonly_with_options(&translation, ui, id.with("translation"), &());
+        ui.end_row();
+        ui.label("rotation");
+        env.ui_for_reflect_readonly_with_options(&rotation, ui, id.with("rotation"), &());
+        ui.end_row();
+        ui.label("scale");
+        env.ui_for_reflect_readonly_with_options(&scale, ui, id.with("scale"), &());
+        ui.end_row();
+    });
+}
+
+/// [`GlobalTransform`] is computed from the [`Transform`] hierarchy, so it is always displayed read-only.
+impl InspectorPrimitive for GlobalTransform {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        global_transform_ui(self, ui, id, &mut env);
+
+        let Some(entity) = inspected_root_entity(&mut env) else {
+            return false;
+        };
+        let Some(queue) = env.context.queue.as_mut() else {
+            return false;
+        };
+        let copy = ui
+            .button("Copy to Transform")
+            .on_hover_text("Set the Transform of this entity to the decomposed GlobalTransform");
+        if copy.clicked() {
+            let transform = self.compute_transform();
+            queue.push(move |world: &mut World| {
+                if let Some(mut value) = world.get_mut::<Transform>(entity) {
+                    *value = transform;
+                }
+            });
+        }
         false
     }
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        ui.label(self.to_string());
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        global_transform_ui(self, ui, id, &mut env);
     }
 }
 
@@ -77,7 +458,11 @@ impl InspectorPrimitive for Entity {
                         );
                         if options.despawnable && world.contains_entity(entity) {
                             if let Some(queue) = queue {
-                                if egui_utils::label_button(ui, "✖ Despawn", egui::Color32::RED) {
+                                if egui_utils::label_button(
+                                    ui,
+                                    "✖ Despawn",
+                                    ui.visuals().error_fg_color,
+                                ) {
                                     queue.push(move |world: &mut World| {
                                         world.entity_mut(entity).despawn();
                                     });
@@ -297,6 +682,22 @@ impl InspectorPrimitive for Color {
     }
 }
 
+/// Multi-edits colors with a single color picker, starting from the first color if they differ.
+///
+/// Every color is only overwritten once the picker is edited.
+pub fn color_ui_many(
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> bool {
+    multiedit::show_mixed(ui, values, projector, |ui, color: &mut Color, _| {
+        color.ui(ui, options, id, env)
+    })
+}
+
 #[cfg(feature = "bevy_render")]
 impl InspectorPrimitive for RenderLayers {
     fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
@@ -336,3 +737,271 @@ impl InspectorPrimitive for RenderLayers {
         }
     }
 }
+
+/// A node of the [`AnimationGraph`] of an [`AnimationPlayer`]
+#[cfg(feature = "bevy_animation")]
+struct AnimationNodeRow {
+    index: AnimationNodeIndex,
+    label: String,
+    /// The duration of clip nodes
+    duration: Option<f32>,
+    weight: Option<f32>,
+}
+
+/// The nodes of the graph next to the inspected animation player, or the animations it plays if it has no graph
+#[cfg(feature = "bevy_animation")]
+fn animation_node_rows(
+    player: &AnimationPlayer,
+    env: &mut InspectorUi<'_, '_>,
+) -> (Option<Handle<AnimationGraph>>, Vec<AnimationNodeRow>) {
+    let playing_rows = || {
+        let mut rows: Vec<_> = player
+            .playing_animations()
+            .map(|(&index, _)| AnimationNodeRow {
+                index,
+                label: format!("Node {}", index.index()),
+                duration: Some(0.0),
+                weight: None,
+            })
+            .collect();
+        rows.sort_by_key(|row| row.index);
+        rows
+    };
+
+    let Some(entity) = env.watch.as_ref().and_then(|watch| watch.entity()) else {
+        return (None, playing_rows());
+    };
+    let Some(world) = env.context.world.as_mut() else {
+        return (None, playing_rows());
+    };
+    let graph_handle = world
+        .get_entity_component_reflect(
+            entity,
+            TypeId::of::<AnimationGraphHandle>(),
+            env.type_registry,
+        )
+        .ok()
+        .and_then(|value| {
+            value
+                .downcast_ref::<AnimationGraphHandle>()
+                .map(|handle| handle.0.clone())
+        });
+    let Some(graph_handle) = graph_handle else {
+        return (None, playing_rows());
+    };
+
+    let nodes: Vec<_> = match world.get_resource_mut::<Assets<AnimationGraph>>() {
+        Ok(graphs) => match graphs.get(&graph_handle) {
+            Some(graph) => graph
+                .nodes()
+                .map(|index| (index, graph[index].clone(), index == graph.root))
+                .collect(),
+            None => return (None, playing_rows()),
+        },
+        Err(_) => return (None, playing_rows()),
+    };
+
+    let (asset_server, clips) =
+        world.get_two_resources_mut::<bevy_asset::AssetServer, Assets<AnimationClip>>();
+    let (asset_server, clips) = (asset_server.ok(), clips.ok());
+    let rows = nodes
+        .into_iter()
+        .map(|(index, node, is_root)| {
+            let (label, duration) = match &node.node_type {
+                AnimationNodeType::Clip(clip) => {
+                    let label = asset_server
+                        .as_ref()
+                        .and_then(|asset_server| asset_server.get_path(clip))
+                        .map_or_else(
+                            || format!("Clip {}", index.index()),
+                            |path| path.label().map_or(path.to_string(), str::to_owned),
+                        );
+                    let duration = clips
+                        .as_ref()
+                        .and_then(|clips| clips.get(clip))
+                        .map_or(0.0, AnimationClip::duration);
+                    (label, Some(duration))
+                }
+                AnimationNodeType::Blend => ("Blend".to_owned(), None),
+                AnimationNodeType::Add => ("Add".to_owned(), None),
+            };
+            AnimationNodeRow {
+                index,
+                label: match is_root {
+                    true => format!("{label} (root)"),
+                    false => label,
+                },
+                duration,
+                weight: Some(node.weight),
+            }
+        })
+        .collect();
+    (Some(graph_handle), rows)
+}
+
+/// Controls for the clips of the [`AnimationGraph`] of the entity, with play/pause, a timeline to seek, speed and
+/// the weights of the graph nodes.
+#[cfg(feature = "bevy_animation")]
+impl InspectorPrimitive for AnimationPlayer {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let (graph, rows) = animation_node_rows(self, &mut env);
+
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let all_paused = self.all_paused();
+            let any_playing = self.playing_animations().next().is_some();
+            ui.add_enabled_ui(any_playing, |ui| {
+                let (text, hover) = match all_paused {
+                    true => ("▶", "Resume all"),
+                    false => ("⏸", "Pause all"),
+                };
+                if ui.button(text).on_hover_text(hover).clicked() {
+                    match all_paused {
+                        true => self.resume_all(),
+                        false => self.pause_all(),
+                    };
+                    changed = true;
+                }
+                if ui.button("⏮").on_hover_text("Rewind all").clicked() {
+                    self.rewind_all();
+                    changed = true;
+                }
+                if ui.button("⏹").on_hover_text("Stop all").clicked() {
+                    self.stop_all();
+                    changed = true;
+                }
+            });
+            if graph.is_none() {
+                ui.weak("No `AnimationGraphHandle` on this entity");
+            }
+        });
+
+        if rows.is_empty() {
+            return changed;
+        }
+
+        let mut new_weights = Vec::new();
+        egui::Grid::new(id).num_columns(4).show(ui, |ui| {
+            for row in &rows {
+                ui.label(&row.label);
+
+                match row.weight {
+                    Some(mut weight) => {
+                        if ui
+                            .add(egui::Slider::new(&mut weight, 0.0..=1.0))
+                            .on_hover_text("Weight")
+                            .changed()
+                        {
+                            new_weights.push((row.index, weight));
+                        }
+                    }
+                    None => {
+                        ui.label("");
+                    }
+                }
+
+                let Some(duration) = row.duration else {
+                    ui.end_row();
+                    continue;
+                };
+                ui.horizontal(|ui| match self.animation_mut(row.index) {
+                    None => {
+                        if ui.button("▶").on_hover_text("Play").clicked() {
+                            self.play(row.index);
+                            changed = true;
+                        }
+                    }
+                    Some(active) => {
+                        let paused = active.is_paused();
+                        if ui
+                            .button(if paused { "▶" } else { "⏸" })
+                            .on_hover_text(if paused { "Resume" } else { "Pause" })
+                            .clicked()
+                        {
+                            match paused {
+                                true => active.resume(),
+                                false => active.pause(),
+                            };
+                            changed = true;
+                        }
+                        let mut repeat = active.repeat_mode() == RepeatAnimation::Forever;
+                        if ui
+                            .toggle_value(&mut repeat, "🔁")
+                            .on_hover_text("Repeat")
+                            .changed()
+                        {
+                            active.set_repeat(match repeat {
+                                true => RepeatAnimation::Forever,
+                                false => RepeatAnimation::Never,
+                            });
+                            changed = true;
+                        }
+                        if ui.button("⏹").on_hover_text("Stop").clicked() {
+                            self.stop(row.index);
+                            changed = true;
+                        }
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    let Some(active) = self.animation_mut(row.index) else {
+                        return;
+                    };
+                    let mut seek_time = active.seek_time();
+                    let max = duration.max(seek_time);
+                    if ui
+                        .add(egui::Slider::new(&mut seek_time, 0.0..=max).suffix("s"))
+                        .on_hover_text("Seek")
+                        .changed()
+                    {
+                        active.seek_to(seek_time);
+                        changed = true;
+                    }
+                    let mut speed = active.speed();
+                    if ui
+                        .add(egui::DragValue::new(&mut speed).speed(0.01).suffix("×"))
+                        .on_hover_text("Speed")
+                        .changed()
+                    {
+                        active.set_speed(speed);
+                        changed = true;
+                    }
+                });
+                ui.end_row();
+            }
+        });
+
+        if let (Some(graph), false) = (graph, new_weights.is_empty()) {
+            if let Some(world) = env.context.world.as_mut() {
+                if let Ok(mut graphs) = world.get_resource_mut::<Assets<AnimationGraph>>() {
+                    if let Some(graph) = graphs.get_mut(&graph) {
+                        for (index, weight) in new_weights {
+                            if let Some(node) = graph.get_mut(index) {
+                                node.weight = weight;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, options, id, env));
+    }
+}
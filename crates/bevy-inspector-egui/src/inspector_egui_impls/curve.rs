@@ -0,0 +1,145 @@
+//! Curve editor for control points, see [`CurveOptions`].
+
+use bevy_math::Vec2;
+use egui::{emath::RectTransform, pos2, Pos2, Rect, Sense, Shape, Stroke};
+
+use crate::inspector_options::std_options::CurveOptions;
+
+const POINT_RADIUS: f32 = 4.0;
+
+pub fn curve_ui(
+    points: &mut Vec<Vec2>,
+    options: &CurveOptions,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    curve(points, options, ui, id, true)
+}
+
+pub fn curve_ui_readonly(points: &[Vec2], options: &CurveOptions, ui: &mut egui::Ui, id: egui::Id) {
+    curve(&mut points.to_vec(), options, ui, id, false);
+}
+
+fn curve(
+    points: &mut Vec<Vec2>,
+    options: &CurveOptions,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    editable: bool,
+) -> bool {
+    let sense = match editable {
+        true => Sense::click(),
+        false => Sense::hover(),
+    };
+    let (response, painter) =
+        ui.allocate_painter(egui::vec2(ui.available_width(), options.height), sense);
+
+    let bounds = data_bounds(points, options);
+    // flip the y axis, so that up is positive
+    let to_screen = RectTransform::from_to(
+        Rect::from_min_max(
+            pos2(bounds.min.x, bounds.max.y),
+            pos2(bounds.max.x, bounds.min.y),
+        ),
+        response.rect.shrink(POINT_RADIUS),
+    );
+    let from_screen = to_screen.inverse();
+    let clamp = |point: Vec2| match (options.min, options.max) {
+        (Some(min), Some(max)) => point.clamp(min, max),
+        _ => point,
+    };
+
+    let visuals = ui.visuals();
+    painter.rect_stroke(response.rect, 2.0, visuals.widgets.noninteractive.bg_stroke);
+    let line = points
+        .iter()
+        .map(|point| to_screen * pos2(point.x, point.y))
+        .collect();
+    painter.add(Shape::line(
+        line,
+        Stroke::new(1.5, visuals.widgets.inactive.fg_stroke.color),
+    ));
+
+    let mut changed = false;
+    let mut remove = None;
+    for i in 0..points.len() {
+        let center = to_screen * pos2(points[i].x, points[i].y);
+        if !editable {
+            painter.circle_filled(
+                center,
+                POINT_RADIUS,
+                visuals.widgets.inactive.fg_stroke.color,
+            );
+            continue;
+        }
+
+        let point_rect = Rect::from_center_size(center, egui::Vec2::splat(POINT_RADIUS * 3.0));
+        let point_response = ui.interact(point_rect, id.with(i), Sense::click_and_drag());
+
+        if point_response.dragged() {
+            let pos = from_screen * (center + point_response.drag_delta());
+            let mut new = clamp(Vec2::new(pos.x, pos.y));
+            if options.sorted {
+                let min_x = i.checked_sub(1).map_or(f32::NEG_INFINITY, |j| points[j].x);
+                let max_x = points.get(i + 1).map_or(f32::INFINITY, |point| point.x);
+                // not `clamp`, which panics if the points are unsorted or NaN
+                new.x = new.x.max(min_x).min(max_x);
+            }
+            points[i] = new;
+            changed = true;
+        }
+        if point_response.secondary_clicked() {
+            remove = Some(i);
+        }
+
+        let style = ui.style().interact(&point_response);
+        painter.circle(center, POINT_RADIUS, style.bg_fill, style.fg_stroke);
+        point_response.on_hover_text(format!("({:.3}, {:.3})", points[i].x, points[i].y));
+    }
+
+    if let Some(i) = remove {
+        points.remove(i);
+        changed = true;
+    }
+
+    let response = match editable {
+        true => response.on_hover_text(
+            "Drag points to move them, double click to add and right click to remove them",
+        ),
+        false => response,
+    };
+    if response.double_clicked() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let pos: Pos2 = from_screen * pos;
+            let point = clamp(Vec2::new(pos.x, pos.y));
+            let index = match options.sorted {
+                true => points.partition_point(|other| other.x < point.x),
+                false => points.len(),
+            };
+            points.insert(index, point);
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+/// The displayed area, from the options or fitted around the points
+fn data_bounds(points: &[Vec2], options: &CurveOptions) -> bevy_math::Rect {
+    let fitted = points
+        .iter()
+        .fold(None, |rect: Option<bevy_math::Rect>, &point| {
+            Some(
+                rect.map_or(bevy_math::Rect::from_corners(point, point), |rect| {
+                    rect.union_point(point)
+                }),
+            )
+        })
+        .unwrap_or(bevy_math::Rect::new(0.0, 0.0, 1.0, 1.0));
+    let margin = (fitted.size() * 0.1).max(Vec2::splat(0.1));
+
+    bevy_math::Rect::from_corners(
+        options.min.unwrap_or(fitted.min - margin),
+        options.max.unwrap_or(fitted.max + margin),
+    )
+}
@@ -0,0 +1,150 @@
+//! UI for file paths, shared by [`PathBuf`](std::path::PathBuf) and strings with [`StringOptions::file_picker`](crate::inspector_options::std_options::StringOptions::file_picker).
+
+use std::path::{Component, Path};
+
+use bevy_asset::AssetPath;
+
+use crate::inspector_options::std_options::FilePickerOptions;
+
+/// The folder bevy's `AssetPlugin` loads assets from by default
+const ASSETS_FOLDER: &str = "assets";
+
+pub fn file_path_ui(path: &mut String, options: &FilePickerOptions, ui: &mut egui::Ui) -> bool {
+    let mut changed = false;
+    ui.vertical(|ui| {
+        let response = ui.horizontal(|ui| {
+            let response = ui.text_edit_singleline(path);
+            changed |= response.changed();
+
+            #[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+            if ui.button("…").on_hover_text("Pick file").clicked() {
+                if let Some(picked) = pick_file(path, options) {
+                    *path = picked;
+                    changed = true;
+                }
+            }
+            response
+        });
+        validation_ui(path, options, ui, response.inner.id);
+    });
+    changed
+}
+
+pub fn file_path_ui_readonly(path: &str, options: &FilePickerOptions, ui: &mut egui::Ui) {
+    ui.vertical(|ui| {
+        let response = ui.text_edit_singleline(&mut &*path);
+        validation_ui(path, options, ui, response.id);
+    });
+}
+
+/// Displays the problem with `path`, which is only validated again after it changed since checking it touches the file system
+fn validation_ui(path: &str, options: &FilePickerOptions, ui: &mut egui::Ui, id: egui::Id) {
+    let id = id.with("validation");
+    let cached = ui.data(|data| data.get_temp::<(String, Option<String>)>(id));
+    let problem = match cached {
+        Some((validated, problem)) if validated == path => problem,
+        _ => {
+            let problem = validate(path, options);
+            ui.data_mut(|data| data.insert_temp(id, (path.to_owned(), problem.clone())));
+            problem
+        }
+    };
+    if let Some(problem) = problem {
+        ui.colored_label(ui.visuals().warn_fg_color, problem);
+    }
+}
+
+/// Returns a description of what's wrong with the path, if anything
+fn validate(path: &str, options: &FilePickerOptions) -> Option<String> {
+    if path.is_empty() {
+        return None;
+    }
+
+    let asset_path = match options.relative_to_assets {
+        true => match AssetPath::try_parse(path) {
+            Ok(asset_path) => Some(asset_path),
+            Err(e) => return Some(format!("invalid asset path: {e}")),
+        },
+        false => None,
+    };
+    let file_path = asset_path
+        .as_ref()
+        .map_or(Path::new(path), |asset_path| asset_path.path());
+
+    let extensions: Vec<&str> = options.extensions().collect();
+    if !extensions.is_empty() {
+        let extension = file_path.extension().and_then(|ext| ext.to_str());
+        let allowed = extension.is_some_and(|extension| {
+            extensions
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+        });
+        if !allowed {
+            return Some(format!("expected a file of type {}", extensions.join(", ")));
+        }
+    }
+
+    if let Some(asset_path) = &asset_path {
+        if file_path.has_root() || file_path.is_absolute() {
+            return Some("path should be relative to the assets folder".into());
+        }
+        if file_path
+            .components()
+            .any(|component| component == Component::ParentDir)
+        {
+            return Some("path should not leave the assets folder".into());
+        }
+
+        // other asset sources (e.g. `embedded://`) can't be checked on the file system
+        #[cfg(not(target_arch = "wasm32"))]
+        if matches!(asset_path.source(), bevy_asset::io::AssetSourceId::Default)
+            && !assets_root().join(file_path).exists()
+        {
+            return Some(format!("file does not exist in {ASSETS_FOLDER}/"));
+        }
+    }
+
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn assets_root() -> std::path::PathBuf {
+    bevy_asset::io::file::FileAssetReader::get_base_path().join(ASSETS_FOLDER)
+}
+
+#[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+fn pick_file(current: &str, options: &FilePickerOptions) -> Option<String> {
+    let mut dialog = rfd::FileDialog::new();
+
+    let extensions: Vec<&str> = options.extensions().collect();
+    if !extensions.is_empty() {
+        dialog = dialog.add_filter(extensions.join(", "), &extensions);
+    }
+
+    let root = assets_root();
+    let root = root.canonicalize().unwrap_or(root);
+    let current = match options.relative_to_assets {
+        true => root.join(current),
+        false => std::path::PathBuf::from(current),
+    };
+    match current.parent().filter(|dir| dir.is_dir()) {
+        Some(dir) => dialog = dialog.set_directory(dir),
+        None if options.relative_to_assets => dialog = dialog.set_directory(&root),
+        None => {}
+    }
+
+    let picked = dialog.pick_file()?;
+
+    if options.relative_to_assets {
+        if let Ok(relative) = picked.strip_prefix(&root) {
+            // asset paths always use forward slashes
+            let components: Vec<_> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect();
+            return Some(components.join("/"));
+        }
+    }
+
+    Some(picked.to_string_lossy().into_owned())
+}
@@ -175,7 +175,6 @@ vec_ui_many!(dvec4_ui_many DVec4>f64: 4 x y z w);
 mat_ui!(mat2_ui mat2_ui_readonly Mat2: x_axis y_axis);
 mat_ui!(mat3_ui mat3_ui_readonly Mat3: x_axis y_axis z_axis);
 mat_ui!(mat3a_ui mat3a_ui_readonly Mat3A: x_axis y_axis z_axis);
-mat_ui!(mat4_ui mat4_ui_readonly Mat4: x_axis y_axis z_axis w_axis);
 mat_ui!(dmat2_ui dmat2_ui_readonly DMat2: x_axis y_axis);
 mat_ui!(dmat3_ui dmat3_ui_readonly DMat3: x_axis y_axis z_axis);
 mat_ui!(dmat4_ui dmat4_ui_readonly DMat4: x_axis y_axis z_axis w_axis);
@@ -353,3 +352,350 @@ pub mod quat {
 
     many_ui!(quat_ui_many quat_ui Quat);
 }
+
+/// [`Mat4`] and [`Affine3A`], which can be edited as raw columns or decomposed into translation, rotation and scale.
+pub mod affine {
+    use std::any::Any;
+
+    use bevy_math::{prelude::*, Affine3A, Vec4};
+    use bevy_reflect::PartialReflect;
+
+    use crate::{
+        inspector_options::std_options::{MatDisplay, MatOptions},
+        reflect_inspector::{InspectorUi, ProjectorReflect},
+    };
+
+    #[derive(Clone, Copy, PartialEq)]
+    struct Decomposed {
+        translation: Vec3,
+        rotation: Quat,
+        scale: Vec3,
+    }
+
+    trait Decompose: Copy + 'static {
+        /// Whether the value is an affine transform, so that recomposing it doesn't lose information
+        fn is_affine(&self) -> bool;
+        fn decompose(&self) -> Decomposed;
+        fn recompose(decomposed: Decomposed) -> Self;
+        fn raw_ui(&mut self, ui: &mut egui::Ui, env: &mut InspectorUi<'_, '_>) -> bool;
+    }
+
+    impl Decompose for Mat4 {
+        fn is_affine(&self) -> bool {
+            self.row(3) == Vec4::W
+        }
+
+        fn decompose(&self) -> Decomposed {
+            let (scale, rotation, translation) = self.to_scale_rotation_translation();
+            Decomposed {
+                translation,
+                rotation,
+                scale,
+            }
+        }
+
+        fn recompose(decomposed: Decomposed) -> Self {
+            Mat4::from_scale_rotation_translation(
+                decomposed.scale,
+                decomposed.rotation,
+                decomposed.translation,
+            )
+        }
+
+        fn raw_ui(&mut self, ui: &mut egui::Ui, env: &mut InspectorUi<'_, '_>) -> bool {
+            let mut changed = false;
+            ui.vertical(|ui| {
+                changed |= env.ui_for_reflect(&mut self.x_axis, ui);
+                changed |= env.ui_for_reflect(&mut self.y_axis, ui);
+                changed |= env.ui_for_reflect(&mut self.z_axis, ui);
+                changed |= env.ui_for_reflect(&mut self.w_axis, ui);
+            });
+            changed
+        }
+    }
+
+    impl Decompose for Affine3A {
+        fn is_affine(&self) -> bool {
+            true
+        }
+
+        fn decompose(&self) -> Decomposed {
+            let (scale, rotation, translation) = self.to_scale_rotation_translation();
+            Decomposed {
+                translation,
+                rotation,
+                scale,
+            }
+        }
+
+        fn recompose(decomposed: Decomposed) -> Self {
+            Affine3A::from_scale_rotation_translation(
+                decomposed.scale,
+                decomposed.rotation,
+                decomposed.translation,
+            )
+        }
+
+        fn raw_ui(&mut self, ui: &mut egui::Ui, env: &mut InspectorUi<'_, '_>) -> bool {
+            let mut changed = false;
+            egui::Grid::new("affine3a raw").show(ui, |ui| {
+                ui.label("matrix3");
+                ui.vertical(|ui| {
+                    changed |= env.ui_for_reflect(&mut self.matrix3.x_axis, ui);
+                    changed |= env.ui_for_reflect(&mut self.matrix3.y_axis, ui);
+                    changed |= env.ui_for_reflect(&mut self.matrix3.z_axis, ui);
+                });
+                ui.end_row();
+                ui.label("translation");
+                changed |= env.ui_for_reflect(&mut self.translation, ui);
+                ui.end_row();
+            });
+            changed
+        }
+    }
+
+    /// Which parts of the decomposition differ between the values of a multi-edit
+    #[derive(Default)]
+    struct Mixed {
+        translation: bool,
+        rotation: bool,
+        scale: bool,
+    }
+
+    /// Edits `decomposed`, returning which of its parts changed
+    fn decomposed_ui(
+        decomposed: &mut Decomposed,
+        mixed: &Mixed,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        env: &mut InspectorUi<'_, '_>,
+    ) -> (bool, bool, bool) {
+        let label = |ui: &mut egui::Ui, name: &str, mixed: bool| {
+            if mixed {
+                ui.label(format!("{name} (mixed)"))
+                    .on_hover_text("This differs between the selected values");
+            } else {
+                ui.label(name);
+            }
+        };
+
+        let mut changed = (false, false, false);
+        egui::Grid::new(id.with("decomposed")).show(ui, |ui| {
+            label(ui, "translation", mixed.translation);
+            changed.0 = env.ui_for_reflect_with_options(
+                &mut decomposed.translation,
+                ui,
+                id.with("translation"),
+                &(),
+            );
+            ui.end_row();
+
+            label(ui, "rotation", mixed.rotation);
+            changed.1 = env.ui_for_reflect_with_options(
+                &mut decomposed.rotation,
+                ui,
+                id.with("rotation"),
+                &(),
+            );
+            ui.end_row();
+
+            label(ui, "scale", mixed.scale);
+            changed.2 =
+                env.ui_for_reflect_with_options(&mut decomposed.scale, ui, id.with("scale"), &());
+            ui.end_row();
+        });
+        changed
+    }
+
+    /// Shows the toggle between raw and decomposed display, remembering the choice per `id`.
+    fn display_toggle(
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        affine: bool,
+    ) -> MatDisplay {
+        let default = options
+            .downcast_ref::<MatOptions>()
+            .map(|options| options.display)
+            .unwrap_or_default();
+        let display_id = id.with("mat_display");
+        let mut display = ui.data(|data| data.get_temp(display_id).unwrap_or(default));
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut display, MatDisplay::Raw, "Raw");
+            ui.add_enabled_ui(affine, |ui| {
+                ui.selectable_value(&mut display, MatDisplay::Decomposed, "TRS")
+                    .on_hover_text("Translation, rotation and scale")
+                    .on_disabled_hover_text("Only affine matrices can be decomposed");
+            });
+        });
+        ui.data_mut(|data| data.insert_temp(display_id, display));
+
+        match affine {
+            true => display,
+            false => MatDisplay::Raw,
+        }
+    }
+
+    fn affine_display_ui<T: Decompose>(
+        value: &mut T,
+        display: MatDisplay,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        env: &mut InspectorUi<'_, '_>,
+    ) -> bool {
+        match display {
+            MatDisplay::Raw => value.raw_ui(ui, env),
+            MatDisplay::Decomposed => {
+                let mut decomposed = value.decompose();
+                let (t, r, s) = decomposed_ui(&mut decomposed, &Mixed::default(), ui, id, env);
+                let changed = t || r || s;
+                if changed {
+                    *value = T::recompose(decomposed);
+                }
+                changed
+            }
+        }
+    }
+
+    fn affine_ui<T: Decompose>(
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let value = value.downcast_mut::<T>().unwrap();
+
+        ui.vertical(|ui| {
+            let display = display_toggle(ui, id, options, value.is_affine());
+            affine_display_ui(value, display, ui, id, &mut env)
+        })
+        .inner
+    }
+
+    fn affine_ui_readonly<T: Decompose>(
+        value: &dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) {
+        let mut value = *value.downcast_ref::<T>().unwrap();
+
+        ui.vertical(|ui| {
+            let display = display_toggle(ui, id, options, value.is_affine());
+            ui.add_enabled_ui(false, |ui| {
+                affine_display_ui(&mut value, display, ui, id, &mut env);
+            });
+        });
+    }
+
+    /// Multi-edits the decomposition, so that e.g. setting the translation keeps the individual rotations and scales.
+    fn affine_ui_many<T: Decompose>(
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+        values: &mut [&mut dyn PartialReflect],
+        projector: &dyn ProjectorReflect,
+    ) -> bool {
+        let decomposed: Vec<Decomposed> = values
+            .iter_mut()
+            .map(|value| {
+                projector(*value)
+                    .try_downcast_ref::<T>()
+                    .unwrap()
+                    .decompose()
+            })
+            .collect();
+        let Some(&first) = decomposed.first() else {
+            return false;
+        };
+        let mixed = Mixed {
+            translation: decomposed
+                .iter()
+                .any(|d| d.translation != first.translation),
+            rotation: decomposed.iter().any(|d| d.rotation != first.rotation),
+            scale: decomposed.iter().any(|d| d.scale != first.scale),
+        };
+
+        let mut edited = first;
+        let (t, r, s) = decomposed_ui(&mut edited, &mixed, ui, id, &mut env);
+        if !(t || r || s) {
+            return false;
+        }
+
+        for (value, mut decomposed) in values.iter_mut().zip(decomposed) {
+            if t {
+                decomposed.translation = edited.translation;
+            }
+            if r {
+                decomposed.rotation = edited.rotation;
+            }
+            if s {
+                decomposed.scale = edited.scale;
+            }
+            *projector(*value).try_downcast_mut::<T>().unwrap() = T::recompose(decomposed);
+        }
+        true
+    }
+
+    pub fn mat4_ui(
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        affine_ui::<Mat4>(value, ui, options, id, env)
+    }
+    pub fn mat4_ui_readonly(
+        value: &dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        affine_ui_readonly::<Mat4>(value, ui, options, id, env)
+    }
+    pub fn mat4_ui_many(
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+        values: &mut [&mut dyn PartialReflect],
+        projector: &dyn ProjectorReflect,
+    ) -> bool {
+        affine_ui_many::<Mat4>(ui, options, id, env, values, projector)
+    }
+
+    pub fn affine3a_ui(
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        affine_ui::<Affine3A>(value, ui, options, id, env)
+    }
+    pub fn affine3a_ui_readonly(
+        value: &dyn Any,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        affine_ui_readonly::<Affine3A>(value, ui, options, id, env)
+    }
+    pub fn affine3a_ui_many(
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+        values: &mut [&mut dyn PartialReflect],
+        projector: &dyn ProjectorReflect,
+    ) -> bool {
+        affine_ui_many::<Affine3A>(ui, options, id, env, values, projector)
+    }
+}
@@ -0,0 +1,250 @@
+//! Gradient editor for color stops, see [`ColorGradient`] and [`GradientOptions`].
+
+use std::any::Any;
+
+use bevy_color::{Color, ColorToPacked, LinearRgba, Mix, Srgba};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use egui::{epaint::Mesh, pos2, Color32, Rect, Sense, Shape, Stroke};
+
+use super::InspectorPrimitive;
+use crate::{inspector_options::std_options::GradientOptions, reflect_inspector::InspectorUi};
+
+const HANDLE_SIZE: f32 = 8.0;
+
+/// A color ramp defined by color stops at positions between `0.0` and `1.0`.
+///
+/// It is displayed using a gradient editor, where stops can be dragged to move them,
+/// double clicking adds a stop and right clicking removes it.
+/// The same editor is used for `Vec<(f32, Color)>` fields with [`GradientOptions`], e.g. from `#[inspector(gradient(..))]`.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq, Default)]
+pub struct ColorGradient {
+    /// Positions and colors of the stops, sorted by position
+    pub stops: Vec<(f32, Color)>,
+}
+
+impl Default for ColorGradient {
+    fn default() -> Self {
+        ColorGradient::new([(0.0, Color::BLACK), (1.0, Color::WHITE)])
+    }
+}
+
+impl ColorGradient {
+    /// Creates a gradient from the given stops, which don't have to be sorted
+    pub fn new(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        ColorGradient { stops }
+    }
+
+    /// Samples the color at position `t`, interpolating between the neighbouring stops in linear space
+    pub fn sample(&self, t: f32) -> Color {
+        sample(&self.stops, t)
+    }
+}
+
+impl InspectorPrimitive for ColorGradient {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<GradientOptions>()
+            .cloned()
+            .unwrap_or_default();
+        gradient_ui(&mut self.stops, &options, ui, id, env)
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<GradientOptions>()
+            .cloned()
+            .unwrap_or_default();
+        gradient_ui_readonly(&self.stops, &options, ui, id);
+    }
+}
+
+fn sample(stops: &[(f32, Color)], t: f32) -> Color {
+    let next = stops.partition_point(|(position, _)| *position < t);
+    match (
+        next.checked_sub(1).map(|i| stops[i]),
+        stops.get(next).copied(),
+    ) {
+        (None, None) => Color::NONE,
+        (Some((_, color)), None) | (None, Some((_, color))) => color,
+        (Some((start, from)), Some((end, to))) => {
+            let t = (t - start) / (end - start).max(f32::EPSILON);
+            LinearRgba::from(from).mix(&LinearRgba::from(to), t).into()
+        }
+    }
+}
+
+pub(crate) fn to_color32(color: Color) -> Color32 {
+    let [r, g, b, a] = Srgba::from(color).to_u8_array();
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+pub fn gradient_ui(
+    stops: &mut Vec<(f32, Color)>,
+    options: &GradientOptions,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: InspectorUi<'_, '_>,
+) -> bool {
+    let selected_id = id.with("selected_stop");
+    let mut selected = ui.data_mut(|data| data.get_temp::<usize>(selected_id));
+
+    let (changed, response) = gradient(stops, options, ui, id, &mut selected);
+    let mut changed = changed;
+
+    response.on_hover_text(
+        "Drag stops to move them, double click to add and right click to remove them",
+    );
+
+    if let Some(i) = selected.filter(|&i| i < stops.len()) {
+        // between the neighbours like when dragging the handle, to keep the stops sorted
+        let min = i.checked_sub(1).map_or(0.0, |j| stops[j].0);
+        let max = stops.get(i + 1).map_or(1.0, |(position, _)| *position);
+        let (position, color) = &mut stops[i];
+        ui.horizontal(|ui| {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(position)
+                        .range(min..=max.max(min))
+                        .speed(0.005),
+                )
+                .changed();
+            changed |= color.ui(ui, &(), id.with("selected_color"), env);
+        });
+    }
+
+    ui.data_mut(|data| match selected {
+        Some(selected) => data.insert_temp(selected_id, selected),
+        None => data.remove::<usize>(selected_id),
+    });
+
+    changed
+}
+
+pub fn gradient_ui_readonly(
+    stops: &[(f32, Color)],
+    options: &GradientOptions,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) {
+    ui.add_enabled_ui(false, |ui| {
+        gradient(&mut stops.to_vec(), options, ui, id, &mut None);
+    });
+}
+
+fn gradient(
+    stops: &mut Vec<(f32, Color)>,
+    options: &GradientOptions,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    selected: &mut Option<usize>,
+) -> (bool, egui::Response) {
+    let size = egui::vec2(ui.available_width(), options.height + HANDLE_SIZE);
+    let (response, painter) = ui.allocate_painter(size, Sense::click());
+    let bar = Rect::from_min_size(
+        response.rect.min,
+        egui::vec2(response.rect.width(), options.height),
+    )
+    .shrink2(egui::vec2(HANDLE_SIZE / 2.0, 0.0));
+    let to_x = |position: f32| egui::lerp(bar.x_range(), position);
+    let from_x = |x: f32| egui::remap_clamp(x, bar.x_range(), 0.0..=1.0);
+
+    let mut mesh = Mesh::default();
+    let mut add_segment = |from: (f32, Color32), to: (f32, Color32)| {
+        let rect = Rect::from_x_y_ranges(to_x(from.0)..=to_x(to.0), bar.y_range());
+        let idx = mesh.vertices.len() as u32;
+        mesh.colored_vertex(rect.left_top(), from.1);
+        mesh.colored_vertex(rect.right_top(), to.1);
+        mesh.colored_vertex(rect.left_bottom(), from.1);
+        mesh.colored_vertex(rect.right_bottom(), to.1);
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+    };
+    let mut sorted: Vec<_> = stops
+        .iter()
+        .map(|&(position, color)| (position.clamp(0.0, 1.0), to_color32(color)))
+        .collect();
+    sorted.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    if let (Some(&(first, first_color)), Some(&(last, last_color))) =
+        (sorted.first(), sorted.last())
+    {
+        add_segment((0.0, first_color), (first, first_color));
+        for window in sorted.windows(2) {
+            add_segment(window[0], window[1]);
+        }
+        add_segment((last, last_color), (1.0, last_color));
+    }
+    painter.add(Shape::mesh(mesh));
+    painter.rect_stroke(bar, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+    let mut changed = false;
+    let mut remove = None;
+    for i in 0..stops.len() {
+        let (position, color) = stops[i];
+        let tip = pos2(to_x(position.clamp(0.0, 1.0)), bar.bottom());
+        let handle = Rect::from_center_size(
+            tip + egui::vec2(0.0, HANDLE_SIZE / 2.0),
+            egui::Vec2::splat(HANDLE_SIZE),
+        );
+        let handle_response = ui.interact(handle, id.with(i), Sense::click_and_drag());
+
+        if handle_response.dragged() {
+            let min = i.checked_sub(1).map_or(0.0, |j| stops[j].0);
+            let max = stops.get(i + 1).map_or(1.0, |(position, _)| *position);
+            // not `clamp`, which panics if the stops are out of order
+            stops[i].0 = from_x(tip.x + handle_response.drag_delta().x)
+                .max(min)
+                .min(max);
+            changed = true;
+        }
+        if handle_response.clicked() || handle_response.drag_started() {
+            *selected = Some(i);
+        }
+        if handle_response.secondary_clicked() && stops.len() > 1 {
+            remove = Some(i);
+        }
+
+        let mut stroke = ui.style().interact(&handle_response).fg_stroke;
+        if *selected == Some(i) {
+            stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+        }
+        painter.add(Shape::convex_polygon(
+            vec![tip, handle.right_bottom(), handle.left_bottom()],
+            to_color32(color),
+            stroke,
+        ));
+        handle_response.on_hover_text(format!("{position:.3}"));
+    }
+
+    if let Some(i) = remove {
+        stops.remove(i);
+        *selected = None;
+        changed = true;
+    }
+
+    if response.double_clicked() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let position = from_x(pos.x);
+            let index = stops.partition_point(|(other, _)| *other < position);
+            stops.insert(index, (position, sample(stops, position)));
+            *selected = Some(index);
+            changed = true;
+        }
+    }
+
+    (changed, response)
+}
@@ -23,6 +23,13 @@ use super::InspectorPrimitive;
 
 mod image_texture_conversion;
 
+/// Side length of the thumbnails shown next to image handles
+const THUMBNAIL_SIZE: f32 = 32.0;
+
+/// Image handles are shown as a thumbnail next to a picker of the loaded images.
+///
+/// A new image can also be dropped onto the row (e.g. from the [asset browser](crate::bevy_inspector::asset_browser))
+/// or its asset path pasted while hovering it.
 impl InspectorPrimitive for Handle<Image> {
     fn ui(
         &mut self,
@@ -37,29 +44,30 @@ impl InspectorPrimitive for Handle<Image> {
             return false;
         };
 
-        update_and_show_image(self, world, ui);
-
-        let (asset_server, images) =
-            match world.get_two_resources_mut::<bevy_asset::AssetServer, Assets<Image>>() {
-                (Ok(a), Ok(b)) => (a, b),
-                (a, b) => {
-                    if let Err(e) = a {
-                        show_error(e, ui, &pretty_type_name::<bevy_asset::AssetServer>());
-                    }
-                    if let Err(e) = b {
-                        show_error(e, ui, &pretty_type_name::<Assets<Image>>());
+        let image_paths = {
+            let (asset_server, images) =
+                match world.get_two_resources_mut::<bevy_asset::AssetServer, Assets<Image>>() {
+                    (Ok(a), Ok(b)) => (a, b),
+                    (a, b) => {
+                        if let Err(e) = a {
+                            show_error(e, ui, &pretty_type_name::<bevy_asset::AssetServer>());
+                        }
+                        if let Err(e) = b {
+                            show_error(e, ui, &pretty_type_name::<Assets<Image>>());
+                        }
+                        return false;
                     }
-                    return false;
-                }
-            };
+                };
 
-        // get all loaded image paths
-        let mut image_paths = Vec::new();
-        for image in images.iter() {
-            if let Some(image_path) = asset_server.get_path(image.0) {
-                image_paths.push(image_path.to_string());
+            // get all loaded image paths
+            let mut image_paths = Vec::new();
+            for image in images.iter() {
+                if let Some(image_path) = asset_server.get_path(image.0) {
+                    image_paths.push(image_path.to_string());
+                }
             }
-        }
+            image_paths
+        };
 
         // first, get the typed search text from a stored egui data value
         let mut selected_path = None;
@@ -70,20 +78,26 @@ impl InspectorPrimitive for Handle<Image> {
             );
         });
 
-        // build and show the dropdown
-        let dropdown = DropDownBox::from_iter(
-            image_paths.iter(),
-            id.with("image_picker"),
-            &mut image_picker_search_text,
-            |ui, path| {
-                let response = ui.selectable_label(false, path);
-                if response.clicked() {
-                    selected_path = Some(path.to_string());
-                }
-                response
-            },
-        );
-        ui.add(dropdown);
+        // build and show the thumbnail and dropdown, accepting dropped images
+        let (row, dropped) = ui.dnd_drop_zone::<Handle<Image>, _>(egui::Frame::none(), |ui| {
+            ui.horizontal(|ui| {
+                image_thumbnail(self, world, ui, THUMBNAIL_SIZE);
+
+                let dropdown = DropDownBox::from_iter(
+                    image_paths.iter(),
+                    id.with("image_picker"),
+                    &mut image_picker_search_text,
+                    |ui, path| {
+                        let response = ui.selectable_label(false, path);
+                        if response.clicked() {
+                            selected_path = Some(path.to_string());
+                        }
+                        response
+                    },
+                );
+                ui.add(dropdown);
+            });
+        });
 
         // update the typed search text
         ui.data_mut(|data| {
@@ -91,12 +105,40 @@ impl InspectorPrimitive for Handle<Image> {
                 image_picker_search_text;
         });
 
-        // if the user selected an option, update the image handle
-        if let Some(selected_path) = selected_path {
-            *self = asset_server.load(selected_path);
+        if let Some(dropped) = dropped {
+            *self = Handle::clone(&dropped);
+            return true;
         }
 
-        false
+        // paste of an asset path while hovering the row, unless a text field is focused
+        let pasted_path = (row.response.contains_pointer()
+            && ui.memory(|memory| memory.focused().is_none()))
+        .then(|| {
+            ui.input(|input| {
+                input.events.iter().find_map(|event| match event {
+                    egui::Event::Paste(text) if !text.trim().is_empty() => {
+                        Some(text.trim().to_owned())
+                    }
+                    _ => None,
+                })
+            })
+        })
+        .flatten();
+
+        // if the user selected an option, update the image handle
+        let Some(path) = selected_path.or(pasted_path) else {
+            return false;
+        };
+        match world.get_resource_mut::<bevy_asset::AssetServer>() {
+            Ok(asset_server) => {
+                *self = asset_server.load(path);
+                true
+            }
+            Err(e) => {
+                show_error(e, ui, &pretty_type_name::<bevy_asset::AssetServer>());
+                false
+            }
+        }
     }
 
     fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
@@ -105,29 +147,31 @@ impl InspectorPrimitive for Handle<Image> {
             return;
         };
 
-        update_and_show_image(self, world, ui);
+        let path = world
+            .get_resource_mut::<bevy_asset::AssetServer>()
+            .ok()
+            .and_then(|asset_server| asset_server.get_path(self).map(|path| path.to_string()));
+        ui.horizontal(|ui| {
+            image_thumbnail(self, world, ui, THUMBNAIL_SIZE);
+            match path {
+                Some(path) => ui.label(path),
+                None => ui.weak("<unnamed>"),
+            };
+        });
     }
 }
 
-static SCALED_DOWN_TEXTURES: LazyLock<Mutex<ScaledDownTextures>> = LazyLock::new(Default::default);
-
-fn update_and_show_image(
+/// Shows `image` scaled down to fit into a `size`x`size` square, and a bigger version of it on hover.
+pub(crate) fn image_thumbnail(
     image: &Handle<Image>,
     world: &mut RestrictedWorldView,
     ui: &mut egui::Ui,
-) {
+    size: f32,
+) -> egui::Response {
     let (mut egui_user_textures, mut images) =
         match world.get_two_resources_mut::<bevy_egui::EguiUserTextures, Assets<Image>>() {
             (Ok(a), Ok(b)) => (a, b),
-            (a, b) => {
-                if let Err(e) = a {
-                    show_error(e, ui, &pretty_type_name::<bevy_egui::EguiContext>());
-                }
-                if let Err(e) = b {
-                    show_error(e, ui, &pretty_type_name::<Assets<Image>>());
-                }
-                return;
-            }
+            _ => return ui.allocate_response(egui::Vec2::splat(size), egui::Sense::hover()),
         };
 
     let mut scaled_down_textures = SCALED_DOWN_TEXTURES.lock().unwrap();
@@ -139,40 +183,48 @@ fn update_and_show_image(
         &mut images,
         &mut egui_user_textures,
     );
-    let (rescaled_handle, texture_id) = match rescaled {
-        Some(it) => it,
-        None => {
-            ui.label("<texture>");
-            return;
-        }
+    let Some((rescaled_image, texture_id)) =
+        rescaled.and_then(|(handle, texture_id)| Some((images.get(&handle)?, texture_id)))
+    else {
+        let (rect, response) =
+            ui.allocate_exact_size(egui::Vec2::splat(size), egui::Sense::hover());
+        ui.painter()
+            .rect_stroke(rect, 2.0, ui.visuals().widgets.noninteractive.bg_stroke);
+        return response;
     };
 
-    let rescaled_image = images.get(&rescaled_handle).unwrap();
-    show_image(rescaled_image, texture_id, ui);
-}
-
-fn show_image(
-    image: &Image,
-    texture_id: egui::TextureId,
-    ui: &mut egui::Ui,
-) -> Option<egui::Response> {
-    let size = image.texture_descriptor.size;
-    let size = egui::Vec2::new(size.width as f32, size.height as f32);
-
+    let full_size = rescaled_image.texture_descriptor.size;
+    let full_size = egui::Vec2::new(full_size.width as f32, full_size.height as f32);
     let source = SizedTexture {
         id: texture_id,
-        size,
+        size: full_size,
     };
+    ui.add(egui::Image::new(source).fit_to_exact_size(egui::Vec2::splat(size)))
+        .on_hover_ui(|ui| {
+            ui.image(source);
+        })
+}
 
-    if size.max_elem() >= 128.0 {
-        let response = egui::CollapsingHeader::new("Texture").show(ui, |ui| ui.image(source));
-        response.body_response
-    } else {
-        let response = ui.image(source);
-        Some(response)
-    }
+/// The egui texture of `image` at its original resolution, for previews which need more detail than a thumbnail.
+#[cfg(feature = "bevy_sprite")]
+pub(crate) fn image_texture(
+    image: &Handle<Image>,
+    world: &mut RestrictedWorldView,
+) -> Option<SizedTexture> {
+    let (mut egui_user_textures, images) =
+        match world.get_two_resources_mut::<bevy_egui::EguiUserTextures, Assets<Image>>() {
+            (Ok(a), Ok(b)) => (a, b),
+            _ => return None,
+        };
+    let size = images.get(image)?.size_f32();
+    Some(SizedTexture {
+        id: egui_user_textures.add_image(image.clone_weak()),
+        size: egui::vec2(size.x, size.y),
+    })
 }
 
+static SCALED_DOWN_TEXTURES: LazyLock<Mutex<ScaledDownTextures>> = LazyLock::new(Default::default);
+
 #[derive(Default)]
 struct ScaledDownTextures {
     textures: HashMap<Handle<Image>, Handle<Image>>,
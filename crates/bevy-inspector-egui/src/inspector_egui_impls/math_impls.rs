@@ -0,0 +1,376 @@
+use std::any::Any;
+
+use bevy_math::{
+    bounding::{Aabb2d, Aabb3d},
+    primitives::{Capsule2d, Capsule3d, Circle, Cuboid, Cylinder, Plane3d, Rectangle, Sphere},
+    Dir2, Dir3, Dir3A, Ray2d, Ray3d, Rect, Vec2, Vec3,
+};
+use bevy_reflect::PartialReflect;
+
+use super::InspectorPrimitive;
+use crate::{
+    inspector_options::std_options::{DirDisplay, DirOptions, NumberOptions},
+    reflect_inspector::InspectorUi,
+};
+
+fn value(
+    value: &mut dyn PartialReflect,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> bool {
+    env.ui_for_reflect_with_options(value, ui, id, &())
+}
+
+fn length(value: &mut f32, ui: &mut egui::Ui, id: egui::Id, env: &mut InspectorUi<'_, '_>) -> bool {
+    env.ui_for_reflect_with_options(value, ui, id, &NumberOptions::<f32>::positive())
+}
+
+fn extent2(
+    value: &mut Vec2,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> bool {
+    let mut axes = value.to_array();
+    let changed = extent_ui(&mut axes, ui, id, env);
+    *value = Vec2::from_array(axes);
+    changed
+}
+
+fn extent3(
+    value: &mut Vec3,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> bool {
+    let mut axes = value.to_array();
+    let changed = extent_ui(&mut axes, ui, id, env);
+    *value = Vec3::from_array(axes);
+    changed
+}
+
+/// Edits the (positive) axes of an extent, with a toggle to scale all of them together.
+fn extent_ui<const N: usize>(
+    axes: &mut [f32; N],
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    env: &mut InspectorUi<'_, '_>,
+) -> bool {
+    let linked_id = id.with("link_axes");
+    let mut linked = ui.data(|data| data.get_temp::<bool>(linked_id).unwrap_or(false));
+
+    let before = *axes;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 4.0;
+        for (i, axis) in axes.iter_mut().enumerate() {
+            changed |= length(axis, ui, id.with(i), env);
+        }
+        let toggle = ui
+            .toggle_value(&mut linked, "🔗")
+            .on_hover_text("Scale all axes together");
+        if toggle.changed() {
+            ui.data_mut(|data| data.insert_temp(linked_id, linked));
+        }
+    });
+
+    if changed && linked {
+        link_axes(&before, axes);
+    }
+
+    changed
+}
+
+/// Scales all axes by the factor the changed axis was scaled by.
+/// If that axis was zero before, all axes are set to its new value.
+pub(crate) fn link_axes<const N: usize>(before: &[f32; N], after: &mut [f32; N]) {
+    let Some(changed) = (0..N).find(|&i| before[i] != after[i]) else {
+        return;
+    };
+    let factor = after[changed] / before[changed];
+    for i in (0..N).filter(|&i| i != changed) {
+        after[i] = match factor.is_finite() {
+            true => before[i] * factor,
+            false => after[changed],
+        };
+    }
+}
+
+macro_rules! impl_shape {
+    ($($ty:ty { $($field:ident: $kind:ident),* })*) => {$(
+        impl InspectorPrimitive for $ty {
+            fn ui(
+                &mut self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) -> bool {
+                let mut changed = false;
+                egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                    $(
+                        ui.label(stringify!($field));
+                        changed |= $kind(&mut self.$field, ui, id.with(stringify!($field)), &mut env);
+                        ui.end_row();
+                    )*
+                });
+                changed
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) {
+                egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                    $(
+                        ui.label(stringify!($field));
+                        env.ui_for_reflect_readonly(&self.$field, ui);
+                        ui.end_row();
+                    )*
+                });
+            }
+        }
+    )*};
+}
+
+impl_shape! {
+    Circle { radius: length }
+    Sphere { radius: length }
+    Rectangle { half_size: extent2 }
+    Cuboid { half_size: extent3 }
+    Capsule2d { radius: length, half_length: length }
+    Capsule3d { radius: length, half_length: length }
+    Cylinder { radius: length, half_height: length }
+    Plane3d { normal: value, half_size: extent2 }
+    Ray2d { origin: value, direction: value }
+    Ray3d { origin: value, direction: value }
+}
+
+/// Axis aligned boxes, where editing `min` past `max` (or the other way around) moves both.
+macro_rules! impl_bounds {
+    ($($ty:ty),*) => {$(
+        impl InspectorPrimitive for $ty {
+            fn ui(
+                &mut self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) -> bool {
+                let mut changed = false;
+                egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                    ui.label("min");
+                    if env.ui_for_reflect_with_options(&mut self.min, ui, id.with("min"), &()) {
+                        self.max = self.max.max(self.min);
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label("max");
+                    if env.ui_for_reflect_with_options(&mut self.max, ui, id.with("max"), &()) {
+                        self.min = self.min.min(self.max);
+                        changed = true;
+                    }
+                    ui.end_row();
+                });
+                changed
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) {
+                egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+                    ui.label("min");
+                    env.ui_for_reflect_readonly(&self.min, ui);
+                    ui.end_row();
+
+                    ui.label("max");
+                    env.ui_for_reflect_readonly(&self.max, ui);
+                    ui.end_row();
+                });
+            }
+        }
+    )*};
+}
+
+impl_bounds!(Rect, Aabb2d, Aabb3d);
+
+fn dir2_angle_ui(dir: &mut Vec2, ui: &mut egui::Ui, _: egui::Id) -> bool {
+    let mut angle = dir.y.atan2(dir.x).to_degrees();
+    let changed = ui
+        .add(egui::Slider::new(&mut angle, -180.0..=180.0).suffix("°"))
+        .changed();
+    if changed {
+        *dir = Vec2::from_angle(angle.to_radians());
+    }
+    changed
+}
+
+/// Yaw around the Y axis and pitch above the XZ plane in degrees.
+///
+/// They are kept in memory while editing, so that the yaw doesn't get lost when pitching straight up or down.
+#[derive(Clone, Copy)]
+struct YawPitch {
+    yaw: f32,
+    pitch: f32,
+}
+
+impl YawPitch {
+    fn from_dir(dir: Vec3) -> Self {
+        YawPitch {
+            yaw: dir.x.atan2(dir.z).to_degrees(),
+            pitch: dir.y.clamp(-1.0, 1.0).asin().to_degrees(),
+        }
+    }
+
+    fn to_dir(self) -> Vec3 {
+        let (yaw, pitch) = (self.yaw.to_radians(), self.pitch.to_radians());
+        Vec3::new(
+            pitch.cos() * yaw.sin(),
+            pitch.sin(),
+            pitch.cos() * yaw.cos(),
+        )
+    }
+}
+
+fn dir3_angles_ui<V: Into<Vec3> + From<Vec3> + Copy>(
+    dir: &mut V,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> bool {
+    let current: Vec3 = (*dir).into();
+    let angles_id = id.with("yaw_pitch");
+    let mut angles = ui
+        .data(|data| data.get_temp::<YawPitch>(angles_id))
+        .filter(|angles| angles.to_dir().abs_diff_eq(current, 1e-4))
+        .unwrap_or_else(|| YawPitch::from_dir(current));
+
+    let mut changed = false;
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        ui.label("yaw");
+        changed |= ui
+            .add(egui::Slider::new(&mut angles.yaw, -180.0..=180.0).suffix("°"))
+            .changed();
+        ui.end_row();
+
+        ui.label("pitch");
+        changed |= ui
+            .add(egui::Slider::new(&mut angles.pitch, -90.0..=90.0).suffix("°"))
+            .changed();
+        ui.end_row();
+    });
+
+    if changed {
+        *dir = V::from(angles.to_dir());
+    }
+    ui.data_mut(|data| data.insert_temp(angles_id, angles));
+
+    changed
+}
+
+/// Directions are edited as angles (or like their vector, with [`DirDisplay::Raw`]) and always renormalized.
+/// Edits which would make the direction zero, infinite or NaN are discarded.
+macro_rules! impl_dir {
+    ($($ty:ty => $angles_ui:ident),*) => {$(
+        impl InspectorPrimitive for $ty {
+            fn ui(
+                &mut self,
+                ui: &mut egui::Ui,
+                options: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) -> bool {
+                let options = options
+                    .downcast_ref::<DirOptions>()
+                    .cloned()
+                    .unwrap_or_default();
+
+                let mut vec = **self;
+                let changed = match options.display {
+                    DirDisplay::Raw => env.ui_for_reflect_with_options(&mut vec, ui, id, &()),
+                    DirDisplay::Angles => {
+                        ui.vertical(|ui| {
+                            let changed = $angles_ui(&mut vec, ui, id);
+                            if options.show_components {
+                                env.ui_for_reflect_readonly_with_options(&vec, ui, id.with("components"), &());
+                            }
+                            changed
+                        })
+                        .inner
+                    }
+                };
+                if !changed {
+                    return false;
+                }
+                match <$ty>::new(vec) {
+                    Ok(dir) => {
+                        *self = dir;
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut egui::Ui,
+                options: &dyn Any,
+                id: egui::Id,
+                mut env: InspectorUi<'_, '_>,
+            ) {
+                let options = options
+                    .downcast_ref::<DirOptions>()
+                    .cloned()
+                    .unwrap_or_default();
+
+                match options.display {
+                    DirDisplay::Raw => env.ui_for_reflect_readonly_with_options(&**self, ui, id, &()),
+                    DirDisplay::Angles => {
+                        let mut vec = **self;
+                        ui.add_enabled_ui(false, |ui| $angles_ui(&mut vec, ui, id));
+                        if options.show_components {
+                            env.ui_for_reflect_readonly_with_options(&vec, ui, id.with("components"), &());
+                        }
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl_dir!(Dir2 => dir2_angle_ui, Dir3 => dir3_angles_ui, Dir3A => dir3_angles_ui);
+
+#[cfg(test)]
+mod tests {
+    use super::{link_axes, YawPitch};
+    use bevy_math::Vec3;
+
+    #[test]
+    fn linked_axes_scale_proportionally() {
+        let mut axes = [2.0, 1.0, 4.0];
+        axes[0] = 4.0;
+        link_axes(&[2.0, 1.0, 4.0], &mut axes);
+        assert_eq!(axes, [4.0, 2.0, 8.0]);
+
+        let mut axes = [0.0, 1.0];
+        axes[0] = 3.0;
+        link_axes(&[0.0, 1.0], &mut axes);
+        assert_eq!(axes, [3.0, 3.0]);
+    }
+
+    #[test]
+    fn yaw_pitch_roundtrip() {
+        for dir in [Vec3::X, Vec3::NEG_Z, Vec3::new(1.0, 2.0, -3.0).normalize()] {
+            let roundtrip = YawPitch::from_dir(dir).to_dir();
+            assert!(roundtrip.abs_diff_eq(dir, 1e-5), "{dir} became {roundtrip}");
+        }
+    }
+}
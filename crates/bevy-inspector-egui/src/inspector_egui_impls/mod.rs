@@ -13,10 +13,25 @@ use std::{
 };
 
 mod bevy_impls;
+pub(crate) mod curve;
+mod file_picker;
 mod glam_impls;
+pub(crate) mod gradient;
 #[cfg(feature = "bevy_image")]
-mod image;
+pub(crate) mod image;
+mod math_impls;
+pub mod multiedit;
+pub mod overrides;
+#[cfg(any(feature = "rapier2d", feature = "rapier3d"))]
+mod rapier;
+#[cfg(feature = "bevy_sprite")]
+mod sprite;
 mod std_impls;
+#[cfg(feature = "bevy_text")]
+mod text;
+
+pub use gradient::ColorGradient;
+pub use overrides::InspectorOverrides;
 
 type InspectorEguiImplFn =
     fn(&mut dyn Any, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi<'_, '_>) -> bool;
@@ -84,7 +99,7 @@ pub trait InspectorPrimitive: Reflect {
     );
 }
 
-fn ui_many_vtable<T: Reflect + PartialEq + Clone + Default + InspectorPrimitive>(
+fn ui_many_vtable<T: Reflect + PartialEq + Clone + InspectorPrimitive>(
     ui: &mut egui::Ui,
     options: &dyn Any,
     id: egui::Id,
@@ -92,24 +107,9 @@ fn ui_many_vtable<T: Reflect + PartialEq + Clone + Default + InspectorPrimitive>
     values: &mut [&mut dyn bevy_reflect::PartialReflect],
     projector: &dyn ProjectorReflect,
 ) -> bool {
-    let same = crate::inspector_egui_impls::iter_all_eq(values.iter_mut().map(|value| {
-        projector(*value)
-            .try_downcast_mut::<T>()
-            .expect("non-fully-reflected value passed to ui_many_vtable")
-    }));
-
-    let mut temp = same.cloned().unwrap_or_default();
-    if T::ui(&mut temp, ui, options, id, env) {
-        for value in values.iter_mut() {
-            let value = projector(*value)
-                .try_downcast_mut::<T>()
-                .expect("non-fully-reflected value passed to ui_many_vtable");
-            *value = temp.clone();
-        }
-
-        return true;
-    }
-    false
+    multiedit::show_mixed(ui, values, projector, |ui, temp: &mut T, _| {
+        T::ui(temp, ui, options, id, env)
+    })
 }
 
 fn ui_vtable<T: InspectorPrimitive>(
@@ -280,6 +280,23 @@ pub fn register_std_impls(type_registry: &mut TypeRegistry) {
 
     add::<std::time::Duration>(type_registry);
     add_of_with_many::<Instant>(type_registry, many_unimplemented::<Instant>);
+
+    macro_rules! add_nonzero {
+        ($($ty:ident),*) => {$(
+            type_registry.register::<std::num::$ty>();
+            add::<std::num::$ty>(type_registry);
+        )*};
+    }
+    add_nonzero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize);
+    add_nonzero!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize);
+
+    type_registry.register::<uuid::Uuid>();
+    add::<uuid::Uuid>(type_registry);
+    #[cfg(feature = "smol_str")]
+    {
+        type_registry.register::<smol_str::SmolStr>();
+        add::<smol_str::SmolStr>(type_registry);
+    }
 }
 
 /// Register [`InspectorEguiImpl`]s for [`bevy_math`]/`glam` types
@@ -304,31 +321,86 @@ pub fn register_glam_impls(type_registry: &mut TypeRegistry) {
     add_raw::<bevy_math::Mat2>(type_registry, glam_impls::mat2_ui, glam_impls::mat2_ui_readonly, many_unimplemented::<bevy_math::Mat2>);
     add_raw::<bevy_math::Mat3>(type_registry, glam_impls::mat3_ui, glam_impls::mat3_ui_readonly, many_unimplemented::<bevy_math::Mat3>);
     add_raw::<bevy_math::Mat3A>(type_registry, glam_impls::mat3a_ui, glam_impls::mat3a_ui_readonly, many_unimplemented::<bevy_math::Mat3A>);
-    add_raw::<bevy_math::Mat4>(type_registry, glam_impls::mat4_ui, glam_impls::mat4_ui_readonly, many_unimplemented::<bevy_math::Mat4>);
+    add_raw::<bevy_math::Mat4>(type_registry, glam_impls::affine::mat4_ui, glam_impls::affine::mat4_ui_readonly, glam_impls::affine::mat4_ui_many);
+    type_registry.register::<bevy_math::Affine3A>();
+    add_raw::<bevy_math::Affine3A>(type_registry, glam_impls::affine::affine3a_ui, glam_impls::affine::affine3a_ui_readonly, glam_impls::affine::affine3a_ui_many);
     add_raw::<bevy_math::DMat2>(type_registry, glam_impls::dmat2_ui, glam_impls::dmat2_ui_readonly, many_unimplemented::<bevy_math::DMat2>);
     add_raw::<bevy_math::DMat3>(type_registry, glam_impls::dmat3_ui, glam_impls::dmat3_ui_readonly, many_unimplemented::<bevy_math::DMat3>);
     add_raw::<bevy_math::DMat4>(type_registry, glam_impls::dmat4_ui, glam_impls::dmat4_ui_readonly, many_unimplemented::<bevy_math::DMat4>);
 
     add_raw::<bevy_math::Quat>(type_registry, glam_impls::quat::quat_ui, glam_impls::quat::quat_ui_readonly, glam_impls::quat::quat_ui_many);
+
+    macro_rules! add_math {
+        ($($ty:ty),*) => {$(
+            type_registry.register::<$ty>();
+            add_of_with_many::<$ty>(type_registry, many_unimplemented::<$ty>);
+        )*};
+    }
+    add_math!(bevy_math::Dir2, bevy_math::Dir3, bevy_math::Dir3A);
+    add_math!(bevy_math::Rect, bevy_math::bounding::Aabb2d, bevy_math::bounding::Aabb3d, bevy_math::Ray2d, bevy_math::Ray3d);
+    add_math!(bevy_math::primitives::Circle, bevy_math::primitives::Sphere, bevy_math::primitives::Rectangle, bevy_math::primitives::Cuboid);
+    add_math!(bevy_math::primitives::Capsule2d, bevy_math::primitives::Capsule3d, bevy_math::primitives::Cylinder, bevy_math::primitives::Plane3d);
 }
 
 /// Register [`InspectorEguiImpl`]s for `bevy` types
 #[rustfmt::skip]
 pub fn register_bevy_impls(type_registry: &mut TypeRegistry) {
+    type_registry.register::<bevy_ecs::entity::Entity>();
     add_of_with_many::<bevy_ecs::entity::Entity>(type_registry, many_unimplemented::<bevy_ecs::entity::Entity>);
-    add::<bevy_color::Color>(type_registry);
+    add_of_with_many::<bevy_color::Color>(type_registry, bevy_impls::color_ui_many);
+    type_registry.register::<bevy_transform::components::Transform>();
+    add_of_with_many::<bevy_transform::components::Transform>(type_registry, bevy_impls::transform_ui_many);
+    type_registry.register::<bevy_transform::components::GlobalTransform>();
+    add_of_with_many::<bevy_transform::components::GlobalTransform>(type_registry, many_unimplemented::<bevy_transform::components::GlobalTransform>);
+    type_registry.register::<gradient::ColorGradient>();
+    add::<gradient::ColorGradient>(type_registry);
 
     #[cfg(feature = "bevy_render")] 
     {
+      type_registry.register::<bevy_asset::Handle<bevy_render::mesh::Mesh>>();
       add_of_with_many::<bevy_asset::Handle<bevy_render::mesh::Mesh>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_render::mesh::Mesh>>);
+      type_registry.register::<bevy_render::view::RenderLayers>();
       add::<bevy_render::view::RenderLayers>(type_registry);
     }
+    #[cfg(feature = "bevy_animation")]
+    {
+      type_registry.register::<bevy_animation::AnimationPlayer>();
+      add_of_with_many::<bevy_animation::AnimationPlayer>(type_registry, many_unimplemented::<bevy_animation::AnimationPlayer>);
+    }
     #[cfg(feature = "bevy_image")]
     {
+      type_registry.register::<bevy_asset::Handle<bevy_image::Image>>();
       add_of_with_many::<bevy_asset::Handle<bevy_image::Image>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_image::Image>>);
     }
+    #[cfg(feature = "bevy_sprite")]
+    {
+      type_registry.register::<bevy_sprite::Sprite>();
+      add_of_with_many::<bevy_sprite::Sprite>(type_registry, many_unimplemented::<bevy_sprite::Sprite>);
+      type_registry.register::<bevy_sprite::TextureAtlas>();
+      add_of_with_many::<bevy_sprite::TextureAtlas>(type_registry, many_unimplemented::<bevy_sprite::TextureAtlas>);
+    }
+    #[cfg(feature = "bevy_text")]
+    {
+      type_registry.register::<bevy_text::Text2d>();
+      add_of_with_many::<bevy_text::Text2d>(type_registry, many_unimplemented::<bevy_text::Text2d>);
+      type_registry.register::<bevy_text::TextSpan>();
+      add_of_with_many::<bevy_text::TextSpan>(type_registry, many_unimplemented::<bevy_text::TextSpan>);
+      type_registry.register::<bevy_text::JustifyText>();
+      add_of_with_many::<bevy_text::JustifyText>(type_registry, many_unimplemented::<bevy_text::JustifyText>);
+      type_registry.register::<bevy_asset::Handle<bevy_text::Font>>();
+      add_of_with_many::<bevy_asset::Handle<bevy_text::Font>>(type_registry, many_unimplemented::<bevy_asset::Handle<bevy_text::Font>>);
+    }
+    #[cfg(all(feature = "bevy_text", feature = "bevy_ui"))]
+    {
+      type_registry.register::<bevy_ui::widget::Text>();
+      add_of_with_many::<bevy_ui::widget::Text>(type_registry, many_unimplemented::<bevy_ui::widget::Text>);
+    }
 
-    add::<uuid::Uuid>(type_registry);
+
+    #[cfg(feature = "rapier2d")]
+    rapier::register_rapier2d_impls(type_registry);
+    #[cfg(feature = "rapier3d")]
+    rapier::register_rapier3d_impls(type_registry);
 }
 
 pub(crate) fn change_slider<T>(
@@ -372,6 +444,100 @@ where
     }
 }
 
+/// How editing a number of multiple values changes them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum MultiEditMode {
+    /// Set all values to the edited value, or offset them while they differ
+    #[default]
+    Absolute,
+    /// Offset every value by the dragged amount
+    Offset,
+    /// Scale every value by the dragged percentage
+    Scale,
+}
+
+impl MultiEditMode {
+    /// A small button cycling through the modes, stored in egui memory at `id`
+    pub(crate) fn toggle(ui: &mut egui::Ui, id: egui::Id) -> MultiEditMode {
+        let mut mode = ui.data_mut(|data| *data.get_temp_mut_or_default::<MultiEditMode>(id));
+        let (label, hover) = match mode {
+            MultiEditMode::Absolute => ("=", "Set all values (click for relative offset)"),
+            MultiEditMode::Offset => ("±", "Offset all values (click for relative scale)"),
+            MultiEditMode::Scale => ("%", "Scale all values (click to set all values)"),
+        };
+        if ui.small_button(label).on_hover_text(hover).clicked() {
+            mode = match mode {
+                MultiEditMode::Absolute => MultiEditMode::Offset,
+                MultiEditMode::Offset => MultiEditMode::Scale,
+                MultiEditMode::Scale => MultiEditMode::Absolute,
+            };
+            ui.data_mut(|data| data.insert_temp(id, mode));
+        }
+        mode
+    }
+}
+
+/// A change relative to the existing value, see [`relative_change_slider`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RelativeChange {
+    Offset(f64),
+    Scale(f64),
+}
+
+impl RelativeChange {
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            RelativeChange::Offset(offset) => value + offset,
+            RelativeChange::Scale(factor) => value * factor,
+        }
+    }
+}
+
+/// A drag value for the [`MultiEditMode::Offset`] or [`MultiEditMode::Scale`] of multiple values.
+///
+/// It displays the total offset or percentage of the current drag, and calls `f` with the change since the last frame.
+/// The total is reset once the drag stops.
+pub(crate) fn relative_change_slider(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    mode: MultiEditMode,
+    integral: bool,
+    f: impl FnOnce(RelativeChange),
+) -> bool {
+    let initial = match mode {
+        MultiEditMode::Scale => 100.0,
+        _ => 0.0,
+    };
+    let old_total = ui.data(|data| data.get_temp::<f64>(id).unwrap_or(initial));
+    let mut total = old_total;
+
+    let widget = match mode {
+        MultiEditMode::Scale => egui::DragValue::new(&mut total)
+            .speed(1.0)
+            .suffix("%")
+            .range(1.0..=f64::MAX),
+        _ => egui::DragValue::new(&mut total)
+            .speed(if integral { 1.0 } else { 0.1 })
+            .max_decimals(if integral { 0 } else { 3 })
+            .prefix("±"),
+    };
+    let response = ui.add(widget);
+    let changed = response.changed() && total != old_total;
+    if changed {
+        f(match mode {
+            MultiEditMode::Scale => RelativeChange::Scale(total / old_total),
+            _ => RelativeChange::Offset(total - old_total),
+        });
+    }
+
+    if response.drag_stopped() || response.lost_focus() {
+        ui.data_mut(|data| data.remove::<f64>(id));
+    } else {
+        ui.data_mut(|data| data.insert_temp(id, total));
+    }
+    changed
+}
+
 pub(crate) fn iter_all_eq<T: PartialEq>(mut iter: impl Iterator<Item = T>) -> Option<T> {
     let first = iter.next()?;
     iter.all(|elem| elem == first).then_some(first)
@@ -389,22 +555,12 @@ macro_rules! many_ui {
             values: &mut [&mut dyn bevy_reflect::PartialReflect],
             projector: &dyn $crate::reflect_inspector::ProjectorReflect,
         ) -> bool {
-            let same = $crate::inspector_egui_impls::iter_all_eq(
-                values
-                    .iter_mut()
-                    .map(|value| projector(*value).try_downcast_ref::<$ty>().unwrap()),
-            );
-
-            let mut temp = same.cloned().unwrap_or_default();
-            if $inner(&mut temp, ui, options, id, env) {
-                for value in values.iter_mut() {
-                    let value = projector(*value).try_downcast_mut::<$ty>().unwrap();
-                    *value = temp.clone();
-                }
-
-                return true;
-            }
-            false
+            $crate::inspector_egui_impls::multiedit::show_mixed(
+                ui,
+                values,
+                projector,
+                |ui, temp: &mut $ty, _| $inner(temp, ui, options, id, env),
+            )
         }
     };
 }
@@ -0,0 +1,109 @@
+//! Helpers for [`InspectorEguiImpl`](super::InspectorEguiImpl)s editing multiple values at once.
+//!
+//! A `fn_many` implementation should display the shared value if all values are equal, and otherwise a "mixed" state
+//! which doesn't overwrite the values until the user actually edits it. [`show_mixed`] implements that pattern:
+//!
+//! ```rust
+//! use bevy_inspector_egui::inspector_egui_impls::multiedit;
+//! use bevy_inspector_egui::reflect_inspector::{InspectorUi, ProjectorReflect};
+//! use bevy_reflect::PartialReflect;
+//! # use std::any::Any;
+//!
+//! #[derive(bevy_reflect::Reflect, Clone, PartialEq)]
+//! struct Health(u32);
+//!
+//! fn health_ui_many(
+//!     ui: &mut egui::Ui,
+//!     _options: &dyn Any,
+//!     _id: egui::Id,
+//!     _env: InspectorUi<'_, '_>,
+//!     values: &mut [&mut dyn PartialReflect],
+//!     projector: &dyn ProjectorReflect,
+//! ) -> bool {
+//!     multiedit::show_mixed(ui, values, projector, |ui, health: &mut Health, mixed| {
+//!         let widget = egui::DragValue::new(&mut health.0);
+//!         ui.add(multiedit::mixed_drag_value(widget, mixed)).changed()
+//!     })
+//! }
+//! ```
+
+use bevy_reflect::{PartialReflect, Reflect};
+
+use crate::reflect_inspector::ProjectorReflect;
+
+/// The text displayed instead of a value when the edited values differ
+pub const MIXED_TEXT: &str = "—";
+
+/// The projected `values`, if they are all equal
+pub fn all_equal<'a, T: Reflect + PartialEq>(
+    values: &'a mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+) -> Option<&'a T> {
+    let mut iter = values.iter_mut().map(|value| {
+        projector(*value)
+            .try_downcast_ref::<T>()
+            .expect("non-fully-reflected value passed to multiedit::all_equal")
+    });
+    let first = iter.next()?;
+    iter.all(|value| value == first).then_some(first)
+}
+
+/// Sets every projected value to `new`
+pub fn set_all<T: Reflect + Clone>(
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+    new: &T,
+) {
+    for value in values.iter_mut() {
+        *projector(*value)
+            .try_downcast_mut::<T>()
+            .expect("non-fully-reflected value passed to multiedit::set_all") = new.clone();
+    }
+}
+
+/// Displays the standard indicator next to a widget editing differing values
+pub fn mixed_indicator(ui: &mut egui::Ui) -> egui::Response {
+    ui.weak("mixed")
+        .on_hover_text("The values differ, editing sets all of them to the same value")
+}
+
+/// Displays [`MIXED_TEXT`] in the drag value instead of its number if the values are `mixed`
+pub fn mixed_drag_value(widget: egui::DragValue<'_>, mixed: bool) -> egui::DragValue<'_> {
+    match mixed {
+        true => widget.custom_formatter(|_, _| MIXED_TEXT.to_string()),
+        false => widget,
+    }
+}
+
+/// Edits multiple values of type `T` using a single widget.
+///
+/// `edit` receives a copy of the first value and whether the values differ. In that case, the [`mixed_indicator`] is
+/// displayed after it. Only if `edit` returns `true` is the edited copy written to all values, so differing values
+/// stay untouched until the user changes them.
+pub fn show_mixed<T: Reflect + Clone + PartialEq>(
+    ui: &mut egui::Ui,
+    values: &mut [&mut dyn PartialReflect],
+    projector: &dyn ProjectorReflect,
+    edit: impl FnOnce(&mut egui::Ui, &mut T, bool) -> bool,
+) -> bool {
+    let Some(first) = values.first_mut() else {
+        return false;
+    };
+    let mut temp = projector(*first)
+        .try_downcast_ref::<T>()
+        .expect("non-fully-reflected value passed to multiedit::show_mixed")
+        .clone();
+    let mixed = all_equal::<T>(values, projector).is_none();
+
+    ui.horizontal(|ui| {
+        let changed = edit(ui, &mut temp, mixed);
+        if mixed {
+            mixed_indicator(ui);
+        }
+        if changed {
+            set_all(values, projector, &temp);
+        }
+        changed
+    })
+    .inner
+}
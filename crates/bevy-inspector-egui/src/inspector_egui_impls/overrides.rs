@@ -0,0 +1,127 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use bevy_ecs::system::Resource;
+use bevy_utils::HashMap;
+
+use crate::reflect_inspector::InspectorUi;
+
+type OverrideFn =
+    dyn Fn(&mut dyn Any, &mut egui::Ui, egui::Id, InspectorUi<'_, '_>) -> bool + Send + Sync;
+type OverrideFnReadonly =
+    dyn Fn(&dyn Any, &mut egui::Ui, egui::Id, InspectorUi<'_, '_>) + Send + Sync;
+
+#[derive(Clone)]
+pub(crate) struct InspectorOverride {
+    fn_mut: Arc<OverrideFn>,
+    fn_readonly: Arc<OverrideFnReadonly>,
+}
+
+impl InspectorOverride {
+    pub(crate) fn execute(
+        &self,
+        value: &mut dyn Any,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        (self.fn_mut)(value, ui, id, env)
+    }
+
+    pub(crate) fn execute_readonly(
+        &self,
+        value: &dyn Any,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) {
+        (self.fn_readonly)(value, ui, id, env);
+    }
+}
+
+/// Custom UIs for types, which take precedence over their [`InspectorEguiImpl`](super::InspectorEguiImpl) type data.
+///
+/// This allows changing how foreign types are displayed, whose type registration can't be changed reliably.
+/// Use [`InspectorAppExt::override_inspector_ui`](crate::InspectorAppExt::override_inspector_ui) to add overrides.
+///
+/// Overrides are used by the [`bevy_inspector`](crate::bevy_inspector) functions, as long as the world they get
+/// has access to this resource. Editing multiple values at once falls back to the regular UI.
+#[derive(Resource, Default, Clone)]
+pub struct InspectorOverrides {
+    overrides: Arc<HashMap<TypeId, InspectorOverride>>,
+}
+
+impl InspectorOverrides {
+    /// Displays `T` using `ui`, which returns whether the value was changed.
+    ///
+    /// In read-only contexts, a clone of the value is displayed in a disabled UI.
+    pub fn insert<T: Clone + 'static>(
+        &mut self,
+        ui: impl Fn(&mut T, &mut egui::Ui, egui::Id, InspectorUi<'_, '_>) -> bool
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        let ui = Arc::new(ui);
+        let ui_readonly = ui.clone();
+        let inspector_override = InspectorOverride {
+            fn_mut: Arc::new(move |value, egui_ui, id, env| {
+                ui(value.downcast_mut::<T>().unwrap(), egui_ui, id, env)
+            }),
+            fn_readonly: Arc::new(move |value, egui_ui, id, env| {
+                let mut copy = value.downcast_ref::<T>().unwrap().clone();
+                egui_ui.add_enabled_ui(false, |egui_ui| ui_readonly(&mut copy, egui_ui, id, env));
+            }),
+        };
+        Arc::make_mut(&mut self.overrides).insert(TypeId::of::<T>(), inspector_override);
+    }
+
+    /// Removes the override of `T`, so that it is displayed as usual
+    pub fn remove<T: 'static>(&mut self) {
+        Arc::make_mut(&mut self.overrides).remove(&TypeId::of::<T>());
+    }
+
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.overrides.contains_key(&type_id)
+    }
+
+    pub(crate) fn get(&self, type_id: TypeId) -> Option<&InspectorOverride> {
+        self.overrides.get(&type_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectorOverrides;
+    use bevy_ecs::prelude::*;
+
+    #[test]
+    fn override_takes_precedence() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<f32>();
+        let mut overrides = InspectorOverrides::default();
+        overrides.insert::<f32>(|value, _, _, _| {
+            *value = 42.0;
+            true
+        });
+        world.insert_resource(overrides);
+
+        let mut value = 0.0f32;
+        let mut changed = false;
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                changed = crate::bevy_inspector::ui_for_value(&mut value, ui, &mut world);
+            });
+        });
+
+        assert!(changed);
+        assert_eq!(value, 42.0);
+    }
+}
@@ -0,0 +1,147 @@
+//! UI for `bevy_rapier2d` and `bevy_rapier3d` components, behind the `rapier2d` and `rapier3d` features.
+//!
+//! The bitflag components `Group` (in `CollisionGroups` and `SolverGroups`) and `LockedAxes`
+//! are displayed as checkboxes instead of their raw bits, and coefficients like friction and damping can't become negative.
+//!
+//! `Collider`, `ImpulseJoint` and `MultibodyJoint` don't implement `Reflect` in `bevy_rapier`, so they can't be displayed.
+
+use std::any::Any;
+
+use bevy_reflect::TypeRegistry;
+
+use super::InspectorPrimitive;
+use crate::reflect_inspector::InspectorUi;
+
+/// Checkboxes for each of the single-bit `flags`, returns the new bits if one was toggled
+fn bitflags_ui<'a>(
+    bits: u32,
+    flags: impl Iterator<Item = (&'a str, u32)>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    columns: usize,
+) -> Option<u32> {
+    let mut new_bits = None;
+    egui::Grid::new(id).show(ui, |ui| {
+        let single_bit_flags = flags.filter(|(_, flag)| flag.count_ones() == 1);
+        for (i, (name, flag)) in single_bit_flags.enumerate() {
+            let mut set = bits & flag != 0;
+            if ui.checkbox(&mut set, name).changed() {
+                new_bits = Some(bits ^ flag);
+            }
+            if (i + 1) % columns == 0 {
+                ui.end_row();
+            }
+        }
+    });
+    new_bits
+}
+
+fn bitflags_ui_readonly<'a>(
+    bits: u32,
+    flags: impl Iterator<Item = (&'a str, u32)>,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    columns: usize,
+) {
+    ui.add_enabled_ui(false, |ui| bitflags_ui(bits, flags, ui, id, columns));
+}
+
+/// Shortens `GROUP_12` to `12` and `TRANSLATION_LOCKED_X` to `translation x`
+fn flag_label(name: &str) -> String {
+    name.strip_prefix("GROUP_")
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| name.replace("_LOCKED", "").replace('_', " ").to_lowercase())
+}
+
+macro_rules! rapier_impls {
+    ($rapier:ident, $register:ident) => {
+        impl InspectorPrimitive for $rapier::geometry::Group {
+            fn ui(
+                &mut self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                _: InspectorUi<'_, '_>,
+            ) -> bool {
+                let labels: Vec<_> = Self::all()
+                    .iter_names()
+                    .map(|(name, flag)| (flag_label(name), flag.bits()))
+                    .collect();
+                let flags = labels.iter().map(|(name, flag)| (name.as_str(), *flag));
+                match bitflags_ui(self.bits(), flags, ui, id, 8) {
+                    Some(bits) => {
+                        *self = Self::from_bits_truncate(bits);
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                _: InspectorUi<'_, '_>,
+            ) {
+                let labels: Vec<_> = Self::all()
+                    .iter_names()
+                    .map(|(name, flag)| (flag_label(name), flag.bits()))
+                    .collect();
+                let flags = labels.iter().map(|(name, flag)| (name.as_str(), *flag));
+                bitflags_ui_readonly(self.bits(), flags, ui, id, 8);
+            }
+        }
+
+        impl InspectorPrimitive for $rapier::dynamics::LockedAxes {
+            fn ui(
+                &mut self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                _: InspectorUi<'_, '_>,
+            ) -> bool {
+                let labels: Vec<_> = Self::all()
+                    .iter_names()
+                    .map(|(name, flag)| (flag_label(name), u32::from(flag.bits())))
+                    .collect();
+                let flags = labels.iter().map(|(name, flag)| (name.as_str(), *flag));
+                match bitflags_ui(u32::from(self.bits()), flags, ui, id, 3) {
+                    Some(bits) => {
+                        *self = Self::from_bits_truncate(bits as u8);
+                        true
+                    }
+                    None => false,
+                }
+            }
+
+            fn ui_readonly(
+                &self,
+                ui: &mut egui::Ui,
+                _: &dyn Any,
+                id: egui::Id,
+                _: InspectorUi<'_, '_>,
+            ) {
+                let labels: Vec<_> = Self::all()
+                    .iter_names()
+                    .map(|(name, flag)| (flag_label(name), u32::from(flag.bits())))
+                    .collect();
+                let flags = labels.iter().map(|(name, flag)| (name.as_str(), *flag));
+                bitflags_ui_readonly(u32::from(self.bits()), flags, ui, id, 3);
+            }
+        }
+
+        /// Register [`InspectorEguiImpl`](super::InspectorEguiImpl)s for the rapier bitflag types
+        pub fn $register(type_registry: &mut TypeRegistry) {
+            type_registry.register::<$rapier::geometry::Group>();
+            type_registry.register::<$rapier::dynamics::LockedAxes>();
+            super::add::<$rapier::geometry::Group>(type_registry);
+            super::add::<$rapier::dynamics::LockedAxes>(type_registry);
+        }
+    };
+}
+
+#[cfg(feature = "rapier2d")]
+rapier_impls!(bevy_rapier2d, register_rapier2d_impls);
+#[cfg(feature = "rapier3d")]
+rapier_impls!(bevy_rapier3d, register_rapier3d_impls);
@@ -0,0 +1,350 @@
+use std::any::Any;
+
+use bevy_asset::Assets;
+use bevy_math::{URect, Vec2};
+use bevy_sprite::{Anchor, Sprite, TextureAtlas, TextureAtlasLayout};
+use egui::load::SizedTexture;
+
+use crate::{reflect_inspector::InspectorUi, restricted_world_view::RestrictedWorldView};
+
+use super::{gradient::to_color32, image::image_texture, InspectorPrimitive};
+
+/// Maximum side length of the atlas frame picker
+const ATLAS_PREVIEW_SIZE: f32 = 256.0;
+/// Side length of the preview of the displayed frame
+const FRAME_PREVIEW_SIZE: f32 = 64.0;
+
+/// The named anchors, in the layout of the anchor picker
+const ANCHOR_GRID: [[Anchor; 3]; 3] = [
+    [Anchor::TopLeft, Anchor::TopCenter, Anchor::TopRight],
+    [Anchor::CenterLeft, Anchor::Center, Anchor::CenterRight],
+    [
+        Anchor::BottomLeft,
+        Anchor::BottomCenter,
+        Anchor::BottomRight,
+    ],
+];
+
+fn atlas_layout(
+    atlas: &TextureAtlas,
+    world: &mut RestrictedWorldView,
+) -> Option<TextureAtlasLayout> {
+    let layouts = world
+        .get_resource_mut::<Assets<TextureAtlasLayout>>()
+        .ok()?;
+    layouts.get(&atlas.layout).cloned()
+}
+
+/// Sprites show their texture atlas as a frame picker, and flip and anchor next to a preview of the displayed frame.
+impl InspectorPrimitive for Sprite {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("image");
+            changed |= env.ui_for_reflect_with_options(&mut self.image, ui, id.with("image"), &());
+            ui.end_row();
+
+            ui.label("texture_atlas");
+            changed |= env.ui_for_reflect_with_options(
+                &mut self.texture_atlas,
+                ui,
+                id.with("texture_atlas"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("color");
+            changed |= env.ui_for_reflect_with_options(&mut self.color, ui, id.with("color"), &());
+            ui.end_row();
+
+            ui.label("custom_size");
+            changed |= env.ui_for_reflect_with_options(
+                &mut self.custom_size,
+                ui,
+                id.with("custom_size"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("rect");
+            changed |= env.ui_for_reflect_with_options(&mut self.rect, ui, id.with("rect"), &());
+            ui.end_row();
+
+            ui.label("image_mode");
+            changed |= env.ui_for_reflect_with_options(
+                &mut self.image_mode,
+                ui,
+                id.with("image_mode"),
+                &(),
+            );
+            ui.end_row();
+
+            ui.label("flip");
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .toggle_value(&mut self.flip_x, "⬌ x")
+                    .on_hover_text("Flip horizontally")
+                    .changed();
+                changed |= ui
+                    .toggle_value(&mut self.flip_y, "⬍ y")
+                    .on_hover_text("Flip vertically")
+                    .changed();
+            });
+            ui.end_row();
+
+            ui.label("anchor");
+            ui.horizontal(|ui| {
+                changed |= anchor_picker(&mut self.anchor, ui);
+                if let Anchor::Custom(custom) = &mut self.anchor {
+                    changed |=
+                        env.ui_for_reflect_with_options(custom, ui, id.with("custom_anchor"), &());
+                }
+            });
+            ui.end_row();
+        });
+
+        let Some(world) = &mut env.context.world else {
+            return changed;
+        };
+        let texture = image_texture(&self.image, world);
+        let layout = self
+            .texture_atlas
+            .as_ref()
+            .and_then(|atlas| atlas_layout(atlas, world));
+
+        ui.horizontal_top(|ui| {
+            if let (Some(atlas), Some(layout)) = (&mut self.texture_atlas, &layout) {
+                changed |= atlas_frame_picker(atlas, layout, texture, ui);
+            }
+            frame_preview(self, layout.as_ref(), texture, ui);
+        });
+
+        changed
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+/// Texture atlases show their index next to the number of frames in the layout.
+///
+/// The frame picker is shown below [`Sprite`]s, where the image of the atlas is known.
+impl InspectorPrimitive for TextureAtlas {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        mut env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let layout = env
+            .context
+            .world
+            .as_mut()
+            .and_then(|world| atlas_layout(self, world));
+
+        let mut changed = false;
+        egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+            ui.label("layout");
+            changed |=
+                env.ui_for_reflect_with_options(&mut self.layout, ui, id.with("layout"), &());
+            ui.end_row();
+
+            ui.label("index");
+            ui.horizontal(|ui| {
+                let frames = layout.as_ref().map_or(0, |layout| layout.textures.len());
+                let mut drag = egui::DragValue::new(&mut self.index);
+                if frames > 0 {
+                    drag = drag.range(0..=frames - 1);
+                }
+                changed |= ui.add(drag).changed();
+                if frames > 0 {
+                    ui.weak(format!("of {frames}"));
+                }
+            });
+            ui.end_row();
+        });
+
+        changed
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+/// The atlas layout scaled down, with the texture behind it if available.
+/// Clicking a frame selects it as the atlas index.
+fn atlas_frame_picker(
+    atlas: &mut TextureAtlas,
+    layout: &TextureAtlasLayout,
+    texture: Option<SizedTexture>,
+    ui: &mut egui::Ui,
+) -> bool {
+    let layout_size = layout.size.as_vec2();
+    if layout_size.x <= 0.0 || layout_size.y <= 0.0 {
+        return false;
+    }
+    let scale =
+        (ATLAS_PREVIEW_SIZE / layout_size.max_element()).min(ui.available_width() / layout_size.x);
+    let size = egui::vec2(layout_size.x, layout_size.y) * scale;
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+
+    match texture {
+        Some(texture) => egui::Image::new(texture).paint_at(ui, rect),
+        None => {
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+        }
+    }
+
+    let to_preview = |frame: URect| {
+        let min = frame.min.as_vec2() * scale;
+        let max = frame.max.as_vec2() * scale;
+        egui::Rect::from_min_max(
+            rect.min + egui::vec2(min.x, min.y),
+            rect.min + egui::vec2(max.x, max.y),
+        )
+    };
+    let hovered = response.hover_pos().and_then(|pos| {
+        layout
+            .textures
+            .iter()
+            .position(|frame| to_preview(*frame).contains(pos))
+    });
+
+    for (i, frame) in layout.textures.iter().enumerate() {
+        let frame_rect = to_preview(*frame);
+        let stroke = match i {
+            _ if i == atlas.index => egui::Stroke::new(2.0, visuals.selection.stroke.color),
+            _ if Some(i) == hovered => egui::Stroke::new(1.0, visuals.warn_fg_color),
+            _ => egui::Stroke::new(1.0, visuals.weak_text_color().gamma_multiply(0.6)),
+        };
+        painter.rect_stroke(frame_rect, 0.0, stroke);
+    }
+
+    let mut changed = false;
+    if let Some(hovered) = hovered {
+        if response.clicked() && hovered != atlas.index {
+            atlas.index = hovered;
+            changed = true;
+        }
+        response.on_hover_text(format!("frame {hovered}"));
+    }
+    changed
+}
+
+/// The currently displayed part of the sprite image, flipped like the sprite, with a dot at its anchor
+fn frame_preview(
+    sprite: &Sprite,
+    layout: Option<&TextureAtlasLayout>,
+    texture: Option<SizedTexture>,
+    ui: &mut egui::Ui,
+) {
+    let Some(texture) = texture else {
+        return;
+    };
+    let texture_size = Vec2::new(texture.size.x, texture.size.y);
+    if texture_size.x <= 0.0 || texture_size.y <= 0.0 {
+        return;
+    }
+
+    // the atlas frame is relative to the layout size, which may differ from the image size
+    let (min, max) = match (&sprite.texture_atlas, layout) {
+        (Some(atlas), Some(layout)) => match layout.textures.get(atlas.index) {
+            Some(frame) => {
+                let layout_size = layout.size.as_vec2().max(Vec2::ONE);
+                (
+                    frame.min.as_vec2() / layout_size,
+                    frame.max.as_vec2() / layout_size,
+                )
+            }
+            None => (Vec2::ZERO, Vec2::ONE),
+        },
+        _ => (Vec2::ZERO, Vec2::ONE),
+    };
+    let (min, max) = match sprite.rect {
+        Some(rect) => (min + rect.min / texture_size, min + rect.max / texture_size),
+        None => (min, max),
+    };
+
+    let frame_size = (max - min) * texture_size;
+    if frame_size.x <= 0.0 || frame_size.y <= 0.0 {
+        return;
+    }
+    let scale = FRAME_PREVIEW_SIZE / frame_size.max_element();
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(frame_size.x, frame_size.y) * scale,
+        egui::Sense::hover(),
+    );
+
+    let (mut uv_min, mut uv_max) = (egui::pos2(min.x, min.y), egui::pos2(max.x, max.y));
+    if sprite.flip_x {
+        std::mem::swap(&mut uv_min.x, &mut uv_max.x);
+    }
+    if sprite.flip_y {
+        std::mem::swap(&mut uv_min.y, &mut uv_max.y);
+    }
+    egui::Image::new(texture)
+        .uv(egui::Rect::from_min_max(uv_min, uv_max))
+        .tint(to_color32(sprite.color))
+        .paint_at(ui, rect);
+
+    let visuals = ui.visuals();
+    let painter = ui.painter_at(rect.expand(4.0));
+    painter.rect_stroke(rect, 0.0, visuals.widgets.noninteractive.bg_stroke);
+    // anchors have y pointing up, with the center of the sprite at the origin
+    let anchor = sprite.anchor.as_vec();
+    let anchor_pos = rect.center() + egui::vec2(anchor.x, -anchor.y) * rect.size();
+    painter.circle(
+        anchor_pos,
+        3.0,
+        visuals.warn_fg_color,
+        egui::Stroke::new(1.0, visuals.extreme_bg_color),
+    );
+}
+
+/// A 3x3 grid of the named anchors, and a toggle for a custom anchor
+fn anchor_picker(anchor: &mut Anchor, ui: &mut egui::Ui) -> bool {
+    let mut changed = false;
+    ui.vertical(|ui| {
+        ui.spacing_mut().item_spacing = egui::vec2(2.0, 2.0);
+        for row in ANCHOR_GRID {
+            ui.horizontal(|ui| {
+                for candidate in row {
+                    let selected = *anchor == candidate;
+                    let text = if selected { "●" } else { "○" };
+                    if ui
+                        .selectable_label(selected, text)
+                        .on_hover_text(format!("{candidate:?}"))
+                        .clicked()
+                        && !selected
+                    {
+                        *anchor = candidate;
+                        changed = true;
+                    }
+                }
+            });
+        }
+    });
+
+    let mut custom = matches!(anchor, Anchor::Custom(_));
+    if ui.toggle_value(&mut custom, "custom").changed() {
+        *anchor = match custom {
+            true => Anchor::Custom(anchor.as_vec()),
+            false => Anchor::Center,
+        };
+        changed = true;
+    }
+    changed
+}
@@ -2,17 +2,35 @@ use std::{borrow::Cow, ops::AddAssign, path::PathBuf};
 
 use bevy_reflect::{PartialReflect, Reflect, TypePath};
 use bevy_utils::Instant;
-use egui::{DragValue, RichText, TextBuffer};
+use egui::{DragValue, RichText};
 
-use super::{change_slider, iter_all_eq, InspectorPrimitive, InspectorUi};
+use super::{
+    change_slider,
+    file_picker::{file_path_ui, file_path_ui_readonly},
+    iter_all_eq, relative_change_slider, InspectorPrimitive, InspectorUi, MultiEditMode,
+};
 use crate::{
+    egui_utils::set_error_style,
     inspector_options::{
-        std_options::{NumberDisplay, NumberOptions, RangeOptions},
+        std_options::{
+            FilePickerOptions, NumberDisplay, NumberOptions, NumberUnit, RangeOptions,
+            StringOptions,
+        },
         InspectorOptionsType,
     },
     reflect_inspector::ProjectorReflect,
+    theme::InspectorTheme,
+};
+use std::{
+    any::Any,
+    fmt::Display,
+    num::{
+        NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+        NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    str::FromStr,
+    time::Duration,
 };
-use std::{any::Any, time::Duration};
 
 // just for orphan rules
 trait Num: egui::emath::Numeric {}
@@ -53,19 +71,10 @@ impl<T: Reflect + Num> InspectorPrimitive for T {
             .downcast_ref::<NumberOptions<T>>()
             .cloned()
             .unwrap_or_default();
-        let decimal_range = 0..=1usize;
         ui.add(
-            egui::Button::new(
-                RichText::new(format!(
-                    "{}{}{}",
-                    options.prefix,
-                    egui::emath::format_with_decimals_in_range(self.to_f64(), decimal_range),
-                    options.suffix
-                ))
-                .monospace(),
-            )
-            .truncate()
-            .sense(egui::Sense::hover()),
+            egui::Button::new(RichText::new(number_text(*self, &options)).monospace())
+                .truncate()
+                .sense(egui::Sense::hover()),
         );
     }
 }
@@ -96,28 +105,50 @@ pub fn number_ui_readonly<T: egui::emath::Numeric>(
         .downcast_ref::<NumberOptions<T>>()
         .cloned()
         .unwrap_or_default();
-    let decimal_range = 0..=1usize;
     ui.add(
-        egui::Button::new(
-            RichText::new(format!(
-                "{}{}{}",
-                options.prefix,
-                egui::emath::format_with_decimals_in_range(value.to_f64(), decimal_range),
-                options.suffix
-            ))
-            .monospace(),
-        )
-        .truncate()
-        .sense(egui::Sense::hover()),
+        egui::Button::new(RichText::new(number_text(*value, &options)).monospace())
+            .truncate()
+            .sense(egui::Sense::hover()),
     );
 }
 
+/// The text of a read-only number, converted to its [`NumberUnit`]
+fn number_text<T: egui::emath::Numeric>(value: T, options: &NumberOptions<T>) -> String {
+    let (scale, suffix) = match options.unit {
+        Some(unit) if options.suffix.is_empty() => (unit_scale::<T>(unit), unit.suffix()),
+        Some(unit) => (unit_scale::<T>(unit), options.suffix.as_str()),
+        None => (1.0, options.suffix.as_str()),
+    };
+    let decimal_range = 0..=1usize;
+    format!(
+        "{}{}{}",
+        options.prefix,
+        egui::emath::format_with_decimals_in_range(value.to_f64() * scale, decimal_range),
+        suffix
+    )
+}
+
+/// Integers are displayed without conversion, since they couldn't store the fractions of the converted value
+fn unit_scale<T: egui::emath::Numeric>(unit: NumberUnit) -> f64 {
+    match T::INTEGRAL {
+        true => 1.0,
+        false => unit.display_scale(),
+    }
+}
+
 fn display_number<T: egui::emath::Numeric>(
     value: &mut T,
     options: &NumberOptions<T>,
     ui: &mut egui::Ui,
     default_speed: f32,
 ) -> bool {
+    if let Some(unit) = options.unit {
+        return display_number_in_unit(value, options, unit, ui, default_speed);
+    }
+    if InspectorTheme::current(ui.ctx()).number_steppers {
+        return number_stepper(value, options, ui, default_speed);
+    }
+
     let mut changed = match options.display {
         NumberDisplay::Drag => {
             let mut widget = egui::DragValue::new(value);
@@ -129,8 +160,8 @@ fn display_number<T: egui::emath::Numeric>(
             }
             match (options.min, options.max) {
                 (Some(min), Some(max)) => widget = widget.range(min.to_f64()..=max.to_f64()),
-                (Some(min), None) => widget = widget.range(min.to_f64()..=f64::MAX),
-                (None, Some(max)) => widget = widget.range(f64::MIN..=max.to_f64()),
+                (Some(min), None) => widget = widget.range(min.to_f64()..=T::MAX.to_f64()),
+                (None, Some(max)) => widget = widget.range(T::MIN.to_f64()..=max.to_f64()),
                 (None, None) => {}
             }
             if options.speed != 0.0 {
@@ -141,36 +172,197 @@ fn display_number<T: egui::emath::Numeric>(
             ui.add(widget).changed()
         }
         NumberDisplay::Slider => {
-            let min = options.min.unwrap_or_else(|| T::from_f64(0.0));
-            let max = options.max.unwrap_or_else(|| T::from_f64(1.0));
-            let range = min..=max;
-            let widget = egui::Slider::new(value, range);
+            // a missing bound defaults to 0 or 1, or to one away from the other bound if that would be out of order
+            let (min, max) = match (options.min, options.max) {
+                (Some(min), Some(max)) => (min, max),
+                (Some(min), None) => (min, T::from_f64(min.to_f64().max(0.0) + 1.0)),
+                (None, Some(max)) => (T::from_f64(max.to_f64().min(1.0) - 1.0), max),
+                (None, None) => (T::from_f64(0.0), T::from_f64(1.0)),
+            };
+            let widget = egui::Slider::new(value, min..=max);
             ui.add(widget).changed()
         }
     };
 
+    changed |= clamp_number(value, options);
+    changed
+}
+
+/// Displays `value` converted to `unit`, with the suffix and drag speed of the unit unless `options` set their own
+fn display_number_in_unit<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    unit: NumberUnit,
+    ui: &mut egui::Ui,
+    default_speed: f32,
+) -> bool {
+    let suffix = match options.suffix.is_empty() {
+        true => unit.suffix().to_owned(),
+        false => options.suffix.clone(),
+    };
+    let speed = if options.speed != 0.0 {
+        options.speed
+    } else {
+        unit.default_speed().unwrap_or(0.0)
+    };
+
+    let scale = unit_scale::<T>(unit);
+    if scale == 1.0 {
+        let options = NumberOptions {
+            suffix,
+            speed,
+            unit: None,
+            ..options.clone()
+        };
+        return display_number(value, &options, ui, default_speed);
+    }
+
+    let displayed_options = NumberOptions {
+        min: options.min.map(|min| min.to_f64() * scale),
+        max: options.max.map(|max| max.to_f64() * scale),
+        speed,
+        prefix: options.prefix.clone(),
+        suffix,
+        display: options.display,
+        unit: None,
+    };
+    let mut displayed = value.to_f64() * scale;
+    if !display_number(&mut displayed, &displayed_options, ui, default_speed) {
+        return false;
+    }
+    *value = T::from_f64(displayed / scale);
+    clamp_number(value, options);
+    true
+}
+
+/// A number between −/+ buttons, with a slider in between if the number has bounds. Used by [`InspectorTheme::touch`].
+fn number_stepper<T: egui::emath::Numeric>(
+    value: &mut T,
+    options: &NumberOptions<T>,
+    ui: &mut egui::Ui,
+    default_speed: f32,
+) -> bool {
+    let step = match (options.min, options.max) {
+        _ if T::INTEGRAL => 1.0,
+        (Some(min), Some(max)) => (max.to_f64() - min.to_f64()) / 20.0,
+        _ if options.speed != 0.0 => options.speed as f64,
+        _ => default_speed as f64,
+    };
+
+    let mut changed = ui
+        .horizontal(|ui| {
+            let mut changed = false;
+            let can_decrease = options.min.is_none_or(|min| *value > min);
+            if ui
+                .add_enabled(can_decrease, egui::Button::new("−"))
+                .clicked()
+            {
+                *value = T::from_f64(value.to_f64() - step);
+                changed = true;
+            }
+            changed |= match (options.min, options.max) {
+                (Some(min), Some(max)) => {
+                    let widget = egui::Slider::new(value, min..=max)
+                        .prefix(&options.prefix)
+                        .suffix(&options.suffix);
+                    ui.add(widget).changed()
+                }
+                _ => {
+                    let widget = DragValue::new(value)
+                        .prefix(&options.prefix)
+                        .suffix(&options.suffix)
+                        .speed(step);
+                    ui.add(widget).changed()
+                }
+            };
+            let can_increase = options.max.is_none_or(|max| *value < max);
+            if ui
+                .add_enabled(can_increase, egui::Button::new("+"))
+                .clicked()
+            {
+                *value = T::from_f64(value.to_f64() + step);
+                changed = true;
+            }
+            changed
+        })
+        .inner;
+
+    changed |= clamp_number(value, options);
+    changed
+}
+
+/// Clamps `value` into the bounds of `options`, comparing in `T` so that large integers don't lose precision
+fn clamp_number<T: egui::emath::Numeric>(value: &mut T, options: &NumberOptions<T>) -> bool {
     if let Some(min) = options.min {
-        let as_f64 = value.to_f64();
-        let min = min.to_f64();
-        if as_f64 < min {
-            *value = T::from_f64(min);
-            changed = true;
+        if *value < min {
+            *value = min;
+            return true;
         }
     }
     if let Some(max) = options.max {
-        let as_f64 = value.to_f64();
-        let max = max.to_f64();
-        if as_f64 > max {
-            *value = T::from_f64(max);
-            changed = true;
+        if *value > max {
+            *value = max;
+            return true;
         }
     }
-    changed
+    false
 }
 
+macro_rules! impl_nonzero {
+    ($($nonzero:ty => $int:ty),*) => {
+        $(
+            impl InspectorPrimitive for $nonzero {
+                fn ui(
+                    &mut self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    _: egui::Id,
+                    _: InspectorUi<'_, '_>,
+                ) -> bool {
+                    let options = options
+                        .downcast_ref::<NumberOptions<$int>>()
+                        .cloned()
+                        .unwrap_or_default();
+                    let mut value = self.get();
+                    if !display_number(&mut value, &options, ui, 0.1) {
+                        return false;
+                    }
+                    // zero is skipped in the direction of the change, or replaced by one for unsigned numbers
+                    let skip_zero = match self.get() > 0 {
+                        true => <$int>::checked_sub(0, 1),
+                        false => Some(1),
+                    };
+                    match <$nonzero>::new(value).or_else(|| skip_zero.and_then(<$nonzero>::new)) {
+                        Some(value) if value != *self => {
+                            *self = value;
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+
+                fn ui_readonly(
+                    &self,
+                    ui: &mut egui::Ui,
+                    options: &dyn Any,
+                    id: egui::Id,
+                    env: InspectorUi<'_, '_>,
+                ) {
+                    self.get().ui_readonly(ui, options, id, env);
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroIsize => isize
+);
+
 pub fn number_ui_many<T>(
     ui: &mut egui::Ui,
-    _: &dyn Any,
+    options: &dyn Any,
     id: egui::Id,
     _env: InspectorUi<'_, '_>,
     values: &mut [&mut dyn PartialReflect],
@@ -179,6 +371,10 @@ pub fn number_ui_many<T>(
 where
     T: Reflect + egui::emath::Numeric + AddAssign<T>,
 {
+    let options = options
+        .downcast_ref::<NumberOptions<T>>()
+        .cloned()
+        .unwrap_or_default();
     let same = iter_all_eq(
         values
             .iter_mut()
@@ -186,19 +382,38 @@ where
     )
     .map(T::to_f64);
 
-    change_slider(ui, id, same, |change, overwrite| {
-        for value in values.iter_mut() {
-            let value = projector(*value)
-                .try_downcast_mut::<T>()
-                .expect("non-fully-reflected value passed to number_ui_many");
-            let change = T::from_f64(change);
-            if overwrite {
-                *value = change;
-            } else {
-                *value += change;
-            }
+    ui.horizontal(|ui| {
+        let mode = MultiEditMode::toggle(ui, id.with("multi_edit_mode"));
+        if mode != MultiEditMode::Absolute {
+            // relative changes keep the differences between the values
+            return relative_change_slider(ui, id.with("relative"), mode, T::INTEGRAL, |change| {
+                for value in values.iter_mut() {
+                    let value = projector(*value)
+                        .try_downcast_mut::<T>()
+                        .expect("non-fully-reflected value passed to number_ui_many");
+                    let new = change.apply(value.to_f64());
+                    *value = T::from_f64(if T::INTEGRAL { new.round() } else { new });
+                    clamp_number(value, &options);
+                }
+            });
         }
+
+        change_slider(ui, id, same, |change, overwrite| {
+            for value in values.iter_mut() {
+                let value = projector(*value)
+                    .try_downcast_mut::<T>()
+                    .expect("non-fully-reflected value passed to number_ui_many");
+                let change = T::from_f64(change);
+                if overwrite {
+                    *value = change;
+                } else {
+                    *value += change;
+                }
+                clamp_number(value, &options);
+            }
+        })
     })
+    .inner
 }
 
 impl InspectorPrimitive for bool {
@@ -221,31 +436,50 @@ impl InspectorPrimitive for bool {
 }
 
 impl InspectorPrimitive for String {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        if self.contains('\n') {
-            ui.text_edit_multiline(self).changed()
-        } else {
-            ui.text_edit_singleline(self).changed()
-        }
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+        display_string(self, &options, ui)
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
-            ui.text_edit_multiline(&mut self.as_str());
-        } else {
-            ui.text_edit_singleline(&mut self.as_str());
-        }
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+        display_string_readonly(self, &options, ui);
     }
 }
 
 impl InspectorPrimitive for Cow<'static, str> {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+
         let mut clone = self.to_string();
-        let changed = if self.contains('\n') {
-            ui.text_edit_multiline(&mut clone).changed()
-        } else {
-            ui.text_edit_singleline(&mut clone).changed()
-        };
+        let changed = display_string(&mut clone, &options, ui);
 
         if changed {
             *self = Cow::Owned(clone);
@@ -254,13 +488,60 @@ impl InspectorPrimitive for Cow<'static, str> {
         changed
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        if self.contains('\n') {
-            ui.text_edit_multiline(&mut self.as_str());
-        } else {
-            ui.text_edit_singleline(&mut self.as_str());
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+        display_string_readonly(self, &options, ui);
+    }
+}
+
+fn display_string(value: &mut String, options: &StringOptions, ui: &mut egui::Ui) -> bool {
+    if let Some(file_picker) = &options.file_picker {
+        return file_path_ui(value, file_picker, ui);
+    }
+
+    let edit = if options.multiline || value.contains('\n') {
+        egui::TextEdit::multiline(value)
+    } else {
+        egui::TextEdit::singleline(value)
+    };
+    let mut edit = edit.password(options.password);
+    if let Some(max_len) = options.max_len {
+        edit = edit.char_limit(max_len);
+    }
+    let mut changed = ui.add(edit).changed();
+
+    // the char limit only applies to newly typed text, so also truncate values set from elsewhere
+    if let Some(max_len) = options.max_len {
+        if let Some((idx, _)) = value.char_indices().nth(max_len) {
+            value.truncate(idx);
+            changed = true;
         }
     }
+
+    changed
+}
+
+fn display_string_readonly(value: &str, options: &StringOptions, ui: &mut egui::Ui) {
+    if let Some(file_picker) = &options.file_picker {
+        return file_path_ui_readonly(value, file_picker, ui);
+    }
+
+    let mut value = value;
+    let edit = if options.multiline || value.contains('\n') {
+        egui::TextEdit::multiline(&mut value)
+    } else {
+        egui::TextEdit::singleline(&mut value)
+    };
+    ui.add(edit.password(options.password));
 }
 
 impl InspectorPrimitive for Duration {
@@ -323,6 +604,102 @@ impl InspectorPrimitive for Instant {
     }
 }
 
+impl InspectorPrimitive for uuid::Uuid {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        parsed_text_ui(self, ui, id)
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
+        ui.label(self.to_string());
+    }
+}
+
+#[cfg(feature = "smol_str")]
+impl InspectorPrimitive for smol_str::SmolStr {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+        let mut value = self.to_string();
+        let changed = display_string(&mut value, &options, ui);
+        if changed {
+            *self = smol_str::SmolStr::from(value);
+        }
+        changed
+    }
+
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<StringOptions>()
+            .cloned()
+            .unwrap_or_default();
+        display_string_readonly(self, &options, ui);
+    }
+}
+
+/// Single line text edit for values that round-trip through [`FromStr`] and [`Display`].
+///
+/// While the text is being edited or doesn't parse it is kept in temporary storage,
+/// so that intermediate states can be typed. Parse errors are shown on hover.
+fn parsed_text_ui<T>(value: &mut T, ui: &mut egui::Ui, id: egui::Id) -> bool
+where
+    T: FromStr + Display,
+    T::Err: Display,
+{
+    let buffer_id = id.with("parse_buffer");
+    let mut text = ui
+        .memory_mut(|memory| memory.data.get_temp::<String>(buffer_id))
+        .unwrap_or_else(|| value.to_string());
+
+    let invalid = text.parse::<T>().is_err();
+    let response = ui
+        .scope(|ui| {
+            if invalid {
+                set_error_style(ui);
+            }
+            ui.add(egui::TextEdit::singleline(&mut text))
+        })
+        .inner;
+
+    let parsed = text.parse::<T>();
+    let keep_text = response.has_focus() || parsed.is_err();
+    let mut changed = false;
+    match parsed {
+        Ok(parsed) => {
+            if response.changed() {
+                *value = parsed;
+                changed = true;
+            }
+        }
+        Err(error) => {
+            response.on_hover_text(error.to_string());
+        }
+    }
+
+    ui.memory_mut(|memory| {
+        if keep_text {
+            memory.data.insert_temp(buffer_id, text);
+        } else {
+            memory.data.remove::<String>(buffer_id);
+        }
+    });
+
+    changed
+}
+
 impl<T: Reflect + TypePath + egui::emath::Numeric + InspectorOptionsType> InspectorPrimitive
     for std::ops::Range<T>
 {
@@ -454,18 +831,57 @@ impl<T: Reflect + TypePath + egui::emath::Numeric + InspectorOptionsType> Inspec
 }
 
 impl InspectorPrimitive for PathBuf {
-    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
-        let mut str = self.to_string_lossy();
-        let changed = ui.text_edit_singleline(&mut str).changed();
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) -> bool {
+        let options = options
+            .downcast_ref::<FilePickerOptions>()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut str = self.to_string_lossy().into_owned();
+        let changed = file_path_ui(&mut str, &options, ui);
 
         if changed {
-            *self = PathBuf::from(str.as_str());
+            *self = PathBuf::from(str);
         }
 
         changed
     }
 
-    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) {
-        ui.text_edit_singleline(&mut self.to_string_lossy());
+    fn ui_readonly(
+        &self,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        _: egui::Id,
+        _: InspectorUi<'_, '_>,
+    ) {
+        let options = options
+            .downcast_ref::<FilePickerOptions>()
+            .cloned()
+            .unwrap_or_default();
+        file_path_ui_readonly(&self.to_string_lossy(), &options, ui);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::number_text;
+    use crate::inspector_options::std_options::{NumberOptions, NumberUnit};
+
+    #[test]
+    fn number_text_converts_to_unit() {
+        let degrees = NumberOptions::default().with_unit(NumberUnit::Degrees);
+        assert_eq!(number_text(std::f32::consts::FRAC_PI_2, &degrees), "90°");
+
+        let percent = NumberOptions::default().with_unit(NumberUnit::Percent);
+        assert_eq!(number_text(0.25f64, &percent), "25%");
+        // integers can't store the converted fractions, so they only get the suffix
+        let percent = NumberOptions::<u8>::default().with_unit(NumberUnit::Percent);
+        assert_eq!(number_text(25u8, &percent), "25%");
     }
 }
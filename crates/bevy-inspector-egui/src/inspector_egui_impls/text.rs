@@ -0,0 +1,272 @@
+use std::any::{Any, TypeId};
+
+use bevy_asset::{AssetServer, Assets, Handle};
+use bevy_color::Color;
+use bevy_ecs::entity::Entity;
+use bevy_hierarchy::Children;
+use bevy_reflect::{FromReflect, TypeRegistry};
+use bevy_text::{Font, JustifyText, Text2d, TextColor, TextFont, TextSpan};
+
+use crate::{
+    bevy_inspector::errors::{no_world_in_context, show_error},
+    dropdown::DropDownBox,
+    reflect_inspector::InspectorUi,
+    restricted_world_view::RestrictedWorldView,
+    utils::pretty_type_name,
+};
+
+use super::{gradient::to_color32, InspectorPrimitive};
+
+/// Font sizes above this are clamped in the preview, so that it stays readable in the inspector
+const MAX_PREVIEW_FONT_SIZE: f32 = 48.0;
+
+fn component<T: FromReflect>(
+    world: &mut RestrictedWorldView<'_>,
+    entity: Entity,
+    type_registry: &TypeRegistry,
+) -> Option<T> {
+    let value = world
+        .get_entity_component_reflect(entity, TypeId::of::<T>(), type_registry)
+        .ok()?;
+    T::from_reflect(value.as_partial_reflect())
+}
+
+/// Font size and color of a text section
+struct SectionStyle {
+    font_size: f32,
+    color: Color,
+}
+
+impl SectionStyle {
+    fn of(world: &mut RestrictedWorldView, entity: Entity, type_registry: &TypeRegistry) -> Self {
+        let font_size = component::<TextFont>(world, entity, type_registry)
+            .unwrap_or_default()
+            .font_size;
+        let color = component::<TextColor>(world, entity, type_registry)
+            .unwrap_or_default()
+            .0;
+        SectionStyle { font_size, color }
+    }
+
+    fn append(&self, text: &str, job: &mut egui::text::LayoutJob) {
+        job.append(
+            text,
+            0.0,
+            egui::TextFormat::simple(
+                egui::FontId::proportional(self.font_size.clamp(1.0, MAX_PREVIEW_FONT_SIZE)),
+                to_color32(self.color),
+            ),
+        );
+    }
+}
+
+/// Multiline editor for the string of a text section, followed by a preview with the font size and color of every section.
+///
+/// For text roots, the [`TextSpan`] children are included in the preview.
+fn text_ui(text: &mut String, ui: &mut egui::Ui, id: egui::Id, env: InspectorUi<'_, '_>) -> bool {
+    let rows = text.lines().count().clamp(1, 8);
+    let changed = ui
+        .add(
+            egui::TextEdit::multiline(text)
+                .id(id.with("text"))
+                .desired_rows(rows)
+                .desired_width(f32::INFINITY),
+        )
+        .changed();
+    text_preview(text, ui, env);
+    changed
+}
+
+fn text_preview(text: &str, ui: &mut egui::Ui, env: InspectorUi<'_, '_>) {
+    let (Some(entity), Some(world)) = (
+        env.watch.as_ref().and_then(|watch| watch.entity()),
+        env.context.world.as_mut(),
+    ) else {
+        return;
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    SectionStyle::of(world, entity, env.type_registry).append(text, &mut job);
+
+    let children = component::<Children>(world, entity, env.type_registry);
+    for child in children.iter().flat_map(|children| children.iter()) {
+        let Some(span) = component::<TextSpan>(world, *child, env.type_registry) else {
+            continue;
+        };
+        SectionStyle::of(world, *child, env.type_registry).append(&span.0, &mut job);
+    }
+
+    egui::Frame::canvas(ui.style()).show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.label(job);
+    });
+}
+
+impl InspectorPrimitive for Text2d {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        text_ui(&mut self.0, ui, id, env)
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+impl InspectorPrimitive for TextSpan {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        text_ui(&mut self.0, ui, id, env)
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+#[cfg(feature = "bevy_ui")]
+impl InspectorPrimitive for bevy_ui::widget::Text {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        text_ui(&mut self.0, ui, id, env)
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = self.clone();
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+/// Justification as a row of toggle buttons
+impl InspectorPrimitive for JustifyText {
+    fn ui(&mut self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi<'_, '_>) -> bool {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            for (justify, text) in [
+                (JustifyText::Left, "Left"),
+                (JustifyText::Center, "Center"),
+                (JustifyText::Right, "Right"),
+                (JustifyText::Justified, "Justified"),
+            ] {
+                changed |= ui.selectable_value(self, justify, text).changed();
+            }
+        });
+        changed
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, id: egui::Id, env: InspectorUi<'_, '_>) {
+        let mut copy = *self;
+        ui.add_enabled_ui(false, |ui| copy.ui(ui, &(), id, env));
+    }
+}
+
+/// The path of the font or `<default font>`
+fn font_name(font: &Handle<Font>, asset_server: &AssetServer) -> String {
+    match asset_server.get_path(font) {
+        Some(path) => path.to_string(),
+        None if *font == Handle::default() => "<default font>".to_owned(),
+        None => format!("{:?}", font.id()),
+    }
+}
+
+/// Font handles are shown as a picker of the loaded fonts
+impl InspectorPrimitive for Handle<Font> {
+    fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        _: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi<'_, '_>,
+    ) -> bool {
+        let Some(world) = &mut env.context.world else {
+            no_world_in_context(ui, &pretty_type_name::<Handle<Font>>());
+            return false;
+        };
+        let (asset_server, mut fonts) =
+            match world.get_two_resources_mut::<AssetServer, Assets<Font>>() {
+                (Ok(a), Ok(b)) => (a, b),
+                (a, b) => {
+                    if let Err(e) = a {
+                        show_error(e, ui, &pretty_type_name::<AssetServer>());
+                    }
+                    if let Err(e) = b {
+                        show_error(e, ui, &pretty_type_name::<Assets<Font>>());
+                    }
+                    return false;
+                }
+            };
+
+        let font_ids: Vec<_> = fonts.ids().collect();
+        let mut options: Vec<(String, Handle<Font>)> = font_ids
+            .into_iter()
+            .filter_map(|font_id| {
+                let handle = fonts.get_strong_handle(font_id)?;
+                Some((font_name(&handle, &asset_server), handle))
+            })
+            .collect();
+        options.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let search_id = id.with("font_picker_search_text");
+        let mut search_text =
+            ui.data_mut(|data| data.get_temp::<String>(search_id).unwrap_or_default());
+        let mut selected = None;
+        ui.horizontal(|ui| {
+            ui.label(font_name(self, &asset_server));
+            let dropdown = DropDownBox::from_iter(
+                options.iter().map(|(name, _)| name),
+                id.with("font_picker"),
+                &mut search_text,
+                |ui, name| {
+                    let response = ui.selectable_label(false, name);
+                    if response.clicked() {
+                        selected = options
+                            .iter()
+                            .find(|(n, _)| n == name)
+                            .map(|(_, handle)| handle.clone());
+                    }
+                    response
+                },
+            );
+            ui.add(dropdown);
+        });
+        ui.data_mut(|data| data.insert_temp(search_id, search_text));
+
+        match selected {
+            Some(handle) if handle != *self => {
+                *self = handle;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn ui_readonly(&self, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, env: InspectorUi<'_, '_>) {
+        let Some(world) = &mut env.context.world else {
+            no_world_in_context(ui, &pretty_type_name::<Handle<Font>>());
+            return;
+        };
+        match world.get_resource_mut::<AssetServer>() {
+            Ok(asset_server) => {
+                ui.label(font_name(self, &asset_server));
+            }
+            Err(e) => show_error(e, ui, &pretty_type_name::<AssetServer>()),
+        }
+    }
+}
@@ -63,6 +63,60 @@ fn insert_options_enum<T: 'static>(
     }
 }
 
+/// Registers the rapier component types before inserting options, since the physics plugin may be added later
+#[cfg(any(feature = "rapier2d", feature = "rapier3d"))]
+macro_rules! register_rapier_options {
+    ($type_registry:ident, $rapier:ident) => {{
+        use $rapier::prelude::{
+            AdditionalMassProperties, ColliderMassProperties, Damping, Friction, MassProperties,
+            Restitution, SoftCcd,
+        };
+
+        $type_registry.register::<Friction>();
+        $type_registry.register::<Restitution>();
+        $type_registry.register::<Damping>();
+        $type_registry.register::<SoftCcd>();
+        $type_registry.register::<MassProperties>();
+        $type_registry.register::<ColliderMassProperties>();
+        $type_registry.register::<AdditionalMassProperties>();
+
+        insert_options_struct::<Friction>(
+            $type_registry,
+            &[("coefficient", &NumberOptions::<f32>::positive())],
+        );
+        insert_options_struct::<Restitution>(
+            $type_registry,
+            &[("coefficient", &NumberOptions::<f32>::positive())],
+        );
+        insert_options_struct::<Damping>(
+            $type_registry,
+            &[
+                ("linear_damping", &NumberOptions::<f32>::positive()),
+                ("angular_damping", &NumberOptions::<f32>::positive()),
+            ],
+        );
+        insert_options_struct::<SoftCcd>(
+            $type_registry,
+            &[("prediction", &NumberOptions::<f32>::positive())],
+        );
+        insert_options_struct::<MassProperties>(
+            $type_registry,
+            &[("mass", &NumberOptions::<f32>::positive())],
+        );
+        insert_options_enum::<ColliderMassProperties>(
+            $type_registry,
+            &[
+                ("Density", "0", &NumberOptions::<f32>::positive()),
+                ("Mass", "0", &NumberOptions::<f32>::positive()),
+            ],
+        );
+        insert_options_enum::<AdditionalMassProperties>(
+            $type_registry,
+            &[("Mass", "0", &NumberOptions::<f32>::positive())],
+        );
+    }};
+}
+
 pub fn register_default_options(type_registry: &mut TypeRegistry) {
     insert_options_struct::<bevy_color::Srgba>(
         type_registry,
@@ -249,4 +303,9 @@ pub fn register_default_options(type_registry: &mut TypeRegistry) {
             ("effective_speed", &NumberOptions::<f64>::positive()),
         ],
     );
+
+    #[cfg(feature = "rapier2d")]
+    register_rapier_options!(type_registry, bevy_rapier2d);
+    #[cfg(feature = "rapier3d")]
+    register_rapier_options!(type_registry, bevy_rapier3d);
 }
@@ -1,8 +1,10 @@
 //! Way of associating options to fields using [`struct@InspectorOptions`]
 
-use std::{any::Any, collections::HashMap};
+use std::{any::Any, borrow::Cow, collections::HashMap, sync::Arc};
 
-use bevy_reflect::{FromType, TypeData};
+use bevy_reflect::{FromType, PartialReflect, Reflect, TypeData, TypeRegistry};
+
+use crate::reflect_inspector::InspectorUi;
 
 pub(crate) mod default_options;
 
@@ -18,6 +20,8 @@ pub enum Target {
         variant_index: usize,
         field_index: usize,
     },
+    /// The value itself rather than one of its fields, e.g. for [`OptionOptions`](std_options::OptionOptions)
+    Container,
 }
 
 pub use bevy_inspector_egui_derive::InspectorOptions;
@@ -49,6 +53,11 @@ pub use bevy_inspector_egui_derive::InspectorOptions;
 #[derive(Default)]
 pub struct InspectorOptions {
     options: HashMap<Target, Box<dyn TypeData>>,
+    validators: HashMap<Target, Validator>,
+    widgets: HashMap<Target, CustomWidget>,
+    displays: HashMap<Target, FieldDisplay>,
+    variants: HashMap<usize, VariantDisplay>,
+    computed: Vec<ComputedRow>,
 }
 
 impl std::fmt::Debug for InspectorOptions {
@@ -57,6 +66,21 @@ impl std::fmt::Debug for InspectorOptions {
         for entry in self.options.keys() {
             options.field(&format!("{entry:?}"), &"..");
         }
+        for entry in self.validators.keys() {
+            options.field(&format!("{entry:?} (validator)"), &"..");
+        }
+        for entry in self.widgets.keys() {
+            options.field(&format!("{entry:?} (widget)"), &"..");
+        }
+        for (entry, display) in &self.displays {
+            options.field(&format!("{entry:?} (display)"), display);
+        }
+        for (variant_index, display) in &self.variants {
+            options.field(&format!("variant {variant_index}"), display);
+        }
+        for row in &self.computed {
+            options.field(&format!("{} (computed)", row.name), &"..");
+        }
         options.finish()
     }
 }
@@ -69,6 +93,11 @@ impl Clone for InspectorOptions {
                 .iter()
                 .map(|(target, data)| (*target, TypeData::clone_type_data(&**data)))
                 .collect(),
+            validators: self.validators.clone(),
+            widgets: self.widgets.clone(),
+            displays: self.displays.clone(),
+            variants: self.variants.clone(),
+            computed: self.computed.clone(),
         }
     }
 }
@@ -77,6 +106,18 @@ impl InspectorOptions {
         Self::default()
     }
 
+    /// Options for a newtype like `struct Percent(f32)`, which will be passed to the wrapped value.
+    ///
+    /// ```rust
+    /// # use bevy_inspector_egui::inspector_options::{InspectorOptions, std_options::{NumberOptions, NumberDisplay}};
+    /// let options = InspectorOptions::newtype(NumberOptions::<f32>::normalized().with_display(NumberDisplay::Slider));
+    /// ```
+    pub fn newtype<T: TypeData>(options: T) -> Self {
+        let mut inspector_options = InspectorOptions::new();
+        inspector_options.insert(Target::Field(0), options);
+        inspector_options
+    }
+
     pub fn insert<T: TypeData>(&mut self, target: Target, options: T) {
         self.options.insert(target, Box::new(options));
     }
@@ -90,6 +131,263 @@ impl InspectorOptions {
     pub fn iter(&self) -> impl Iterator<Item = (Target, &dyn TypeData)> + '_ {
         self.options.iter().map(|(target, data)| (*target, &**data))
     }
+
+    pub fn insert_validator(&mut self, target: Target, validator: Validator) {
+        self.validators.insert(target, validator);
+    }
+    pub fn validator(&self, target: Target) -> Option<&Validator> {
+        self.validators.get(&target)
+    }
+
+    pub fn insert_widget(&mut self, target: Target, widget: CustomWidget) {
+        self.widgets.insert(target, widget);
+    }
+    pub fn widget(&self, target: Target) -> Option<&CustomWidget> {
+        self.widgets.get(&target)
+    }
+
+    pub fn insert_display(&mut self, target: Target, display: FieldDisplay) {
+        self.displays.insert(target, display);
+    }
+    pub fn display(&self, target: Target) -> Option<&FieldDisplay> {
+        self.displays.get(&target)
+    }
+
+    pub fn insert_variant_display(&mut self, variant_index: usize, display: VariantDisplay) {
+        self.variants.insert(variant_index, display);
+    }
+    pub fn variant_display(&self, variant_index: usize) -> Option<&VariantDisplay> {
+        self.variants.get(&variant_index)
+    }
+
+    pub fn push_computed(&mut self, row: ComputedRow) {
+        self.computed.push(row);
+    }
+    pub fn computed(&self) -> &[ComputedRow] {
+        &self.computed
+    }
+}
+
+/// Checks the value of a field after it was edited, set using `#[inspector(validate = path::to_fn)]`.
+///
+/// The function receives the value as `&mut T`, so it can also correct it (which is reported as a change of the field),
+/// and returns an error message if it is invalid.
+/// Fields failing validation are displayed in red, with the message as tooltip.
+///
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// fn even(value: &mut u32) -> Result<(), String> {
+///     match *value % 2 {
+///         0 => Ok(()),
+///         _ => Err(format!("{value} is not even")),
+///     }
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Config {
+///     #[inspector(validate = even)]
+///     lanes: u32,
+/// }
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct Validator(Arc<dyn Fn(&mut dyn PartialReflect) -> Result<(), String> + Send + Sync>);
+
+impl Validator {
+    pub fn new<T: Reflect>(
+        validate: impl Fn(&mut T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Validator(Arc::new(
+            move |value: &mut dyn PartialReflect| match value.try_downcast_mut::<T>() {
+                Some(value) => validate(value),
+                None => Ok(()),
+            },
+        ))
+    }
+
+    /// Values which aren't a `T` are always valid
+    pub fn validate(&self, value: &mut dyn PartialReflect) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+/// How a field is displayed in its struct, set using `#[inspector(label = "Name", collapse, default_open, tooltip = "..")]`.
+///
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Config {
+///     #[inspector(label = "Render distance")]
+///     render_distance: f32,
+///     #[inspector(collapse)]
+///     keybindings: Vec<String>,
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldDisplay {
+    /// Displayed instead of the field name
+    pub label: Option<Cow<'static, str>>,
+    /// Only display the field after expanding it using a toggle button in front of the label
+    pub collapse: bool,
+    /// Like `collapse`, but expanded initially
+    pub default_open: bool,
+    /// Shown when hovering the label, unless the `documentation` feature provides the doc comment of the field.
+    /// `#[inspector(docs)]` on a struct or enum sets it to the doc comment of each field.
+    pub tooltip: Option<Cow<'static, str>>,
+}
+
+impl FieldDisplay {
+    pub fn is_collapsible(&self) -> bool {
+        self.collapse || self.default_open
+    }
+}
+
+/// How an enum variant is displayed in the variant selection, set using `#[inspector(label = "Name", skip)]` on the variant.
+///
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// enum Difficulty {
+///     #[default]
+///     Normal,
+///     #[inspector(label = "Nightmare!")]
+///     Hard,
+///     #[inspector(skip)]
+///     Debug,
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VariantDisplay {
+    /// Displayed instead of the variant name
+    pub label: Option<Cow<'static, str>>,
+    /// Don't offer the variant in the selection. It is still displayed if it is the current variant.
+    pub skip: bool,
+}
+
+/// Function displaying a field instead of the default UI, set using `#[inspector(with = path::to_fn)]`.
+///
+/// It receives the field, the options from the other attributes of the field and the [`InspectorUi`] for displaying nested values,
+/// and returns whether the value was changed. Read-only values are still displayed using the default UI.
+///
+/// ```rust
+/// use std::any::Any;
+/// use bevy_inspector_egui::{prelude::*, reflect_inspector::InspectorUi};
+/// use bevy_reflect::Reflect;
+///
+/// fn percentage(value: &mut f32, ui: &mut egui::Ui, _: &dyn Any, _: egui::Id, _: InspectorUi) -> bool {
+///     ui.add(egui::Slider::new(value, 0.0..=1.0).custom_formatter(|value, _| format!("{:.0}%", value * 100.0)))
+///         .changed()
+/// }
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Config {
+///     #[inspector(with = percentage)]
+///     volume: f32,
+/// }
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct CustomWidget(
+    Arc<
+        dyn Fn(
+                &mut dyn PartialReflect,
+                &mut egui::Ui,
+                &dyn Any,
+                egui::Id,
+                InspectorUi,
+            ) -> Option<bool>
+            + Send
+            + Sync,
+    >,
+);
+
+impl CustomWidget {
+    pub fn new<T: Reflect>(
+        widget: impl Fn(&mut T, &mut egui::Ui, &dyn Any, egui::Id, InspectorUi) -> bool
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        CustomWidget(Arc::new(
+            move |value: &mut dyn PartialReflect, ui: &mut egui::Ui, options: &dyn Any, id, env| {
+                let value = value.try_downcast_mut::<T>()?;
+                Some(widget(value, ui, options, id, env))
+            },
+        ))
+    }
+
+    /// Returns `None` if the value isn't a `T`
+    pub fn ui(
+        &self,
+        value: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        options: &dyn Any,
+        id: egui::Id,
+        env: InspectorUi,
+    ) -> Option<bool> {
+        (self.0)(value, ui, options, id, env)
+    }
+}
+
+/// Read-only row displayed beneath the fields of a struct, set using `#[inspector(computed = Self::function)]` on the struct.
+///
+/// ```rust
+/// use bevy_inspector_egui::prelude::*;
+/// use bevy_math::Vec3;
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Reflect, Default, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// #[inspector(computed = Self::speed)]
+/// struct Velocity {
+///     linear: Vec3,
+/// }
+///
+/// impl Velocity {
+///     fn speed(&self) -> f32 {
+///         self.linear.length()
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ComputedRow {
+    name: Cow<'static, str>,
+    compute: Arc<dyn Fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>> + Send + Sync>,
+}
+
+impl ComputedRow {
+    pub fn new<T: Reflect, V: PartialReflect>(
+        name: impl Into<Cow<'static, str>>,
+        compute: impl Fn(&T) -> V + Send + Sync + 'static,
+    ) -> Self {
+        ComputedRow {
+            name: name.into(),
+            compute: Arc::new(move |value: &dyn PartialReflect| {
+                let value = value.try_downcast_ref::<T>()?;
+                Some(Box::new(compute(value)))
+            }),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `None` if the value isn't a `T`
+    pub fn compute(&self, value: &dyn PartialReflect) -> Option<Box<dyn PartialReflect>> {
+        (self.compute)(value)
+    }
 }
 
 /// Wrapper of [`struct@InspectorOptions`] to be stored in the [`TypeRegistry`](bevy_reflect::TypeRegistry)
@@ -105,6 +403,39 @@ where
     }
 }
 
+/// Registers `options` as the default [`struct@InspectorOptions`] of `T`.
+///
+/// They will be used whenever a `T` is displayed without more specific options, so a newtype like
+/// `struct Percent(f32)` can be displayed as a slider from `0..=1` everywhere without repeating `#[inspector(..)]` on every field.
+/// Note that type aliases like `type Percent = f32` are indistinguishable from the aliased type, so this only works with newtypes.
+///
+/// ```rust
+/// use bevy_reflect::{Reflect, TypeRegistry};
+/// use bevy_inspector_egui::inspector_options::{register_inspector_options, InspectorOptions, std_options::{NumberOptions, NumberDisplay}};
+///
+/// #[derive(Reflect)]
+/// struct Percent(f32);
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register::<Percent>();
+/// register_inspector_options::<Percent>(
+///     &mut type_registry,
+///     InspectorOptions::newtype(NumberOptions::<f32>::normalized().with_display(NumberDisplay::Slider)),
+/// );
+/// ```
+///
+/// # Panics
+/// Panics if `T` is not registered in the type registry.
+pub fn register_inspector_options<T: 'static>(
+    type_registry: &mut TypeRegistry,
+    options: InspectorOptions,
+) {
+    type_registry
+        .get_mut(std::any::TypeId::of::<T>())
+        .unwrap_or_else(|| panic!("{} not registered", std::any::type_name::<T>()))
+        .insert(ReflectInspectorOptions(options));
+}
+
 /// Helper trait for the [`derive@InspectorOptions`] macro.
 ///
 /// ```skip
@@ -1,7 +1,8 @@
 use bevy_ecs::entity::Entity;
-use std::collections::VecDeque;
+use bevy_math::Vec2;
+use std::{borrow::Cow, collections::VecDeque, path::PathBuf};
 
-use crate::InspectorOptions;
+use crate::{inspector_egui_impls::ColorGradient, InspectorOptions};
 
 use super::{InspectorOptionsType, Target};
 
@@ -39,6 +40,8 @@ pub struct NumberOptions<T> {
     pub prefix: String,
     pub suffix: String,
     pub display: NumberDisplay,
+    /// Unit of the number, adding a suffix, a conversion for display and a default drag speed
+    pub unit: Option<NumberUnit>,
 }
 
 impl<T> Default for NumberOptions<T> {
@@ -50,6 +53,7 @@ impl<T> Default for NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            unit: None,
         }
     }
 }
@@ -62,6 +66,56 @@ pub enum NumberDisplay {
     Slider,
 }
 
+/// The unit of a number in [`NumberOptions`].
+///
+/// Bounds are given in the unit the value is stored in, while a custom speed is in the displayed unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum NumberUnit {
+    Meters,
+    /// An angle stored in radians, displayed in degrees
+    Degrees,
+    Radians,
+    Milliseconds,
+    /// A fraction stored as `0..=1`, displayed as `0..=100%`
+    Percent,
+    /// Only a suffix, like `"px"` or `"N"`
+    Custom(&'static str),
+}
+
+impl NumberUnit {
+    /// The suffix displayed after the number
+    pub fn suffix(self) -> &'static str {
+        match self {
+            NumberUnit::Meters => " m",
+            NumberUnit::Degrees => "°",
+            NumberUnit::Radians => " rad",
+            NumberUnit::Milliseconds => " ms",
+            NumberUnit::Percent => "%",
+            NumberUnit::Custom(suffix) => suffix,
+        }
+    }
+
+    /// The factor from the stored to the displayed value
+    pub fn display_scale(self) -> f64 {
+        match self {
+            NumberUnit::Degrees => 180.0 / std::f64::consts::PI,
+            NumberUnit::Percent => 100.0,
+            _ => 1.0,
+        }
+    }
+
+    /// The drag speed in the displayed unit, if the unit suggests one
+    pub fn default_speed(self) -> Option<f32> {
+        match self {
+            NumberUnit::Meters | NumberUnit::Radians => Some(0.01),
+            NumberUnit::Degrees | NumberUnit::Milliseconds => Some(1.0),
+            NumberUnit::Percent => Some(0.5),
+            NumberUnit::Custom(_) => None,
+        }
+    }
+}
+
 impl<T> NumberOptions<T> {
     pub fn between(min: T, max: T) -> NumberOptions<T> {
         NumberOptions {
@@ -71,6 +125,7 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            unit: None,
         }
     }
     pub fn at_least(min: T) -> NumberOptions<T> {
@@ -81,6 +136,7 @@ impl<T> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            unit: None,
         }
     }
 
@@ -88,6 +144,17 @@ impl<T> NumberOptions<T> {
         NumberOptions { speed, ..self }
     }
 
+    pub fn with_display(self, display: NumberDisplay) -> NumberOptions<T> {
+        NumberOptions { display, ..self }
+    }
+
+    pub fn with_unit(self, unit: NumberUnit) -> NumberOptions<T> {
+        NumberOptions {
+            unit: Some(unit),
+            ..self
+        }
+    }
+
     pub fn map<U>(&self, f: impl Fn(&T) -> U) -> NumberOptions<U> {
         NumberOptions {
             #[allow(clippy::redundant_closure)] // false positive
@@ -97,6 +164,7 @@ impl<T> NumberOptions<T> {
             prefix: self.prefix.clone(),
             suffix: self.suffix.clone(),
             display: NumberDisplay::default(),
+            unit: self.unit,
         }
     }
 }
@@ -109,6 +177,7 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            unit: None,
         }
     }
 
@@ -120,6 +189,7 @@ impl<T: egui::emath::Numeric> NumberOptions<T> {
             prefix: String::new(),
             suffix: String::new(),
             display: NumberDisplay::default(),
+            unit: None,
         }
     }
 }
@@ -138,6 +208,16 @@ impl_options!(u32 => NumberOptions<u32>);
 impl_options!(u64 => NumberOptions<u64>);
 impl_options!(u128 => NumberOptions<u128>);
 impl_options!(usize => NumberOptions<usize>);
+impl_options!(std::num::NonZeroU8 => NumberOptions<u8>);
+impl_options!(std::num::NonZeroU16 => NumberOptions<u16>);
+impl_options!(std::num::NonZeroU32 => NumberOptions<u32>);
+impl_options!(std::num::NonZeroU64 => NumberOptions<u64>);
+impl_options!(std::num::NonZeroUsize => NumberOptions<usize>);
+impl_options!(std::num::NonZeroI8 => NumberOptions<i8>);
+impl_options!(std::num::NonZeroI16 => NumberOptions<i16>);
+impl_options!(std::num::NonZeroI32 => NumberOptions<i32>);
+impl_options!(std::num::NonZeroI64 => NumberOptions<i64>);
+impl_options!(std::num::NonZeroIsize => NumberOptions<isize>);
 
 #[non_exhaustive]
 pub struct RangeOptions<T: InspectorOptionsType> {
@@ -180,6 +260,48 @@ impl<T: InspectorOptionsType + 'static> InspectorOptionsType for std::ops::Range
     }
 }
 
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct StringOptions {
+    /// Always use a multiline text edit, even if the string doesn't contain a newline
+    pub multiline: bool,
+    /// Mask the entered characters
+    pub password: bool,
+    /// Maximum number of characters, longer strings will be truncated
+    pub max_len: Option<usize>,
+    /// Treat the string as a file path, e.g. `#[inspector(file_picker(extensions = "png,jpg"))]`
+    pub file_picker: Option<FilePickerOptions>,
+}
+
+impl_options!(String => StringOptions);
+impl_options!(Cow<'static, str> => StringOptions);
+#[cfg(feature = "smol_str")]
+impl_options!(smol_str::SmolStr => StringOptions);
+
+/// Options for file paths, used by [`PathBuf`] and by strings with [`StringOptions::file_picker`] set.
+///
+/// With the `rfd` feature, a button to open a native file dialog is shown next to the path.
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct FilePickerOptions {
+    /// Comma-separated list of allowed file extensions, e.g. `"png,jpg"`. Empty means any file.
+    pub extensions: Cow<'static, str>,
+    /// Expect the path to be relative to the `assets` folder and warn if it isn't
+    pub relative_to_assets: bool,
+}
+
+impl FilePickerOptions {
+    /// Iterates over the allowed file extensions
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.extensions
+            .split(',')
+            .map(|extension| extension.trim().trim_start_matches('.'))
+            .filter(|extension| !extension.is_empty())
+    }
+}
+
+impl_options!(PathBuf => FilePickerOptions);
+
 #[derive(Default, Clone)]
 #[non_exhaustive]
 pub struct QuatOptions {
@@ -197,6 +319,45 @@ pub enum QuatDisplay {
 
 impl_options!(bevy_math::Quat => QuatOptions);
 
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct MatOptions {
+    pub display: MatDisplay,
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub enum MatDisplay {
+    /// Edit the columns of the matrix
+    #[default]
+    Raw,
+    /// Edit the translation, rotation and scale of affine matrices
+    Decomposed,
+}
+
+impl_options!(bevy_math::Mat4 => MatOptions);
+impl_options!(bevy_math::Affine3A => MatOptions);
+
+#[derive(Default, Clone)]
+#[non_exhaustive]
+pub struct DirOptions {
+    pub display: DirDisplay,
+    /// Show the components of the direction read-only below the angles
+    pub show_components: bool,
+}
+
+#[derive(Copy, Clone, Default)]
+pub enum DirDisplay {
+    /// Edit the components, renormalizing after every change
+    Raw,
+    /// Edit the angle of a [`Dir2`](bevy_math::Dir2), or yaw and pitch of a [`Dir3`](bevy_math::Dir3)
+    #[default]
+    Angles,
+}
+
+impl_options!(bevy_math::Dir2 => DirOptions);
+impl_options!(bevy_math::Dir3 => DirOptions);
+impl_options!(bevy_math::Dir3A => DirOptions);
+
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct EntityOptions {
@@ -223,12 +384,160 @@ pub enum EntityDisplay {
 
 impl_options!(Entity => EntityOptions);
 
+/// Options for [`Transform`](bevy_transform::components::Transform)
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct TransformOptions {
+    /// Lock the scale axes together by default, so that editing one scales all of them
+    pub uniform_scale: bool,
+    /// Round the translation to multiples of this after editing it
+    pub translation_snap: Option<f32>,
+    /// Round the rotation around each axis to multiples of this many degrees after editing it
+    pub rotation_snap: Option<f32>,
+    /// Indicate that the transform is relative to the parent entity
+    pub show_space: bool,
+}
+
+impl Default for TransformOptions {
+    fn default() -> Self {
+        Self {
+            uniform_scale: false,
+            translation_snap: None,
+            rotation_snap: None,
+            show_space: true,
+        }
+    }
+}
+
+impl_options!(bevy_transform::components::Transform => TransformOptions);
+
+/// Options for `Vec<Vec2>`, which is displayed as an editable curve through its control points.
+///
+/// Points can be dragged around, added by double clicking and removed by right clicking them.
+/// Since `#[inspector(..)]` on a `Vec<Vec2>` sets the options of its elements, the curve editor is selected using `curve(..)`:
+///
+/// ```rust
+/// # use bevy_math::Vec2;
+/// # use bevy_reflect::{FromType, Reflect};
+/// # use bevy_inspector_egui::{prelude::*, inspector_options::{std_options::CurveOptions, Target}};
+/// #[derive(Reflect, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Animation {
+///     #[inspector(curve(min = Vec2::ZERO, max = Vec2::ONE, sorted))]
+///     easing: Vec<Vec2>,
+/// }
+///
+/// let options = <InspectorOptions as FromType<Animation>>::from_type();
+/// let curve = options.get(Target::Field(0)).unwrap().downcast_ref::<CurveOptions>().unwrap();
+/// assert!(curve.sorted);
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct CurveOptions {
+    /// Lower left corner of the displayed area. Points are clamped to the area if `min` and `max` are set.
+    pub min: Option<Vec2>,
+    /// Upper right corner of the displayed area.
+    pub max: Option<Vec2>,
+    /// Keep the points sorted by their `x` coordinate, like for easing curves
+    pub sorted: bool,
+    /// Height of the curve editor
+    pub height: f32,
+}
+
+impl Default for CurveOptions {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            sorted: false,
+            height: 120.0,
+        }
+    }
+}
+
+impl CurveOptions {
+    /// Options for easing curves going from `(0, 0)` to `(1, 1)`
+    pub fn easing() -> Self {
+        Self {
+            min: Some(Vec2::ZERO),
+            max: Some(Vec2::ONE),
+            sorted: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Options for [`ColorGradient`] and `Vec<(f32, Color)>`, which is displayed as a gradient editor with the same options.
+///
+/// On a `Vec<(f32, Color)>` field, the gradient editor is selected using `#[inspector(gradient(..))]`:
+///
+/// ```rust
+/// # use bevy_color::Color;
+/// # use bevy_reflect::{FromType, Reflect};
+/// # use bevy_inspector_egui::{prelude::*, inspector_options::{std_options::GradientOptions, Target}};
+/// #[derive(Reflect, InspectorOptions)]
+/// #[reflect(InspectorOptions)]
+/// struct Sky {
+///     #[inspector(gradient(height = 16.0))]
+///     colors: Vec<(f32, Color)>,
+/// }
+///
+/// let options = <InspectorOptions as FromType<Sky>>::from_type();
+/// let gradient = options.get(Target::Field(0)).unwrap().downcast_ref::<GradientOptions>().unwrap();
+/// assert_eq!(gradient.height, 16.0);
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct GradientOptions {
+    /// Height of the gradient bar
+    pub height: f32,
+}
+
+impl Default for GradientOptions {
+    fn default() -> Self {
+        Self { height: 24.0 }
+    }
+}
+
+impl_options!(ColorGradient => GradientOptions);
+
+/// Options for `Option<T>`, stored as [`Target::Container`] next to the options of the inner value
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct OptionOptions {
+    /// When switching from `None` to `Some`, always construct a default value (using `ReflectDefault` or `ReflectFromWorld`)
+    /// instead of restoring the value from before it was set to `None`
+    pub replace_with_default: bool,
+}
+
+/// Derive options of `Option<T>`, which are the options of `T` plus [`OptionOptions`].
+///
+/// Dereferences to the options of `T`, so that `#[inspector(min = 0.0, replace_with_default)]` works on an `Option<f32>`.
+#[derive(Default)]
+pub struct OptionDeriveOptions<T> {
+    pub replace_with_default: bool,
+    pub inner: T,
+}
+
+impl<T> std::ops::Deref for OptionDeriveOptions<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+impl<T> std::ops::DerefMut for OptionDeriveOptions<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
 impl<T: InspectorOptionsType> InspectorOptionsType for Option<T> {
-    type DeriveOptions = T::DeriveOptions;
+    type DeriveOptions = OptionDeriveOptions<T::DeriveOptions>;
     type Options = InspectorOptions;
 
     fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
-        let inner_options = T::options_from_derive(options);
+        let inner_options = T::options_from_derive(options.inner);
 
         let mut inspector_options = InspectorOptions::new();
         inspector_options.insert(
@@ -238,6 +547,12 @@ impl<T: InspectorOptionsType> InspectorOptionsType for Option<T> {
             },
             inner_options,
         );
+        inspector_options.insert(
+            Target::Container,
+            OptionOptions {
+                replace_with_default: options.replace_with_default,
+            },
+        );
 
         inspector_options
     }
@@ -135,18 +135,29 @@
 //! **A:** Implement [`InspectorPrimitive`](crate::inspector_egui_impls::InspectorPrimitive) and call `app.register_type_data::<T, InspectorEguiImpl>`.
 
 pub mod bevy_inspector;
+#[cfg(feature = "commands")]
+pub mod commands;
+#[cfg(feature = "editor")]
+pub mod editor;
+#[cfg(all(feature = "bevy_render", feature = "bevy_image"))]
+pub mod game_view;
+pub mod gamepad;
 pub mod inspector_egui_impls;
 pub mod inspector_options;
-#[cfg(feature = "bevy_render")]
+pub mod localization;
 pub mod quick;
 pub mod reflect_inspector;
+#[cfg(feature = "remote")]
+pub mod remote;
 pub mod restricted_world_view;
+pub mod theme;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
 
 pub mod dropdown;
 mod egui_utils;
 mod utils;
 
-#[cfg(feature = "bevy_render")]
 pub use bevy_egui;
 pub use egui;
 
@@ -158,6 +169,23 @@ impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
             return;
         }
 
+        app.init_resource::<bevy_inspector::auto_expand::AutoExpandSettings>();
+        app.init_resource::<bevy_inspector::component_filter::ComponentFilterSettings>();
+        app.init_resource::<bevy_inspector::doctor::InspectorErrors>();
+        app.init_resource::<bevy_inspector::favorites::Favorites>();
+        app.init_resource::<bevy_inspector::history::ValueHistory>();
+        app.init_resource::<bevy_inspector::notes::InspectorNotes>();
+        #[cfg(feature = "ron")]
+        app.init_resource::<bevy_inspector::tweaks::TweakSession>();
+        app.add_systems(
+            bevy_app::Last,
+            bevy_inspector::history::record_value_history,
+        );
+        app.add_systems(bevy_app::Update, theme::apply_inspector_theme);
+        app.add_systems(bevy_app::Update, localization::apply_inspector_localization);
+        #[cfg(feature = "bevy_ui")]
+        app.add_systems(bevy_app::Update, bevy_inspector::ui_layout::flash_outlines);
+
         // Defensively register stuff since bevy only registers glam, color types used by other structs internally
         app.register_type::<bevy_math::IVec2>()
             .register_type::<bevy_math::IVec3>()
@@ -207,6 +235,135 @@ impl bevy_app::Plugin for DefaultInspectorConfigPlugin {
 #[doc(inline)]
 pub use inspector_options::InspectorOptions;
 
+/// Extension methods on [`App`](bevy_app::App) for configuring how types are displayed in the inspector
+pub trait InspectorAppExt {
+    /// Registers `T` and sets its default [`struct@InspectorOptions`]. See [`register_inspector_options`](inspector_options::register_inspector_options).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// use bevy_inspector_egui::{prelude::*, inspector_options::std_options::{NumberOptions, NumberDisplay}};
+    ///
+    /// #[derive(Reflect)]
+    /// struct Percent(f32);
+    ///
+    /// App::new().register_inspector_options::<Percent>(InspectorOptions::newtype(
+    ///     NumberOptions::<f32>::normalized().with_display(NumberDisplay::Slider),
+    /// ));
+    /// ```
+    fn register_inspector_options<T>(&mut self, options: InspectorOptions) -> &mut Self
+    where
+        T: bevy_reflect::GetTypeRegistration;
+
+    /// Displays `T` using `ui` instead of its regular UI, see [`InspectorOverrides`](inspector_egui_impls::InspectorOverrides).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// use bevy_inspector_egui::prelude::*;
+    ///
+    /// App::new().override_inspector_ui::<Name>(|name, ui, _, _| {
+    ///     let mut text = name.as_str().to_owned();
+    ///     let changed = ui.text_edit_singleline(&mut text).changed();
+    ///     if changed {
+    ///         name.set(text);
+    ///     }
+    ///     changed
+    /// });
+    /// ```
+    fn override_inspector_ui<T: Clone + 'static>(
+        &mut self,
+        ui: impl Fn(&mut T, &mut egui::Ui, egui::Id, reflect_inspector::InspectorUi<'_, '_>) -> bool
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self;
+
+    /// Displays `icon` before the names of entities with the component `C` in the hierarchy, see [`EntityIcons`](bevy_inspector::EntityIcons).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// use bevy_inspector_egui::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// App::new().register_entity_icon::<Enemy>("👾");
+    /// ```
+    fn register_entity_icon<C: bevy_ecs::component::Component>(
+        &mut self,
+        icon: &'static str,
+    ) -> &mut Self;
+
+    /// Names entities without a [`Name`](bevy_core::Name) using `rule`, before the built-in heuristics of
+    /// [`guess_entity_name`](bevy_inspector::guess_entity_name). See [`EntityNameRules`](bevy_inspector::EntityNameRules).
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// use bevy_inspector_egui::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy {
+    ///     kind: &'static str,
+    /// }
+    ///
+    /// // displayed as `Enemy (goblin) (12v1)`
+    /// App::new().register_entity_name_rule(|entity| {
+    ///     let enemy = entity.get::<Enemy>()?;
+    ///     Some(format!("Enemy ({})", enemy.kind))
+    /// });
+    /// ```
+    fn register_entity_name_rule(
+        &mut self,
+        rule: impl Fn(bevy_ecs::world::EntityRef<'_>) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl InspectorAppExt for bevy_app::App {
+    fn register_inspector_options<T>(&mut self, options: InspectorOptions) -> &mut Self
+    where
+        T: bevy_reflect::GetTypeRegistration,
+    {
+        self.register_type::<T>();
+        let type_registry = self
+            .world()
+            .resource::<bevy_ecs::prelude::AppTypeRegistry>();
+        inspector_options::register_inspector_options::<T>(&mut type_registry.write(), options);
+        self
+    }
+
+    fn override_inspector_ui<T: Clone + 'static>(
+        &mut self,
+        ui: impl Fn(&mut T, &mut egui::Ui, egui::Id, reflect_inspector::InspectorUi<'_, '_>) -> bool
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<inspector_egui_impls::InspectorOverrides>()
+            .insert::<T>(ui);
+        self
+    }
+
+    fn register_entity_icon<C: bevy_ecs::component::Component>(
+        &mut self,
+        icon: &'static str,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<bevy_inspector::EntityIcons>()
+            .insert::<C>(icon);
+        self
+    }
+
+    fn register_entity_name_rule(
+        &mut self,
+        rule: impl Fn(bevy_ecs::world::EntityRef<'_>) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<bevy_inspector::EntityNameRules>()
+            .add(rule);
+        self
+    }
+}
+
 #[doc(hidden)]
 pub mod __macro_exports {
     pub use bevy_reflect;
@@ -217,4 +374,5 @@ pub mod prelude {
     // for `#[derive(Reflect)] #[reflect(InspectorOptions)]
     pub use crate::inspector_options::InspectorOptions;
     pub use crate::inspector_options::ReflectInspectorOptions;
+    pub use crate::InspectorAppExt;
 }
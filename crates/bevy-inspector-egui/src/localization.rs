@@ -0,0 +1,139 @@
+//! Translating the labels of the inspector.
+//!
+//! Insert an [`InspectorLocalization`] resource with your [`LabelLocalizer`] to translate field names, component names
+//! and the built-in strings of the inspector before they are displayed.
+//! UIs built on a custom [`egui::Context`] can call [`InspectorLocalization::apply`] directly.
+//!
+//! ```no_run
+//! use bevy_app::App;
+//! use bevy_inspector_egui::localization::{InspectorLocalization, Label, LabelLocalizer};
+//!
+//! struct German;
+//!
+//! impl LabelLocalizer for German {
+//!     fn localize(&self, label: Label<'_>) -> Option<String> {
+//!         match label {
+//!             Label::Builtin("Children") => Some("Kinder".into()),
+//!             Label::Builtin("Add element") => Some("Element hinzufügen".into()),
+//!             Label::Field { field: "translation", .. } => Some("Position".into()),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! App::new().insert_resource(InspectorLocalization::new(German));
+//! ```
+
+use std::{borrow::Cow, sync::Arc};
+
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+
+/// A label of the inspector which can be translated by a [`LabelLocalizer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Label<'a> {
+    /// The name of a struct field, or its `#[inspector(label = "..")]`
+    Field { type_path: &'a str, field: &'a str },
+    /// The name of a component, as displayed in the header of the entity inspector
+    Component {
+        type_path: Option<&'a str>,
+        name: &'a str,
+    },
+    /// A string of the inspector itself, like `"Children"` or `"Add element"`
+    Builtin(&'a str),
+}
+
+impl<'a> Label<'a> {
+    /// The untranslated text of the label
+    pub fn text(&self) -> &'a str {
+        match *self {
+            Label::Field { field, .. } => field,
+            Label::Component { name, .. } => name,
+            Label::Builtin(text) => text,
+        }
+    }
+}
+
+/// Translates the labels of the inspector, see the [module docs](self)
+pub trait LabelLocalizer: Send + Sync + 'static {
+    /// Returns the translation of `label`, or `None` to display it [as is](Label::text)
+    fn localize(&self, label: Label<'_>) -> Option<String>;
+}
+
+/// The [`LabelLocalizer`] used by the inspector
+#[derive(Resource, Clone)]
+pub struct InspectorLocalization(Arc<dyn LabelLocalizer>);
+
+impl InspectorLocalization {
+    pub fn new(localizer: impl LabelLocalizer) -> Self {
+        InspectorLocalization(Arc::new(localizer))
+    }
+
+    fn id() -> egui::Id {
+        egui::Id::new("bevy_inspector_egui::localization")
+    }
+
+    /// Makes the inspector UIs shown in `ctx` use this localizer
+    pub fn apply(&self, ctx: &egui::Context) {
+        let current = ctx.data(|data| data.get_temp::<InspectorLocalization>(Self::id()));
+        if current.is_some_and(|current| Arc::ptr_eq(&current.0, &self.0)) {
+            return;
+        }
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self.clone()));
+    }
+}
+
+/// Translates `label` with the localizer [applied](InspectorLocalization::apply) to `ctx`
+pub fn localize<'a>(ctx: &egui::Context, label: Label<'a>) -> Cow<'a, str> {
+    ctx.data(|data| data.get_temp::<InspectorLocalization>(InspectorLocalization::id()))
+        .and_then(|localization| localization.0.localize(label))
+        .map_or(Cow::Borrowed(label.text()), Cow::Owned)
+}
+
+/// Shorthand for [localizing](localize) a [`Label::Builtin`]
+pub(crate) fn builtin<'a>(ui: &egui::Ui, text: &'a str) -> Cow<'a, str> {
+    localize(ui.ctx(), Label::Builtin(text))
+}
+
+/// Applies the [`InspectorLocalization`] resource to every egui context
+pub(crate) fn apply_inspector_localization(
+    localization: Option<Res<InspectorLocalization>>,
+    mut contexts: Query<&mut EguiContext>,
+) {
+    let Some(localization) = localization else {
+        return;
+    };
+    for mut context in &mut contexts {
+        localization.apply(context.get_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{localize, InspectorLocalization, Label, LabelLocalizer};
+
+    struct Shouting;
+
+    impl LabelLocalizer for Shouting {
+        fn localize(&self, label: Label<'_>) -> Option<String> {
+            match label {
+                Label::Builtin(_) => None,
+                _ => Some(label.text().to_uppercase()),
+            }
+        }
+    }
+
+    #[test]
+    fn localize_falls_back_to_text() {
+        let ctx = egui::Context::default();
+        let field = Label::Field {
+            type_path: "foo::Bar",
+            field: "baz",
+        };
+        assert_eq!(localize(&ctx, field), "baz");
+
+        InspectorLocalization::new(Shouting).apply(&ctx);
+        assert_eq!(localize(&ctx, field), "BAZ");
+        assert_eq!(localize(&ctx, Label::Builtin("Children")), "Children");
+    }
+}
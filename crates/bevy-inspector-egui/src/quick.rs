@@ -5,6 +5,35 @@
 //! **Cons:** not configurable
 //!
 //! When you want something more custom, you can use these plugins as a starting point.
+//!
+//! The plugins don't need the `bevy_render` feature, so they also work in headless apps or apps with a custom renderer.
+//! Without it, `bevy_egui` still runs the egui passes of every entity with an [`EguiContext`], but doesn't draw them:
+//! draw the [`EguiRenderOutput`](bevy_egui::EguiRenderOutput) of the context yourself, and point the plugins at it
+//! using [`InspectorWindow::Entity`] if it isn't the primary window.
+//!
+//! A headless app without `bevy_render` needs the input and window events `bevy_egui` reads,
+//! and the context should only be inserted after the `EguiPlugin` was added:
+//!
+//! ```no_run
+//! use bevy::{input::InputPlugin, prelude::*, window::ExitCondition};
+//! use bevy_inspector_egui::bevy_egui::EguiContext;
+//! use bevy_inspector_egui::quick::{InspectorWindow, WorldInspectorPlugin};
+//!
+//! let mut app = App::new();
+//! app.add_plugins((
+//!     MinimalPlugins,
+//!     InputPlugin,
+//!     WindowPlugin {
+//!         primary_window: None,
+//!         exit_condition: ExitCondition::DontExit,
+//!         ..default()
+//!     },
+//! ));
+//! let context = app.world_mut().spawn_empty().id();
+//! app.add_plugins(WorldInspectorPlugin::new().with_window(InspectorWindow::Entity(context)));
+//! app.world_mut().entity_mut(context).insert(EguiContext::default());
+//! app.run();
+//! ```
 
 use std::{marker::PhantomData, sync::Mutex};
 
@@ -15,24 +44,26 @@ use bevy_core::TypeRegistrationPlugin;
 use bevy_ecs::{
     prelude::*,
     query::QueryFilter,
-    schedule::{BoxedCondition, ScheduleLabel},
+    schedule::{common_conditions::run_once, BoxedCondition, ScheduleLabel, SystemConfigs},
 };
 use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_input::{common_conditions::input_toggle_active, keyboard::KeyCode};
 use bevy_reflect::Reflect;
 use bevy_state::state::FreelyMutableState;
-use bevy_window::PrimaryWindow;
+use bevy_window::{PrimaryWindow, Window};
 
 use crate::{bevy_inspector, DefaultInspectorConfigPlugin};
 
 const DEFAULT_SIZE: (f32, f32) = (320., 160.);
 
 #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
-struct Inspect;
+pub(crate) struct Inspect;
 
-struct InspectSchedulePlugin;
+pub(crate) struct InspectSchedulePlugin;
 impl Plugin for InspectSchedulePlugin {
     fn build(&self, app: &mut App) {
         app.init_schedule(Inspect);
+        app.init_resource::<InspectorVisibility>();
 
         app.world_mut()
             .resource_mut::<MainScheduleOrder>()
@@ -40,6 +71,241 @@ impl Plugin for InspectSchedulePlugin {
     }
 }
 
+/// Whether the quick plugins are shown, for toggling all of them at runtime.
+///
+/// This applies in addition to conditions like [`WorldInspectorPlugin::run_if`] and [`WorldInspectorPlugin::toggle_with_key`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{InspectorVisibility, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .add_systems(Update, hide_during_cutscene)
+///         .run();
+/// }
+///
+/// # #[derive(Resource)]
+/// # struct Cutscene;
+/// fn hide_during_cutscene(cutscene: Option<Res<Cutscene>>, mut visibility: ResMut<InspectorVisibility>) {
+///     visibility.visible = cutscene.is_none();
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct InspectorVisibility {
+    pub visible: bool,
+}
+
+impl Default for InspectorVisibility {
+    fn default() -> Self {
+        InspectorVisibility { visible: true }
+    }
+}
+
+impl InspectorVisibility {
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+}
+
+/// Run condition which is true unless the quick plugins were hidden using [`InspectorVisibility`]
+pub fn inspector_visible(visibility: Option<Res<InspectorVisibility>>) -> bool {
+    visibility.is_none_or(|visibility| visibility.visible)
+}
+
+/// The window the quick plugins draw their UI into, configured using e.g. [`WorldInspectorPlugin::with_window`]
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{InspectorWindow, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(
+///             WorldInspectorPlugin::new()
+///                 .with_window(InspectorWindow::Separate("Inspector".into())),
+///         )
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InspectorWindow {
+    /// The [`PrimaryWindow`]
+    #[default]
+    Primary,
+    /// An existing window entity, or any other entity with an [`EguiContext`] whose output you draw yourself
+    Entity(Entity),
+    /// A separate OS window with this title, which is spawned the first time it is drawn into.
+    ///
+    /// Plugins using the same title share the window. Once it is closed, it isn't spawned again.
+    Separate(String),
+}
+
+/// Marks windows spawned for [`InspectorWindow::Separate`]
+#[derive(Component, Debug, Clone)]
+pub struct SeparateInspectorWindow {
+    pub title: String,
+}
+
+/// Titles of the [`InspectorWindow::Separate`] windows which were already spawned
+#[derive(Resource, Default)]
+struct SpawnedInspectorWindows(Vec<String>);
+
+impl InspectorWindow {
+    /// The egui context of the window, spawning it for [`InspectorWindow::Separate`] if necessary.
+    ///
+    /// Returns `None` if the window doesn't exist (yet), since `bevy_egui` sets up the context one frame after the window is spawned.
+    pub fn egui_context(&self, world: &mut World) -> Option<EguiContext> {
+        let window = match self {
+            InspectorWindow::Primary => world
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .get_single(world)
+                .ok()?,
+            InspectorWindow::Entity(entity) => *entity,
+            InspectorWindow::Separate(title) => {
+                let existing = world
+                    .query::<(Entity, &SeparateInspectorWindow)>()
+                    .iter(world)
+                    .find(|(_, window)| window.title == *title)
+                    .map(|(entity, _)| entity);
+                if existing.is_none() {
+                    let mut spawned = world.get_resource_or_init::<SpawnedInspectorWindows>();
+                    if !spawned.0.contains(title) {
+                        spawned.0.push(title.clone());
+                        world.spawn((
+                            Window {
+                                title: title.clone(),
+                                ..Default::default()
+                            },
+                            SeparateInspectorWindow {
+                                title: title.clone(),
+                            },
+                        ));
+                    }
+                }
+                existing?
+            }
+        };
+
+        world.get::<EguiContext>(window).cloned()
+    }
+}
+
+/// How the quick plugins display their UI, see e.g. [`WorldInspectorPlugin::overlay`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InspectorStyle {
+    /// A floating egui window
+    #[default]
+    Window,
+    /// A semi-transparent panel at the right of the window, which leaves the rest of the game visible and clickable
+    Overlay,
+}
+
+impl InspectorStyle {
+    fn show(
+        self,
+        ctx: &egui::Context,
+        title: &str,
+        window: egui::Window<'_>,
+        add_contents: impl FnOnce(&mut egui::Ui),
+    ) {
+        match self {
+            InspectorStyle::Window => {
+                window.show(ctx, add_contents);
+            }
+            InspectorStyle::Overlay => {
+                let style = ctx.style();
+                let frame = egui::Frame::side_top_panel(&style)
+                    .fill(style.visuals.panel_fill.gamma_multiply(0.8));
+                egui::SidePanel::right(egui::Id::new(("inspector_overlay", title)))
+                    .frame(frame)
+                    .show(ctx, |ui| {
+                        ui.strong(title);
+                        add_contents(ui);
+                    });
+            }
+        }
+    }
+}
+
+/// The settings every quick plugin has, configured using the methods added by `quick_plugin_settings!`
+#[derive(Default)]
+struct QuickPluginSettings {
+    condition: Mutex<Option<BoxedCondition>>,
+    window: InspectorWindow,
+    toggle_key: Option<KeyCode>,
+    style: InspectorStyle,
+}
+
+impl QuickPluginSettings {
+    /// The system drawing the plugin's `ui`, which only runs if the conditions of the settings and [`inspector_visible`] hold
+    fn ui_system(
+        &self,
+        ui: impl Fn(&mut World, &InspectorWindow, InspectorStyle) + Send + Sync + 'static,
+    ) -> SystemConfigs {
+        let condition = self.condition.lock().unwrap().take();
+        let window = self.window.clone();
+        let style = self.style;
+        let mut system = (move |world: &mut World| ui(world, &window, style)).into_configs();
+        if let Some(condition) = condition {
+            system.run_if_dyn(condition);
+        }
+        if let Some(key) = self.toggle_key {
+            system = system.run_if(input_toggle_active(false, key));
+        }
+        system.run_if(inspector_visible)
+    }
+}
+
+/// Adds the `EguiPlugin`, [`DefaultInspectorConfigPlugin`] and the `Inspect` schedule the quick plugins need, unless they're already added
+fn add_required_plugins(app: &mut App) {
+    if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+        app.add_plugins(DefaultInspectorConfigPlugin);
+    }
+    if !app.is_plugin_added::<EguiPlugin>() {
+        app.add_plugins(EguiPlugin);
+    }
+    if !app.is_plugin_added::<InspectSchedulePlugin>() {
+        app.add_plugins(InspectSchedulePlugin);
+    }
+}
+
+/// Adds the builder methods for the [`QuickPluginSettings`] in the `settings` field of a quick plugin
+macro_rules! quick_plugin_settings {
+    ($plugin:ident $(<$generic:ident>)?) => {
+        impl$(<$generic>)? $plugin$(<$generic>)? {
+            /// Only show the UI of the specified condition is active
+            pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+                let condition_system = IntoSystem::into_system(condition);
+                self.settings.condition =
+                    Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+                self
+            }
+
+            /// Draw the UI into another window instead of the primary one
+            pub fn with_window(mut self, window: InspectorWindow) -> Self {
+                self.settings.window = window;
+                self
+            }
+
+            /// Only show the UI after pressing `key`, and hide it again on the next press
+            pub fn toggle_with_key(mut self, key: KeyCode) -> Self {
+                self.settings.toggle_key = Some(key);
+                self
+            }
+
+            /// Display the UI as a semi-transparent panel at the side of the window instead of a floating window
+            pub fn overlay(mut self) -> Self {
+                self.settings.style = InspectorStyle::Overlay;
+                self
+            }
+        }
+    };
+}
+
 /// Plugin displaying a egui window with an entity list, resources and assets
 ///
 /// You can use [`WorldInspectorPlugin::run_if`] to control when the window is shown, for example
@@ -59,7 +325,9 @@ impl Plugin for InspectSchedulePlugin {
 /// ```
 #[derive(Default)]
 pub struct WorldInspectorPlugin {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
+    #[cfg(feature = "bevy_render")]
+    entity_row_toggles: bool,
 }
 
 impl WorldInspectorPlugin {
@@ -67,55 +335,51 @@ impl WorldInspectorPlugin {
         Self::default()
     }
 
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+    /// Show a visibility toggle and render layer badge next to entity names.
+    /// See [`EntityRowToggles`](bevy_inspector::entity_row::EntityRowToggles) for enabling them individually.
+    #[cfg(feature = "bevy_render")]
+    pub fn entity_row_toggles(mut self) -> Self {
+        self.entity_row_toggles = true;
         self
     }
 }
 
+quick_plugin_settings!(WorldInspectorPlugin);
+
 impl Plugin for WorldInspectorPlugin {
     fn build(&self, app: &mut bevy_app::App) {
         check_default_plugins(app, "WorldInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
+        add_required_plugins(app);
+        #[cfg(feature = "bevy_gizmos")]
+        if !app.is_plugin_added::<bevy_inspector::gizmos::InspectorGizmosPlugin>() {
+            app.add_plugins(bevy_inspector::gizmos::InspectorGizmosPlugin);
         }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-        if !app.is_plugin_added::<InspectSchedulePlugin>() {
-            app.add_plugins(InspectSchedulePlugin);
+        #[cfg(feature = "bevy_render")]
+        if self.entity_row_toggles {
+            app.insert_resource(bevy_inspector::entity_row::EntityRowToggles::all());
         }
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = world_inspector_ui.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Inspect, system);
+        app.add_systems(Inspect, self.settings.ui_system(world_inspector_ui));
     }
 }
 
-fn world_inspector_ui(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
-
-    let Ok(egui_context) = egui_context else {
+fn world_inspector_ui(world: &mut World, window: &InspectorWindow, style: InspectorStyle) {
+    let Some(mut egui_context) = window.egui_context(world) else {
         return;
     };
-    let mut egui_context = egui_context.clone();
 
-    egui::Window::new("World Inspector")
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    style.show(
+        egui_context.get_mut(),
+        "World Inspector",
+        egui::Window::new("World Inspector").default_size(DEFAULT_SIZE),
+        |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
                 bevy_inspector::ui_for_world(world, ui);
                 ui.allocate_space(ui.available_size());
             });
-        });
+        },
+    );
 }
 
 /// Plugin displaying an egui window for a single resource.
@@ -123,6 +387,7 @@ fn world_inspector_ui(world: &mut World) {
 ///
 /// You can use [`ResourceInspectorPlugin::run_if`] to control when the window is shown, for example
 /// in combination with `input_toggle_active`.
+/// To display several resources in the same window, use [`ResourceGroupInspectorPlugin`] instead.
 ///
 /// ```no_run
 /// use bevy::prelude::*;
@@ -150,7 +415,7 @@ fn world_inspector_ui(world: &mut World) {
 /// }
 /// ```
 pub struct ResourceInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> T>,
 }
 
@@ -158,7 +423,7 @@ impl<T> Default for ResourceInspectorPlugin<T> {
     fn default() -> Self {
         Self {
             marker: PhantomData,
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
         }
     }
 }
@@ -167,57 +432,156 @@ impl<T> ResourceInspectorPlugin<T> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_settings!(ResourceInspectorPlugin<T>);
+
 impl<T: Resource + Reflect> Plugin for ResourceInspectorPlugin<T> {
     fn build(&self, app: &mut bevy_app::App) {
         check_default_plugins(app, "ResourceInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-        if !app.is_plugin_added::<InspectSchedulePlugin>() {
-            app.add_plugins(InspectSchedulePlugin);
-        }
+        add_required_plugins(app);
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = inspector_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Inspect, system);
+        app.add_systems(Inspect, self.settings.ui_system(inspector_ui::<T>));
     }
 }
 
-fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
-
-    let Ok(egui_context) = egui_context else {
+fn inspector_ui<T: Resource + Reflect>(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
         return;
     };
-    let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<T>())
-        .default_size((0., 0.))
-        .show(egui_context.get_mut(), |ui| {
+    let title = pretty_type_name::<T>();
+    style.show(
+        egui_context.get_mut(),
+        &title,
+        egui::Window::new(&title).default_size((0., 0.)),
+        |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
                 bevy_inspector::ui_for_resource::<T>(world, ui);
 
                 ui.allocate_space(ui.available_size());
             });
+        },
+    );
+}
+
+/// Plugin displaying an egui window for several resources, each in a collapsible section.
+/// Remember to insert the resources and call [`App::register_type`](bevy_app::App::register_type).
+///
+/// The resources are shown in the order they were added using [`ResourceGroupInspectorPlugin::with`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::ResourceGroupInspectorPlugin;
+///
+/// #[derive(Reflect, Resource, Default)]
+/// #[reflect(Resource)]
+/// struct Configuration {
+///     name: String,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .init_resource::<Configuration>()
+///         .register_type::<Configuration>()
+///         .add_plugins(
+///             ResourceGroupInspectorPlugin::new()
+///                 .with_title("Settings")
+///                 .with::<Configuration>()
+///                 .with::<ClearColor>(),
+///         )
+///         .run();
+/// }
+/// ```
+pub struct ResourceGroupInspectorPlugin {
+    settings: QuickPluginSettings,
+    title: String,
+    resources: Vec<(String, fn(&mut World, &mut egui::Ui))>,
+}
+
+impl Default for ResourceGroupInspectorPlugin {
+    fn default() -> Self {
+        Self {
+            settings: QuickPluginSettings::default(),
+            title: "Resources".to_owned(),
+            resources: Vec::new(),
+        }
+    }
+}
+
+impl ResourceGroupInspectorPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also display the resource `R`, below the ones added before
+    pub fn with<R: Resource + Reflect>(mut self) -> Self {
+        self.resources
+            .push((pretty_type_name::<R>(), resource_section_ui::<R>));
+        self
+    }
+
+    /// The title of the window, `Resources` by default
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+}
+
+fn resource_section_ui<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    bevy_inspector::ui_for_resource::<R>(world, ui);
+}
+
+quick_plugin_settings!(ResourceGroupInspectorPlugin);
+
+impl Plugin for ResourceGroupInspectorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "ResourceGroupInspectorPlugin");
+
+        add_required_plugins(app);
+
+        let title = self.title.clone();
+        let resources = self.resources.clone();
+        let system = self.settings.ui_system(move |world, window, style| {
+            resource_group_ui(world, window, style, &title, &resources);
         });
+        app.add_systems(Inspect, system);
+    }
+}
+
+fn resource_group_ui(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+    title: &str,
+    resources: &[(String, fn(&mut World, &mut egui::Ui))],
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    style.show(
+        egui_context.get_mut(),
+        title,
+        egui::Window::new(title).default_size(DEFAULT_SIZE),
+        |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                for (name, resource_ui) in resources {
+                    egui::CollapsingHeader::new(name)
+                        .default_open(true)
+                        .show(ui, |ui| resource_ui(world, ui));
+                }
+
+                ui.allocate_space(ui.available_size());
+            });
+        },
+    );
 }
 
 /// Plugin displaying an egui window for an app state.
@@ -250,14 +614,14 @@ fn inspector_ui<T: Resource + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct StateInspectorPlugin<T> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> T>,
 }
 
 impl<T> Default for StateInspectorPlugin<T> {
     fn default() -> Self {
         StateInspectorPlugin {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -266,62 +630,51 @@ impl<T> StateInspectorPlugin<T> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_settings!(StateInspectorPlugin<T>);
+
 impl<T: FreelyMutableState + Reflect> Plugin for StateInspectorPlugin<T> {
     fn build(&self, app: &mut bevy_app::App) {
         check_default_plugins(app, "StateInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-        if !app.is_plugin_added::<InspectSchedulePlugin>() {
-            app.add_plugins(InspectSchedulePlugin);
-        }
+        add_required_plugins(app);
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = state_ui::<T>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Inspect, system);
+        app.add_systems(Inspect, self.settings.ui_system(state_ui::<T>));
     }
 }
 
-fn state_ui<T: FreelyMutableState + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
-
-    let Ok(egui_context) = egui_context else {
+fn state_ui<T: FreelyMutableState + Reflect>(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
         return;
     };
-    let mut egui_context = egui_context.clone();
 
-    egui::Window::new(std::any::type_name::<T>())
-        .resizable(false)
-        .title_bar(false)
-        .show(egui_context.get_mut(), |ui| {
+    let title = pretty_type_name::<T>();
+    style.show(
+        egui_context.get_mut(),
+        &title,
+        egui::Window::new(std::any::type_name::<T>())
+            .resizable(false)
+            .title_bar(false),
+        |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
                 ui.heading(pretty_type_name::<T>());
                 bevy_inspector::ui_for_state::<T>(world, ui);
             });
-        });
+        },
+    );
 }
 
 /// Plugin displaying an egui window for all assets of type `A`.
 /// Remember to call [`App::register_asset_reflect`](bevy_asset::AssetApp::register_asset_reflect).
 ///
+/// The assets can be filtered by path and sorted, and new ones can be created if `A` registers `ReflectDefault`,
+/// see [`ui_for_assets_with_controls`](bevy_inspector::ui_for_assets_with_controls).
+///
 /// You can use [`AssetInspectorPlugin::run_if`] to control when the window is shown, for example
 /// in combination with `input_toggle_active`.
 ///
@@ -337,14 +690,14 @@ fn state_ui<T: FreelyMutableState + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct AssetInspectorPlugin<A> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> A>,
 }
 
 impl<A> Default for AssetInspectorPlugin<A> {
     fn default() -> Self {
         Self {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -353,57 +706,42 @@ impl<A> AssetInspectorPlugin<A> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_settings!(AssetInspectorPlugin<A>);
+
 impl<A: Asset + Reflect> Plugin for AssetInspectorPlugin<A> {
     fn build(&self, app: &mut bevy_app::App) {
         check_default_plugins(app, "AssetInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
-        }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
-        }
-        if !app.is_plugin_added::<InspectSchedulePlugin>() {
-            app.add_plugins(InspectSchedulePlugin);
-        }
+        add_required_plugins(app);
 
-        let condition = self.condition.lock().unwrap().take();
-        let mut system = asset_inspector_ui::<A>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Inspect, system);
+        app.add_systems(Inspect, self.settings.ui_system(asset_inspector_ui::<A>));
     }
 }
 
-fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
-
-    let Ok(egui_context) = egui_context else {
+fn asset_inspector_ui<A: Asset + Reflect>(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
         return;
     };
-    let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<A>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    let title = pretty_type_name::<A>();
+    style.show(
+        egui_context.get_mut(),
+        &title,
+        egui::Window::new(&title).default_size(DEFAULT_SIZE),
+        |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_assets::<A>(world, ui);
+                bevy_inspector::ui_for_assets_with_controls::<A>(world, ui);
 
                 ui.allocate_space(ui.available_size());
             });
-        });
+        },
+    );
 }
 
 /// Plugin displaying an egui window for all entities matching the filter `F`.
@@ -419,14 +757,14 @@ fn asset_inspector_ui<A: Asset + Reflect>(world: &mut World) {
 /// }
 /// ```
 pub struct FilterQueryInspectorPlugin<F> {
-    condition: Mutex<Option<BoxedCondition>>,
+    settings: QuickPluginSettings,
     marker: PhantomData<fn() -> F>,
 }
 
 impl<F> Default for FilterQueryInspectorPlugin<F> {
     fn default() -> Self {
         Self {
-            condition: Mutex::new(None),
+            settings: QuickPluginSettings::default(),
             marker: PhantomData,
         }
     }
@@ -435,15 +773,10 @@ impl<A> FilterQueryInspectorPlugin<A> {
     pub fn new() -> Self {
         Self::default()
     }
-
-    /// Only show the UI of the specified condition is active
-    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
-        let condition_system = IntoSystem::into_system(condition);
-        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
-        self
-    }
 }
 
+quick_plugin_settings!(FilterQueryInspectorPlugin<F>);
+
 impl<F: 'static> Plugin for FilterQueryInspectorPlugin<F>
 where
     F: QueryFilter,
@@ -451,47 +784,413 @@ where
     fn build(&self, app: &mut bevy_app::App) {
         check_default_plugins(app, "FilterQueryInspectorPlugin");
 
-        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
-            app.add_plugins(DefaultInspectorConfigPlugin);
+        add_required_plugins(app);
+        #[cfg(feature = "bevy_gizmos")]
+        if !app.is_plugin_added::<bevy_inspector::gizmos::InspectorGizmosPlugin>() {
+            app.add_plugins(bevy_inspector::gizmos::InspectorGizmosPlugin);
         }
-        if !app.is_plugin_added::<EguiPlugin>() {
-            app.add_plugins(EguiPlugin);
+
+        app.add_systems(Inspect, self.settings.ui_system(entity_query_ui::<F>));
+    }
+}
+
+fn entity_query_ui<F: QueryFilter>(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    let title = pretty_type_name::<F>();
+    style.show(
+        egui_context.get_mut(),
+        &title,
+        egui::Window::new(&title).default_size(DEFAULT_SIZE),
+        |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                bevy_inspector::ui_for_entities_filtered(world, ui, false, &Filter::<F>::all());
+                ui.allocate_space(ui.available_size());
+            });
+        },
+    );
+}
+
+/// Plugin displaying an egui window with the component `C` of all entities having it, below their names.
+/// Remember to call [`App::register_type`](bevy_app::App::register_type).
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::ComponentInspectorPlugin;
+///
+/// #[derive(Component, Reflect)]
+/// struct Stats {
+///     speed: f32,
+///     health: u32,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .register_type::<Stats>()
+///         .add_plugins(ComponentInspectorPlugin::<Stats>::default())
+///         .run();
+/// }
+/// ```
+pub struct ComponentInspectorPlugin<C> {
+    settings: QuickPluginSettings,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for ComponentInspectorPlugin<C> {
+    fn default() -> Self {
+        Self {
+            settings: QuickPluginSettings::default(),
+            marker: PhantomData,
         }
+    }
+}
+
+impl<C> ComponentInspectorPlugin<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+quick_plugin_settings!(ComponentInspectorPlugin<C>);
+
+impl<C: Component + Reflect> Plugin for ComponentInspectorPlugin<C> {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "ComponentInspectorPlugin");
+
+        add_required_plugins(app);
+
+        app.add_systems(
+            Inspect,
+            self.settings.ui_system(component_inspector_ui::<C>),
+        );
+    }
+}
+
+fn component_inspector_ui<C: Component + Reflect>(
+    world: &mut World,
+    window: &InspectorWindow,
+    style: InspectorStyle,
+) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    let title = pretty_type_name::<C>();
+    style.show(
+        egui_context.get_mut(),
+        &title,
+        egui::Window::new(&title).default_size(DEFAULT_SIZE),
+        |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                bevy_inspector::ui_for_components::<C>(world, ui);
+                ui.allocate_space(ui.available_size());
+            });
+        },
+    );
+}
+
+/// Plugin displaying an egui window listing common setup problems and how to fix them.
+///
+/// Unlike the other plugins, this doesn't add the `EguiPlugin` or [`DefaultInspectorConfigPlugin`] itself,
+/// so that it can tell you when they're missing. The problems are also logged once, in case nothing renders at all.
+/// See [`bevy_inspector::doctor`] for the checks that are performed.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{InspectorDoctorPlugin, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .add_plugins(InspectorDoctorPlugin::default())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct InspectorDoctorPlugin {
+    settings: QuickPluginSettings,
+}
+
+impl InspectorDoctorPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+quick_plugin_settings!(InspectorDoctorPlugin);
+
+impl Plugin for InspectorDoctorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "InspectorDoctorPlugin");
+
         if !app.is_plugin_added::<InspectSchedulePlugin>() {
             app.add_plugins(InspectSchedulePlugin);
         }
 
-        let condition: Option<Box<dyn ReadOnlySystem<In = (), Out = bool>>> =
-            self.condition.lock().unwrap().take();
-        let mut system = entity_query_ui::<F>.into_configs();
-        if let Some(condition) = condition {
-            system.run_if_dyn(condition);
-        }
-        app.add_systems(Inspect, system);
+        app.add_systems(
+            Inspect,
+            (
+                log_doctor_problems.run_if(run_once),
+                self.settings.ui_system(doctor_ui),
+            ),
+        );
     }
 }
 
-fn entity_query_ui<F: QueryFilter>(world: &mut World) {
-    let egui_context = world
-        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
-        .get_single(world);
+fn log_doctor_problems(world: &mut World) {
+    for problem in bevy_inspector::doctor::diagnose(world) {
+        bevy_log::warn!("{}\n  fix: {}", problem.description, problem.fix);
+    }
+}
 
-    let Ok(egui_context) = egui_context else {
+fn doctor_ui(world: &mut World, window: &InspectorWindow, style: InspectorStyle) {
+    let Some(mut egui_context) = window.egui_context(world) else {
         return;
     };
-    let mut egui_context = egui_context.clone();
 
-    egui::Window::new(pretty_type_name::<F>())
-        .default_size(DEFAULT_SIZE)
-        .show(egui_context.get_mut(), |ui| {
+    style.show(
+        egui_context.get_mut(),
+        "Inspector Doctor",
+        egui::Window::new("Inspector Doctor").default_size(DEFAULT_SIZE),
+        |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
-                bevy_inspector::ui_for_entities_filtered(world, ui, false, &Filter::<F>::all());
+                bevy_inspector::doctor::ui_for_doctor(world, ui);
                 ui.allocate_space(ui.available_size());
             });
-        });
+        },
+    );
+}
+
+/// Plugin displaying an egui window with the fields pinned to the [`WatchList`](bevy_inspector::watch::WatchList).
+///
+/// Fields can be pinned by right clicking their label in the entity inspector, e.g. of the [`WorldInspectorPlugin`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::{WatchWindowPlugin, WorldInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(WorldInspectorPlugin::new())
+///         .add_plugins(WatchWindowPlugin::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct WatchWindowPlugin {
+    settings: QuickPluginSettings,
+}
+
+impl WatchWindowPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+quick_plugin_settings!(WatchWindowPlugin);
+
+impl Plugin for WatchWindowPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "WatchWindowPlugin");
+
+        add_required_plugins(app);
+
+        app.init_resource::<bevy_inspector::watch::WatchList>();
+
+        app.add_systems(Inspect, self.settings.ui_system(watch_window_ui));
+    }
+}
+
+fn watch_window_ui(world: &mut World, window: &InspectorWindow, style: InspectorStyle) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    style.show(
+        egui_context.get_mut(),
+        "Watch",
+        egui::Window::new("Watch").default_size(DEFAULT_SIZE),
+        |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                bevy_inspector::watch::ui_for_watch_list(world, ui);
+                ui.allocate_space(ui.available_size());
+            });
+        },
+    );
+}
+
+/// Plugin displaying an egui window with the [`AssetEventLog`](bevy_inspector::asset_events::AssetEventLog):
+/// the created, modified and removed assets, with buttons to reload them from disk.
+///
+/// The events of images, meshes, shaders and standard materials are logged by default,
+/// other asset types can be added using [`AssetEventLogPlugin::with_asset`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::quick::AssetEventLogPlugin;
+///
+/// #[derive(Asset, TypePath)]
+/// struct Level;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins)
+///         .init_asset::<Level>()
+///         .add_plugins(AssetEventLogPlugin::new().with_asset::<Level>())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct AssetEventLogPlugin {
+    settings: QuickPluginSettings,
+    assets: Vec<fn(&mut App)>,
+}
+
+impl AssetEventLogPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also log the events of the asset type `A`
+    pub fn with_asset<A: Asset>(mut self) -> Self {
+        self.assets.push(log_asset_events::<A>);
+        self
+    }
+}
+
+/// Adds the [`log_asset_events`](bevy_inspector::asset_events::log_asset_events) system for `A`, if it is an initialized asset type
+fn log_asset_events<A: Asset>(app: &mut App) {
+    if app
+        .world()
+        .contains_resource::<Events<bevy_asset::AssetEvent<A>>>()
+    {
+        app.add_systems(Update, bevy_inspector::asset_events::log_asset_events::<A>);
+    }
+}
+
+quick_plugin_settings!(AssetEventLogPlugin);
+
+impl Plugin for AssetEventLogPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "AssetEventLogPlugin");
+
+        add_required_plugins(app);
+
+        app.init_resource::<bevy_inspector::asset_events::AssetEventLog>();
+
+        #[cfg(feature = "bevy_image")]
+        log_asset_events::<bevy_image::Image>(app);
+        #[cfg(feature = "bevy_render")]
+        {
+            log_asset_events::<bevy_render::mesh::Mesh>(app);
+            log_asset_events::<bevy_render::render_resource::Shader>(app);
+        }
+        #[cfg(feature = "bevy_pbr")]
+        log_asset_events::<bevy_pbr::StandardMaterial>(app);
+        for add in &self.assets {
+            add(app);
+        }
+
+        app.add_systems(Inspect, self.settings.ui_system(asset_event_log_ui));
+    }
+}
+
+fn asset_event_log_ui(world: &mut World, window: &InspectorWindow, style: InspectorStyle) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    style.show(
+        egui_context.get_mut(),
+        "Asset Events",
+        egui::Window::new("Asset Events").default_size(DEFAULT_SIZE),
+        |ui| {
+            bevy_inspector::asset_events::ui_for_asset_event_log(world, ui);
+        },
+    );
+}
+
+/// Plugin displaying an egui window with the [log console](bevy_inspector::log_console): the captured log events,
+/// filterable by level and text.
+///
+/// The events are captured by a tracing layer, which has to be installed by the `LogPlugin`:
+///
+/// ```no_run
+/// use bevy::{log::LogPlugin, prelude::*};
+/// use bevy_inspector_egui::{bevy_inspector::log_console, quick::LogInspectorPlugin};
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins(DefaultPlugins.set(LogPlugin {
+///             custom_layer: log_console::log_capture_layer,
+///             ..default()
+///         }))
+///         .add_plugins(LogInspectorPlugin::new())
+///         .run();
+/// }
+/// ```
+#[cfg(feature = "log_console")]
+#[derive(Default)]
+pub struct LogInspectorPlugin {
+    settings: QuickPluginSettings,
+}
+
+#[cfg(feature = "log_console")]
+impl LogInspectorPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "log_console")]
+quick_plugin_settings!(LogInspectorPlugin);
+
+#[cfg(feature = "log_console")]
+impl Plugin for LogInspectorPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        check_default_plugins(app, "LogInspectorPlugin");
+
+        add_required_plugins(app);
+
+        if !app
+            .world()
+            .contains_resource::<bevy_inspector::log_console::LogBuffer>()
+        {
+            bevy_log::warn!(
+                "`LogInspectorPlugin` can't capture log events, set `LogPlugin::custom_layer` to `log_console::log_capture_layer`"
+            );
+        }
+
+        app.add_systems(Inspect, self.settings.ui_system(log_console_ui));
+    }
 }
 
-fn check_default_plugins(app: &bevy_app::App, name: &str) {
+#[cfg(feature = "log_console")]
+fn log_console_ui(world: &mut World, window: &InspectorWindow, style: InspectorStyle) {
+    let Some(mut egui_context) = window.egui_context(world) else {
+        return;
+    };
+
+    style.show(
+        egui_context.get_mut(),
+        "Log",
+        egui::Window::new("Log").default_size(DEFAULT_SIZE),
+        |ui| {
+            bevy_inspector::log_console::ui_for_log_console(world, ui);
+        },
+    );
+}
+
+pub(crate) fn check_default_plugins(app: &bevy_app::App, name: &str) {
     if !app.is_plugin_added::<TypeRegistrationPlugin>() {
         panic!(
             r#"`{name}` should be added after the default plugins:
@@ -502,3 +1201,45 @@ fn check_default_plugins(app: &bevy_app::App, name: &str) {
         );
     }
 }
+
+#[cfg(all(test, not(feature = "bevy_render")))]
+mod tests {
+    use super::{InspectorWindow, WorldInspectorPlugin};
+    use bevy::{
+        app::App,
+        input::InputPlugin,
+        window::{ExitCondition, WindowPlugin},
+        MinimalPlugins,
+    };
+    use bevy_egui::EguiContext;
+
+    #[test]
+    fn headless_world_inspector() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..Default::default()
+            },
+        ));
+        let context = app.world_mut().spawn_empty().id();
+        app.add_plugins(WorldInspectorPlugin::new().with_window(InspectorWindow::Entity(context)));
+        app.world_mut()
+            .entity_mut(context)
+            .insert(EguiContext::default());
+        app.update();
+        app.update();
+
+        let ctx = app
+            .world_mut()
+            .get_mut::<EguiContext>(context)
+            .unwrap()
+            .get_mut()
+            .clone();
+        let layer = egui::LayerId::new(egui::Order::Middle, egui::Id::new("World Inspector"));
+        assert!(ctx.memory(|memory| memory.areas().visible_last_frame(&layer)));
+    }
+}
@@ -0,0 +1,123 @@
+//! Copying and pasting field values as RON from the context menu of field labels.
+//!
+//! Copied values go to the system clipboard and are also remembered in the egui memory,
+//! which is used for pasting when the system clipboard can't be read.
+//! Pasting only stores the text, which is applied the next time the field is displayed.
+
+use bevy_reflect::{PartialReflect, TypeRegistry};
+
+use crate::restricted_world_view::RestrictedWorldView;
+
+fn copied_id() -> egui::Id {
+    egui::Id::new("bevy_inspector_egui::copied_ron")
+}
+
+fn paste_id(field_id: egui::Id) -> egui::Id {
+    field_id.with("paste_ron")
+}
+
+/// "Copy as RON" and "Paste RON" buttons for the field displayed with `field_id`
+pub(crate) fn copy_paste_buttons(
+    ui: &mut egui::Ui,
+    value: &dyn PartialReflect,
+    field_id: egui::Id,
+    type_registry: &TypeRegistry,
+    world: Option<&mut RestrictedWorldView<'_>>,
+) {
+    if ui.button("Copy as RON").clicked() {
+        match crate::utils::ron::serialize(value, type_registry, true) {
+            Ok(text) => {
+                crate::utils::copy_text(ui.ctx(), text.clone());
+                ui.data_mut(|data| data.insert_temp(copied_id(), text));
+            }
+            Err(error) => bevy_log::warn!("Could not copy value as RON: {error}"),
+        }
+        ui.close_menu();
+    }
+    if ui.button("Paste RON").clicked() {
+        let text =
+            read_clipboard(world).or_else(|| ui.data(|data| data.get_temp::<String>(copied_id())));
+        if let Some(text) = text {
+            ui.data_mut(|data| data.insert_temp(paste_id(field_id), text));
+        }
+        ui.close_menu();
+    }
+}
+
+#[cfg(all(feature = "egui_clipboard", not(target_os = "android")))]
+fn read_clipboard(world: Option<&mut RestrictedWorldView<'_>>) -> Option<String> {
+    world?
+        .get_resource_mut::<bevy_egui::EguiClipboard>()
+        .ok()?
+        .get_contents()
+}
+
+#[cfg(not(all(feature = "egui_clipboard", not(target_os = "android"))))]
+fn read_clipboard(_world: Option<&mut RestrictedWorldView<'_>>) -> Option<String> {
+    None
+}
+
+/// Applies text pasted into the field displayed with `field_id`, if it is a valid RON value of the field's type
+pub(crate) fn apply_paste(
+    ui: &mut egui::Ui,
+    value: &mut dyn PartialReflect,
+    field_id: egui::Id,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let Some(text) = ui.data_mut(|data| data.remove_temp::<String>(paste_id(field_id))) else {
+        return false;
+    };
+    let Some(registration) = value
+        .get_represented_type_info()
+        .and_then(|info| type_registry.get(info.type_id()))
+    else {
+        return false;
+    };
+    let result = crate::utils::ron::deserialize(&text, registration, type_registry)
+        .and_then(|pasted| value.try_apply(pasted.as_ref()).map_err(|e| e.to_string()));
+    match result {
+        Ok(()) => true,
+        Err(error) => {
+            bevy_log::warn!(
+                "Could not paste into {}: {error}",
+                registration.type_info().type_path_table().short_path()
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{Reflect, TypeRegistry};
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    struct Color {
+        r: f32,
+        g: f32,
+    }
+
+    #[test]
+    fn pastes_only_matching_types() {
+        let mut type_registry = TypeRegistry::new();
+        type_registry.register::<Color>();
+        let ctx = egui::Context::default();
+        let id = egui::Id::new("field");
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut value = Color::default();
+                ui.data_mut(|data| {
+                    data.insert_temp(super::paste_id(id), "(r: 1.0, g: 0.5)".to_owned())
+                });
+                assert!(super::apply_paste(ui, &mut value, id, &type_registry));
+                assert_eq!(value, Color { r: 1.0, g: 0.5 });
+                assert!(!super::apply_paste(ui, &mut value, id, &type_registry));
+
+                ui.data_mut(|data| data.insert_temp(super::paste_id(id), "3.0".to_owned()));
+                assert!(!super::apply_paste(ui, &mut value, id, &type_registry));
+                assert_eq!(value, Color { r: 1.0, g: 0.5 });
+            });
+        });
+    }
+}
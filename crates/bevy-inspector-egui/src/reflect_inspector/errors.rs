@@ -29,6 +29,8 @@ pub fn no_default_value(ui: &mut egui::Ui, type_name: &str) {
         (FontId::monospace(12.0), type_name),
         (FontId::proportional(13.0), " has no "),
         (FontId::monospace(12.0), "ReflectDefault"),
+        (FontId::proportional(13.0), " or "),
+        (FontId::monospace(12.0), "ReflectFromReflect"),
         (
             FontId::proportional(13.0),
             " type data, so no value of it can be constructed.",
@@ -59,29 +59,53 @@
 //! }
 //! ```
 
+use crate::bevy_inspector::doctor::InspectorError;
+use crate::bevy_inspector::history::{self, RecordedField, ValueHistory};
+use crate::bevy_inspector::watch::{FieldOwner, FieldPath, WatchList, WatchedField};
+#[cfg(feature = "ron")]
+use crate::egui_utils::error_label;
 use crate::egui_utils::show_docs;
-use crate::inspector_egui_impls::{iter_all_eq, InspectorEguiImpl};
-use crate::inspector_options::{InspectorOptions, ReflectInspectorOptions, Target};
+use crate::inspector_egui_impls::{
+    curve, gradient, iter_all_eq, InspectorEguiImpl, InspectorOverrides,
+};
+use crate::inspector_options::std_options::{CurveOptions, GradientOptions, OptionOptions};
+use crate::inspector_options::{
+    FieldDisplay, InspectorOptions, ReflectInspectorOptions, Target, VariantDisplay,
+};
+use crate::localization::{builtin, localize, Label};
 use crate::restricted_world_view::RestrictedWorldView;
 use crate::{
-    egui_utils::{add_button, down_button, remove_button, up_button},
+    egui_utils::{
+        add_button, down_button, field_label, remove_button, reset_button, set_error_style,
+        up_button,
+    },
     utils::pretty_type_name_str,
 };
+use bevy_color::Color;
+use bevy_ecs::prelude::{AppTypeRegistry, Component, Entity, ReflectComponent, World};
+use bevy_ecs::reflect::ReflectFromWorld;
 use bevy_ecs::world::CommandQueue;
+use bevy_math::Vec2;
 use bevy_reflect::{std_traits::ReflectDefault, DynamicStruct};
 use bevy_reflect::{
-    Array, DynamicEnum, DynamicTuple, DynamicVariant, Enum, EnumInfo, List, ListInfo, Map, Reflect,
-    ReflectMut, ReflectRef, Struct, StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Access, NamedField, OpaqueInfo, PartialReflect, ReflectFromReflect, Set, SetInfo,
+};
+use bevy_reflect::{
+    Array, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicTuple, DynamicTupleStruct,
+    DynamicVariant, Enum, EnumInfo, List, ListInfo, Map, Reflect, ReflectMut, ReflectRef, Struct,
+    StructInfo, Tuple, TupleInfo, TupleStruct, TupleStructInfo, TypeInfo, TypeRegistry,
+    VariantInfo, VariantType,
 };
-use bevy_reflect::{OpaqueInfo, PartialReflect, Set, SetInfo};
 use egui::{Grid, WidgetText};
 use std::borrow::Cow;
+use std::fmt::Write;
 use std::{
     any::{Any, TypeId},
     borrow::Borrow,
 };
 
+#[cfg(feature = "ron")]
+mod clipboard;
 pub(crate) mod errors;
 
 pub trait ProjectorReflect: Fn(&mut dyn PartialReflect) -> &mut dyn PartialReflect {}
@@ -124,6 +148,72 @@ pub struct Context<'a> {
     pub queue: Option<&'a mut CommandQueue>,
 }
 
+/// Structural changes which are applied once the UI is done, so that they can't invalidate the world access of the UI.
+///
+/// Each method returns `false` if there is no [`Context::queue`] to defer the change to.
+impl Context<'_> {
+    /// Inserts the reflected `component` into `entity`, using its [`ReflectComponent`] registration in the [`AppTypeRegistry`]
+    pub fn defer_insert(&mut self, entity: Entity, component: Box<dyn PartialReflect>) -> bool {
+        self.defer(move |world| {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            let Some(registration) = component
+                .get_represented_type_info()
+                .and_then(|info| type_registry.get(info.type_id()))
+            else {
+                bevy_log::warn!(
+                    "Can't insert `{}`, it isn't registered in the type registry",
+                    component.reflect_type_path()
+                );
+                return;
+            };
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                bevy_log::warn!(
+                    "Can't insert `{}`, it doesn't `#[reflect(Component)]`",
+                    component.reflect_type_path()
+                );
+                return;
+            };
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                reflect_component.insert(&mut entity, component.as_ref(), &type_registry);
+            }
+        })
+    }
+
+    /// Removes the component `T` from `entity`
+    pub fn defer_remove<T: Component>(&mut self, entity: Entity) -> bool {
+        self.defer(move |world| {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                entity.remove::<T>();
+            }
+        })
+    }
+
+    /// Despawns `entity`, without its children
+    pub fn defer_despawn(&mut self, entity: Entity) -> bool {
+        self.defer(move |world| {
+            world.despawn(entity);
+        })
+    }
+
+    fn defer(&mut self, command: impl FnOnce(&mut World) + Send + 'static) -> bool {
+        match self.queue.as_deref_mut() {
+            Some(queue) => {
+                queue.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records `error` into the [`InspectorErrors`](crate::bevy_inspector::doctor::InspectorErrors), if there is a world
+    pub(crate) fn report_error(&mut self, error: InspectorError) {
+        if let Some(world) = self.world.as_mut() {
+            crate::bevy_inspector::doctor::report(world, error);
+        }
+    }
+}
+
 /// Function which will be executed for every field recursively, which can be used to skip regular traversal.
 ///
 /// This can be used to recognize `Handle<T>` types and display them as their actual value instead.
@@ -176,6 +266,12 @@ pub struct InspectorUi<'a, 'c> {
     /// Same as [`short_circuit`](InspectorUi::short_circuit), but for read only usage.
     pub short_circuit_readonly: ShortCircuitFnReadonly,
     pub short_circuit_many: ShortCircuitFnMany,
+
+    /// The currently displayed field, if fields can be pinned to the [watch window](crate::bevy_inspector::watch)
+    /// or recorded in the [value history](crate::bevy_inspector::history)
+    pub(crate) watch: Option<&'a mut FieldPath>,
+    /// UIs which take precedence over the [`InspectorEguiImpl`] of a type
+    pub(crate) overrides: Option<InspectorOverrides>,
 }
 
 impl<'a, 'c> InspectorUi<'a, 'c> {
@@ -192,6 +288,8 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
             short_circuit: short_circuit.unwrap_or(|_, _, _, _, _| None),
             short_circuit_readonly: short_circuit_readonly.unwrap_or(|_, _, _, _, _| None),
             short_circuit_many: short_circuit_many.unwrap_or(|_, _, _, _, _, _, _, _| None),
+            watch: None,
+            overrides: None,
         }
     }
 
@@ -237,9 +335,18 @@ impl InspectorUi<'_, '_> {
         }
 
         if let Some(reflected) = value.try_as_reflect_mut() {
+            let type_id = reflected.reflect_type_info().type_id();
+            let inspector_override = self
+                .overrides
+                .as_ref()
+                .and_then(|overrides| overrides.get(type_id))
+                .cloned();
+            if let Some(inspector_override) = inspector_override {
+                return inspector_override.execute(reflected.as_any_mut(), ui, id, self.reborrow());
+            }
             if let Some(s) = self
                 .type_registry
-                .get_type_data::<InspectorEguiImpl>(reflected.reflect_type_info().type_id())
+                .get_type_data::<InspectorEguiImpl>(type_id)
             {
                 if let Some(value) = value.try_as_reflect_mut() {
                     return s.execute(value.as_any_mut(), ui, options, id, self.reborrow());
@@ -289,6 +396,20 @@ impl InspectorUi<'_, '_> {
         }
 
         if let Some(value_reflect) = value.try_as_reflect() {
+            let inspector_override = self
+                .overrides
+                .as_ref()
+                .and_then(|overrides| overrides.get(value_reflect.type_id()))
+                .cloned();
+            if let Some(inspector_override) = inspector_override {
+                inspector_override.execute_readonly(
+                    value_reflect.as_any(),
+                    ui,
+                    id,
+                    self.reborrow(),
+                );
+                return;
+            }
             if let Some(s) = self
                 .type_registry
                 .get_type_data::<InspectorEguiImpl>(value_reflect.type_id())
@@ -343,6 +464,9 @@ impl InspectorUi<'_, '_> {
     ) -> bool {
         let Some(registration) = self.type_registry.get(type_id) else {
             errors::not_in_type_registry(ui, name);
+            self.context.report_error(InspectorError::NotRegistered {
+                type_name: name.to_owned(),
+            });
             return false;
         };
         let info = registration.type_info();
@@ -412,7 +536,10 @@ fn ui_for_empty_collection(ui: &mut egui::Ui, label: impl Into<WidgetText>) -> b
     let mut add = false;
     ui.vertical_centered(|ui| {
         ui.label(label);
-        if add_button(ui).on_hover_text("Add element").clicked() {
+        if add_button(ui)
+            .on_hover_text(builtin(ui, "Add element"))
+            .clicked()
+        {
             add = true;
         }
     });
@@ -427,21 +554,33 @@ fn ui_for_list_controls(ui: &mut egui::Ui, index: usize, len: usize) -> Option<L
     use ListOp::*;
     let mut op = None;
     ui.horizontal_top(|ui| {
-        if add_button(ui).on_hover_text("Add element").clicked() {
+        if add_button(ui)
+            .on_hover_text(builtin(ui, "Add element"))
+            .clicked()
+        {
             op = Some(AddElement(index));
         }
-        if remove_button(ui).on_hover_text("Remove element").clicked() {
+        if remove_button(ui)
+            .on_hover_text(builtin(ui, "Remove element"))
+            .clicked()
+        {
             op = Some(RemoveElement(index));
         }
         let up_enabled = index > 0;
         ui.add_enabled_ui(up_enabled, |ui| {
-            if up_button(ui).on_hover_text("Move element up").clicked() {
+            if up_button(ui)
+                .on_hover_text(builtin(ui, "Move element up"))
+                .clicked()
+            {
                 op = Some(MoveElementUp(index));
             }
         });
         let down_enabled = len.checked_sub(1).map(|l| index < l).unwrap_or(false);
         ui.add_enabled_ui(down_enabled, |ui| {
-            if down_button(ui).on_hover_text("Move element down").clicked() {
+            if down_button(ui)
+                .on_hover_text(builtin(ui, "Move element down"))
+                .clicked()
+            {
                 op = Some(MoveElementDown(index));
             }
         });
@@ -486,24 +625,74 @@ impl InspectorUi<'_, '_> {
             return false;
         };
 
+        // for resetting single fields
+        let default = self
+            .type_registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<ReflectDefault>())
+            .map(|reflect_default| reflect_default.default());
+
         let mut changed = false;
         Grid::new(id).show(ui, |ui| {
             for i in 0..value.field_len() {
                 let field_info = type_info.field_at(i).unwrap();
 
-                let _response = ui.label(field_info.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field_info.docs());
-
-                let field = value.field_at_mut(i).unwrap();
-                changed |= self.ui_for_reflect_with_options(
-                    field,
-                    ui,
+                let access = Access::Field(field_info.name().into());
+                let display = field_display(options, Target::Field(i));
+                let name = display
+                    .and_then(|display| display.label.as_deref())
+                    .unwrap_or(field_info.name());
+                let name = localize(
+                    ui.ctx(),
+                    Label::Field {
+                        type_path: type_info.type_path(),
+                        field: name,
+                    },
+                );
+                let (label, open) = field_header(ui, id.with(i), display, |ui| {
+                    field_label(ui, &name, value.field_at(i).unwrap(), id.with(i))
+                });
+                let response = self.field_menu(
+                    label,
+                    access.clone(),
+                    value.field_at(i).unwrap(),
                     id.with(i),
-                    inspector_options_struct_field(options, i),
                 );
+                show_docs(response, field_docs(Some(field_info), display));
+
+                if open {
+                    let field = value.field_at_mut(i).unwrap();
+                    changed |= self.with_path(access, |this| {
+                        this.ui_for_field_with_options(
+                            field,
+                            ui,
+                            id.with(i),
+                            options,
+                            Target::Field(i),
+                        )
+                    });
+                } else {
+                    ui.weak("…");
+                }
+
+                let default_field = match default.as_ref().map(|default| default.reflect_ref()) {
+                    Some(ReflectRef::Struct(default)) => default.field_at(i),
+                    _ => None,
+                };
+                if let Some(default_field) = default_field {
+                    let field = value.field_at_mut(i).unwrap();
+                    let is_default = field.reflect_partial_eq(default_field) == Some(true);
+                    let response = ui
+                        .add_enabled_ui(!is_default, reset_button)
+                        .inner
+                        .on_hover_text(builtin(ui, "Reset to default"));
+                    if response.clicked() && field.try_apply(default_field).is_ok() {
+                        changed = true;
+                    }
+                }
                 ui.end_row();
             }
+            self.ui_for_computed_rows(value.as_partial_reflect(), ui, id, options);
         });
         changed
     }
@@ -523,19 +712,34 @@ impl InspectorUi<'_, '_> {
             for i in 0..value.field_len() {
                 let field_info = type_info.field_at(i).unwrap();
 
-                let _response = ui.label(field_info.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field_info.docs());
-
-                let field = value.field_at(i).unwrap();
-                self.ui_for_reflect_readonly_with_options(
-                    field,
-                    ui,
-                    id.with(i),
-                    inspector_options_struct_field(options, i),
+                let display = field_display(options, Target::Field(i));
+                let name = display
+                    .and_then(|display| display.label.as_deref())
+                    .unwrap_or(field_info.name());
+                let name = localize(
+                    ui.ctx(),
+                    Label::Field {
+                        type_path: type_info.type_path(),
+                        field: name,
+                    },
                 );
+                let (response, open) = field_header(ui, id.with(i), display, |ui| ui.label(name));
+                show_docs(response, field_docs(Some(field_info), display));
+
+                if open {
+                    let field = value.field_at(i).unwrap();
+                    self.ui_for_reflect_readonly_with_options(
+                        field,
+                        ui,
+                        id.with(i),
+                        inspector_options_struct_field(options, i),
+                    );
+                } else {
+                    ui.weak("…");
+                }
                 ui.end_row();
             }
+            self.ui_for_computed_rows(value.as_partial_reflect(), ui, id, options);
         });
     }
 
@@ -551,9 +755,19 @@ impl InspectorUi<'_, '_> {
         let mut changed = false;
         Grid::new(id).show(ui, |ui| {
             for (i, field) in info.iter().enumerate() {
-                let _response = ui.label(field.name());
-                #[cfg(feature = "documentation")]
-                show_docs(_response, field.docs());
+                let display = field_display(options, Target::Field(i));
+                let name = display
+                    .and_then(|display| display.label.as_deref())
+                    .unwrap_or(field.name());
+                let name = localize(
+                    ui.ctx(),
+                    Label::Field {
+                        type_path: info.type_path(),
+                        field: name,
+                    },
+                );
+                let response = ui.label(name);
+                show_docs(response, field_docs(Some(field), display));
 
                 changed |= self.ui_for_reflect_many_with_options(
                     field.type_id(),
@@ -580,23 +794,38 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
-        maybe_grid(value.field_len(), ui, id, |ui, label| {
-            (0..value.field_len())
+        let has_computed = has_computed_rows(options);
+        maybe_grid_label_if(value.field_len(), ui, id, has_computed, |ui, label| {
+            let changed = (0..value.field_len())
                 .map(|i| {
                     if label {
-                        ui.label(i.to_string());
+                        let label =
+                            field_label(ui, &i.to_string(), value.field(i).unwrap(), id.with(i));
+                        self.field_menu(
+                            label,
+                            Access::TupleIndex(i),
+                            value.field(i).unwrap(),
+                            id.with(i),
+                        );
                     }
                     let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
-                        field,
-                        ui,
-                        id.with(i),
-                        inspector_options_struct_field(options, i),
-                    );
+                    let changed = self.with_path(Access::TupleIndex(i), |this| {
+                        this.ui_for_field_with_options(
+                            field,
+                            ui,
+                            id.with(i),
+                            options,
+                            Target::Field(i),
+                        )
+                    });
                     ui.end_row();
                     changed
                 })
-                .fold(false, or)
+                .fold(false, or);
+            if label {
+                self.ui_for_computed_rows(value.as_partial_reflect(), ui, id, options);
+            }
+            changed
         })
     }
 
@@ -607,7 +836,8 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
-        maybe_grid_readonly(value.field_len(), ui, id, |ui, label| {
+        let has_computed = has_computed_rows(options);
+        maybe_grid_readonly_label_if(value.field_len(), ui, id, has_computed, |ui, label| {
             for i in 0..value.field_len() {
                 if label {
                     ui.label(i.to_string());
@@ -621,6 +851,9 @@ impl InspectorUi<'_, '_> {
                 );
                 ui.end_row();
             }
+            if label {
+                self.ui_for_computed_rows(value.as_partial_reflect(), ui, id, options);
+            }
         })
     }
 
@@ -670,15 +903,23 @@ impl InspectorUi<'_, '_> {
             (0..value.field_len())
                 .map(|i| {
                     if label {
-                        ui.label(i.to_string());
+                        self.field_menu(
+                            ui.label(i.to_string()),
+                            Access::TupleIndex(i),
+                            value.field(i).unwrap(),
+                            id.with(i),
+                        );
                     }
                     let field = value.field_mut(i).unwrap();
-                    let changed = self.ui_for_reflect_with_options(
-                        field,
-                        ui,
-                        id.with(i),
-                        inspector_options_struct_field(options, i),
-                    );
+                    let changed = self.with_path(Access::TupleIndex(i), |this| {
+                        this.ui_for_field_with_options(
+                            field,
+                            ui,
+                            id.with(i),
+                            options,
+                            Target::Field(i),
+                        )
+                    });
                     ui.end_row();
                     changed
                 })
@@ -812,6 +1053,23 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) -> bool {
+        if let Some(curve_options) = options.downcast_ref::<CurveOptions>() {
+            if let Some(points) = list
+                .as_partial_reflect_mut()
+                .try_downcast_mut::<Vec<Vec2>>()
+            {
+                return curve::curve_ui(points, curve_options, ui, id);
+            }
+        }
+        if let Some(gradient_options) = options.downcast_ref::<GradientOptions>() {
+            if let Some(stops) = list
+                .as_partial_reflect_mut()
+                .try_downcast_mut::<Vec<(f32, Color)>>()
+            {
+                return gradient::gradient_ui(stops, gradient_options, ui, id, self.reborrow());
+            }
+        }
+
         use ListOp::*;
         let mut changed = false;
 
@@ -823,10 +1081,22 @@ impl InspectorUi<'_, '_> {
             }
             for i in 0..len {
                 egui::Grid::new((id, i)).show(ui, |ui| {
-                    ui.label(i.to_string());
+                    self.field_menu(
+                        ui.label(i.to_string()),
+                        Access::ListIndex(i),
+                        list.get(i).unwrap(),
+                        id.with(i),
+                    );
                     let val = list.get_mut(i).unwrap();
                     ui.horizontal_top(|ui| {
-                        changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+                        #[cfg(feature = "ron")]
+                        {
+                            changed |=
+                                clipboard::apply_paste(ui, val, id.with(i), self.type_registry);
+                        }
+                        changed |= self.with_path(Access::ListIndex(i), |this| {
+                            this.ui_for_reflect_with_options(val, ui, id.with(i), options)
+                        });
                     });
                     ui.end_row();
 
@@ -855,6 +1125,9 @@ impl InspectorUi<'_, '_> {
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
             if error {
                 errors::no_default_value(ui, info.type_path());
+                self.context.report_error(InspectorError::NoDefault {
+                    type_name: pretty_type_name_str(info.type_path()),
+                });
             }
             if ui.input(|input| input.pointer.any_down()) {
                 ui.data_mut(|data| data.insert_temp::<bool>(error_id, false));
@@ -871,6 +1144,20 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        if let Some(curve_options) = options.downcast_ref::<CurveOptions>() {
+            if let Some(points) = list.as_partial_reflect().try_downcast_ref::<Vec<Vec2>>() {
+                return curve::curve_ui_readonly(points, curve_options, ui, id);
+            }
+        }
+        if let Some(gradient_options) = options.downcast_ref::<GradientOptions>() {
+            if let Some(stops) = list
+                .as_partial_reflect()
+                .try_downcast_ref::<Vec<(f32, Color)>>()
+            {
+                return gradient::gradient_ui_readonly(stops, gradient_options, ui, id);
+            }
+        }
+
         ui.vertical(|ui| {
             let len = list.len();
             for i in 0..len {
@@ -958,6 +1245,9 @@ impl InspectorUi<'_, '_> {
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
             if error {
                 errors::no_default_value(ui, info.type_path());
+                self.context.report_error(InspectorError::NoDefault {
+                    type_name: pretty_type_name_str(info.type_path()),
+                });
             }
             if ui.input(|input| input.pointer.any_down()) {
                 ui.data_mut(|data| data.insert_temp::<bool>(error_id, false));
@@ -1000,7 +1290,10 @@ impl InspectorUi<'_, '_> {
                 if let Some((key, value)) = map.get_at_mut(i) {
                     self.ui_for_reflect_readonly_with_options(key, ui, id.with(i), &());
                     changed |= self.ui_for_reflect_with_options(value, ui, id.with(i), &());
-                    if remove_button(ui).on_hover_text("Remove element").clicked() {
+                    if remove_button(ui)
+                        .on_hover_text(builtin(ui, "Remove element"))
+                        .clicked()
+                    {
                         to_delete = Some(i);
                     }
                     ui.end_row();
@@ -1141,7 +1434,10 @@ impl InspectorUi<'_, '_> {
                         self.ui_for_reflect_readonly_with_options(val, ui, id.with(i), options);
                     });
                     ui.horizontal_top(|ui| {
-                        if remove_button(ui).on_hover_text("Remove element").clicked() {
+                        if remove_button(ui)
+                            .on_hover_text(builtin(ui, "Remove element"))
+                            .clicked()
+                        {
                             let copy = val.clone_value();
                             op = Some(RemoveElement(copy));
                         }
@@ -1176,6 +1472,9 @@ impl InspectorUi<'_, '_> {
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
             if error {
                 errors::no_default_value(ui, set_info.type_path());
+                self.context.report_error(InspectorError::NoDefault {
+                    type_name: pretty_type_name_str(set_info.type_path()),
+                });
             }
             if ui.input(|input| input.pointer.any_down()) {
                 ui.data_mut(|data| data.insert_temp::<bool>(error_id, false));
@@ -1343,7 +1642,10 @@ impl InspectorUi<'_, '_> {
                             );
                         });
                         ui.horizontal_top(|ui| {
-                            if remove_button(ui).on_hover_text("Remove element").clicked() {
+                            if remove_button(ui)
+                                .on_hover_text(builtin(ui, "Remove element"))
+                                .clicked()
+                            {
                                 let copy = value_to_check.clone_value();
                                 op = Some(RemoveElement(copy));
                             }
@@ -1368,6 +1670,9 @@ impl InspectorUi<'_, '_> {
             let error = ui.data_mut(|data| *data.get_temp_mut_or_default::<bool>(error_id));
             if error {
                 errors::no_default_value(ui, info.type_path());
+                self.context.report_error(InspectorError::NoDefault {
+                    type_name: pretty_type_name_str(info.type_path()),
+                });
             }
             if ui.input(|input| input.pointer.any_down()) {
                 ui.data_mut(|data| data.insert_temp::<bool>(error_id, false));
@@ -1400,7 +1705,9 @@ impl InspectorUi<'_, '_> {
             for i in 0..len {
                 let val = array.get_mut(i).unwrap();
                 ui.horizontal_top(|ui| {
-                    changed |= self.ui_for_reflect_with_options(val, ui, id.with(i), options);
+                    changed |= self.with_path(Access::ListIndex(i), |this| {
+                        this.ui_for_reflect_with_options(val, ui, id.with(i), options)
+                    });
                 });
 
                 if i != len - 1 {
@@ -1449,12 +1756,15 @@ impl InspectorUi<'_, '_> {
             TypeInfo::Enum(info) => info,
             _ => unreachable!("invalid reflect impl: type info mismatch"),
         };
+        if is_option(type_info) {
+            return self.ui_for_option(value, ui, id, options, type_info);
+        }
 
         let mut changed = false;
 
         ui.vertical(|ui| {
             let changed_variant =
-                self.ui_for_enum_variant_select(id, ui, value.variant_index(), type_info);
+                self.ui_for_enum_variant_select(id, ui, value.variant_index(), type_info, options);
             if let Some((_new_variant, dynamic_enum)) = changed_variant {
                 changed = true;
                 value.apply(&dynamic_enum);
@@ -1466,32 +1776,53 @@ impl InspectorUi<'_, '_> {
                 maybe_grid_label_if(value.field_len(), ui, id, always_show_label, |ui, label| {
                     (0..value.field_len())
                         .map(|i| {
+                            let access = match value.name_at(i) {
+                                Some(name) => Access::Field(name.to_owned().into()),
+                                None => Access::TupleIndex(i),
+                            };
                             if label {
-                                #[cfg(feature = "documentation")]
-                                let field_docs = type_info.variant_at(variant_index).and_then(
+                                let field_info = type_info.variant_at(variant_index).and_then(
                                     |info| match info {
-                                        VariantInfo::Struct(info) => info.field_at(i)?.docs(),
+                                        VariantInfo::Struct(info) => info.field_at(i),
                                         _ => None,
                                     },
                                 );
+                                let display = field_display(
+                                    options,
+                                    Target::VariantField {
+                                        variant_index,
+                                        field_index: i,
+                                    },
+                                );
 
-                                let _response = if let Some(name) = value.name_at(i) {
+                                let response = if let Some(name) = value.name_at(i) {
                                     ui.label(name)
                                 } else {
                                     ui.label(i.to_string())
                                 };
-                                #[cfg(feature = "documentation")]
-                                show_docs(_response, field_docs);
+                                let response = self.field_menu(
+                                    response,
+                                    access.clone(),
+                                    value.field_at(i).expect("invalid reflect impl: field len"),
+                                    id.with(i),
+                                );
+                                show_docs(response, field_docs(field_info, display));
                             }
                             let field_value = value
                                 .field_at_mut(i)
                                 .expect("invalid reflect impl: field len");
-                            let changed = self.ui_for_reflect_with_options(
-                                field_value,
-                                ui,
-                                id.with(i),
-                                inspector_options_enum_variant_field(options, variant_index, i),
-                            );
+                            let changed = self.with_path(access, |this| {
+                                this.ui_for_field_with_options(
+                                    field_value,
+                                    ui,
+                                    id.with(i),
+                                    options,
+                                    Target::VariantField {
+                                        variant_index,
+                                        field_index: i,
+                                    },
+                                )
+                            });
                             ui.end_row();
                             changed
                         })
@@ -1527,7 +1858,8 @@ impl InspectorUi<'_, '_> {
             let mut variant = info.variant_at(variant_index).unwrap();
 
             ui.vertical(|ui| {
-                let variant_changed = self.ui_for_enum_variant_select(id, ui, variant_index, info);
+                let variant_changed =
+                    self.ui_for_enum_variant_select(id, ui, variant_index, info, options);
                 if let Some((new_variant_idx, dynamic_enum)) = variant_changed {
                     changed = true;
                     variant = info.variant_at(new_variant_idx).unwrap();
@@ -1625,23 +1957,33 @@ impl InspectorUi<'_, '_> {
         ui: &mut egui::Ui,
         active_variant_idx: usize,
         info: &bevy_reflect::EnumInfo,
+        options: &dyn Any,
     ) -> Option<(usize, DynamicEnum)> {
         let mut changed_variant = None;
 
         ui.horizontal_top(|ui| {
             egui::ComboBox::new(id.with("select"), "")
-                .selected_text(info.variant_names()[active_variant_idx])
+                .selected_text(variant_label(
+                    options,
+                    active_variant_idx,
+                    info.variant_names()[active_variant_idx],
+                ))
                 .show_ui(ui, |ui| {
                     for (i, variant) in info.iter().enumerate() {
                         let variant_name = variant.name();
                         let is_active_variant = i == active_variant_idx;
 
-                        let variant_is_constructable =
-                            variant_constructable(self.type_registry, variant);
+                        let skip = variant_display(options, i).is_some_and(|display| display.skip);
+                        if skip && !is_active_variant {
+                            continue;
+                        }
+                        let label = variant_label(options, i, variant_name);
+
+                        let variant_is_constructable = self.variant_constructable(variant);
 
                         ui.add_enabled_ui(variant_is_constructable.is_ok(), |ui| {
                             let mut variant_label_response =
-                                ui.selectable_label(is_active_variant, variant_name);
+                                ui.selectable_label(is_active_variant, label);
 
                             if let Err(fields) = variant_is_constructable {
                                 variant_label_response = variant_label_response
@@ -1682,6 +2024,117 @@ impl InspectorUi<'_, '_> {
         changed_variant
     }
 
+    /// `Option<T>` as a checkbox for whether it is `Some`, followed by the inner value.
+    ///
+    /// The value is remembered when unchecking the checkbox, and restored when checking it again,
+    /// unless [`OptionOptions::replace_with_default`] is set.
+    fn ui_for_option(
+        &mut self,
+        value: &mut dyn Enum,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        info: &EnumInfo,
+    ) -> bool {
+        let some_variant = info.variant_at(1).unwrap();
+        let option_options = options
+            .downcast_ref::<InspectorOptions>()
+            .and_then(|options| options.get(Target::Container))
+            .and_then(|options| options.downcast_ref::<OptionOptions>())
+            .cloned()
+            .unwrap_or_default();
+        let stash_id = id.with("option_stash");
+
+        let mut changed = false;
+        ui.horizontal_top(|ui| {
+            let is_some = value.variant_index() == 1;
+            let stashed = (!option_options.replace_with_default)
+                .then(|| ui.data(|data| data.get_temp::<StashedValue>(stash_id)))
+                .flatten();
+            let constructable = match stashed {
+                Some(_) => Ok(()),
+                None => self.variant_constructable(some_variant),
+            };
+
+            let mut checked = is_some;
+            let response = ui
+                .add_enabled_ui(is_some || constructable.is_ok(), |ui| {
+                    ui.checkbox(&mut checked, "")
+                })
+                .inner;
+            if let Err(fields) = constructable {
+                response.on_disabled_hover_ui(|ui| {
+                    errors::unconstructable_variant(ui, info.type_path(), "Some", &fields);
+                });
+            }
+
+            if checked != is_some {
+                if checked {
+                    let dynamic_enum = match stashed {
+                        Some(StashedValue(inner)) => {
+                            let mut tuple = DynamicTuple::default();
+                            tuple.insert_boxed(inner);
+                            Ok(DynamicEnum::new("Some", DynamicVariant::Tuple(tuple)))
+                        }
+                        None => self.construct_default_variant(some_variant, ui),
+                    };
+                    if let Ok(dynamic_enum) = dynamic_enum {
+                        value.apply(&dynamic_enum);
+                        changed = true;
+                    }
+                } else {
+                    let inner = value.field_at(0).unwrap().clone_value();
+                    ui.data_mut(|data| data.insert_temp(stash_id, StashedValue(inner)));
+                    value.apply(&DynamicEnum::new("None", DynamicVariant::Unit));
+                    changed = true;
+                }
+            }
+
+            if value.variant_index() == 1 {
+                let field = value.field_at_mut(0).unwrap();
+                changed |= self.with_path(Access::TupleIndex(0), |this| {
+                    this.ui_for_field_with_options(
+                        field,
+                        ui,
+                        id.with(0),
+                        options,
+                        Target::VariantField {
+                            variant_index: 1,
+                            field_index: 0,
+                        },
+                    )
+                });
+            } else {
+                ui.weak("None");
+            }
+        });
+        changed
+    }
+
+    fn ui_for_option_readonly(
+        &mut self,
+        value: &dyn Enum,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) {
+        ui.horizontal_top(|ui| {
+            let mut checked = value.variant_index() == 1;
+            ui.add_enabled(false, egui::Checkbox::without_text(&mut checked));
+            match value.field_at(0) {
+                Some(field) => self.ui_for_reflect_readonly_with_options(
+                    field,
+                    ui,
+                    id.with(0),
+                    inspector_options_enum_variant_field(options, 1, 0),
+                ),
+                None => {
+                    ui.weak("None");
+                }
+            }
+        });
+    }
+
     fn ui_for_enum_readonly(
         &mut self,
         value: &dyn Enum,
@@ -1689,8 +2142,12 @@ impl InspectorUi<'_, '_> {
         id: egui::Id,
         options: &dyn Any,
     ) {
+        if value.get_represented_enum_info().is_some_and(is_option) {
+            return self.ui_for_option_readonly(value, ui, id, options);
+        }
         ui.vertical(|ui| {
-            let active_variant = value.variant_name();
+            let active_variant =
+                variant_label(options, value.variant_index(), value.variant_name());
             ui.add_enabled_ui(false, |ui| {
                 egui::ComboBox::new(id, "")
                     .selected_text(active_variant)
@@ -1734,7 +2191,15 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) -> bool {
+        #[cfg(feature = "ron")]
+        if let Some(changed) = self.ui_for_value_ron(value, ui, _id) {
+            return changed;
+        }
+
         errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        self.context.report_error(InspectorError::NoInspectorImpl {
+            type_name: pretty_type_name_str(value.reflect_short_type_path()),
+        });
         false
     }
 
@@ -1745,7 +2210,73 @@ impl InspectorUi<'_, '_> {
         _id: egui::Id,
         _options: &dyn Any,
     ) {
+        #[cfg(feature = "ron")]
+        if serde_registration(self.type_registry, value).is_some() {
+            match crate::utils::ron::serialize(value, self.type_registry, false) {
+                Ok(text) => ui.label(egui::RichText::new(text).monospace()),
+                Err(error) => error_label(ui, error),
+            };
+            return;
+        }
+
         errors::reflect_value_no_impl(ui, value.reflect_short_type_path());
+        self.context.report_error(InspectorError::NoInspectorImpl {
+            type_name: pretty_type_name_str(value.reflect_short_type_path()),
+        });
+    }
+
+    /// Fallback for opaque values without an [`InspectorEguiImpl`], which edits the value as RON.
+    /// Returns `None` if the value can't be (de)serialized.
+    #[cfg(feature = "ron")]
+    fn ui_for_value_ron(
+        &mut self,
+        value: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+    ) -> Option<bool> {
+        let registration = serde_registration(self.type_registry, value)?;
+        let draft_id = id.with("ron_draft");
+        let error_id = id.with("ron_error");
+
+        let draft = ui.data_mut(|data| data.get_temp::<String>(draft_id));
+        let mut text = match draft {
+            Some(draft) => draft,
+            None => match crate::utils::ron::serialize(value, self.type_registry, false) {
+                Ok(text) => text,
+                Err(error) => {
+                    error_label(ui, error);
+                    return Some(false);
+                }
+            },
+        };
+
+        let mut changed = false;
+        let response =
+            ui.add(egui::TextEdit::singleline(&mut text).font(egui::TextStyle::Monospace));
+        if response.changed() {
+            let result = crate::utils::ron::deserialize(&text, registration, self.type_registry)
+                .and_then(|new| value.try_apply(new.as_ref()).map_err(|e| e.to_string()));
+            changed = result.is_ok();
+            ui.data_mut(|data| match result {
+                Ok(()) => data.remove::<String>(error_id),
+                Err(error) => data.insert_temp(error_id, error),
+            });
+        }
+
+        if response.has_focus() {
+            ui.data_mut(|data| data.insert_temp(draft_id, text));
+        } else {
+            ui.data_mut(|data| {
+                data.remove::<String>(draft_id);
+                data.remove::<String>(error_id);
+            });
+        }
+
+        if let Some(error) = ui.data_mut(|data| data.get_temp::<String>(error_id)) {
+            error_label(ui, error);
+        }
+
+        Some(changed)
     }
 
     fn ui_for_value_many(
@@ -1756,6 +2287,9 @@ impl InspectorUi<'_, '_> {
         _options: &dyn Any,
     ) -> bool {
         errors::reflect_value_no_impl(ui, info.type_path());
+        self.context.report_error(InspectorError::NoInspectorImpl {
+            type_name: pretty_type_name_str(info.type_path()),
+        });
         false
     }
 }
@@ -1768,15 +2302,336 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
             short_circuit: self.short_circuit,
             short_circuit_readonly: self.short_circuit_readonly,
             short_circuit_many: self.short_circuit_many,
+            watch: self.watch.as_deref_mut(),
+            overrides: self.overrides.clone(),
+        }
+    }
+
+    /// Displays a field with `access` appended to the path of the watched field
+    fn with_path<T>(&mut self, access: Access<'_>, f: impl FnOnce(&mut Self) -> T) -> T {
+        let Some(watch) = self.watch.as_deref_mut() else {
+            return f(self);
+        };
+        let len = watch.path.len();
+        let _ = write!(watch.path, "{access}");
+        let result = f(self);
+        if let Some(watch) = self.watch.as_deref_mut() {
+            watch.path.truncate(len);
+        }
+        result
+    }
+
+    /// Adds a context menu to a field label to copy and paste the field as RON, pin the field at `access`
+    /// to the watch window, or to record its value history for fields of resources
+    fn field_menu(
+        &mut self,
+        response: egui::Response,
+        access: Access<'_>,
+        #[allow(unused_variables)] field: &dyn PartialReflect,
+        #[allow(unused_variables)] field_id: egui::Id,
+    ) -> egui::Response {
+        let type_registry = self.type_registry;
+        let Context { world, queue } = &mut *self.context;
+        let watch = self.watch.as_deref().zip(queue.as_deref_mut());
+        #[cfg(not(feature = "ron"))]
+        if watch.is_none() {
+            return response;
+        }
+        let watch =
+            watch.map(|(watch, queue)| (watch.owner, format!("{}{access}", watch.path), queue));
+        let show_plot = match &watch {
+            Some((FieldOwner::Resource(resource), path, _)) => world
+                .as_mut()
+                .and_then(|world| world.get_resource_mut::<ValueHistory>().ok())
+                .and_then(|history| history.get(*resource, path).map(|field| field.show_plot)),
+            _ => None,
+        };
+
+        response.context_menu(|ui| {
+            #[cfg(feature = "ron")]
+            {
+                clipboard::copy_paste_buttons(ui, field, field_id, type_registry, world.as_mut());
+                if watch.is_some() {
+                    ui.separator();
+                }
+            }
+            #[cfg(not(feature = "ron"))]
+            let _ = type_registry;
+            let Some((owner, path, queue)) = watch else {
+                return;
+            };
+            match (owner, show_plot) {
+                (FieldOwner::Component { entity, component }, _) => {
+                    if ui.button("Pin to watch window").clicked() {
+                        let field = WatchedField {
+                            entity,
+                            component,
+                            path,
+                        };
+                        queue.push(move |world: &mut bevy_ecs::world::World| {
+                            world.get_resource_or_init::<WatchList>().pin(field);
+                        });
+                        ui.close_menu();
+                    }
+                }
+                (FieldOwner::Resource(resource), None) => {
+                    if ui.button("Record history").clicked() {
+                        queue.push(move |world: &mut bevy_ecs::world::World| {
+                            world
+                                .get_resource_or_init::<ValueHistory>()
+                                .record_by_type_id(resource, path);
+                        });
+                        ui.close_menu();
+                    }
+                }
+                (FieldOwner::Resource(resource), Some(show_plot)) => {
+                    let toggle = if show_plot { "Hide plot" } else { "Show plot" };
+                    if ui.button(toggle).clicked() {
+                        let path = path.clone();
+                        queue.push(move |world: &mut bevy_ecs::world::World| {
+                            let mut history = world.get_resource_or_init::<ValueHistory>();
+                            if let Some(field) = history.get_mut(resource, &path) {
+                                field.show_plot = !show_plot;
+                            }
+                        });
+                        ui.close_menu();
+                    }
+                    if ui.button("Stop recording").clicked() {
+                        queue.push(move |world: &mut bevy_ecs::world::World| {
+                            world
+                                .get_resource_or_init::<ValueHistory>()
+                                .stop(resource, &path);
+                        });
+                        ui.close_menu();
+                    }
+                }
+            }
+        });
+        response
+    }
+
+    /// The recorded history of the currently displayed resource field, if its plot is shown
+    fn recorded_field(&mut self) -> Option<RecordedField> {
+        let watch = self.watch.as_deref()?;
+        let FieldOwner::Resource(resource) = watch.owner else {
+            return None;
+        };
+        let history = self
+            .context
+            .world
+            .as_mut()?
+            .get_resource_mut::<ValueHistory>()
+            .ok()?;
+        history
+            .get(resource, &watch.path)
+            .filter(|field| field.show_plot)
+            .cloned()
+    }
+
+    /// Display a field of a struct, tuple or enum with its options from the parent's [`InspectorOptions`].
+    ///
+    /// If the parent has a [`CustomWidget`](crate::inspector_options::CustomWidget) for the field, it is used instead of the default UI.
+    /// If it has a [`Validator`](crate::inspector_options::Validator), it is run when the field
+    /// is first displayed and after every edit, and a failed validation is displayed in red with the error as tooltip.
+    /// Corrections made by the validator are reported as a change.
+    ///
+    /// Recorded resource fields have a [sparkline](history::sparkline) of their history below them.
+    fn ui_for_field_with_options(
+        &mut self,
+        field: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        target: Target,
+    ) -> bool {
+        #[cfg(feature = "ron")]
+        let pasted = clipboard::apply_paste(ui, field, id, self.type_registry);
+        #[cfg(not(feature = "ron"))]
+        let pasted = false;
+        let Some(recorded) = self.recorded_field() else {
+            return self.ui_for_field_with_validator(field, ui, id, options, target) || pasted;
+        };
+        ui.vertical(|ui| {
+            let changed = self.ui_for_field_with_validator(field, ui, id, options, target);
+            history::sparkline(ui, &recorded);
+            changed || pasted
+        })
+        .inner
+    }
+
+    fn ui_for_field_with_validator(
+        &mut self,
+        field: &mut dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+        target: Target,
+    ) -> bool {
+        let options = options.downcast_ref::<InspectorOptions>();
+        let field_options = options
+            .and_then(|options| options.get(target))
+            .unwrap_or(&());
+        let widget = options.and_then(|options| options.widget(target));
+        let field_ui = |this: &mut Self, field: &mut dyn PartialReflect, ui: &mut egui::Ui| {
+            widget
+                .and_then(|widget| widget.ui(field, ui, field_options, id, this.reborrow()))
+                .unwrap_or_else(|| this.ui_for_reflect_with_options(field, ui, id, field_options))
+        };
+        let Some(validator) = options.and_then(|options| options.validator(target)) else {
+            return field_ui(self, field, ui);
+        };
+
+        let error_id = id.with("validation_error");
+        let previous_error = ui.data(|data| data.get_temp::<Option<String>>(error_id));
+        let response = ui.scope(|ui| {
+            if let Some(Some(_)) = previous_error {
+                set_error_style(ui);
+            }
+            field_ui(self, field, ui)
+        });
+        let mut changed = response.inner;
+
+        let error = match previous_error {
+            Some(error) if !changed => error,
+            _ => {
+                let before = field.clone_value();
+                let error = validator.validate(field).err();
+                // the validator may have corrected the value
+                changed |= !field.reflect_partial_eq(before.as_ref()).unwrap_or(true);
+                ui.data_mut(|data| data.insert_temp(error_id, error.clone()));
+                error
+            }
+        };
+        if let Some(error) = error {
+            response.response.on_hover_text(error);
+        }
+
+        changed
+    }
+
+    /// Grid rows for the [`ComputedRow`](crate::inspector_options::ComputedRow)s of `options`, computed from `value`
+    fn ui_for_computed_rows(
+        &mut self,
+        value: &dyn PartialReflect,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        options: &dyn Any,
+    ) {
+        let Some(options) = options.downcast_ref::<InspectorOptions>() else {
+            return;
+        };
+        for (i, row) in options.computed().iter().enumerate() {
+            let Some(computed) = row.compute(value) else {
+                continue;
+            };
+            ui.label(egui::RichText::new(row.name()).italics());
+            self.ui_for_reflect_readonly_with_options(
+                computed.as_ref(),
+                ui,
+                id.with(("computed", i)),
+                &(),
+            );
+            ui.end_row();
         }
     }
 
+    /// Constructs a value of the given type, using (in that order)
+    /// - [`ReflectDefault`]
+    /// - [`ReflectFromWorld`], if the context has exclusive access to the world
+    /// - [`ReflectFromReflect`] on a value whose fields are constructed recursively
     fn get_default_value_for(&mut self, type_id: TypeId) -> Option<Box<dyn Reflect>> {
-        if let Some(reflect_default) = self.type_registry.get_type_data::<ReflectDefault>(type_id) {
+        let registration = self.type_registry.get(type_id)?;
+
+        if let Some(reflect_default) = registration.data::<ReflectDefault>() {
             return Some(reflect_default.default());
         }
 
-        None
+        if let Some(reflect_from_world) = registration.data::<ReflectFromWorld>() {
+            if let Some(world) = self.context.world.as_mut().and_then(|w| w.get_world_mut()) {
+                return Some(reflect_from_world.from_world(world));
+            }
+        }
+
+        let reflect_from_reflect = registration.data::<ReflectFromReflect>()?;
+        let dynamic = self.construct_dynamic_value(registration.type_info())?;
+        reflect_from_reflect.from_reflect(dynamic.as_partial_reflect())
+    }
+
+    /// Whether [`get_default_value_for`](Self::get_default_value_for) can construct a value, without constructing it.
+    fn can_construct_default_value_for(&self, type_id: TypeId) -> bool {
+        let Some(registration) = self.type_registry.get(type_id) else {
+            return false;
+        };
+
+        if registration.data::<ReflectDefault>().is_some() {
+            return true;
+        }
+        if registration.data::<ReflectFromWorld>().is_some()
+            && self
+                .context
+                .world
+                .as_ref()
+                .is_some_and(|world| world.has_exclusive_access())
+        {
+            return true;
+        }
+        if registration.data::<ReflectFromReflect>().is_none() {
+            return false;
+        }
+
+        let constructable = |type_id: TypeId| self.can_construct_default_value_for(type_id);
+        match registration.type_info() {
+            TypeInfo::Struct(info) => info.iter().all(|field| constructable(field.type_id())),
+            TypeInfo::TupleStruct(info) => info.iter().all(|field| constructable(field.type_id())),
+            TypeInfo::Tuple(info) => info.iter().all(|field| constructable(field.type_id())),
+            TypeInfo::Enum(info) => info
+                .iter()
+                .any(|variant| self.variant_constructable(variant).is_ok()),
+            TypeInfo::List(_) | TypeInfo::Map(_) | TypeInfo::Set(_) => true,
+            TypeInfo::Array(_) | TypeInfo::Opaque(_) => false,
+        }
+    }
+
+    /// Constructs a dynamic value of the given type with recursively constructed fields,
+    /// to be turned into a concrete value using [`ReflectFromReflect`].
+    fn construct_dynamic_value(&mut self, info: &TypeInfo) -> Option<Box<dyn PartialReflect>> {
+        let value: Box<dyn PartialReflect> = match info {
+            TypeInfo::Struct(info) => {
+                let mut dynamic = DynamicStruct::default();
+                for field in info.iter() {
+                    let value = self.get_default_value_for(field.type_id())?;
+                    dynamic.insert_boxed(field.name(), value.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let mut dynamic = DynamicTupleStruct::default();
+                for field in info.iter() {
+                    let value = self.get_default_value_for(field.type_id())?;
+                    dynamic.insert_boxed(value.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            TypeInfo::Tuple(info) => {
+                let mut dynamic = DynamicTuple::default();
+                for field in info.iter() {
+                    let value = self.get_default_value_for(field.type_id())?;
+                    dynamic.insert_boxed(value.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            TypeInfo::Enum(info) => {
+                let variant = info
+                    .iter()
+                    .find(|variant| self.variant_constructable(variant).is_ok())?;
+                Box::new(self.default_variant(variant).ok()?)
+            }
+            TypeInfo::List(_) => Box::new(DynamicList::default()),
+            TypeInfo::Map(_) => Box::new(DynamicMap::default()),
+            TypeInfo::Set(_) => Box::new(DynamicSet::default()),
+            TypeInfo::Array(_) | TypeInfo::Opaque(_) => return None,
+        };
+        Some(value)
     }
 
     fn construct_default_variant(
@@ -1784,17 +2639,23 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         variant: &VariantInfo,
         ui: &mut egui::Ui,
     ) -> Result<DynamicEnum, ()> {
+        self.default_variant(variant).map_err(|field_type_path| {
+            errors::no_default_value(ui, field_type_path);
+            self.context.report_error(InspectorError::NoDefault {
+                type_name: pretty_type_name_str(field_type_path),
+            });
+        })
+    }
+
+    /// Constructs the variant with default fields, or returns the type path of the first field which can't be constructed
+    fn default_variant(&mut self, variant: &VariantInfo) -> Result<DynamicEnum, &'static str> {
         let dynamic_variant = match variant {
             VariantInfo::Struct(struct_info) => {
                 let mut dynamic_struct = DynamicStruct::default();
                 for field in struct_info.iter() {
-                    let field_default_value = match self.get_default_value_for(field.type_id()) {
-                        Some(value) => value,
-                        None => {
-                            errors::no_default_value(ui, field.type_path());
-                            return Err(());
-                        }
-                    };
+                    let field_default_value = self
+                        .get_default_value_for(field.type_id())
+                        .ok_or(field.type_path())?;
                     dynamic_struct.insert_boxed(field.name(), field_default_value.clone_value());
                 }
                 DynamicVariant::Struct(dynamic_struct)
@@ -1802,13 +2663,9 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
             VariantInfo::Tuple(tuple_info) => {
                 let mut dynamic_tuple = DynamicTuple::default();
                 for field in tuple_info.iter() {
-                    let field_default_value = match self.get_default_value_for(field.type_id()) {
-                        Some(value) => value,
-                        None => {
-                            errors::no_default_value(ui, field.type_path());
-                            return Err(());
-                        }
-                    };
+                    let field_default_value = self
+                        .get_default_value_for(field.type_id())
+                        .ok_or(field.type_path())?;
                     dynamic_tuple.insert_boxed(field_default_value.clone_value());
                 }
                 DynamicVariant::Tuple(dynamic_tuple)
@@ -1818,6 +2675,49 @@ impl<'a, 'c> InspectorUi<'a, 'c> {
         let dynamic_enum = DynamicEnum::new(variant.name(), dynamic_variant);
         Ok(dynamic_enum)
     }
+
+    fn variant_constructable<'v>(&self, variant: &'v VariantInfo) -> Result<(), Vec<&'v str>> {
+        let unconstructable_fields: Vec<&'v str> = match variant {
+            VariantInfo::Struct(variant) => variant
+                .iter()
+                .filter_map(|field| {
+                    (!self.can_construct_default_value_for(field.type_id()))
+                        .then_some(field.type_path())
+                })
+                .collect(),
+            VariantInfo::Tuple(variant) => variant
+                .iter()
+                .filter_map(|field| {
+                    (!self.can_construct_default_value_for(field.type_id()))
+                        .then_some(field.type_path())
+                })
+                .collect(),
+            VariantInfo::Unit(_) => return Ok(()),
+        };
+
+        if unconstructable_fields.is_empty() {
+            Ok(())
+        } else {
+            Err(unconstructable_fields)
+        }
+    }
+}
+
+/// The registration of the value's type, if it has [`ReflectSerialize`](bevy_reflect::ReflectSerialize) and [`ReflectDeserialize`](bevy_reflect::ReflectDeserialize) type data
+#[cfg(feature = "ron")]
+fn serde_registration<'a>(
+    type_registry: &'a TypeRegistry,
+    value: &dyn PartialReflect,
+) -> Option<&'a bevy_reflect::TypeRegistration> {
+    let type_id = value.get_represented_type_info()?.type_id();
+    let registration = type_registry.get(type_id)?;
+    (registration
+        .data::<bevy_reflect::ReflectSerialize>()
+        .is_some()
+        && registration
+            .data::<bevy_reflect::ReflectDeserialize>()
+            .is_some())
+    .then_some(registration)
 }
 
 #[must_use]
@@ -1878,39 +2778,84 @@ fn maybe_grid_readonly_label_if(
     }
 }
 
-fn variant_constructable<'a>(
-    type_registry: &TypeRegistry,
-    variant: &'a VariantInfo,
-) -> Result<(), Vec<&'a str>> {
-    let type_id_is_constructable = |type_id: TypeId| {
-        type_registry
-            .get_type_data::<ReflectDefault>(type_id)
-            .is_some()
-    };
+fn field_display(options: &dyn Any, target: Target) -> Option<&FieldDisplay> {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.display(target))
+}
 
-    let unconstructable_fields: Vec<&'a str> = match variant {
-        VariantInfo::Struct(variant) => variant
-            .iter()
-            .filter_map(|field| {
-                (!type_id_is_constructable(field.type_id())).then_some(field.type_path())
-            })
-            .collect(),
-        VariantInfo::Tuple(variant) => variant
-            .iter()
-            .filter_map(|field| {
-                (!type_id_is_constructable(field.type_id())).then_some(field.type_path())
-            })
-            .collect(),
-        VariantInfo::Unit(_) => return Ok(()),
+/// The doc comment of a field from the type registry if the `documentation` feature is enabled,
+/// or else the [`FieldDisplay::tooltip`] baked in by `#[inspector(docs)]`
+fn field_docs<'a>(
+    _field: Option<&'a NamedField>,
+    display: Option<&'a FieldDisplay>,
+) -> Option<&'a str> {
+    #[cfg(feature = "documentation")]
+    if let Some(docs) = _field.and_then(NamedField::docs) {
+        return Some(docs);
+    }
+    display?.tooltip.as_deref()
+}
+
+/// The label of a field, preceded by a toggle button if it is collapsible. Returns whether the field should be displayed.
+fn field_header(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    display: Option<&FieldDisplay>,
+    label: impl FnOnce(&mut egui::Ui) -> egui::Response,
+) -> (egui::Response, bool) {
+    let Some(display) = display.filter(|display| display.is_collapsible()) else {
+        return (label(ui), true);
     };
 
-    if unconstructable_fields.is_empty() {
-        Ok(())
-    } else {
-        Err(unconstructable_fields)
+    let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ui.ctx(),
+        id.with("collapse"),
+        display.default_open,
+    );
+    let response = ui
+        .horizontal(|ui| {
+            state.show_toggle_button(ui, egui::collapsing_header::paint_default_icon);
+            label(ui)
+        })
+        .inner;
+    let open = state.is_open();
+    state.store(ui.ctx());
+    (response, open)
+}
+
+fn variant_display(options: &dyn Any, variant_index: usize) -> Option<&VariantDisplay> {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .and_then(|options| options.variant_display(variant_index))
+}
+
+fn variant_label<'a>(options: &'a dyn Any, variant_index: usize, name: &'a str) -> &'a str {
+    variant_display(options, variant_index)
+        .and_then(|display| display.label.as_deref())
+        .unwrap_or(name)
+}
+
+fn is_option(info: &EnumInfo) -> bool {
+    info.type_path_table().module_path() == Some("core::option")
+        && info.type_path_table().ident() == Some("Option")
+}
+
+/// The value of an `Option<T>` before it was set to `None`
+struct StashedValue(Box<dyn PartialReflect>);
+
+impl Clone for StashedValue {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_value())
     }
 }
 
+fn has_computed_rows(options: &dyn Any) -> bool {
+    options
+        .downcast_ref::<InspectorOptions>()
+        .is_some_and(|options| !options.computed().is_empty())
+}
+
 fn inspector_options_struct_field(options: &dyn Any, field: usize) -> &dyn Any {
     options
         .downcast_ref::<InspectorOptions>()
@@ -1937,3 +2882,80 @@ fn inspector_options_enum_variant_field<'a>(
 fn or(a: bool, b: bool) -> bool {
     a || b
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Context;
+    use bevy_ecs::{prelude::*, world::CommandQueue};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[test]
+    fn deferred_structural_changes() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Health(1)).id();
+
+        let mut queue = CommandQueue::default();
+        let mut cx = Context {
+            world: None,
+            queue: Some(&mut queue),
+        };
+        assert!(cx.defer_insert(a, Box::new(Health(10))));
+        assert!(cx.defer_remove::<Health>(b));
+        assert!(cx.defer_despawn(b));
+        assert!(world.get::<Health>(a).is_none());
+
+        queue.apply(&mut world);
+        assert_eq!(world.get::<Health>(a), Some(&Health(10)));
+        assert!(world.get_entity(b).is_err());
+
+        assert!(!Context::default().defer_despawn(a));
+    }
+
+    #[derive(Reflect, Debug)]
+    struct Lanes {
+        count: u32,
+    }
+
+    #[test]
+    fn validator_corrections_are_changes() {
+        use crate::inspector_options::{
+            register_inspector_options, InspectorOptions, Target, Validator,
+        };
+
+        let mut type_registry = bevy_reflect::TypeRegistry::new();
+        type_registry.register::<Lanes>();
+        let mut options = InspectorOptions::new();
+        options.insert_validator(
+            Target::Field(0),
+            Validator::new(|count: &mut u32| {
+                *count += *count % 2;
+                Ok(())
+            }),
+        );
+        register_inspector_options::<Lanes>(&mut type_registry, options);
+
+        let ctx = egui::Context::default();
+        let mut lanes = Lanes { count: 3 };
+        let mut changed = Vec::new();
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    changed.push(super::ui_for_value(&mut lanes, ui, &type_registry));
+                });
+            });
+        }
+        assert_eq!(lanes.count, 4);
+        assert!(changed[0]);
+        assert!(!changed.last().unwrap());
+    }
+}
@@ -0,0 +1,552 @@
+//! Inspecting a bevy app running in another process using the [Bevy Remote Protocol](https://docs.rs/bevy_remote).
+//!
+//! The game needs to add the `RemotePlugin` and `RemoteHttpPlugin` from `bevy_remote`.
+//! The inspector app then adds the [`RemoteWorldInspectorPlugin`], which periodically fetches the entities
+//! and the components of the selected entity as serialized reflect values, and sends edits back using `bevy/insert`.
+//!
+//! Components are displayed using the inspector app's [`AppTypeRegistry`], so their types need to be registered there as well.
+//! Components of unknown types are shown as JSON.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_inspector_egui::remote::RemoteWorldInspectorPlugin;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins(DefaultPlugins)
+//!         .add_plugins(RemoteWorldInspectorPlugin::new())
+//!         .run();
+//! }
+//! ```
+
+use std::sync::{
+    mpsc::{self, Receiver, Sender},
+    Mutex,
+};
+
+use bevy_app::{App, Plugin};
+use bevy_core::Name;
+use bevy_ecs::{prelude::*, schedule::BoxedCondition};
+use bevy_egui::{EguiContext, EguiPlugin};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    ReflectFromReflect, TypePath, TypeRegistry,
+};
+use bevy_window::PrimaryWindow;
+use serde::de::DeserializeSeed;
+use serde_json::{json, Value};
+
+use crate::{
+    quick::{check_default_plugins, Inspect, InspectSchedulePlugin},
+    reflect_inspector::{Context, InspectorUi},
+    DefaultInspectorConfigPlugin,
+};
+
+/// The address `bevy_remote`'s `RemoteHttpPlugin` listens on by default
+pub const DEFAULT_URL: &str = "http://127.0.0.1:15702";
+
+const DEFAULT_SIZE: (f32, f32) = (320., 160.);
+/// Seconds between fetching the remote world
+const REFRESH_INTERVAL: f64 = 0.25;
+
+/// Plugin displaying an egui window with the entities of a remote bevy app, see the [module docs](self).
+pub struct RemoteWorldInspectorPlugin {
+    url: String,
+    condition: Mutex<Option<BoxedCondition>>,
+}
+
+impl Default for RemoteWorldInspectorPlugin {
+    fn default() -> Self {
+        RemoteWorldInspectorPlugin {
+            url: DEFAULT_URL.to_owned(),
+            condition: Mutex::new(None),
+        }
+    }
+}
+
+impl RemoteWorldInspectorPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connect to `url` instead of the [`DEFAULT_URL`]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Only show the UI of the specified condition is active
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        let condition_system = IntoSystem::into_system(condition);
+        self.condition = Mutex::new(Some(Box::new(condition_system) as BoxedCondition));
+        self
+    }
+}
+
+impl Plugin for RemoteWorldInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        check_default_plugins(app, "RemoteWorldInspectorPlugin");
+
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        if !app.is_plugin_added::<InspectSchedulePlugin>() {
+            app.add_plugins(InspectSchedulePlugin);
+        }
+
+        app.insert_resource(RemoteWorld::connect(self.url.clone()));
+
+        let condition = self.condition.lock().unwrap().take();
+        let mut system = remote_world_inspector_ui.into_configs();
+        if let Some(condition) = condition {
+            system.run_if_dyn(condition);
+        }
+        app.add_systems(Inspect, system);
+    }
+}
+
+/// An entity of the remote world, with its [`Name`] if it has one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteEntity {
+    pub entity: Entity,
+    pub name: Option<String>,
+}
+
+impl RemoteEntity {
+    fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{name} ({})", self.entity),
+            None => format!("Entity ({})", self.entity),
+        }
+    }
+}
+
+enum Job {
+    Refresh { selected: Option<Entity> },
+    Insert { entity: Entity, components: Value },
+}
+
+enum JobResult {
+    Refreshed {
+        entities: Vec<RemoteEntity>,
+        components: Option<(Entity, Vec<(String, Value)>)>,
+    },
+    Inserted,
+    Error(String),
+}
+
+/// Connection to the remote world and the last state fetched from it.
+///
+/// Requests are made on a background thread, so that a slow or unreachable app doesn't block the UI.
+#[derive(Resource)]
+pub struct RemoteWorld {
+    /// Jobs with their sequence number
+    jobs: Sender<(u64, Job)>,
+    /// Results with the sequence number of their job
+    results: Mutex<Receiver<(u64, JobResult)>>,
+    pending: usize,
+    /// Sequence number of the last job sent
+    sequence: u64,
+    /// Sequence number of the last [`Job::Insert`], refreshes requested before it would undo the edit
+    last_insert: u64,
+    last_refresh: Option<f64>,
+
+    entities: Vec<RemoteEntity>,
+    selected: Option<Entity>,
+    /// Type paths and serialized values of the components of the selected entity
+    components: Vec<(String, Value)>,
+    error: Option<String>,
+}
+
+impl RemoteWorld {
+    /// Starts a background thread talking to the app at `url`
+    pub fn connect(url: String) -> Self {
+        let (jobs, job_receiver) = mpsc::channel();
+        let (result_sender, results) = mpsc::channel();
+        std::thread::spawn(move || {
+            for (sequence, job) in job_receiver {
+                let result = match job {
+                    Job::Refresh { selected } => refresh(&url, selected),
+                    Job::Insert { entity, components } => request(
+                        &url,
+                        "bevy/insert",
+                        json!({ "entity": entity.to_bits(), "components": components }),
+                    )
+                    .map(|_| JobResult::Inserted),
+                };
+                let result = result.unwrap_or_else(JobResult::Error);
+                if result_sender.send((sequence, result)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        RemoteWorld::from_channels(jobs, results)
+    }
+
+    fn from_channels(jobs: Sender<(u64, Job)>, results: Receiver<(u64, JobResult)>) -> Self {
+        RemoteWorld {
+            jobs,
+            results: Mutex::new(results),
+            pending: 0,
+            sequence: 0,
+            last_insert: 0,
+            last_refresh: None,
+            entities: Vec::new(),
+            selected: None,
+            components: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn entities(&self) -> &[RemoteEntity] {
+        &self.entities
+    }
+
+    pub fn selected(&self) -> Option<Entity> {
+        self.selected
+    }
+
+    /// The last error returned by the remote app, if the last request failed
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn send(&mut self, job: Job) {
+        let sequence = self.sequence + 1;
+        let is_insert = matches!(job, Job::Insert { .. });
+        if self.jobs.send((sequence, job)).is_ok() {
+            self.sequence = sequence;
+            self.pending += 1;
+            if is_insert {
+                self.last_insert = sequence;
+            }
+        }
+    }
+
+    fn receive(&mut self) {
+        let results: Vec<_> = self.results.get_mut().unwrap().try_iter().collect();
+        for (sequence, result) in results {
+            self.pending = self.pending.saturating_sub(1);
+            match result {
+                JobResult::Refreshed {
+                    entities,
+                    components,
+                } => {
+                    self.entities = entities;
+                    match components {
+                        // values fetched before the last edit was sent would overwrite it
+                        Some((entity, _))
+                            if sequence < self.last_insert && Some(entity) == self.selected => {}
+                        Some((entity, components)) if Some(entity) == self.selected => {
+                            self.components = components;
+                        }
+                        _ => self.components.clear(),
+                    }
+                    self.error = None;
+                }
+                JobResult::Inserted => {}
+                JobResult::Error(error) => self.error = Some(error),
+            }
+        }
+    }
+
+    /// Receives finished requests and starts a new refresh every [`REFRESH_INTERVAL`]
+    fn update(&mut self, time: f64) {
+        self.receive();
+
+        let due = self
+            .last_refresh
+            .is_none_or(|last_refresh| time - last_refresh >= REFRESH_INTERVAL);
+        if self.pending == 0 && due {
+            self.last_refresh = Some(time);
+            self.send(Job::Refresh {
+                selected: self.selected,
+            });
+        }
+    }
+}
+
+/// Makes a JSON-RPC request to the remote app
+fn request(url: &str, method: &str, params: Value) -> Result<Value, String> {
+    let body = json!({ "jsonrpc": "2.0", "id": 0, "method": method, "params": params });
+    let response: Value = match ureq::post(url).send_json(body) {
+        Ok(response) => response.into_json().map_err(|e| e.to_string())?,
+        Err(ureq::Error::Status(_, response)) => response.into_json().map_err(|e| e.to_string())?,
+        Err(e) => return Err(e.to_string()),
+    };
+
+    if let Some(error) = response.get("error") {
+        let message = error.get("message").and_then(Value::as_str);
+        return Err(format!("{method}: {}", message.unwrap_or("unknown error")));
+    }
+    response
+        .get("result")
+        .cloned()
+        .ok_or_else(|| format!("{method}: invalid response"))
+}
+
+fn parse_entity(value: &Value) -> Option<Entity> {
+    value
+        .as_u64()
+        .and_then(|bits| Entity::try_from_bits(bits).ok())
+}
+
+fn refresh(url: &str, selected: Option<Entity>) -> Result<JobResult, String> {
+    let name_path = Name::type_path();
+    let rows = request(
+        url,
+        "bevy/query",
+        json!({ "data": { "option": [name_path] } }),
+    )?;
+    let mut entities: Vec<RemoteEntity> = rows
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|row| {
+            let entity = parse_entity(row.get("entity")?)?;
+            let name = row
+                .get("components")
+                .and_then(|components| components.get(name_path))
+                .and_then(Value::as_str)
+                .map(ToOwned::to_owned);
+            Some(RemoteEntity { entity, name })
+        })
+        .collect();
+    entities.sort_by_key(|entity| entity.entity);
+
+    let components = match selected.filter(|selected| {
+        entities
+            .iter()
+            .any(|remote_entity| remote_entity.entity == *selected)
+    }) {
+        Some(entity) => {
+            let paths = request(url, "bevy/list", json!({ "entity": entity.to_bits() }))?;
+            let response = request(
+                url,
+                "bevy/get",
+                json!({ "entity": entity.to_bits(), "components": paths }),
+            )?;
+            let mut components: Vec<(String, Value)> = response
+                .get("components")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(path, value)| (path.clone(), value.clone()))
+                .collect();
+            components.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Some((entity, components))
+        }
+        None => None,
+    };
+
+    Ok(JobResult::Refreshed {
+        entities,
+        components,
+    })
+}
+
+fn remote_world_inspector_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+        .get_single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    let type_registry = world.resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let mut remote_world = world.resource_mut::<RemoteWorld>();
+
+    let ctx = egui_context.get_mut();
+    remote_world.update(ctx.input(|input| input.time));
+    if remote_world.pending > 0 {
+        // make sure the results are picked up even if nothing else happens
+        ctx.request_repaint();
+    }
+
+    egui::Window::new("Remote World Inspector")
+        .default_size(DEFAULT_SIZE)
+        .show(ctx, |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                ui_for_remote_world(&mut remote_world, ui, &type_registry);
+                ui.allocate_space(ui.available_size());
+            });
+        });
+}
+
+/// Display the entities of the remote world and the components of the selected one
+pub fn ui_for_remote_world(
+    remote_world: &mut RemoteWorld,
+    ui: &mut egui::Ui,
+    type_registry: &TypeRegistry,
+) {
+    if let Some(error) = &remote_world.error {
+        crate::egui_utils::error_label(ui, error);
+    }
+
+    egui::CollapsingHeader::new("Entities")
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt("remote_entities")
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    for remote_entity in &remote_world.entities {
+                        let selected = remote_world.selected == Some(remote_entity.entity);
+                        if ui
+                            .selectable_label(selected, remote_entity.label())
+                            .clicked()
+                        {
+                            remote_world.selected = match selected {
+                                true => None,
+                                false => Some(remote_entity.entity),
+                            };
+                            remote_world.components.clear();
+                            remote_world.last_refresh = None;
+                        }
+                    }
+                });
+        });
+
+    let Some(entity) = remote_world.selected else {
+        return;
+    };
+    ui.separator();
+
+    let mut changes = serde_json::Map::new();
+    for (type_path, value) in &mut remote_world.components {
+        let id = egui::Id::new((entity, type_path.as_str()));
+        let name = type_registry
+            .get_with_type_path(type_path)
+            .map_or(type_path.as_str(), |registration| {
+                registration.type_info().type_path_table().short_path()
+            });
+        egui::CollapsingHeader::new(name)
+            .id_salt(id)
+            .show(ui, |ui| {
+                if let Some(new_value) =
+                    ui_for_remote_component(type_path, value, ui, id, type_registry)
+                {
+                    *value = new_value.clone();
+                    changes.insert(type_path.clone(), new_value);
+                }
+            });
+    }
+
+    if !changes.is_empty() {
+        remote_world.send(Job::Insert {
+            entity,
+            components: Value::Object(changes),
+        });
+    }
+}
+
+/// Displays the serialized component, returning the new serialized value if it was changed
+fn ui_for_remote_component(
+    type_path: &str,
+    value: &Value,
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    type_registry: &TypeRegistry,
+) -> Option<Value> {
+    let Some(registration) = type_registry.get_with_type_path(type_path) else {
+        ui_for_json(value, ui);
+        return None;
+    };
+    let reflected =
+        match TypedReflectDeserializer::new(registration, type_registry).deserialize(value) {
+            Ok(reflected) => reflected,
+            Err(e) => {
+                crate::egui_utils::error_label(ui, e.to_string());
+                ui_for_json(value, ui);
+                return None;
+            }
+        };
+    // the inspector displays concrete types like `Vec3` better than their dynamic representation
+    let mut reflected = match registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(&*reflected))
+    {
+        Some(concrete) => concrete.into_partial_reflect(),
+        None => reflected,
+    };
+
+    let mut cx = Context::default();
+    let changed = InspectorUi::new_no_short_circuit(type_registry, &mut cx)
+        .ui_for_reflect_with_options(&mut *reflected, ui, id, &());
+    if !changed {
+        return None;
+    }
+
+    let serializer = TypedReflectSerializer::new(&*reflected, type_registry);
+    match serde_json::to_value(serializer) {
+        Ok(new_value) => Some(new_value),
+        Err(e) => {
+            crate::egui_utils::error_label(ui, e.to_string());
+            None
+        }
+    }
+}
+
+fn ui_for_json(value: &Value, ui: &mut egui::Ui) {
+    let text = serde_json::to_string_pretty(value).unwrap_or_default();
+    ui.label(egui::RichText::new(text).monospace());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use bevy_ecs::entity::Entity;
+    use serde_json::json;
+
+    use super::{Job, JobResult, RemoteWorld};
+
+    #[test]
+    fn refreshes_from_before_an_edit_are_ignored() {
+        let (jobs, job_receiver) = mpsc::channel();
+        let (result_sender, results) = mpsc::channel();
+        let mut remote_world = RemoteWorld::from_channels(jobs, results);
+        let entity = Entity::from_raw(1);
+        remote_world.selected = Some(entity);
+        let refreshed = |value| JobResult::Refreshed {
+            entities: Vec::new(),
+            components: Some((entity, vec![("Health".to_owned(), json!(value))])),
+        };
+
+        remote_world.send(Job::Refresh {
+            selected: Some(entity),
+        });
+        remote_world.components = vec![("Health".to_owned(), json!(5))];
+        remote_world.send(Job::Insert {
+            entity,
+            components: json!({ "Health": 5 }),
+        });
+        let sequences: Vec<u64> = job_receiver
+            .try_iter()
+            .map(|(sequence, _)| sequence)
+            .collect();
+
+        result_sender.send((sequences[0], refreshed(10))).unwrap();
+        result_sender
+            .send((sequences[1], JobResult::Inserted))
+            .unwrap();
+        remote_world.receive();
+        assert_eq!(remote_world.components[0].1, json!(5));
+        assert_eq!(remote_world.pending, 0);
+
+        remote_world.send(Job::Refresh {
+            selected: Some(entity),
+        });
+        let (sequence, _) = job_receiver.try_recv().unwrap();
+        result_sender.send((sequence, refreshed(6))).unwrap();
+        remote_world.receive();
+        assert_eq!(remote_world.components[0].1, json!(6));
+    }
+}
@@ -3,7 +3,8 @@
 use std::any::TypeId;
 
 use bevy_ecs::{
-    change_detection::MutUntyped, prelude::*, world::unsafe_world_cell::UnsafeWorldCell,
+    change_detection::MutUntyped, component::ComponentId, prelude::*,
+    world::unsafe_world_cell::UnsafeWorldCell,
 };
 use bevy_reflect::{Reflect, ReflectFromPtr, TypeRegistry};
 use smallvec::{smallvec, SmallVec};
@@ -48,6 +49,9 @@ pub struct RestrictedWorldView<'w> {
     world: UnsafeWorldCell<'w>,
     resources: Allowed<TypeId>,
     components: Allowed<EntityComponent>,
+    /// Only set for views created from a `&mut World`, and never for the views split off from them,
+    /// since the views share the world and may read its metadata regardless of their allow lists
+    exclusive: bool,
 }
 
 #[derive(Clone)]
@@ -96,26 +100,22 @@ impl<T: Clone + PartialEq> Allowed<T> {
             }
         }
     }
-    fn without_many(&self, values: impl Iterator<Item = T>) -> Allowed<T>
-    where
-        T: Copy,
-    {
+    fn without_many(&self, values: &[T]) -> Allowed<T> {
         match self {
             Allowed::AllowList(list) => {
-                let new = list.clone();
+                let mut new = list.clone();
                 for value in values {
-                    let position = list
+                    let position = new
                         .iter()
-                        .position(|item| *item == value)
+                        .position(|item| item == value)
                         .expect("called `without` without access");
-                    let mut new = list.clone();
                     new.swap_remove(position);
                 }
                 Allowed::AllowList(new)
             }
             Allowed::ForbidList(list) => {
                 let mut new = list.clone();
-                new.extend(values);
+                new.extend(values.iter().cloned());
                 Allowed::ForbidList(new)
             }
         }
@@ -137,6 +137,7 @@ impl<'w> RestrictedWorldView<'w> {
             world: world.as_unsafe_world_cell(),
             resources: Allowed::everything(),
             components: Allowed::everything(),
+            exclusive: true,
         }
     }
 
@@ -151,11 +152,13 @@ impl<'w> RestrictedWorldView<'w> {
             world,
             resources: Allowed::everything(),
             components: Allowed::nothing(),
+            exclusive: false,
         };
         let components = RestrictedWorldView {
             world,
             resources: Allowed::nothing(),
             components: Allowed::everything(),
+            exclusive: false,
         };
 
         (resources, components)
@@ -165,6 +168,20 @@ impl<'w> RestrictedWorldView<'w> {
         self.world
     }
 
+    /// Whether this view has access to everything, i.e. it was created from a `&mut World` and nothing has been split off from it
+    pub fn has_exclusive_access(&self) -> bool {
+        self.exclusive
+    }
+
+    /// Returns the underlying [`World`] if this view [has exclusive access](Self::has_exclusive_access) to it.
+    pub fn get_world_mut(&mut self) -> Option<&mut World> {
+        if !self.has_exclusive_access() {
+            return None;
+        }
+        // SAFETY: `exclusive` is only set by `new`, and split views borrow `self` mutably, so there are no other views of the world
+        Some(unsafe { self.world.world_mut() })
+    }
+
     /// Whether the resource with the given [`TypeId`] may be accessed from this world view
     pub fn allows_access_to_resource(&self, type_id: TypeId) -> bool {
         self.resources.allows_access_to(type_id)
@@ -186,11 +203,13 @@ impl<'w> RestrictedWorldView<'w> {
             world: self.world,
             resources: Allowed::allow_just(resource),
             components: Allowed::nothing(),
+            exclusive: false,
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.without(resource),
             components: self.components.clone(),
+            exclusive: false,
         };
 
         (split, rest)
@@ -210,6 +229,7 @@ impl<'w> RestrictedWorldView<'w> {
             world: self.world,
             resources: self.resources.without(type_id),
             components: self.components,
+            exclusive: false,
         };
 
         Some((resource, rest))
@@ -227,35 +247,46 @@ impl<'w> RestrictedWorldView<'w> {
             world: self.world,
             resources: Allowed::nothing(),
             components: Allowed::allow_just(component),
+            exclusive: false,
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.clone(),
             components: self.components.without(component),
+            exclusive: false,
         };
 
         (split, rest)
     }
 
-    /// Splits this view into one view that only has access the the component-entity pairs `components` (`.0`), and the rest (`.1`)
+    /// Splits this view into one view that only has access the the component-entity pairs `components` (`.0`), and the rest (`.1`).
+    ///
+    /// This allows editing several components of an entity at once, while keeping access to the rest of the world.
+    /// Panics if `components` contains duplicates or a component this view doesn't have access to.
     pub fn split_off_components(
         &mut self,
-        components: impl Iterator<Item = EntityComponent> + Copy,
+        components: &[EntityComponent],
     ) -> (RestrictedWorldView<'_>, RestrictedWorldView<'_>) {
-        for component in components {
+        for (i, &component) in components.iter().enumerate() {
             assert!(self.allows_access_to_component(component));
+            assert!(
+                !components[..i].contains(&component),
+                "duplicate component in `split_off_components`"
+            );
         }
 
-        // INVARIANTS: `self` had `component` access, so `split` has access if we remove it from `self`
+        // INVARIANTS: `self` had access to all `components`, so `split` has access if we remove them from `self`
         let split = RestrictedWorldView {
             world: self.world,
             resources: Allowed::nothing(),
-            components: Allowed::allow(components),
+            components: Allowed::allow(components.iter().copied()),
+            exclusive: false,
         };
         let rest = RestrictedWorldView {
             world: self.world,
             resources: self.resources.clone(),
             components: self.components.without_many(components),
+            exclusive: false,
         };
 
         (split, rest)
@@ -269,6 +300,29 @@ impl<'w> RestrictedWorldView<'w> {
         self.world().entities().contains(entity)
     }
 
+    /// Iterates the entities which have all of the `components`, e.g. to build an entity picker.
+    ///
+    /// This only reads which components entities have, so it doesn't require access to them.
+    /// Use [`RestrictedWorldView::get_entity_component_reflect_ref`] to read the values.
+    /// If one of the types was never used as a component, no entities match.
+    pub fn iter_entities_with(&self, components: &[TypeId]) -> impl Iterator<Item = Entity> + '_ {
+        let world = self.world();
+        let component_ids: Option<Vec<ComponentId>> = components
+            .iter()
+            .map(|&type_id| world.components().get_id(type_id))
+            .collect();
+
+        world
+            .archetypes()
+            .iter()
+            .filter(move |archetype| {
+                component_ids.as_ref().is_some_and(|component_ids| {
+                    component_ids.iter().all(|&id| archetype.contains(id))
+                })
+            })
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+    }
+
     /// Gets a mutable reference to the resource of the given type
     pub fn get_resource_mut<R: Resource>(&mut self) -> Result<Mut<'_, R>, Error> {
         // SAFETY: &mut self
@@ -376,6 +430,47 @@ impl<'w> RestrictedWorldView<'w> {
         Ok(value)
     }
 
+    /// Gets a shared reference in form of a [`&dyn Reflect`](bevy_reflect::Reflect) to a component at an entity.
+    ///
+    /// Returns an error if the type does not register [`Reflect`].
+    pub fn get_entity_component_reflect_ref(
+        &self,
+        entity: Entity,
+        component: TypeId,
+        type_registry: &TypeRegistry,
+    ) -> Result<&'_ dyn Reflect, Error> {
+        if !self.allows_access_to_component((entity, component)) {
+            return Err(Error::NoAccessToComponent((entity, component)));
+        }
+
+        // SAFETY: this only accesses the component ID and doesn't keep any references
+        let component_id = self
+            .world()
+            .components()
+            .get_id(component)
+            .ok_or(Error::NoComponentId(component))?;
+
+        // SAFETY: we have access to (entity, component), and mutable access requires `&mut self`
+        let value = unsafe {
+            self.world()
+                .get_entity(entity)
+                .ok_or(Error::ComponentDoesNotExist((entity, component)))?
+                .get_by_id(component_id)
+                .ok_or(Error::ComponentDoesNotExist((entity, component)))?
+        };
+
+        let registration = type_registry
+            .get(component)
+            .ok_or(Error::NoTypeRegistration(component))?;
+        let reflect_from_ptr = registration
+            .data::<ReflectFromPtr>()
+            .ok_or(Error::NoTypeData(component, "ReflectFromPtr"))?;
+        assert_eq!(reflect_from_ptr.type_id(), component);
+
+        // SAFETY: value is of type component, which was checked above
+        Ok(unsafe { reflect_from_ptr.as_reflect(value) })
+    }
+
     // SAFETY: must ensure distinct access
     pub(crate) unsafe fn get_entity_component_reflect_unchecked(
         &self,
@@ -514,6 +609,33 @@ mod tests {
         assert!(!b_remaining.allows_access_to_resource(TypeId::of::<B>()));
     }
 
+    #[test]
+    fn exclusive_world_access() {
+        let mut world = World::new();
+        let mut world = RestrictedWorldView::new(&mut world);
+        assert!(world.get_world_mut().is_some());
+
+        let (mut a_view, mut remaining) = world.split_off_resource(TypeId::of::<A>());
+        assert!(a_view.get_world_mut().is_none());
+        assert!(remaining.get_world_mut().is_none());
+    }
+
+    #[test]
+    fn no_exclusive_access_after_empty_split() {
+        let mut world = World::new();
+        let mut world = RestrictedWorldView::new(&mut world);
+
+        let (mut split, mut remaining) = world.split_off_components(&[]);
+        assert!(split.get_world_mut().is_none());
+        assert!(remaining.get_world_mut().is_none());
+        assert!(!remaining.has_exclusive_access());
+
+        let mut world = World::new();
+        let (resources, components) = RestrictedWorldView::resources_components(&mut world);
+        assert!(!resources.has_exclusive_access());
+        assert!(!components.has_exclusive_access());
+    }
+
     #[derive(Component, Reflect)]
     struct ComponentA(String);
 
@@ -539,4 +661,82 @@ mod tests {
         component.downcast_mut::<ComponentA>().unwrap().0.clear();
         resource.0.clear();
     }
+
+    #[derive(Component, Reflect)]
+    struct ComponentB(String);
+
+    #[test]
+    fn disjoint_multiple_component_access() {
+        let mut type_registry = TypeRegistry::empty();
+        type_registry.register::<ComponentA>();
+        type_registry.register::<ComponentB>();
+
+        let mut world = World::new();
+        world.insert_resource(A("a".to_string()));
+        let entity = world
+            .spawn((ComponentA("a".to_string()), ComponentB("b".to_string())))
+            .id();
+
+        let mut world = RestrictedWorldView::new(&mut world);
+        let (a, b) = (
+            (entity, TypeId::of::<ComponentA>()),
+            (entity, TypeId::of::<ComponentB>()),
+        );
+
+        let (mut components_view, mut rest) = world.split_off_components(&[a, b]);
+        assert!(!rest.allows_access_to_component(a));
+        assert!(!rest.allows_access_to_component(b));
+        assert!(!components_view.allows_access_to_resource(TypeId::of::<A>()));
+
+        let (mut a_view, mut b_view) = components_view.split_off_component(a);
+        let mut component_a = a_view
+            .get_entity_component_reflect(entity, a.1, &type_registry)
+            .unwrap();
+        let mut component_b = b_view
+            .get_entity_component_reflect(entity, b.1, &type_registry)
+            .unwrap();
+        let mut resource = rest.get_resource_mut::<A>().unwrap();
+
+        component_a.downcast_mut::<ComponentA>().unwrap().0.clear();
+        component_b.downcast_mut::<ComponentB>().unwrap().0.clear();
+        resource.0.clear();
+
+        let (_, remaining) = b_view.split_off_components(&[b]);
+        assert!(!remaining.allows_access_to_component(b));
+    }
+
+    #[test]
+    fn iterate_entities_with_components() {
+        let mut type_registry = TypeRegistry::empty();
+        type_registry.register::<ComponentA>();
+
+        let mut world = World::new();
+        let a = world.spawn(ComponentA("a".to_string())).id();
+        let ab = world
+            .spawn((ComponentA("ab".to_string()), ComponentB("ab".to_string())))
+            .id();
+        world.spawn(ComponentB("b".to_string()));
+
+        let mut world = RestrictedWorldView::new(&mut world);
+        let (a_type, b_type) = (TypeId::of::<ComponentA>(), TypeId::of::<ComponentB>());
+
+        let mut with_a: Vec<Entity> = world.iter_entities_with(&[a_type]).collect();
+        with_a.sort();
+        assert_eq!(with_a, vec![a, ab]);
+        let with_both: Vec<Entity> = world.iter_entities_with(&[a_type, b_type]).collect();
+        assert_eq!(with_both, vec![ab]);
+        assert_eq!(world.iter_entities_with(&[TypeId::of::<u8>()]).count(), 0);
+
+        let (_, rest) = world.split_off_component((a, a_type));
+        let names: Vec<String> = rest
+            .iter_entities_with(&[a_type])
+            .filter_map(|entity| {
+                let value = rest
+                    .get_entity_component_reflect_ref(entity, a_type, &type_registry)
+                    .ok()?;
+                Some(value.downcast_ref::<ComponentA>()?.0.clone())
+            })
+            .collect();
+        assert_eq!(names, vec!["ab".to_string()]);
+    }
 }
@@ -0,0 +1,215 @@
+//! Presets for the sizes, widgets and status colors of the inspector UI.
+//!
+//! Insert an [`InspectorTheme`] resource to apply it to every egui context. UIs built on a custom [`egui::Context`]
+//! can call [`InspectorTheme::apply`] directly.
+//!
+//! ```no_run
+//! use bevy_app::App;
+//! use bevy_inspector_egui::theme::InspectorTheme;
+//!
+//! // larger widgets and +/- steppers for the Steam Deck or mobile builds
+//! App::new().insert_resource(InspectorTheme::touch());
+//!
+//! // status colors which are distinguishable with color vision deficiencies, with shape cues in addition to color
+//! App::new().insert_resource(InspectorTheme::colorblind_safe());
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_egui::EguiContext;
+
+/// Sizes, widgets and colors used by the inspector, see the [module docs](self)
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InspectorTheme {
+    /// Minimum size of buttons, drag values and other interactive widgets. The height is also the height of collapsing headers.
+    pub interact_size: egui::Vec2,
+    /// Space between the text of a button and its border
+    pub button_padding: egui::Vec2,
+    /// Space between widgets
+    pub item_spacing: egui::Vec2,
+    /// Size of checkboxes and of the arrows of collapsing headers
+    pub icon_width: f32,
+    /// Width of sliders
+    pub slider_width: f32,
+    /// Display numbers with −/+ buttons around a slider (if they have bounds) instead of a drag value
+    pub number_steppers: bool,
+    /// Color of recently changed values and components, with the `highlight_changes` feature
+    pub highlight_color: egui::Color32,
+    /// Color of errors and invalid input, or `None` for the `error_fg_color` of the egui visuals
+    pub error_color: Option<egui::Color32>,
+    /// Color of warnings, or `None` for the `warn_fg_color` of the egui visuals
+    pub warning_color: Option<egui::Color32>,
+    /// Color of successful or added things, like created assets or info logs
+    pub success_color: egui::Color32,
+    /// Underline highlighted labels, draw thicker outlines and prefix errors with ⚠, so that they don't depend on color alone
+    pub shape_cues: bool,
+}
+
+impl Default for InspectorTheme {
+    fn default() -> Self {
+        InspectorTheme::desktop()
+    }
+}
+
+impl InspectorTheme {
+    /// The default sizes of egui, for mouse and keyboard
+    pub fn desktop() -> Self {
+        let spacing = egui::style::Spacing::default();
+        InspectorTheme {
+            interact_size: spacing.interact_size,
+            button_padding: spacing.button_padding,
+            item_spacing: spacing.item_spacing,
+            icon_width: spacing.icon_width,
+            slider_width: spacing.slider_width,
+            number_steppers: false,
+            highlight_color: egui::Color32::GOLD,
+            error_color: None,
+            warning_color: None,
+            success_color: egui::Color32::LIGHT_GREEN,
+            shape_cues: false,
+        }
+    }
+
+    /// Larger hit areas and +/- steppers instead of drag values, for touchscreens
+    pub fn touch() -> Self {
+        InspectorTheme {
+            interact_size: egui::vec2(56.0, 36.0),
+            button_padding: egui::vec2(10.0, 6.0),
+            item_spacing: egui::vec2(10.0, 8.0),
+            icon_width: 24.0,
+            slider_width: 180.0,
+            number_steppers: true,
+            ..InspectorTheme::desktop()
+        }
+    }
+
+    /// The [desktop](InspectorTheme::desktop) sizes with the Okabe-Ito palette for status colors and [shape cues](InspectorTheme::shape_cues)
+    pub fn colorblind_safe() -> Self {
+        InspectorTheme {
+            highlight_color: egui::Color32::from_rgb(86, 180, 233), // sky blue
+            error_color: Some(egui::Color32::from_rgb(213, 94, 0)), // vermillion
+            warning_color: Some(egui::Color32::from_rgb(230, 159, 0)), // orange
+            success_color: egui::Color32::from_rgb(0, 158, 115),    // bluish green
+            shape_cues: true,
+            ..InspectorTheme::desktop()
+        }
+    }
+
+    /// Use the [status colors](InspectorTheme::colorblind_safe) which don't depend on telling red and green apart
+    pub fn with_colorblind_safe_colors(self) -> Self {
+        let colors = InspectorTheme::colorblind_safe();
+        InspectorTheme {
+            highlight_color: colors.highlight_color,
+            error_color: colors.error_color,
+            warning_color: colors.warning_color,
+            success_color: colors.success_color,
+            shape_cues: colors.shape_cues,
+            ..self
+        }
+    }
+
+    fn id() -> egui::Id {
+        egui::Id::new("bevy_inspector_egui::theme")
+    }
+
+    /// Sets the sizes and colors of the style of `ctx` and makes the theme [current](InspectorTheme::current).
+    ///
+    /// Colors which are `None` leave the visuals of `ctx` untouched.
+    /// Does nothing if the theme is already applied, so this can be called every frame.
+    pub fn apply(&self, ctx: &egui::Context) {
+        if ctx
+            .data(|data| data.get_temp::<InspectorTheme>(Self::id()))
+            .as_ref()
+            == Some(self)
+        {
+            return;
+        }
+        // every style, so that the sizes survive switching between the dark and light theme
+        ctx.all_styles_mut(|style| {
+            let spacing = &mut style.spacing;
+            spacing.interact_size = self.interact_size;
+            spacing.button_padding = self.button_padding;
+            spacing.item_spacing = self.item_spacing;
+            spacing.icon_width = self.icon_width;
+            let default = egui::style::Spacing::default();
+            spacing.icon_width_inner =
+                self.icon_width * default.icon_width_inner / default.icon_width;
+            spacing.slider_width = self.slider_width;
+            if let Some(error_color) = self.error_color {
+                style.visuals.error_fg_color = error_color;
+            }
+            if let Some(warning_color) = self.warning_color {
+                style.visuals.warn_fg_color = warning_color;
+            }
+        });
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self.clone()));
+    }
+
+    /// The theme last [applied](InspectorTheme::apply) to `ctx`, or the [desktop](InspectorTheme::desktop) theme
+    pub fn current(ctx: &egui::Context) -> InspectorTheme {
+        ctx.data(|data| data.get_temp::<InspectorTheme>(Self::id()))
+            .unwrap_or_default()
+    }
+}
+
+/// Applies the [`InspectorTheme`] resource to every egui context
+pub(crate) fn apply_inspector_theme(
+    theme: Option<Res<InspectorTheme>>,
+    mut contexts: Query<&mut EguiContext>,
+) {
+    let Some(theme) = theme else {
+        return;
+    };
+    for mut context in &mut contexts {
+        theme.apply(context.get_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InspectorTheme;
+
+    #[test]
+    fn apply_sets_style_and_current_theme() {
+        let ctx = egui::Context::default();
+        assert_eq!(InspectorTheme::current(&ctx), InspectorTheme::desktop());
+
+        InspectorTheme::touch().apply(&ctx);
+        assert_eq!(InspectorTheme::current(&ctx), InspectorTheme::touch());
+        assert_eq!(
+            ctx.style().spacing.interact_size,
+            InspectorTheme::touch().interact_size
+        );
+        ctx.set_theme(egui::Theme::Light);
+        assert_eq!(
+            ctx.style().spacing.interact_size,
+            InspectorTheme::touch().interact_size
+        );
+        ctx.set_theme(egui::Theme::Dark);
+
+        InspectorTheme::colorblind_safe().apply(&ctx);
+        assert_eq!(
+            Some(ctx.style().visuals.error_fg_color),
+            InspectorTheme::colorblind_safe().error_color
+        );
+
+        InspectorTheme::desktop().apply(&ctx);
+        assert_eq!(
+            ctx.style().spacing.interact_size,
+            egui::style::Spacing::default().interact_size
+        );
+    }
+
+    #[test]
+    fn apply_keeps_custom_visuals() {
+        let ctx = egui::Context::default();
+        let error_color = egui::Color32::from_rgb(255, 0, 255);
+        ctx.style_mut(|style| style.visuals.error_fg_color = error_color);
+
+        InspectorTheme::desktop().apply(&ctx);
+        assert_eq!(ctx.style().visuals.error_fg_color, error_color);
+        assert_eq!(
+            ctx.style().visuals.warn_fg_color,
+            egui::Visuals::dark().warn_fg_color
+        );
+    }
+}
@@ -5,19 +5,126 @@ pub fn pretty_type_name_str(val: &str) -> String {
     format!("{:?}", disqualified::ShortName(val))
 }
 
+/// Copies `text` to the clipboard, which on the web also works without the `egui_clipboard` feature
+pub(crate) fn copy_text(ctx: &egui::Context, text: String) {
+    #[cfg(all(target_arch = "wasm32", not(feature = "egui_clipboard")))]
+    crate::web::write_clipboard(&text);
+    ctx.copy_text(text);
+}
+
+pub(crate) mod pretty_name_cache {
+    use std::{
+        any::TypeId,
+        sync::{Arc, LazyLock, Mutex, PoisonError},
+    };
+
+    use bevy_ecs::{
+        component::{ComponentId, ComponentInfo},
+        world::{unsafe_world_cell::UnsafeWorldCell, WorldId},
+    };
+    use bevy_utils::HashMap;
+
+    use super::pretty_type_name_str;
+
+    #[derive(PartialEq, Eq, Hash)]
+    enum NameKey {
+        Type(TypeId),
+        /// Dynamic components have no type id, and their ids are only unique within their world
+        Component(WorldId, ComponentId),
+    }
+
+    static PRETTY_NAMES: LazyLock<Mutex<HashMap<NameKey, Arc<str>>>> =
+        LazyLock::new(Default::default);
+
+    fn cached(key: NameKey, type_name: &str) -> Arc<str> {
+        let mut names = PRETTY_NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+        names
+            .entry(key)
+            .or_insert_with(|| pretty_type_name_str(type_name).into())
+            .clone()
+    }
+
+    /// [`pretty_type_name_str`] of the type `type_name` with the id `type_id`, which is only prettified once
+    pub fn pretty_type_name_cached(type_id: TypeId, type_name: &str) -> Arc<str> {
+        cached(NameKey::Type(type_id), type_name)
+    }
+
+    /// The prettified name of the component `info` of the world `world_id`
+    pub fn pretty_component_info_name(world_id: WorldId, info: &ComponentInfo) -> Arc<str> {
+        let key = match info.type_id() {
+            Some(type_id) => NameKey::Type(type_id),
+            None => NameKey::Component(world_id, info.id()),
+        };
+        cached(key, info.name())
+    }
+
+    /// The prettified name of the component `component_id`, if it exists
+    pub fn pretty_component_name(
+        world: UnsafeWorldCell<'_>,
+        component_id: ComponentId,
+    ) -> Option<Arc<str>> {
+        let info = world.components().get_info(component_id)?;
+        Some(pretty_component_info_name(world.id(), info))
+    }
+}
+
 pub mod guess_entity_name {
+    use std::{
+        any::TypeId,
+        sync::{LazyLock, Mutex, PoisonError},
+    };
+
     use bevy_core::Name;
-    use bevy_ecs::{archetype::Archetype, prelude::*, world::unsafe_world_cell::UnsafeWorldCell};
+    use bevy_ecs::{
+        archetype::{Archetype, ArchetypeId},
+        prelude::*,
+        world::{unsafe_world_cell::UnsafeWorldCell, WorldId},
+    };
+    use bevy_utils::HashMap;
 
     use crate::restricted_world_view::RestrictedWorldView;
 
-    /// Guesses an appropriate entity name like `Light (6)` or falls back to `Entity (8)`
+    /// Naming heuristics for entities without a [`Name`], which are consulted in the order they were added before the built-in ones.
+    ///
+    /// See [`InspectorAppExt::register_entity_name_rule`](crate::InspectorAppExt::register_entity_name_rule).
+    #[derive(Resource, Default)]
+    pub struct EntityNameRules {
+        rules: Vec<Box<dyn Fn(EntityRef<'_>) -> Option<String> + Send + Sync>>,
+    }
+
+    impl EntityNameRules {
+        /// Adds a rule returning the name of an entity, or `None` if it doesn't apply to it
+        pub fn add(
+            &mut self,
+            rule: impl Fn(EntityRef<'_>) -> Option<String> + Send + Sync + 'static,
+        ) {
+            self.rules.push(Box::new(rule));
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.rules.is_empty()
+        }
+
+        fn name(&self, entity_ref: EntityRef<'_>) -> Option<String> {
+            self.rules.iter().find_map(|rule| rule(entity_ref))
+        }
+    }
+
+    /// Guesses an appropriate entity name like `Light (6)` or falls back to `Entity (8)`.
+    ///
+    /// The [`Name`] of the entity takes precedence, followed by the [`EntityNameRules`].
     pub fn guess_entity_name(world: &World, entity: Entity) -> String {
         match world.get_entity(entity) {
             Ok(entity_ref) => {
                 if let Some(name) = entity_ref.get::<Name>() {
                     return format!("{} ({})", name.as_str(), entity);
                 }
+                if let Some(name) = world
+                    .get_resource::<EntityNameRules>()
+                    .and_then(|rules| rules.name(entity_ref))
+                {
+                    return format!("{name} ({entity})");
+                }
 
                 guess_entity_name_inner(
                     world.as_unsafe_world_cell_readonly(),
@@ -29,10 +136,14 @@ pub mod guess_entity_name {
         }
     }
 
+    /// Like [`guess_entity_name`], but the [`EntityNameRules`] are only used with exclusive access to the world
     pub(crate) fn guess_entity_name_restricted(
         world: &mut RestrictedWorldView<'_>,
         entity: Entity,
     ) -> String {
+        if let Some(world) = world.get_world_mut() {
+            return guess_entity_name(world, entity);
+        }
         match world.world().get_entity(entity) {
             Some(cell) => {
                 if world.allows_access_to_component((entity, std::any::TypeId::of::<Name>())) {
@@ -47,11 +158,31 @@ pub mod guess_entity_name {
         }
     }
 
+    /// The guessed name of each archetype. Archetypes are never removed, so their ids stay valid.
+    static ARCHETYPE_NAMES: LazyLock<Mutex<HashMap<(WorldId, ArchetypeId), Option<&'static str>>>> =
+        LazyLock::new(Default::default);
+
     fn guess_entity_name_inner(
         world: UnsafeWorldCell<'_>,
         entity: Entity,
         archetype: &Archetype,
     ) -> String {
+        let name = *ARCHETYPE_NAMES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((world.id(), archetype.id()))
+            .or_insert_with(|| guess_archetype_name(world, archetype));
+
+        match name {
+            Some(name) => format!("{name} ({entity})"),
+            None => format!("Entity ({entity})"),
+        }
+    }
+
+    fn guess_archetype_name(
+        world: UnsafeWorldCell<'_>,
+        archetype: &Archetype,
+    ) -> Option<&'static str> {
         #[rustfmt::skip]
         let associations = &[
             ("bevy_window::window::PrimaryWindow", "Primary Window"),
@@ -78,10 +209,126 @@ pub mod guess_entity_name {
                 .iter()
                 .find_map(|&(name, matches)| (component_type == name).then_some(matches))
             {
-                return format!("{name} ({entity})");
+                return Some(name);
             }
         }
 
-        format!("Entity ({entity})")
+        None
+    }
+
+    /// Icons displayed before the names of entities in the hierarchy, for entities having one of the registered components.
+    ///
+    /// See [`InspectorAppExt::register_entity_icon`](crate::InspectorAppExt::register_entity_icon).
+    #[derive(Resource, Default, Debug, Clone)]
+    pub struct EntityIcons {
+        icons: Vec<(TypeId, &'static str)>,
+    }
+
+    impl EntityIcons {
+        /// Displays `icon` before entities with the component `C`, taking precedence over the built-in icons and earlier registrations
+        pub fn insert<C: Component>(&mut self, icon: &'static str) {
+            let type_id = TypeId::of::<C>();
+            self.icons.retain(|&(other, _)| other != type_id);
+            self.icons.insert(0, (type_id, icon));
+        }
+    }
+
+    /// Guesses an icon like `📷` for cameras or `💡` for lights, using the [`EntityIcons`] resource before the built-in components
+    pub fn guess_entity_icon(world: &World, entity: Entity) -> Option<&'static str> {
+        let entity_ref = world.get_entity(entity).ok()?;
+        if let Some(icons) = world.get_resource::<EntityIcons>() {
+            if let Some(&(_, icon)) = icons
+                .icons
+                .iter()
+                .find(|&&(type_id, _)| entity_ref.contains_type_id(type_id))
+            {
+                return Some(icon);
+            }
+        }
+
+        let archetype = entity_ref.archetype();
+        *ARCHETYPE_ICONS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry((world.id(), archetype.id()))
+            .or_insert_with(|| {
+                guess_archetype_icon(world.as_unsafe_world_cell_readonly(), archetype)
+            })
+    }
+
+    /// The built-in icon of each archetype, like [`ARCHETYPE_NAMES`]
+    static ARCHETYPE_ICONS: LazyLock<Mutex<HashMap<(WorldId, ArchetypeId), Option<&'static str>>>> =
+        LazyLock::new(Default::default);
+
+    fn guess_archetype_icon(
+        world: UnsafeWorldCell<'_>,
+        archetype: &Archetype,
+    ) -> Option<&'static str> {
+        // in order of precedence, matching prefixes to include generic components
+        #[rustfmt::skip]
+        let associations = &[
+            ("bevy_window::window::Window", "🗔"),
+            ("bevy_render::camera::camera::Camera", "📷"),
+            ("bevy_pbr::light::point_light::PointLight", "💡"),
+            ("bevy_pbr::light::spot_light::SpotLight", "💡"),
+            ("bevy_pbr::light::directional_light::DirectionalLight", "💡"),
+            ("bevy_audio::audio::AudioPlayer", "🔊"),
+            ("bevy_audio::audio::SpatialListener", "🔊"),
+            ("bevy_render::mesh::components::Mesh3d", "🔷"),
+            ("bevy_render::mesh::components::Mesh2d", "🔷"),
+            ("bevy_sprite::sprite::Sprite", "🖼"),
+            ("bevy_ui::widget::text::Text", "🗛"),
+            ("bevy_text::text2d::Text2d", "🗛"),
+            ("bevy_ui::ui_node::Node", "⬜"),
+        ];
+
+        let type_names: Vec<&str> = archetype
+            .components()
+            .filter_map(|id| Some(world.components().get_info(id)?.name()))
+            .collect();
+
+        associations.iter().find_map(|&(prefix, icon)| {
+            type_names
+                .iter()
+                .any(|name| name.starts_with(prefix))
+                .then_some(icon)
+        })
+    }
+}
+
+#[cfg(feature = "ron")]
+pub mod ron {
+    use bevy_reflect::{
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        PartialReflect, TypeRegistration, TypeRegistry,
+    };
+    use serde::de::DeserializeSeed;
+
+    /// Serializes the value to RON, using multiple lines if `pretty` is set
+    pub fn serialize(
+        value: &dyn PartialReflect,
+        type_registry: &TypeRegistry,
+        pretty: bool,
+    ) -> Result<String, String> {
+        let serializer = TypedReflectSerializer::new(value, type_registry);
+        let result = match pretty {
+            true => ::ron::ser::to_string_pretty(&serializer, ::ron::ser::PrettyConfig::default()),
+            false => ::ron::to_string(&serializer),
+        };
+        result.map_err(|e| e.to_string())
+    }
+
+    /// Deserializes a value of the registered type from RON
+    pub fn deserialize(
+        text: &str,
+        registration: &TypeRegistration,
+        type_registry: &TypeRegistry,
+    ) -> Result<Box<dyn PartialReflect>, String> {
+        let mut deserializer = ::ron::Deserializer::from_str(text).map_err(|e| e.to_string())?;
+        let value = TypedReflectDeserializer::new(registration, type_registry)
+            .deserialize(&mut deserializer)
+            .map_err(|e| e.to_string())?;
+        deserializer.end().map_err(|e| e.to_string())?;
+        Ok(value)
     }
 }
@@ -0,0 +1,49 @@
+//! Fallbacks for the web, where the system clipboard and the file system can't be used directly.
+//!
+//! Copying writes to the browser clipboard even without the `egui_clipboard` feature,
+//! and exports which are written to files on native platforms are downloaded instead.
+//!
+//! ```no_run
+//! bevy_inspector_egui::web::download("session.ron", "()", "text/plain").unwrap();
+//! ```
+
+use wasm_bindgen::{JsCast, JsValue};
+
+fn js_error(error: JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
+
+/// Writes `text` to the clipboard of the browser.
+///
+/// The browser only allows this in response to user input, and completes it asynchronously.
+pub fn write_clipboard(text: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    // the promise is dropped, failures are reported in the console of the browser
+    let _ = window.navigator().clipboard().write_text(text);
+}
+
+/// Lets the browser download `contents` as a file named `file_name`
+pub fn download(file_name: &str, contents: &str, mime_type: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or("no window")?;
+    let document = window.document().ok_or("no document")?;
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(js_error)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+    let anchor: web_sys::HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "not an anchor element")?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url).map_err(js_error)
+}